@@ -0,0 +1,140 @@
+//! Fallbacks for assets that fail to load (e.g. a 404 on a partial web deploy).
+//!
+//! A failed image or sound is replaced in place with an embedded placeholder,
+//! so every handle pointing at it keeps working. Missing music stays silent
+//! rather than looping a placeholder, and missing card art is left to the
+//! card table, which falls back to the card atlas. A warning toast lists what is missing.
+
+use bevy::{
+    asset::{AssetLoadFailedEvent, RenderAssetUsages},
+    audio::AudioSource,
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+
+use crate::game::CARD_ART_DIR;
+
+/// Placeholder sound for missing sound effects.
+const PLACEHOLDER_SOUND: &[u8] = include_bytes!("../assets/audio/sound_effects/step1.ogg");
+/// Neutral grey so a missing image reads as blank rather than broken.
+const PLACEHOLDER_PIXEL: [u8; 4] = [128, 128, 128, 255];
+/// Missing music is left silent instead of looping the placeholder.
+const MUSIC_DIR: &str = "audio/music/";
+/// How long the missing-asset warning stays on screen.
+const TOAST_DURATION_SECS: f32 = 8.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<MissingAssets>();
+    app.add_systems(
+        Update,
+        (
+            (replace_failed_images, replace_failed_sounds),
+            show_missing_assets_toast.run_if(resource_changed::<MissingAssets>),
+            expire_toast,
+        )
+            .chain(),
+    );
+}
+
+/// Paths of every asset that failed to load this session.
+#[derive(Resource, Default)]
+pub struct MissingAssets(pub Vec<String>);
+
+#[derive(Component)]
+struct MissingAssetsToast(Timer);
+
+fn placeholder_image() -> Image {
+    Image::new_fill(
+        Extent3d {
+            width: 1,
+            height: 1,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &PLACEHOLDER_PIXEL,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
+
+fn replace_failed_images(
+    mut failed: MessageReader<AssetLoadFailedEvent<Image>>,
+    mut images: ResMut<Assets<Image>>,
+    mut missing: ResMut<MissingAssets>,
+) {
+    for message in failed.read() {
+        // Most cards have no art of their own yet; that isn't worth a warning.
+        if message.path.to_string().starts_with(CARD_ART_DIR) {
+            continue;
+        }
+        warn!("Missing image {}, using placeholder", message.path);
+        let _ = images.insert(message.id, placeholder_image());
+        missing.0.push(message.path.to_string());
+    }
+}
+
+fn replace_failed_sounds(
+    mut failed: MessageReader<AssetLoadFailedEvent<AudioSource>>,
+    mut sounds: ResMut<Assets<AudioSource>>,
+    mut missing: ResMut<MissingAssets>,
+) {
+    for message in failed.read() {
+        let path = message.path.to_string();
+        if path.starts_with(MUSIC_DIR) {
+            warn!("Missing music {path}, playing nothing");
+        } else {
+            warn!("Missing sound {path}, using placeholder");
+            let _ = sounds.insert(
+                message.id,
+                AudioSource {
+                    bytes: PLACEHOLDER_SOUND.into(),
+                },
+            );
+        }
+        missing.0.push(path);
+    }
+}
+
+fn show_missing_assets_toast(
+    mut commands: Commands,
+    missing: Res<MissingAssets>,
+    existing: Query<Entity, With<MissingAssetsToast>>,
+) {
+    if missing.0.is_empty() {
+        return;
+    }
+    for entity in &existing {
+        commands.entity(entity).despawn();
+    }
+    commands.spawn((
+        Name::new("Missing Assets Toast"),
+        MissingAssetsToast(Timer::from_seconds(TOAST_DURATION_SECS, TimerMode::Once)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(12),
+            right: px(12),
+            max_width: px(420),
+            padding: UiRect::all(px(10)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.5, 0.35, 0.0, 0.9)),
+        GlobalZIndex(20),
+        children![(
+            Text::new(format!("Missing assets:\n{}", missing.0.join("\n"))),
+            TextFont::from_font_size(14.0),
+            TextColor(Color::WHITE),
+        )],
+    ));
+}
+
+fn expire_toast(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut toasts: Query<(Entity, &mut MissingAssetsToast)>,
+) {
+    for (entity, mut toast) in &mut toasts {
+        if toast.0.tick(time.delta()).just_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -1,50 +1,170 @@
 //! A high-level way to load collections of asset handles as resources.
+//!
+//! Resources belong to an [`AssetGroup`]. The title group loads at startup
+//! and stays; the others load when one of their screens is entered and are
+//! dropped once none of them is current, so gameplay-only textures and audio
+//! don't sit in memory on the menus.
+//!
+//! Also logs asset counts on every screen transition to catch leaks in long
+//! sessions.
 
 use std::collections::VecDeque;
 
-use bevy::prelude::*;
+use bevy::{asset::RecursiveDependencyLoadState, platform::collections::HashSet, prelude::*};
+
+use crate::screens::Screen;
+
+/// Frames to wait after a screen change before counting, so handles owned by
+/// despawned entities have been dropped.
+const USAGE_SETTLE_FRAMES: u32 = 2;
+/// Warn when the title screen holds this many more materials than it did the
+/// first time.
+const MATERIAL_GROWTH_WARNING: usize = 50;
 
 pub(super) fn plugin(app: &mut App) {
     app.init_resource::<ResourceHandles>();
-    app.add_systems(PreUpdate, load_resource_assets);
+    app.init_resource::<AssetUsage>();
+    app.add_systems(
+        PreUpdate,
+        (
+            sync_asset_groups.run_if(state_changed::<Screen>),
+            load_resource_assets,
+        )
+            .chain(),
+    );
+    app.add_systems(Last, log_asset_usage);
+}
+
+/// Screens that share a set of loaded resources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssetGroup {
+    /// Menus and anything used everywhere. Loaded at startup, never dropped.
+    Title,
+    /// Matchmaking and the draft.
+    Lobby,
+    /// Matches. Loaded from the lobby on, so online matches start with it
+    /// ready; the loading screen waits for it before solo matches.
+    Gameplay,
+}
+
+impl AssetGroup {
+    const ALL: [Self; 3] = [Self::Title, Self::Lobby, Self::Gameplay];
+
+    /// Whether the group is kept loaded while `screen` is current.
+    fn needed_on(self, screen: Screen) -> bool {
+        match self {
+            Self::Title => true,
+            Self::Lobby => matches!(screen, Screen::Lobby | Screen::Draft),
+            Self::Gameplay => matches!(
+                screen,
+                Screen::Loading | Screen::Lobby | Screen::Draft | Screen::Gameplay | Screen::Map
+            ),
+        }
+    }
 }
 
 pub trait LoadResource {
     /// This will load the [`Resource`] as an [`Asset`]. When all of its asset dependencies
     /// have been loaded, it will be inserted as a resource. This ensures that the resource only
     /// exists when the assets are ready.
+    ///
+    /// The resource is in the [`AssetGroup::Title`] group and loads right away.
     fn load_resource<T: Resource + Asset + Clone + FromWorld>(&mut self) -> &mut Self;
+
+    /// Like [`load_resource`](Self::load_resource), but the resource only
+    /// exists while `group` is loaded. Systems using it should check
+    /// `resource_exists`.
+    fn load_resource_in<T: Resource + Asset + Clone + FromWorld>(
+        &mut self,
+        group: AssetGroup,
+    ) -> &mut Self;
 }
 
 impl LoadResource for App {
     fn load_resource<T: Resource + Asset + Clone + FromWorld>(&mut self) -> &mut Self {
         self.init_asset::<T>();
         let world = self.world_mut();
-        let value = T::from_world(world);
-        let assets = world.resource::<AssetServer>();
-        let handle = assets.add(value);
+        let handle = add_resource_asset::<T>(world);
         let mut handles = world.resource_mut::<ResourceHandles>();
-        handles
-            .waiting
-            .push_back((handle.untyped(), |world, handle| {
-                let assets = world.resource::<Assets<T>>();
-                if let Some(value) = assets.get(handle.id().typed::<T>()) {
-                    world.insert_resource(value.clone());
-                }
-            }));
+        handles.waiting.push_back(Pending {
+            group: AssetGroup::Title,
+            handle,
+            insert: insert_loaded_resource::<T>,
+        });
+        self
+    }
+
+    fn load_resource_in<T: Resource + Asset + Clone + FromWorld>(
+        &mut self,
+        group: AssetGroup,
+    ) -> &mut Self {
+        if group == AssetGroup::Title {
+            return self.load_resource::<T>();
+        }
+        self.init_asset::<T>();
+        let mut handles = self.world_mut().resource_mut::<ResourceHandles>();
+        handles.grouped.push(GroupedResource {
+            group,
+            add: add_resource_asset::<T>,
+            insert: insert_loaded_resource::<T>,
+            remove: |world| {
+                world.remove_resource::<T>();
+            },
+        });
         self
     }
 }
 
+fn add_resource_asset<T: Resource + Asset + Clone + FromWorld>(world: &mut World) -> UntypedHandle {
+    let value = T::from_world(world);
+    world.resource::<AssetServer>().add(value).untyped()
+}
+
+fn insert_loaded_resource<T: Resource + Asset + Clone>(world: &mut World, handle: &UntypedHandle) {
+    let assets = world.resource::<Assets<T>>();
+    if let Some(value) = assets.get(handle.id().typed::<T>()) {
+        world.insert_resource(value.clone());
+    }
+}
+
 /// A function that inserts a loaded resource.
 type InsertLoadedResource = fn(&mut World, &UntypedHandle);
 
-#[derive(Resource, Default)]
+/// A resource asset that hasn't finished loading.
+struct Pending {
+    group: AssetGroup,
+    handle: UntypedHandle,
+    insert: InsertLoadedResource,
+}
+
+/// A resource registered with [`LoadResource::load_resource_in`].
+struct GroupedResource {
+    group: AssetGroup,
+    /// Creates the asset, starting its loads.
+    add: fn(&mut World) -> UntypedHandle,
+    insert: InsertLoadedResource,
+    remove: fn(&mut World),
+}
+
+#[derive(Resource)]
 pub struct ResourceHandles {
     // Use a queue for waiting assets so they can be cycled through and moved to
     // `finished` one at a time.
-    waiting: VecDeque<(UntypedHandle, InsertLoadedResource)>,
-    finished: Vec<UntypedHandle>,
+    waiting: VecDeque<Pending>,
+    finished: Vec<(AssetGroup, UntypedHandle)>,
+    grouped: Vec<GroupedResource>,
+    loaded_groups: HashSet<AssetGroup>,
+}
+
+impl Default for ResourceHandles {
+    fn default() -> Self {
+        Self {
+            waiting: VecDeque::new(),
+            finished: Vec::new(),
+            grouped: Vec::new(),
+            loaded_groups: HashSet::from_iter([AssetGroup::Title]),
+        }
+    }
 }
 
 impl ResourceHandles {
@@ -52,20 +172,169 @@ impl ResourceHandles {
     pub fn is_all_done(&self) -> bool {
         self.waiting.is_empty()
     }
+
+    /// Whether `group` is loaded and all of its resources are available.
+    pub fn is_group_done(&self, group: AssetGroup) -> bool {
+        self.loaded_groups.contains(&group)
+            && !self.waiting.iter().any(|pending| pending.group == group)
+    }
+}
+
+/// Loads the groups the new screen needs and drops the ones it doesn't.
+fn sync_asset_groups(world: &mut World) {
+    let screen = *world.resource::<State<Screen>>().get();
+    world.resource_scope(|world, mut handles: Mut<ResourceHandles>| {
+        for group in AssetGroup::ALL {
+            let needed = group.needed_on(screen);
+            if needed == handles.loaded_groups.contains(&group) {
+                continue;
+            }
+            if needed {
+                for index in 0..handles.grouped.len() {
+                    let resource = &handles.grouped[index];
+                    if resource.group != group {
+                        continue;
+                    }
+                    let insert = resource.insert;
+                    let handle = (resource.add)(world);
+                    handles.waiting.push_back(Pending {
+                        group,
+                        handle,
+                        insert,
+                    });
+                }
+                handles.loaded_groups.insert(group);
+            } else {
+                handles.waiting.retain(|pending| pending.group != group);
+                handles.finished.retain(|(finished, _)| *finished != group);
+                for resource in handles
+                    .grouped
+                    .iter()
+                    .filter(|resource| resource.group == group)
+                {
+                    (resource.remove)(world);
+                }
+                handles.loaded_groups.remove(&group);
+            }
+        }
+    });
 }
 
 fn load_resource_assets(world: &mut World) {
     world.resource_scope(|world, mut resource_handles: Mut<ResourceHandles>| {
         world.resource_scope(|world, assets: Mut<AssetServer>| {
             for _ in 0..resource_handles.waiting.len() {
-                let (handle, insert_fn) = resource_handles.waiting.pop_front().unwrap();
-                if assets.is_loaded_with_dependencies(&handle) {
-                    insert_fn(world, &handle);
-                    resource_handles.finished.push(handle);
+                let pending = resource_handles.waiting.pop_front().unwrap();
+                if assets.is_loaded_with_dependencies(&pending.handle)
+                    || dependency_failed(&assets, &pending.handle)
+                {
+                    (pending.insert)(world, &pending.handle);
+                    resource_handles
+                        .finished
+                        .push((pending.group, pending.handle));
                 } else {
-                    resource_handles.waiting.push_back((handle, insert_fn));
+                    resource_handles.waiting.push_back(pending);
                 }
             }
         });
     });
 }
+
+/// A failed dependency never finishes loading; `asset_fallback` swaps in a
+/// placeholder, so the resource can be inserted anyway.
+fn dependency_failed(assets: &AssetServer, handle: &UntypedHandle) -> bool {
+    matches!(
+        assets.get_recursive_dependency_load_state(handle),
+        Some(RecursiveDependencyLoadState::Failed(_))
+    )
+}
+
+#[derive(Resource, Default)]
+struct AssetUsage {
+    /// Frames left before the pending count is taken.
+    settle: Option<u32>,
+    /// Material count the first time the title screen was counted.
+    title_baseline: Option<usize>,
+}
+
+fn log_asset_usage(
+    screen: Res<State<Screen>>,
+    mut usage: ResMut<AssetUsage>,
+    meshes: Option<Res<Assets<Mesh>>>,
+    materials: Option<Res<Assets<StandardMaterial>>>,
+    images: Option<Res<Assets<Image>>>,
+) {
+    if screen.is_changed() {
+        usage.settle = Some(USAGE_SETTLE_FRAMES);
+        return;
+    }
+    let Some(frames) = usage.settle else {
+        return;
+    };
+    if frames > 0 {
+        usage.settle = Some(frames - 1);
+        return;
+    }
+    usage.settle = None;
+
+    let meshes = meshes.map_or(0, |assets| assets.len());
+    let materials = materials.map_or(0, |assets| assets.len());
+    let images = images.map_or(0, |assets| assets.len());
+    info!(
+        "Assets on {:?}: {meshes} meshes, {materials} materials, {images} images",
+        screen.get()
+    );
+
+    if *screen.get() == Screen::Title {
+        let baseline = *usage.title_baseline.get_or_insert(materials);
+        if materials > baseline + MATERIAL_GROWTH_WARNING {
+            warn!("Material count grew from {baseline} to {materials} across matches");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{asset::AssetPlugin, state::app::StatesPlugin};
+
+    use super::*;
+    use crate::game::test_support::go_to;
+
+    #[derive(Resource, Asset, Clone, TypePath)]
+    struct MatchOnly;
+
+    impl FromWorld for MatchOnly {
+        fn from_world(_world: &mut World) -> Self {
+            Self
+        }
+    }
+
+    #[test]
+    fn gameplay_group_loads_on_demand_and_unloads_on_the_title() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default(), StatesPlugin));
+        app.init_state::<Screen>();
+        app.add_plugins(plugin);
+        app.load_resource_in::<MatchOnly>(AssetGroup::Gameplay);
+
+        go_to(&mut app, Screen::Title);
+        assert!(!app.world().contains_resource::<MatchOnly>());
+        assert!(
+            !app.world()
+                .resource::<ResourceHandles>()
+                .is_group_done(AssetGroup::Gameplay)
+        );
+
+        go_to(&mut app, Screen::Loading);
+        assert!(app.world().contains_resource::<MatchOnly>());
+        assert!(
+            app.world()
+                .resource::<ResourceHandles>()
+                .is_group_done(AssetGroup::Gameplay)
+        );
+
+        go_to(&mut app, Screen::Title);
+        assert!(!app.world().contains_resource::<MatchOnly>());
+        assert_eq!(app.world().resource::<Assets<MatchOnly>>().len(), 0);
+    }
+}
@@ -1,44 +1,289 @@
-use bevy::prelude::*;
+//! Audio buses and music ducking.
+//!
+//! Every sound plays on an [`AudioBus`] (music, sound effects or UI). Each bus
+//! has its own volume in [`AudioMix`], applied on top of the master
+//! [`GlobalVolume`] and saved across sessions. Sounds marked [`DucksMusic`]
+//! pull the music down while they play, and it recovers after they finish.
+
+mod impacts;
+mod soundtrack;
+
+use bevy::{
+    audio::{Pitch, Volume},
+    prelude::*,
+};
+
+use crate::persist::{self, Persisted, save_on_change};
+use soundtrack::MusicLayer;
+
+const SAVE_KEY: &str = "audio.txt";
+/// Music volume while a [`DucksMusic`] sound plays.
+const DUCKED_GAIN: f32 = 0.35;
+/// How quickly the music drops under a ducking sound (per second).
+const DUCK_ATTACK: f32 = 4.0;
+/// How quickly it comes back afterwards (per second).
+const DUCK_RELEASE: f32 = 0.8;
 
 pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(AudioMix::load());
+    app.init_resource::<MusicDuck>();
+    app.add_plugins((soundtrack::plugin, impacts::plugin));
     app.add_systems(
         Update,
-        apply_global_volume.run_if(resource_changed::<GlobalVolume>),
+        (
+            save_on_change::<AudioMix>.run_if(resource_changed::<AudioMix>),
+            duck_music,
+            apply_bus_volumes,
+        ),
     );
 }
 
-/// An organizational marker component that should be added to a spawned [`AudioPlayer`] if it's in the
-/// general "music" category (e.g. global background music, soundtrack).
-///
-/// This can then be used to query for and operate on sounds in that category.
-#[derive(Component, Reflect, Default)]
+/// The mixer channel a sound plays on. Add this to every [`AudioPlayer`].
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
 #[reflect(Component)]
-pub struct Music;
+pub enum AudioBus {
+    /// Background music.
+    Music,
+    /// Gameplay sound effects.
+    Sfx,
+    /// Menu and button feedback.
+    Ui,
+}
 
-/// A music audio instance.
-pub fn music(handle: Handle<AudioSource>) -> impl Bundle {
-    (AudioPlayer(handle), PlaybackSettings::LOOP, Music)
+impl AudioBus {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Music => "Music",
+            Self::Sfx => "Effects",
+            Self::Ui => "Interface",
+        }
+    }
+
+    /// A sound that plays once on this bus and despawns.
+    pub fn one_shot(self, handle: Handle<AudioSource>) -> impl Bundle {
+        (AudioPlayer(handle), PlaybackSettings::DESPAWN, self)
+    }
 }
 
-/// An organizational marker component that should be added to a spawned [`AudioPlayer`] if it's in the
-/// general "sound effect" category (e.g. footsteps, the sound of a magic spell, a door opening).
-///
-/// This can then be used to query for and operate on sounds in that category.
+/// Marks a sound big enough to duck the music while it plays, such as a
+/// heavy hit or the result sting.
 #[derive(Component, Reflect, Default)]
 #[reflect(Component)]
-pub struct SoundEffect;
+pub struct DucksMusic;
+
+/// Player-chosen volume per bus, as linear gain, persisted across sessions.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct AudioMix {
+    pub music: f32,
+    pub sfx: f32,
+    pub ui: f32,
+    /// Button and card feedback sounds.
+    pub feedback: bool,
+}
 
-/// A sound effect audio instance.
-pub fn sound_effect(handle: Handle<AudioSource>) -> impl Bundle {
-    (AudioPlayer(handle), PlaybackSettings::DESPAWN, SoundEffect)
+impl Default for AudioMix {
+    fn default() -> Self {
+        Self {
+            music: 0.8,
+            sfx: 1.0,
+            ui: 0.8,
+            feedback: true,
+        }
+    }
 }
 
-/// [`GlobalVolume`] doesn't apply to already-running audio entities, so this system will update them.
-fn apply_global_volume(
+impl AudioMix {
+    pub fn volume(&self, bus: AudioBus) -> f32 {
+        match bus {
+            AudioBus::Music => self.music,
+            AudioBus::Sfx => self.sfx,
+            AudioBus::Ui => self.ui,
+        }
+    }
+
+    /// Nudge a bus volume, staying within 0–100%.
+    pub fn adjust(&mut self, bus: AudioBus, delta: f32) {
+        let volume = match bus {
+            AudioBus::Music => &mut self.music,
+            AudioBus::Sfx => &mut self.sfx,
+            AudioBus::Ui => &mut self.ui,
+        };
+        // Round so repeated steps land on whole percentages.
+        *volume = ((*volume + delta) * 10.0).round().clamp(0.0, 10.0) / 10.0;
+    }
+
+    fn load() -> Self {
+        let mut mix = Self::default();
+        let Some(contents) = persist::load(SAVE_KEY) else {
+            return mix;
+        };
+        for (key, value) in contents.lines().filter_map(|line| line.split_once('=')) {
+            if key == "feedback" {
+                mix.feedback = value == "on";
+                continue;
+            }
+            let Ok(value) = value.parse::<f32>() else {
+                continue;
+            };
+            let value = value.clamp(0.0, 1.0);
+            match key {
+                "music" => mix.music = value,
+                "sfx" => mix.sfx = value,
+                "ui" => mix.ui = value,
+                _ => {}
+            }
+        }
+        mix
+    }
+}
+
+impl Persisted for AudioMix {
+    fn save(&self) {
+        let lines = [
+            format!("music={}", self.music),
+            format!("sfx={}", self.sfx),
+            format!("ui={}", self.ui),
+            format!("feedback={}", if self.feedback { "on" } else { "off" }),
+        ];
+        persist::store(SAVE_KEY, &lines.join("\n"));
+    }
+}
+
+/// Extra gain on the music layers, below 1 while a ducking sound plays.
+#[derive(Resource)]
+pub(super) struct MusicDuck(f32);
+
+impl Default for MusicDuck {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+fn duck_music(
+    time: Res<Time>,
+    mut duck: ResMut<MusicDuck>,
+    sources: Res<Assets<AudioSource>>,
+    ducking: Query<&AudioPlayer, With<DucksMusic>>,
+    ducking_tones: Query<(), (With<AudioPlayer<Pitch>>, With<DucksMusic>)>,
+) {
+    // Only sounds that loaded; one that failed never plays or despawns.
+    // Synthesized tones need no loading.
+    let playing =
+        !ducking_tones.is_empty() || ducking.iter().any(|player| sources.contains(&player.0));
+    let (target, rate) = if playing {
+        (DUCKED_GAIN, DUCK_ATTACK)
+    } else {
+        (1.0, DUCK_RELEASE)
+    };
+    let step = rate * time.delta_secs();
+    let gain = duck.0 + (target - duck.0).clamp(-step, step);
+    if gain != duck.0 {
+        duck.0 = gain;
+    }
+}
+
+/// [`GlobalVolume`] doesn't apply to already-running audio entities, and bus
+/// volumes aren't known to Bevy at all, so this sets both on new sounds and
+/// again whenever either changes. Music layers are left to the soundtrack,
+/// which fades them every frame.
+fn apply_bus_volumes(
     global_volume: Res<GlobalVolume>,
-    mut audio_query: Query<(&PlaybackSettings, &mut AudioSink)>,
+    mix: Res<AudioMix>,
+    mut audio_query: Query<(&AudioBus, &PlaybackSettings, &mut AudioSink), Without<MusicLayer>>,
+    added: Query<(), (Added<AudioSink>, Without<MusicLayer>)>,
 ) {
-    for (playback, mut sink) in &mut audio_query {
-        sink.set_volume(global_volume.volume * playback.volume);
+    let changed = global_volume.is_changed() || mix.is_changed();
+    if !changed && added.is_empty() {
+        return;
+    }
+    for (bus, playback, mut sink) in &mut audio_query {
+        let volume = global_volume.volume * Volume::Linear(mix.volume(*bus));
+        sink.set_volume(volume * playback.volume);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{asset::AssetPlugin, time::TimeUpdateStrategy};
+
+    use super::*;
+
+    fn run_secs(app: &mut App, secs: f32) {
+        for _ in 0..(secs * 60.0) as usize {
+            app.update();
+        }
+    }
+
+    fn gain(app: &App) -> f32 {
+        app.world().resource::<MusicDuck>().0
+    }
+
+    #[test]
+    fn ducking_sound_lowers_the_music_until_it_ends() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+        app.init_asset::<AudioSource>();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )));
+        app.init_resource::<MusicDuck>();
+        app.add_systems(Update, duck_music);
+
+        let source = app
+            .world_mut()
+            .resource_mut::<Assets<AudioSource>>()
+            .add(AudioSource {
+                bytes: Vec::new().into(),
+            });
+        let sound = app
+            .world_mut()
+            .spawn((AudioPlayer(source), DucksMusic))
+            .id();
+        run_secs(&mut app, 0.1);
+        assert!(gain(&app) < 1.0 && gain(&app) > DUCKED_GAIN);
+        run_secs(&mut app, 0.5);
+        assert_eq!(gain(&app), DUCKED_GAIN);
+
+        app.world_mut().despawn(sound);
+        run_secs(&mut app, 0.3);
+        assert!(gain(&app) > DUCKED_GAIN && gain(&app) < 1.0);
+        run_secs(&mut app, 1.0);
+        assert_eq!(gain(&app), 1.0);
+    }
+
+    #[test]
+    fn synthesized_tone_ducks() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+        app.init_asset::<AudioSource>();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )));
+        app.init_resource::<MusicDuck>();
+        app.add_systems(Update, duck_music);
+
+        app.world_mut()
+            .spawn((AudioPlayer::<Pitch>(Handle::default()), DucksMusic));
+        run_secs(&mut app, 0.5);
+        assert_eq!(gain(&app), DUCKED_GAIN);
+    }
+
+    #[test]
+    fn missing_sound_does_not_duck() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+        app.init_asset::<AudioSource>();
+        app.init_resource::<MusicDuck>();
+        app.add_systems(Update, duck_music);
+
+        let missing = app
+            .world()
+            .resource::<AssetServer>()
+            .load::<AudioSource>("audio/sound_effects/not_there.ogg");
+        app.world_mut().spawn((AudioPlayer(missing), DucksMusic));
+        run_secs(&mut app, 0.5);
+        assert_eq!(gain(&app), 1.0);
     }
 }
@@ -0,0 +1,52 @@
+//! Gameplay impact sounds.
+//!
+//! Heavy hits play a thud on the effects bus that briefly ducks the music.
+
+use bevy::prelude::*;
+
+use super::{AudioBus, DucksMusic};
+use crate::{
+    AppSystems,
+    game::{DamageMessage, Health},
+    screens::Screen,
+};
+
+/// Hits taking at least this fraction of the target's max HP are heavy.
+const HEAVY_HIT: f32 = 0.15;
+const HEAVY_HIT_SOUND: &str = "audio/sound_effects/step4.ogg";
+/// A footstep played well below pitch reads as a heavy thud.
+const HEAVY_HIT_SPEED: f32 = 0.55;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        play_heavy_hits
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+fn play_heavy_hits(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut damage: MessageReader<DamageMessage>,
+    health: Query<&Health>,
+) {
+    // Fold rather than `any` so every message is read this frame.
+    let heavy = damage.read().fold(false, |heavy, msg| {
+        heavy
+            | health
+                .get(msg.target)
+                .is_ok_and(|health| msg.amount >= health.max * HEAVY_HIT)
+    });
+    if heavy {
+        commands.spawn((
+            Name::new("Heavy Hit"),
+            AudioPlayer::new(asset_server.load(HEAVY_HIT_SOUND)),
+            PlaybackSettings::DESPAWN.with_speed(HEAVY_HIT_SPEED),
+            AudioBus::Sfx,
+            DucksMusic,
+            DespawnOnExit(Screen::Gameplay),
+        ));
+    }
+}
@@ -0,0 +1,296 @@
+//! Music that follows the game.
+//!
+//! The menus (title, lobby, draft, map and credits) share one track, so
+//! moving between them never restarts it; matches have their own. Changing
+//! track crossfades instead of cutting. While either player is below
+//! [`LOW_HP`] the match track picks up its tempo and a low drone stem fades in
+//! under it; both settle back once the match is decided. Winning or losing
+//! plays a short chord sting that ducks the music.
+//!
+//! The match track is in the gameplay asset group, so it's only in memory
+//! from the lobby on. The drone and stings are sine tones built at startup.
+
+use std::time::Duration;
+
+use bevy::{
+    audio::{Pitch, Volume},
+    prelude::*,
+};
+
+use super::{AudioBus, AudioMix, DucksMusic, MusicDuck};
+use crate::{
+    asset_tracking::{AssetGroup, LoadResource},
+    game::{GameResult, Health, LocalPlayer, Opponent},
+    screens::Screen,
+};
+
+const CROSSFADE_SECS: f32 = 1.5;
+/// Fraction of max HP below which the match music speeds up.
+const LOW_HP: f32 = 0.3;
+/// Playback speed of the match music at low HP.
+const LOW_HP_SPEED: f32 = 1.12;
+/// How quickly the speed moves toward its target (per second).
+const SPEED_EASE: f32 = 0.1;
+/// A2, looped every second; a whole number of cycles keeps the loop seamless.
+const DRONE_HZ: f32 = 110.0;
+/// Drone volume relative to the match track; a bare sine is loud.
+const DRONE_LEVEL: f32 = 0.2;
+const STING_SECS: f32 = 1.2;
+/// Volume of each sting note, so the three together don't clip.
+const STING_LEVEL: f32 = 0.25;
+/// C major, an octave up.
+const VICTORY_CHORD: [f32; 3] = [523.25, 659.25, 783.99];
+/// C minor.
+const DEFEAT_CHORD: [f32; 3] = [261.63, 311.13, 392.0];
+
+pub(super) fn plugin(app: &mut App) {
+    app.load_resource::<SoundtrackAssets>();
+    app.load_resource_in::<MatchMusicAssets>(AssetGroup::Gameplay);
+    app.init_resource::<Soundtrack>();
+    app.init_resource::<SynthMusic>();
+    app.add_systems(OnEnter(GameResult::Victory), play_victory_sting);
+    app.add_systems(OnEnter(GameResult::Defeat), play_defeat_sting);
+    app.add_systems(
+        Update,
+        (switch_track, fade_music_layers)
+            .chain()
+            .run_if(resource_exists::<SoundtrackAssets>),
+    );
+}
+
+#[derive(Resource, Asset, Clone, Reflect)]
+#[reflect(Resource)]
+struct SoundtrackAssets {
+    #[dependency]
+    menu: Handle<AudioSource>,
+}
+
+impl FromWorld for SoundtrackAssets {
+    fn from_world(world: &mut World) -> Self {
+        let assets = world.resource::<AssetServer>();
+        Self {
+            menu: assets.load("audio/music/Monkeys Spinning Monkeys.ogg"),
+        }
+    }
+}
+
+#[derive(Resource, Asset, Clone, Reflect)]
+#[reflect(Resource)]
+struct MatchMusicAssets {
+    #[dependency]
+    gameplay: Handle<AudioSource>,
+}
+
+impl FromWorld for MatchMusicAssets {
+    fn from_world(world: &mut World) -> Self {
+        let assets = world.resource::<AssetServer>();
+        Self {
+            gameplay: assets.load("audio/music/Fluffing A Duck.ogg"),
+        }
+    }
+}
+
+/// Synthesized parts: the low-HP drone and the result stings.
+#[derive(Resource)]
+struct SynthMusic {
+    drone: Handle<Pitch>,
+    victory: [Handle<Pitch>; 3],
+    defeat: [Handle<Pitch>; 3],
+}
+
+impl FromWorld for SynthMusic {
+    fn from_world(world: &mut World) -> Self {
+        let mut pitches = world.resource_mut::<Assets<Pitch>>();
+        let drone = pitches.add(Pitch::new(DRONE_HZ, Duration::from_secs(1)));
+        let mut chord = |notes: [f32; 3]| {
+            notes.map(|hz| pitches.add(Pitch::new(hz, Duration::from_secs_f32(STING_SECS))))
+        };
+        Self {
+            victory: chord(VICTORY_CHORD),
+            defeat: chord(DEFEAT_CHORD),
+            drone,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Track {
+    Menu,
+    Gameplay,
+}
+
+impl Track {
+    fn for_screen(screen: Screen) -> Option<Self> {
+        match screen {
+            Screen::Splash => None,
+            Screen::Gameplay => Some(Self::Gameplay),
+            Screen::Title | Screen::Loading | Screen::Lobby | Screen::Draft | Screen::Map => {
+                Some(Self::Menu)
+            }
+        }
+    }
+
+    /// The track's audio, if its group is loaded.
+    fn base(
+        self,
+        assets: &SoundtrackAssets,
+        match_music: Option<&MatchMusicAssets>,
+    ) -> Option<Handle<AudioSource>> {
+        match self {
+            Self::Menu => Some(assets.menu.clone()),
+            Self::Gameplay => match_music.map(|music| music.gameplay.clone()),
+        }
+    }
+
+    /// Whether the track speeds up and adds its drone when a player is low.
+    fn intensifies(self) -> bool {
+        self == Self::Gameplay
+    }
+}
+
+/// One looping part of a track.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stem {
+    /// The track itself, heard whenever the track is current.
+    Base,
+    /// The drone under an intensifying track, heard while a player is low.
+    Intensity,
+}
+
+impl Stem {
+    fn level(self) -> f32 {
+        match self {
+            Self::Base => 1.0,
+            Self::Intensity => DRONE_LEVEL,
+        }
+    }
+}
+
+/// The track currently playing (or fading in).
+#[derive(Resource, Default)]
+struct Soundtrack(Option<Track>);
+
+/// A looping stem of a track. Its volume is driven here rather than by
+/// `apply_bus_volumes`.
+#[derive(Component)]
+pub(super) struct MusicLayer {
+    track: Track,
+    stem: Stem,
+    gain: f32,
+    speed: f32,
+}
+
+fn switch_track(
+    mut commands: Commands,
+    screen: Res<State<Screen>>,
+    assets: Res<SoundtrackAssets>,
+    match_music: Option<Res<MatchMusicAssets>>,
+    synth: Res<SynthMusic>,
+    mut soundtrack: ResMut<Soundtrack>,
+) {
+    let wanted = Track::for_screen(*screen.get());
+    if soundtrack.0 == wanted {
+        return;
+    }
+    let Some(track) = wanted else {
+        soundtrack.0 = None;
+        return;
+    };
+    // Keep the old track until the new one's group has loaded.
+    let Some(base) = track.base(&assets, match_music.as_deref()) else {
+        return;
+    };
+    soundtrack.0 = wanted;
+    // The old track fades out on its own once it doesn't match.
+    commands.spawn((
+        Name::new(format!("Music {track:?}")),
+        MusicLayer {
+            track,
+            stem: Stem::Base,
+            gain: 0.0,
+            speed: 1.0,
+        },
+        AudioPlayer(base),
+        PlaybackSettings::LOOP.with_volume(Volume::SILENT),
+        AudioBus::Music,
+    ));
+    if track.intensifies() {
+        commands.spawn((
+            Name::new(format!("Music {track:?} Drone")),
+            MusicLayer {
+                track,
+                stem: Stem::Intensity,
+                gain: 0.0,
+                speed: 1.0,
+            },
+            AudioPlayer(synth.drone.clone()),
+            PlaybackSettings::LOOP.with_volume(Volume::SILENT),
+            AudioBus::Music,
+        ));
+    }
+}
+
+fn fade_music_layers(
+    mut commands: Commands,
+    time: Res<Time>,
+    soundtrack: Res<Soundtrack>,
+    global_volume: Res<GlobalVolume>,
+    mix: Res<AudioMix>,
+    duck: Res<MusicDuck>,
+    result: Option<Res<State<GameResult>>>,
+    players: Query<&Health, Or<(With<LocalPlayer>, With<Opponent>)>>,
+    mut layers: Query<(Entity, &mut MusicLayer, Option<&mut AudioSink>)>,
+) {
+    let low_hp = players.iter().any(|health| health.percentage() < LOW_HP);
+    let decided = result.is_some_and(|result| *result.get() != GameResult::Playing);
+    let intense = low_hp && !decided;
+    let step = time.delta_secs() / CROSSFADE_SECS;
+    let speed_step = time.delta_secs() * SPEED_EASE;
+    for (entity, mut layer, sink) in &mut layers {
+        let current = soundtrack.0 == Some(layer.track);
+        let audible = match layer.stem {
+            Stem::Base => current,
+            Stem::Intensity => current && intense,
+        };
+        let target = if audible { 1.0 } else { 0.0 };
+        layer.gain += (target - layer.gain).clamp(-step, step);
+        if !current && layer.gain <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        // Only the track speeds up; the drone would change pitch.
+        let target_speed = if layer.stem == Stem::Base && layer.track.intensifies() && intense {
+            LOW_HP_SPEED
+        } else {
+            1.0
+        };
+        layer.speed += (target_speed - layer.speed).clamp(-speed_step, speed_step);
+        if let Some(mut sink) = sink {
+            let gain = layer.gain * layer.stem.level() * mix.music * duck.0;
+            sink.set_volume(global_volume.volume * Volume::Linear(gain));
+            sink.set_speed(layer.speed);
+        }
+    }
+}
+
+fn play_victory_sting(mut commands: Commands, synth: Res<SynthMusic>) {
+    spawn_sting(&mut commands, &synth.victory);
+}
+
+fn play_defeat_sting(mut commands: Commands, synth: Res<SynthMusic>) {
+    spawn_sting(&mut commands, &synth.defeat);
+}
+
+/// Plays a chord on the music bus, ducking the match music under it.
+fn spawn_sting(commands: &mut Commands, chord: &[Handle<Pitch>]) {
+    for note in chord {
+        commands.spawn((
+            Name::new("Result Sting"),
+            AudioPlayer(note.clone()),
+            PlaybackSettings::DESPAWN.with_volume(Volume::Linear(STING_LEVEL)),
+            AudioBus::Music,
+            DucksMusic,
+            DespawnOnExit(Screen::Gameplay),
+        ));
+    }
+}
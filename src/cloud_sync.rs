@@ -0,0 +1,344 @@
+//! Optional cloud sync of the local profile, with no account.
+//!
+//! Off until the player turns it on in Settings → Cloud Sync. The profile
+//! (saved decks, stats, match history, rating and display name) is packed
+//! into one text blob and sent to `<endpoint>/<sync code>`: `PUT` uploads,
+//! `GET` downloads. Typing the same sync code on another machine downloads
+//! the profile there. Settings live in `sync.txt`:
+//!
+//! ```text
+//! enabled=true
+//! endpoint=https://sync.example.com/profiles
+//! code=K7QX2MPA9R
+//! ```
+
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::*;
+
+use crate::{
+    game::{CardRegistry, SavedDecks},
+    network::{PlayerName, Rating},
+    persist,
+    stats::ProfileStats,
+};
+
+const SAVE_KEY: &str = "sync.txt";
+/// Save keys that make up the synced profile. Settings stay per machine.
+const PROFILE_KEYS: [&str; 6] = [
+    "deck_ironclad.txt",
+    "deck_silent.txt",
+    "profile.txt",
+    "history.txt",
+    "rating.txt",
+    "name.txt",
+];
+/// Starts each saved value in the blob: `@@ <key>`.
+const BLOB_HEADER: &str = "@@ ";
+/// Sync codes use letters and digits that can't be misread for each other.
+const CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+pub const SYNC_CODE_LEN: usize = 10;
+
+pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(CloudSync::load());
+    app.init_resource::<SyncStatus>();
+    app.init_resource::<SyncReply>();
+    app.add_systems(
+        Update,
+        save_sync_settings
+            .run_if(resource_changed::<CloudSync>.and(not(resource_added::<CloudSync>))),
+    );
+    app.add_systems(Update, finish_sync);
+}
+
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct CloudSync {
+    /// The player agreed to send their profile to the endpoint.
+    pub enabled: bool,
+    pub endpoint: String,
+    pub code: String,
+}
+
+impl CloudSync {
+    fn load() -> Self {
+        let mut sync = Self::default();
+        let contents = persist::load(SAVE_KEY).unwrap_or_default();
+        for (key, value) in contents.lines().filter_map(|line| line.split_once('=')) {
+            let value = value.trim();
+            match key.trim() {
+                "enabled" => sync.enabled = value == "true",
+                "endpoint" => sync.endpoint = value.trim_end_matches('/').to_string(),
+                "code" => sync.code = sanitize_code(value).unwrap_or_default(),
+                _ => {}
+            }
+        }
+        sync
+    }
+
+    fn save(&self) {
+        persist::store(
+            SAVE_KEY,
+            &format!(
+                "enabled={}\nendpoint={}\ncode={}",
+                self.enabled, self.endpoint, self.code
+            ),
+        );
+    }
+
+    /// Give consent and make sure there's a code to sync under. Sync stays
+    /// off if no code can be made.
+    pub fn enable(&mut self) -> Result<(), String> {
+        if self.code.is_empty() {
+            self.code =
+                generate_code().map_err(|error| format!("Couldn't make a sync code: {error}"))?;
+        }
+        self.enabled = true;
+        Ok(())
+    }
+
+    fn url(&self) -> Result<String, &'static str> {
+        if !self.enabled {
+            Err("Cloud sync is off")
+        } else if self.endpoint.is_empty() {
+            Err("No endpoint set in sync.txt")
+        } else if self.code.is_empty() {
+            Err("No sync code")
+        } else {
+            Ok(format!("{}/{}", self.endpoint, self.code))
+        }
+    }
+}
+
+/// Upper-case the code and check it only uses the code alphabet.
+pub fn sanitize_code(raw: &str) -> Option<String> {
+    let code = raw.trim().to_ascii_uppercase();
+    (code.len() == SYNC_CODE_LEN && code.bytes().all(|byte| CODE_ALPHABET.contains(&byte)))
+        .then_some(code)
+}
+
+/// A fresh random code. Without real randomness every machine would end up
+/// with the same code and overwrite each other's profile, so this fails
+/// instead of falling back to anything predictable.
+fn generate_code() -> Result<String, getrandom::Error> {
+    let mut bytes = [0u8; SYNC_CODE_LEN];
+    getrandom::fill(&mut bytes)?;
+    Ok(bytes
+        .iter()
+        .map(|byte| CODE_ALPHABET[*byte as usize % CODE_ALPHABET.len()] as char)
+        .collect())
+}
+
+/// What the last upload or download did, for the Cloud Sync menu.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub enum SyncStatus {
+    #[default]
+    Idle,
+    Busy,
+    Done(String),
+    Failed(String),
+}
+
+impl SyncStatus {
+    pub fn label(&self) -> &str {
+        match self {
+            Self::Idle => "",
+            Self::Busy => "Syncing...",
+            Self::Done(message) | Self::Failed(message) => message,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Reply {
+    Uploaded,
+    Downloaded(String),
+}
+
+/// Filled in by the HTTP callback, which may run on another thread.
+#[derive(Resource, Default)]
+struct SyncReply(Arc<Mutex<Option<Result<Reply, String>>>>);
+
+/// Send the local profile to the endpoint.
+pub fn upload(world: &mut World) {
+    let Some(url) = begin_request(world) else {
+        return;
+    };
+    let mut request = ehttp::Request::post(url, pack_profile().into_bytes());
+    request.method = "PUT".to_string();
+    send(world, request, |_| Ok(Reply::Uploaded));
+}
+
+/// Replace the local profile with the one saved under the sync code.
+pub fn download(world: &mut World) {
+    let Some(url) = begin_request(world) else {
+        return;
+    };
+    send(world, ehttp::Request::get(url), |response| {
+        response
+            .text()
+            .map(|text| Reply::Downloaded(text.to_string()))
+            .ok_or_else(|| "The profile isn't text".to_string())
+    });
+}
+
+fn begin_request(world: &mut World) -> Option<String> {
+    if *world.resource::<SyncStatus>() == SyncStatus::Busy {
+        return None;
+    }
+    match world.resource::<CloudSync>().url() {
+        Ok(url) => {
+            world.insert_resource(SyncStatus::Busy);
+            Some(url)
+        }
+        Err(reason) => {
+            world.insert_resource(SyncStatus::Failed(reason.to_string()));
+            None
+        }
+    }
+}
+
+fn send(
+    world: &mut World,
+    request: ehttp::Request,
+    on_ok: impl FnOnce(&ehttp::Response) -> Result<Reply, String> + Send + 'static,
+) {
+    let slot = world.resource::<SyncReply>().0.clone();
+    ehttp::fetch(request, move |result| {
+        let reply = result.and_then(|response| match response.status {
+            200..=299 => on_ok(&response),
+            404 => Err("Nothing saved under this code".to_string()),
+            status => Err(format!("Server replied {status} {}", response.status_text)),
+        });
+        if let Ok(mut slot) = slot.lock() {
+            *slot = Some(reply);
+        }
+    });
+}
+
+fn finish_sync(world: &mut World) {
+    let Some(reply) = world
+        .resource::<SyncReply>()
+        .0
+        .lock()
+        .ok()
+        .and_then(|mut slot| slot.take())
+    else {
+        return;
+    };
+    let status = match reply {
+        Ok(Reply::Uploaded) => SyncStatus::Done("Profile uploaded".to_string()),
+        Ok(Reply::Downloaded(blob)) => {
+            let saves = unpack_profile(&blob);
+            if saves.is_empty() {
+                SyncStatus::Failed("The downloaded profile is empty".to_string())
+            } else {
+                for (key, contents) in &saves {
+                    persist::store(key, contents);
+                }
+                // Saves deleted on the other machine go here too.
+                for key in missing_keys(&saves) {
+                    persist::remove(key);
+                }
+                reload_profile(world);
+                SyncStatus::Done("Profile downloaded".to_string())
+            }
+        }
+        Err(error) => {
+            warn!("Cloud sync failed: {error}");
+            SyncStatus::Failed(error)
+        }
+    };
+    world.insert_resource(status);
+}
+
+/// Pick up the freshly written saves without a restart.
+fn reload_profile(world: &mut World) {
+    let decks = SavedDecks::load(world.resource::<CardRegistry>());
+    world.insert_resource(decks);
+    world.insert_resource(ProfileStats::load());
+    world.insert_resource(Rating::load());
+    world.insert_resource(PlayerName::load());
+}
+
+/// Every profile save that exists, each under an `@@ <key>` line.
+fn pack_profile() -> String {
+    let mut blob = String::new();
+    for key in PROFILE_KEYS {
+        if let Some(contents) = persist::load(key) {
+            blob.push_str(&format!("{BLOB_HEADER}{key}\n{}\n", contents.trim_end()));
+        }
+    }
+    blob
+}
+
+/// Split a blob back into saves, keeping only keys that belong to the profile.
+fn unpack_profile(blob: &str) -> Vec<(String, String)> {
+    let mut saves: Vec<(String, String)> = Vec::new();
+    for line in blob.lines() {
+        if let Some(key) = line.strip_prefix(BLOB_HEADER) {
+            saves.push((key.trim().to_string(), String::new()));
+        } else if let Some((_, contents)) = saves.last_mut() {
+            contents.push_str(line);
+            contents.push('\n');
+        }
+    }
+    saves.retain(|(key, _)| PROFILE_KEYS.contains(&key.as_str()));
+    saves
+}
+
+/// Profile saves the blob doesn't have.
+fn missing_keys(saves: &[(String, String)]) -> impl Iterator<Item = &'static str> + '_ {
+    PROFILE_KEYS
+        .into_iter()
+        .filter(|key| saves.iter().all(|(saved, _)| saved != key))
+}
+
+fn save_sync_settings(sync: Res<CloudSync>) {
+    sync.save();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blob_splits_into_profile_saves_only() {
+        let blob = "@@ rating.txt\nrating=1234\n@@ save/../ice.txt\nturn=evil\n@@ deck_silent.txt\nStrike\nDefend\n";
+        let saves = unpack_profile(blob);
+        assert_eq!(
+            saves,
+            vec![
+                ("rating.txt".to_string(), "rating=1234\n".to_string()),
+                (
+                    "deck_silent.txt".to_string(),
+                    "Strike\nDefend\n".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn codes_are_checked_against_the_alphabet() {
+        assert_eq!(
+            sanitize_code(" k7qx2mpa9r "),
+            Some("K7QX2MPA9R".to_string())
+        );
+        assert_eq!(sanitize_code("K7QX2MPA9"), None);
+        assert_eq!(sanitize_code("K7QX2MPA0R"), None);
+        assert!(sanitize_code(&generate_code().unwrap()).is_some());
+    }
+
+    #[test]
+    fn saves_missing_from_the_blob_are_cleared() {
+        let saves = unpack_profile("@@ rating.txt\nrating=1234\n@@ name.txt\nAda\n");
+        assert_eq!(
+            missing_keys(&saves).collect::<Vec<_>>(),
+            vec![
+                "deck_ironclad.txt",
+                "deck_silent.txt",
+                "profile.txt",
+                "history.txt"
+            ]
+        );
+    }
+}
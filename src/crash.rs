@@ -0,0 +1,191 @@
+//! Crash reports for players who don't run the game from a terminal.
+//!
+//! The last [`LOG_LINES`] log lines are kept in memory. On a panic they are
+//! written to `crash.txt` with the panic message, a backtrace and basic
+//! system info, before the usual panic output. The next launch shows a
+//! notice on the main menu that points at the report.
+
+use std::{collections::VecDeque, fmt, sync::Mutex};
+
+use bevy::{
+    log::{
+        BoxedLayer,
+        tracing::{
+            Event, Subscriber,
+            field::{Field, Visit},
+        },
+        tracing_subscriber::{Layer, layer::Context},
+    },
+    prelude::*,
+};
+
+use crate::{menus::Menu, persist, theme::widget};
+
+const REPORT_KEY: &str = "crash.txt";
+/// Present until the player has seen the notice for the last crash.
+const PENDING_KEY: &str = "crash_pending.txt";
+/// Log lines kept for the report.
+const LOG_LINES: usize = 200;
+
+/// Most recent log lines, oldest first. A static because the panic hook
+/// can't reach the `World`.
+static RECENT_LOGS: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+pub(super) fn plugin(app: &mut App) {
+    install_panic_hook();
+    if persist::load(PENDING_KEY).is_some() {
+        app.init_resource::<CrashNotice>();
+    }
+    app.add_systems(
+        OnEnter(Menu::Main),
+        spawn_crash_notice.run_if(resource_exists::<CrashNotice>),
+    );
+}
+
+/// `LogPlugin::custom_layer` hook that copies every log line into the buffer.
+pub fn log_layer(_app: &mut App) -> Option<BoxedLayer> {
+    Some(Box::new(RecentLogs))
+}
+
+struct RecentLogs;
+
+impl<S: Subscriber> Layer<S> for RecentLogs {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let mut line = format!("{} {}:", metadata.level(), metadata.target());
+        event.record(&mut LineWriter(&mut line));
+        let Ok(mut logs) = RECENT_LOGS.lock() else {
+            return;
+        };
+        if logs.len() == LOG_LINES {
+            logs.pop_front();
+        }
+        logs.push_back(line);
+    }
+}
+
+/// Writes an event's message, then its other fields as `name=value`.
+struct LineWriter<'a>(&'a mut String);
+
+impl Visit for LineWriter<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.0.push_str(&format!(" {value:?}"));
+        } else {
+            self.0.push_str(&format!(" {}={value:?}", field.name()));
+        }
+    }
+}
+
+fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        persist::store(REPORT_KEY, &crash_report(info));
+        persist::store(PENDING_KEY, "1");
+        previous(info);
+    }));
+}
+
+fn crash_report(info: &std::panic::PanicHookInfo) -> String {
+    // `try_lock`: the panic may have come from inside the logging layer.
+    let logs = RECENT_LOGS
+        .try_lock()
+        .map(|logs| logs.iter().cloned().collect::<Vec<_>>().join("\n"))
+        .unwrap_or_else(|_| "(log buffer unavailable)".to_string());
+    let threads = std::thread::available_parallelism().map_or(0, |count| count.get());
+    format!(
+        "Sensen {version} crashed\n\n\
+         Panic: {info}\n\n\
+         System: {os} {arch}, {threads} threads\n\n\
+         Backtrace:\n{backtrace}\n\n\
+         Last {count} log lines:\n{logs}\n",
+        version = env!("CARGO_PKG_VERSION"),
+        os = std::env::consts::OS,
+        arch = std::env::consts::ARCH,
+        backtrace = std::backtrace::Backtrace::force_capture(),
+        count = LOG_LINES,
+    )
+}
+
+/// The last run crashed and the player hasn't been told yet.
+#[derive(Resource, Default)]
+struct CrashNotice;
+
+#[derive(Component)]
+struct CrashNoticePanel;
+
+fn spawn_crash_notice(mut commands: Commands) {
+    #[cfg(not(target_family = "wasm"))]
+    let text = format!(
+        "Sensen closed unexpectedly last time.\nA crash report was saved to {}/{REPORT_KEY}.",
+        persist::SAVE_DIR
+    );
+    #[cfg(target_family = "wasm")]
+    let text = format!(
+        "Sensen closed unexpectedly last time.\nA crash report was saved in browser storage as {REPORT_KEY}."
+    );
+    commands.spawn((
+        Name::new("Crash Notice"),
+        CrashNoticePanel,
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: px(20),
+            left: px(20),
+            padding: UiRect::all(px(16)),
+            flex_direction: FlexDirection::Column,
+            row_gap: px(10),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.1, 0.05, 0.05, 0.95)),
+        GlobalZIndex(3),
+        DespawnOnExit(Menu::Main),
+        children![
+            widget::label(text),
+            (
+                Name::new("Crash Notice Buttons"),
+                Node {
+                    column_gap: px(10),
+                    ..default()
+                },
+                #[cfg(not(target_family = "wasm"))]
+                children![
+                    widget::button_medium("Open Folder", open_crash_folder),
+                    widget::button_medium("Dismiss", dismiss_crash_notice),
+                ],
+                #[cfg(target_family = "wasm")]
+                children![widget::button_medium("Dismiss", dismiss_crash_notice)],
+            ),
+        ],
+    ));
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn open_crash_folder(
+    click: On<Pointer<Click>>,
+    commands: Commands,
+    panel: Query<Entity, With<CrashNoticePanel>>,
+) {
+    let opener = match std::env::consts::OS {
+        "windows" => "explorer",
+        "macos" => "open",
+        _ => "xdg-open",
+    };
+    let dir = std::path::Path::new(persist::SAVE_DIR);
+    let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+    if let Err(error) = std::process::Command::new(opener).arg(&dir).spawn() {
+        warn!("Failed to open {}: {error}", dir.display());
+    }
+    dismiss_crash_notice(click, commands, panel);
+}
+
+fn dismiss_crash_notice(
+    _: On<Pointer<Click>>,
+    mut commands: Commands,
+    panel: Query<Entity, With<CrashNoticePanel>>,
+) {
+    persist::remove(PENDING_KEY);
+    commands.remove_resource::<CrashNotice>();
+    for entity in &panel {
+        commands.entity(entity).despawn();
+    }
+}
@@ -1,56 +1,46 @@
 //! Spawn the main level.
 
-use bevy::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*};
 
 use crate::{
-    asset_tracking::LoadResource,
-    audio::music,
-    game::{DrawCardsMessage, GameMode, MatchSeed, OpponentBundle, PlayerBundle, create_test_deck},
+    game::{
+        CardRegistry, CharacterClass, Cost, Deck, DeckMode, Draft, DrawCardsMessage, GameMode,
+        GameRng, Health, MatchClock, MatchMutator, MatchSeed, MatchTimeLimit, OpponentBundle,
+        PlayerBundle, Rules, SavedDecks, starting_deck,
+    },
     network::NetworkPlayers,
+    replay::ReplayPlayback,
+    run::RunState,
     screens::Screen,
+    tutorial::Tutorial,
 };
 
 pub(super) fn plugin(app: &mut App) {
-    app.load_resource::<LevelAssets>();
     app.init_resource::<LevelSpawned>();
     app.add_systems(OnEnter(Screen::Gameplay), reset_level_spawned);
     app.add_systems(Update, spawn_level_once.run_if(in_state(Screen::Gameplay)));
 }
 
-#[derive(Resource, Asset, Clone, Reflect)]
-#[reflect(Resource)]
-pub struct LevelAssets {
-    #[dependency]
-    music: Handle<AudioSource>,
-}
-
-impl FromWorld for LevelAssets {
-    fn from_world(world: &mut World) -> Self {
-        let assets = world.resource::<AssetServer>();
-        Self {
-            music: assets.load("audio/music/Fluffing A Duck.ogg"),
-        }
-    }
-}
-
 /// A system that spawns the main level.
 fn spawn_level_once(
     mut commands: Commands,
-    level_assets: Res<LevelAssets>,
     mut draw_messages: MessageWriter<DrawCardsMessage>,
     network_players: Option<Res<NetworkPlayers>>,
     game_mode: Res<GameMode>,
-    mut match_seed: ResMut<MatchSeed>,
+    deck_mode: Res<DeckMode>,
+    local_class: Res<CharacterClass>,
+    registry: Res<CardRegistry>,
+    run: Res<RunState>,
+    saved_decks: Res<SavedDecks>,
+    draft: Option<Res<Draft>>,
+    setup: MatchSetup,
+    rules: Res<Rules>,
     mut spawned: ResMut<LevelSpawned>,
 ) {
     if spawned.0 {
         return;
     }
 
-    if *game_mode == GameMode::Offline {
-        *match_seed = MatchSeed::default();
-    }
-
     let local_handle = if *game_mode == GameMode::Online {
         let Some(players) = network_players.as_ref() else {
             return;
@@ -62,17 +52,6 @@ fn spawn_level_once(
     } else {
         0
     };
-    commands.spawn((
-        Name::new("Level"),
-        Transform::default(),
-        Visibility::default(),
-        DespawnOnExit(Screen::Gameplay),
-        children![(
-            Name::new("Gameplay Music"),
-            music(level_assets.music.clone())
-        )],
-    ));
-
     let opponent_handle = if *game_mode == GameMode::Online {
         let Some(players) = network_players.as_ref() else {
             return;
@@ -90,37 +69,142 @@ fn spawn_level_once(
         1
     };
 
-    let match_seed_value = match_seed.0;
+    // A replay is watched from the side of whoever recorded it.
+    let replay = setup.replay.header();
+    let (local_handle, opponent_handle) = match replay {
+        Some(header) => (header.local_handle, 1 - header.local_handle),
+        None => (local_handle, opponent_handle),
+    };
+
+    let match_seed_value = setup.seed.0;
+
+    // Online each side uses its announced class; offline the opponent mirrors ours.
+    let (local_class, opponent_class) = match network_players.as_ref() {
+        Some(players) if *game_mode == GameMode::Online => (
+            players.class_for(local_handle),
+            players.class_for(opponent_handle),
+        ),
+        _ => match replay {
+            Some(header) => (
+                header.classes[local_handle],
+                header.classes[opponent_handle],
+            ),
+            None => (*local_class, *local_class),
+        },
+    };
+
+    // The tutorial always deals both sides the plain starter deck.
+    let deck_mode = if setup.tutorial.active {
+        DeckMode::Starter
+    } else {
+        *deck_mode
+    };
 
-    // Spawn local player with test deck, cost rate 1.0/sec
+    // A run fight brings its own deck, Solo starter matches use the saved deck,
+    // otherwise build one from the deck mode.
+    let run_fight = run
+        .current_fight()
+        .filter(|_| *game_mode == GameMode::Offline && !setup.tutorial.active && replay.is_none());
+    let mut local_deck = match run_fight {
+        Some(_) => run.deck.clone(),
+        None if *game_mode == GameMode::Offline
+            && deck_mode == DeckMode::Starter
+            && !setup.tutorial.active =>
+        {
+            saved_decks.deck(local_class)
+        }
+        None => starting_deck(
+            deck_mode,
+            local_class,
+            &registry,
+            match_seed_value,
+            local_handle,
+        ),
+    };
+    let mut opponent_deck = starting_deck(
+        deck_mode,
+        opponent_class,
+        &registry,
+        match_seed_value,
+        opponent_handle,
+    );
+    // Drafted decks replace whatever the deck mode would have dealt.
+    if let Some(draft) = draft.filter(|_| deck_mode == DeckMode::Draft && run_fight.is_none()) {
+        local_deck = draft.picks[local_handle].clone();
+        opponent_deck = draft.picks[opponent_handle].clone();
+    }
+
+    // Spawn local player with the selected deck, cost rate 1.0/sec
+    let hp = rules.initial_hp;
+    let cost = Cost::new(1.0, rules.max_cost);
+    let mut player =
+        PlayerBundle::new(local_handle, cost.clone(), hp, local_deck, match_seed_value);
+    let mut opponent =
+        OpponentBundle::new(opponent_handle, cost, hp, opponent_deck, match_seed_value);
+    if let Some(kind) = run_fight {
+        let (enemy_hp, enemy_thorns) = kind.enemy_stats(&rules);
+        player.health = Health {
+            current: run.hp,
+            max: run.max_hp,
+        };
+        opponent.health = Health::new(enemy_hp);
+        opponent.thorns.damage = enemy_thorns;
+    }
+    if let Some(header) = replay {
+        player.deck = Deck::restore(header.decks[local_handle].clone());
+        opponent.deck = Deck::restore(header.decks[opponent_handle].clone());
+    }
+    if let Some(mutator) = setup.mutator.0 {
+        mutator.apply(
+            &mut player.health,
+            &mut player.block,
+            &mut player.cost,
+            &mut player.strength,
+        );
+        mutator.apply(
+            &mut opponent.health,
+            &mut opponent.block,
+            &mut opponent.cost,
+            &mut opponent.strength,
+        );
+    }
+    player.clock = MatchClock::new(setup.time_limit.0);
+    opponent.clock = MatchClock::new(setup.time_limit.0);
     let player_entity = commands
-        .spawn((
-            PlayerBundle::new(local_handle, 1.0, create_test_deck(), match_seed_value),
-            DespawnOnExit(Screen::Gameplay),
-        ))
+        .spawn((player, DespawnOnExit(Screen::Gameplay)))
         .id();
 
-    // Spawn opponent with same HP and a matching deck
+    // Spawn opponent with same HP and a deck from the same mode
     let opponent_entity = commands
-        .spawn((
-            OpponentBundle::new(opponent_handle, 1.0, create_test_deck(), match_seed_value),
-            DespawnOnExit(Screen::Gameplay),
-        ))
+        .spawn((opponent, DespawnOnExit(Screen::Gameplay)))
         .id();
 
-    // Draw initial hand of 5 cards
+    // Draw the opening hand (5 cards unless a mutator changes it)
+    let opening_hand = setup.mutator.0.map_or(5, |mutator| mutator.opening_hand());
     draw_messages.write(DrawCardsMessage {
         player: player_entity,
-        count: 5,
+        count: opening_hand,
     });
     draw_messages.write(DrawCardsMessage {
         player: opponent_entity,
-        count: 5,
+        count: opening_hand,
     });
 
+    commands.insert_resource(GameRng::new(match_seed_value));
+
     spawned.0 = true;
 }
 
+/// The resources that decide how a match is dealt, apart from its decks.
+#[derive(SystemParam)]
+struct MatchSetup<'w> {
+    seed: Res<'w, MatchSeed>,
+    mutator: Res<'w, MatchMutator>,
+    time_limit: Res<'w, MatchTimeLimit>,
+    tutorial: Res<'w, Tutorial>,
+    replay: Res<'w, ReplayPlayback>,
+}
+
 #[derive(Resource, Default)]
 struct LevelSpawned(bool);
 
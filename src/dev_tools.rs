@@ -1,5 +1,10 @@
 //! Development tools for the game. This plugin is only enabled in dev builds.
 
+mod console;
+mod diagnostics;
+mod snapshot;
+mod telemetry;
+
 use bevy::{
     dev_tools::states::log_transitions, input::common_conditions::input_just_pressed, prelude::*,
 };
@@ -21,9 +26,18 @@ pub(super) fn plugin(app: &mut App) {
         Update,
         jump_to_gameplay.run_if(input_just_pressed(KeyCode::F1)),
     );
+
+    // F2: card telemetry dashboard
+    app.add_plugins((snapshot::plugin, telemetry::plugin));
+
+    // F3: frame time and network diagnostics
+    app.add_plugins(diagnostics::plugin);
+
+    // Backtick: dev console
+    app.add_plugins(console::plugin);
 }
 
-const TOGGLE_KEY: KeyCode = KeyCode::Backquote;
+const TOGGLE_KEY: KeyCode = KeyCode::F4;
 
 fn toggle_debug_ui(mut options: ResMut<UiDebugOptions>) {
     options.toggle();
@@ -0,0 +1,363 @@
+//! In-game dev console (backtick).
+//!
+//! While it is open, the keyboard goes to the console only. Commands that
+//! change the match work in offline matches only, and go through the same
+//! messages the game itself sends where one exists (drawing, dying), so the
+//! usual reactions follow.
+
+use bevy::{
+    input::{
+        ButtonState, InputSystems,
+        keyboard::{Key, KeyboardInput},
+    },
+    prelude::*,
+};
+
+use crate::{
+    game::{
+        CardRegistry, Cost, DeathMessage, DrawCardsMessage, GameMode, Hand, Health, LocalPlayer,
+        MAX_HAND_SIZE, Opponent,
+    },
+    logging,
+    screens::Screen,
+};
+
+const TOGGLE_KEY: KeyCode = KeyCode::Backquote;
+/// Output lines kept on screen.
+const LOG_LINES: usize = 12;
+const HELP: &str = "Commands: give <card>, cost <n>, hp <me|opp> <n>, draw <n>, win, lose, \
+    goto <title|lobby|gameplay|map>, verbose [on|off], clear, help";
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<DevConsole>();
+    // Before anything reads the keyboard this frame.
+    app.add_systems(PreUpdate, read_console_keys.after(InputSystems));
+    app.add_systems(Update, (run_console_commands, sync_console_ui).chain());
+}
+
+#[derive(Resource, Debug, Default)]
+struct DevConsole {
+    open: bool,
+    input: String,
+    /// Submitted lines not run yet.
+    submitted: Vec<String>,
+    log: Vec<String>,
+}
+
+impl DevConsole {
+    fn print(&mut self, line: impl Into<String>) {
+        self.log.push(line.into());
+        let overflow = self.log.len().saturating_sub(LOG_LINES);
+        self.log.drain(..overflow);
+    }
+}
+
+#[derive(Component)]
+struct ConsoleRoot;
+
+#[derive(Component)]
+struct ConsoleText;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Side {
+    Me,
+    Opponent,
+}
+
+#[derive(Debug, PartialEq)]
+enum ConsoleCommand {
+    Help,
+    Clear,
+    Goto(Screen),
+    /// Turn verbose logging on or off; flips it without an argument.
+    Verbose(Option<bool>),
+    Match(MatchCommand),
+}
+
+/// Commands that change the match in progress.
+#[derive(Debug, PartialEq)]
+enum MatchCommand {
+    Give(String),
+    Cost(f32),
+    Hp(Side, f32),
+    Draw(usize),
+    Win,
+    Lose,
+}
+
+impl ConsoleCommand {
+    fn parse(line: &str) -> Result<Self, String> {
+        let mut words = line.split_whitespace();
+        let Some(name) = words.next() else {
+            return Err("Empty command".to_string());
+        };
+        let args: Vec<&str> = words.collect();
+        let number = |arg: Option<&&str>| -> Result<f32, String> {
+            let arg = arg.ok_or("Missing number")?;
+            arg.parse().map_err(|_| format!("Not a number: {arg}"))
+        };
+
+        match (name.to_lowercase().as_str(), args.as_slice()) {
+            ("help", []) => Ok(Self::Help),
+            ("clear", []) => Ok(Self::Clear),
+            ("give", [_, ..]) => Ok(Self::Match(MatchCommand::Give(args.join(" ")))),
+            ("cost", [_]) => number(args.first()).map(|cost| Self::Match(MatchCommand::Cost(cost))),
+            ("hp", [side, _]) => {
+                let side = match side.to_lowercase().as_str() {
+                    "me" | "player" => Side::Me,
+                    "opp" | "opponent" => Side::Opponent,
+                    other => return Err(format!("Unknown side: {other} (me or opp)")),
+                };
+                number(args.get(1)).map(|hp| Self::Match(MatchCommand::Hp(side, hp)))
+            }
+            ("draw", []) => Ok(Self::Match(MatchCommand::Draw(1))),
+            ("draw", [count]) => count
+                .parse()
+                .map(|count| Self::Match(MatchCommand::Draw(count)))
+                .map_err(|_| format!("Not a count: {count}")),
+            ("win", []) => Ok(Self::Match(MatchCommand::Win)),
+            ("lose", []) => Ok(Self::Match(MatchCommand::Lose)),
+            ("goto", [screen]) => match screen.to_lowercase().as_str() {
+                "title" => Ok(Self::Goto(Screen::Title)),
+                "lobby" => Ok(Self::Goto(Screen::Lobby)),
+                "gameplay" => Ok(Self::Goto(Screen::Gameplay)),
+                "map" => Ok(Self::Goto(Screen::Map)),
+                other => Err(format!("Unknown screen: {other}")),
+            },
+            ("verbose", []) => Ok(Self::Verbose(None)),
+            ("verbose", [state]) => match state.to_lowercase().as_str() {
+                "on" => Ok(Self::Verbose(Some(true))),
+                "off" => Ok(Self::Verbose(Some(false))),
+                other => Err(format!("Unknown state: {other} (on or off)")),
+            },
+            (
+                "help" | "clear" | "give" | "cost" | "hp" | "draw" | "win" | "lose" | "goto"
+                | "verbose",
+                _,
+            ) => Err(format!("Wrong arguments for {name}. {HELP}")),
+            _ => Err(format!("Unknown command: {name}. {HELP}")),
+        }
+    }
+}
+
+/// Toggle the console and, while it is open, type into it and keep the keys
+/// from reaching the game.
+fn read_console_keys(
+    mut console: ResMut<DevConsole>,
+    mut keys: MessageReader<KeyboardInput>,
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+) {
+    if keyboard.just_pressed(TOGGLE_KEY) {
+        console.open = !console.open;
+    }
+    if !console.open {
+        keys.clear();
+        return;
+    }
+    for key in keys.read() {
+        if key.state != ButtonState::Pressed || key.key_code == TOGGLE_KEY {
+            continue;
+        }
+        match &key.logical_key {
+            Key::Character(text) => console.input.push_str(text),
+            Key::Space => console.input.push(' '),
+            Key::Backspace => {
+                console.input.pop();
+            }
+            Key::Enter => {
+                let line = std::mem::take(&mut console.input);
+                if !line.trim().is_empty() {
+                    console.submitted.push(line);
+                }
+            }
+            Key::Escape => console.open = false,
+            _ => {}
+        }
+    }
+    keyboard.reset_all();
+}
+
+fn run_console_commands(
+    mut console: ResMut<DevConsole>,
+    registry: Res<CardRegistry>,
+    mode: Res<GameMode>,
+    screen: Res<State<Screen>>,
+    mut player: Query<(Entity, &mut Hand, &mut Cost, &mut Health), With<LocalPlayer>>,
+    mut opponent: Query<(Entity, &mut Health), (With<Opponent>, Without<LocalPlayer>)>,
+    mut draw_messages: MessageWriter<DrawCardsMessage>,
+    mut death_messages: MessageWriter<DeathMessage>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    if console.submitted.is_empty() {
+        return;
+    }
+    let in_offline_match = *screen.get() == Screen::Gameplay && *mode == GameMode::Offline;
+
+    for line in std::mem::take(&mut console.submitted) {
+        console.print(format!("> {line}"));
+        let command = match ConsoleCommand::parse(&line) {
+            Ok(ConsoleCommand::Help) => {
+                console.print(HELP);
+                continue;
+            }
+            Ok(ConsoleCommand::Clear) => {
+                console.log.clear();
+                continue;
+            }
+            Ok(ConsoleCommand::Goto(target)) => {
+                next_screen.set(target);
+                continue;
+            }
+            Ok(ConsoleCommand::Verbose(state)) => {
+                let on = state.unwrap_or(!logging::verbose());
+                logging::set_verbose(on);
+                console.print(format!("Verbose logging {}", if on { "on" } else { "off" }));
+                continue;
+            }
+            Ok(ConsoleCommand::Match(command)) => command,
+            Err(error) => {
+                console.print(error);
+                continue;
+            }
+        };
+        if !in_offline_match {
+            console.print("Only available in an offline match");
+            continue;
+        }
+        let (Ok((player, mut hand, mut cost, mut health)), Ok((opponent, mut opponent_health))) =
+            (player.single_mut(), opponent.single_mut())
+        else {
+            console.print("No players on the table");
+            continue;
+        };
+
+        match command {
+            MatchCommand::Give(name) => match registry.get_by_name(&name) {
+                Some(_) if hand.len() >= MAX_HAND_SIZE => console.print("Hand is full"),
+                Some(def) => {
+                    hand.add_card(def.id);
+                    console.print(format!("Added {}", def.name));
+                }
+                None => console.print(format!("Unknown card: {name}")),
+            },
+            MatchCommand::Cost(amount) => {
+                cost.current = amount.clamp(0.0, cost.max);
+            }
+            MatchCommand::Hp(side, amount) => {
+                let health = match side {
+                    Side::Me => &mut health,
+                    Side::Opponent => &mut opponent_health,
+                };
+                health.current = amount.clamp(0.0, health.max);
+            }
+            MatchCommand::Draw(count) => {
+                draw_messages.write(DrawCardsMessage { player, count });
+            }
+            MatchCommand::Win => {
+                death_messages.write(DeathMessage { entity: opponent });
+            }
+            MatchCommand::Lose => {
+                death_messages.write(DeathMessage { entity: player });
+            }
+        }
+    }
+}
+
+fn sync_console_ui(
+    mut commands: Commands,
+    console: Res<DevConsole>,
+    root: Query<Entity, With<ConsoleRoot>>,
+    mut text: Query<&mut Text, With<ConsoleText>>,
+) {
+    if !console.is_changed() {
+        return;
+    }
+    if !console.open {
+        for entity in &root {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    let mut lines = console.log.join("\n");
+    if !lines.is_empty() {
+        lines.push('\n');
+    }
+    lines.push_str(&format!("> {}_", console.input));
+    if let Ok(mut text) = text.single_mut() {
+        text.0 = lines;
+        return;
+    }
+    commands.spawn((
+        Name::new("Dev Console"),
+        ConsoleRoot,
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(0),
+            width: percent(100),
+            padding: UiRect::all(px(10)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+        GlobalZIndex(50),
+        children![(
+            ConsoleText,
+            Text::new(lines),
+            TextFont::from_font_size(16.0),
+            TextColor(Color::srgb(0.8, 1.0, 0.8)),
+        )],
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_commands() {
+        assert_eq!(
+            ConsoleCommand::parse("give Pommel Strike"),
+            Ok(ConsoleCommand::Match(MatchCommand::Give(
+                "Pommel Strike".to_string()
+            )))
+        );
+        assert_eq!(
+            ConsoleCommand::parse("cost 3.5"),
+            Ok(ConsoleCommand::Match(MatchCommand::Cost(3.5)))
+        );
+        assert_eq!(
+            ConsoleCommand::parse("HP opp 1"),
+            Ok(ConsoleCommand::Match(MatchCommand::Hp(Side::Opponent, 1.0)))
+        );
+        assert_eq!(
+            ConsoleCommand::parse("draw"),
+            Ok(ConsoleCommand::Match(MatchCommand::Draw(1)))
+        );
+        assert_eq!(
+            ConsoleCommand::parse("draw 3"),
+            Ok(ConsoleCommand::Match(MatchCommand::Draw(3)))
+        );
+        assert_eq!(
+            ConsoleCommand::parse("goto lobby"),
+            Ok(ConsoleCommand::Goto(Screen::Lobby))
+        );
+        assert_eq!(
+            ConsoleCommand::parse("verbose"),
+            Ok(ConsoleCommand::Verbose(None))
+        );
+        assert_eq!(
+            ConsoleCommand::parse("verbose OFF"),
+            Ok(ConsoleCommand::Verbose(Some(false)))
+        );
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        assert!(ConsoleCommand::parse("").is_err());
+        assert!(ConsoleCommand::parse("teleport").is_err());
+        assert!(ConsoleCommand::parse("cost lots").is_err());
+        assert!(ConsoleCommand::parse("hp both 5").is_err());
+        assert!(ConsoleCommand::parse("win now").is_err());
+        assert!(ConsoleCommand::parse("verbose loud").is_err());
+    }
+}
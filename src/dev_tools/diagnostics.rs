@@ -0,0 +1,226 @@
+//! Frame time and network diagnostics overlay (F3).
+//!
+//! Shows FPS and a graph of recent frame times everywhere, and during online
+//! matches the GGRS frame state: predicted and confirmed frame, frames ahead
+//! of the peer, rollbacks per second, ping per remote peer, and the ICE route
+//! the connection took.
+
+use std::collections::VecDeque;
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use bevy_ggrs::{GgrsSchedule, RollbackFrameCount, Session};
+
+use crate::{
+    network::{IceRoute, NetworkPlayers, SensenGgrsConfig},
+    screens::Screen,
+};
+
+const TOGGLE_KEY: KeyCode = KeyCode::F3;
+/// Frames shown in the graph.
+const GRAPH_SAMPLES: usize = 120;
+const GRAPH_HEIGHT: f32 = 60.0;
+/// Graph pixels per millisecond.
+const GRAPH_SCALE: f32 = 2.0;
+const BAR_WIDTH: f32 = 2.0;
+/// Frame times (ms) still drawn as 60 and 30 fps, with headroom for jitter.
+const SMOOTH_FRAME_MS: f32 = 18.0;
+const SLOW_FRAME_MS: f32 = 35.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<FrameHistory>();
+    app.init_resource::<RollbackHistory>();
+    app.add_systems(OnEnter(Screen::Gameplay), reset_rollback_history);
+    app.add_systems(GgrsSchedule, count_rollbacks);
+    app.add_systems(
+        Update,
+        (
+            record_frame_time,
+            toggle_overlay.run_if(input_just_pressed(TOGGLE_KEY)),
+            update_overlay,
+        )
+            .chain(),
+    );
+}
+
+/// Recent frame times in milliseconds, oldest first.
+#[derive(Resource, Default)]
+struct FrameHistory(VecDeque<f32>);
+
+#[derive(Resource, Default)]
+struct RollbackHistory {
+    /// Real time of each rollback in the last second.
+    times: VecDeque<f32>,
+    /// Furthest rollback frame simulated so far.
+    newest_frame: Option<i64>,
+    /// Whether the frames being simulated are a resimulation.
+    resimulating: bool,
+}
+
+#[derive(Component)]
+struct DiagnosticsOverlay;
+
+#[derive(Component)]
+struct DiagnosticsText;
+
+#[derive(Component)]
+struct FrameBar(usize);
+
+fn record_frame_time(time: Res<Time<Real>>, mut history: ResMut<FrameHistory>) {
+    history.0.push_back(time.delta_secs() * 1000.0);
+    if history.0.len() > GRAPH_SAMPLES {
+        history.0.pop_front();
+    }
+}
+
+fn reset_rollback_history(mut history: ResMut<RollbackHistory>) {
+    *history = RollbackHistory::default();
+}
+
+/// Count rollbacks by watching the rollback frame step back.
+fn count_rollbacks(
+    time: Res<Time<Real>>,
+    frame: Res<RollbackFrameCount>,
+    mut history: ResMut<RollbackHistory>,
+) {
+    let frame = i64::from(frame.0);
+    if history.newest_frame.is_none_or(|newest| frame > newest) {
+        history.newest_frame = Some(frame);
+        history.resimulating = false;
+    } else if !history.resimulating {
+        history.resimulating = true;
+        history.times.push_back(time.elapsed_secs());
+    }
+    let now = time.elapsed_secs();
+    while history.times.front().is_some_and(|at| now - at > 1.0) {
+        history.times.pop_front();
+    }
+}
+
+fn toggle_overlay(mut commands: Commands, overlay: Query<Entity, With<DiagnosticsOverlay>>) {
+    if let Ok(entity) = overlay.single() {
+        commands.entity(entity).despawn();
+        return;
+    }
+    commands
+        .spawn((
+            Name::new("Diagnostics Overlay"),
+            DiagnosticsOverlay,
+            Node {
+                position_type: PositionType::Absolute,
+                top: px(10),
+                right: px(10),
+                flex_direction: FlexDirection::Column,
+                row_gap: px(6),
+                padding: UiRect::all(px(8)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.75)),
+            GlobalZIndex(40),
+            Pickable::IGNORE,
+        ))
+        .with_children(|overlay| {
+            overlay.spawn((
+                DiagnosticsText,
+                Text::default(),
+                TextFont::from_font_size(14.0),
+                TextColor(Color::WHITE),
+            ));
+            overlay
+                .spawn(Node {
+                    height: px(GRAPH_HEIGHT),
+                    align_items: AlignItems::FlexEnd,
+                    ..default()
+                })
+                .with_children(|graph| {
+                    for index in 0..GRAPH_SAMPLES {
+                        graph.spawn((
+                            FrameBar(index),
+                            Node {
+                                width: px(BAR_WIDTH),
+                                height: px(0),
+                                ..default()
+                            },
+                            BackgroundColor(Color::NONE),
+                        ));
+                    }
+                });
+        });
+}
+
+fn update_overlay(
+    frames: Res<FrameHistory>,
+    rollbacks: Res<RollbackHistory>,
+    session: Option<Res<Session<SensenGgrsConfig>>>,
+    players: Option<Res<NetworkPlayers>>,
+    route: Option<Res<IceRoute>>,
+    mut text: Query<&mut Text, With<DiagnosticsText>>,
+    mut bars: Query<(&FrameBar, &mut Node, &mut BackgroundColor)>,
+) {
+    let Ok(mut text) = text.single_mut() else {
+        return;
+    };
+
+    let recent = &frames.0;
+    let average_ms = recent.iter().sum::<f32>() / recent.len().max(1) as f32;
+    let worst_ms = recent.iter().copied().fold(0.0, f32::max);
+    let mut lines = vec![format!(
+        "FPS {:.0}  frame {:.1} ms (worst {:.1})",
+        1000.0 / average_ms.max(f32::EPSILON),
+        recent.back().copied().unwrap_or_default(),
+        worst_ms,
+    )];
+
+    if let Some(Session::P2P(session)) = session.as_deref() {
+        lines.push(format!(
+            "Frame {} predicted, {} confirmed",
+            session.current_frame(),
+            session.confirmed_frame(),
+        ));
+        lines.push(format!(
+            "Frames ahead {}  rollbacks/s {}",
+            session.frames_ahead(),
+            rollbacks.times.len(),
+        ));
+        if let Some(route) = &route {
+            lines.push(format!("Route {}", route.label()));
+        }
+        for handle in session.remote_player_handles() {
+            let peer = players
+                .as_ref()
+                .and_then(|players| players.handles.get(handle))
+                .map_or_else(|| format!("handle {handle}"), |peer| format!("{peer:?}"));
+            match session.network_stats(handle) {
+                Ok(stats) => lines.push(format!(
+                    "{peer}: ping {} ms, {} kbps, behind {}/{}",
+                    stats.ping,
+                    stats.kbps_sent,
+                    stats.local_frames_behind,
+                    stats.remote_frames_behind,
+                )),
+                Err(_) => lines.push(format!("{peer}: no stats yet")),
+            }
+        }
+    }
+    text.0 = lines.join("\n");
+
+    // Newest frame on the right.
+    let offset = GRAPH_SAMPLES - recent.len();
+    for (bar, mut node, mut color) in &mut bars {
+        let Some(ms) = bar
+            .0
+            .checked_sub(offset)
+            .and_then(|index| recent.get(index))
+        else {
+            node.height = px(0);
+            continue;
+        };
+        node.height = px((ms * GRAPH_SCALE).min(GRAPH_HEIGHT));
+        color.0 = if *ms <= SMOOTH_FRAME_MS {
+            Color::srgb(0.3, 0.8, 0.3)
+        } else if *ms <= SLOW_FRAME_MS {
+            Color::srgb(0.9, 0.8, 0.2)
+        } else {
+            Color::srgb(0.9, 0.3, 0.3)
+        };
+    }
+}
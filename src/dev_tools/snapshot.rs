@@ -0,0 +1,172 @@
+//! Whole-match state for external test tooling, read over BRP.
+//!
+//! `GameStateSnapshot` is rebuilt every frame from the screen, the match
+//! state and both players, so a test script can assert on one resource
+//! instead of querying component by component. The player components are
+//! registered too, for finer `world.query` calls.
+
+use bevy::prelude::*;
+use bevy_ggrs::RollbackFrameCount;
+
+use crate::{
+    game::{
+        Acceleration, BarricadeEffect, Block, BrutalityEffect, CardId, CombustEffect,
+        CorruptionEffect, Cost, CostCapBoost, DarkEmbraceEffect, Deck, DemonFormEffect,
+        DiscardPile, EvolveEffect, FeelNoPainEffect, FireBreathingEffect, GameMode, GameResult,
+        Hand, Health, JuggernautEffect, LocalPlayer, MetallicizeEffect, Opponent, PlayLog,
+        PlayQueue, PlayerHandle, Poison, RageEffect, RuptureEffect, Strength, Thorns, Vulnerable,
+        Weak,
+    },
+    screens::Screen,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<GameStateSnapshot>();
+    app.register_type::<GameStateSnapshot>();
+
+    app.register_type::<Hand>()
+        .register_type::<Deck>()
+        .register_type::<DiscardPile>()
+        .register_type::<PlayLog>()
+        .register_type::<PlayQueue>()
+        .register_type::<Cost>()
+        .register_type::<Health>()
+        .register_type::<Block>()
+        .register_type::<Thorns>()
+        .register_type::<Strength>()
+        .register_type::<Vulnerable>()
+        .register_type::<Weak>()
+        .register_type::<Poison>()
+        .register_type::<Acceleration>()
+        .register_type::<CostCapBoost>()
+        .register_type::<RageEffect>()
+        .register_type::<MetallicizeEffect>()
+        .register_type::<DemonFormEffect>()
+        .register_type::<BarricadeEffect>()
+        .register_type::<JuggernautEffect>()
+        .register_type::<CombustEffect>()
+        .register_type::<DarkEmbraceEffect>()
+        .register_type::<EvolveEffect>()
+        .register_type::<FeelNoPainEffect>()
+        .register_type::<FireBreathingEffect>()
+        .register_type::<RuptureEffect>()
+        .register_type::<CorruptionEffect>()
+        .register_type::<BrutalityEffect>();
+
+    app.add_systems(Last, update_snapshot);
+}
+
+/// The match as external tooling sees it.
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+pub struct GameStateSnapshot {
+    pub screen: Screen,
+    pub mode: GameMode,
+    /// `None` outside a match.
+    pub result: Option<GameResult>,
+    /// Rollback frame, online only.
+    pub frame: Option<i32>,
+    pub player: Option<PlayerSnapshot>,
+    pub opponent: Option<PlayerSnapshot>,
+}
+
+/// One side of the table.
+#[derive(Reflect, Debug, Clone, Default)]
+pub struct PlayerSnapshot {
+    pub handle: usize,
+    pub health: f32,
+    pub max_health: f32,
+    pub block: f32,
+    pub thorns: f32,
+    pub cost: f32,
+    pub max_cost: f32,
+    pub strength: f32,
+    /// Seconds left.
+    pub vulnerable: f32,
+    /// Seconds left.
+    pub weak: f32,
+    pub poison: f32,
+    pub hand: Vec<CardId>,
+    /// Cards queued to play once affordable, oldest first.
+    pub queued: Vec<CardId>,
+    pub deck: Vec<CardId>,
+    pub discard: Vec<CardId>,
+    pub played: Vec<CardId>,
+}
+
+type PlayerItem<'a> = (
+    &'a PlayerHandle,
+    &'a Health,
+    &'a Block,
+    &'a Thorns,
+    &'a Cost,
+    &'a Strength,
+    &'a Vulnerable,
+    &'a Weak,
+    &'a Poison,
+    &'a Hand,
+    &'a PlayQueue,
+    &'a Deck,
+    &'a DiscardPile,
+    &'a PlayLog,
+);
+
+fn snapshot_player(
+    (
+        handle,
+        health,
+        block,
+        thorns,
+        cost,
+        strength,
+        vulnerable,
+        weak,
+        poison,
+        hand,
+        queue,
+        deck,
+        discard,
+        play_log,
+    ): PlayerItem<'_>,
+) -> PlayerSnapshot {
+    PlayerSnapshot {
+        handle: handle.0,
+        health: health.current,
+        max_health: health.max,
+        block: block.current,
+        thorns: thorns.damage,
+        cost: cost.current,
+        max_cost: cost.max,
+        strength: strength.amount,
+        vulnerable: vulnerable.duration,
+        weak: weak.duration,
+        poison: poison.stacks,
+        hand: hand.cards.clone(),
+        queued: queue.cards.iter().map(|queued| queued.card).collect(),
+        deck: deck.cards.clone(),
+        discard: discard.cards.clone(),
+        played: play_log.cards.clone(),
+    }
+}
+
+fn update_snapshot(
+    mut snapshot: ResMut<GameStateSnapshot>,
+    screen: Res<State<Screen>>,
+    mode: Res<GameMode>,
+    result: Res<State<GameResult>>,
+    frame: Option<Res<RollbackFrameCount>>,
+    player: Query<PlayerItem<'static>, With<LocalPlayer>>,
+    opponent: Query<PlayerItem<'static>, With<Opponent>>,
+) {
+    let in_match = *screen.get() == Screen::Gameplay;
+    *snapshot = GameStateSnapshot {
+        screen: *screen.get(),
+        mode: *mode,
+        result: in_match.then(|| *result.get()),
+        frame: frame
+            .filter(|_| *mode == GameMode::Online)
+            .map(|frame| frame.0),
+        player: player.single().ok().map(snapshot_player),
+        opponent: opponent.single().ok().map(snapshot_player),
+    };
+}
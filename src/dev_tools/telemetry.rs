@@ -0,0 +1,254 @@
+//! Card telemetry dashboard (F2) - per-card play rate, winrate when drawn and
+//! cost banked at play time, aggregated over every match in this session.
+
+use std::collections::{HashMap, HashSet};
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+
+use crate::{
+    game::{CardId, CardPlayedMessage, CardRegistry, Cost, GameResult, Hand, LocalPlayer},
+    screens::Screen,
+    theme::widget,
+};
+
+const TOGGLE_KEY: KeyCode = KeyCode::F2;
+/// Cards listed per chart, best first.
+const CHART_ROWS: usize = 8;
+/// Width of a full bar.
+const BAR_WIDTH: f32 = 200.0;
+const BAR_COLOR: Color = Color::srgb(0.3, 0.6, 0.9);
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<CardTelemetry>();
+    app.init_resource::<DrawnThisMatch>();
+    app.add_systems(OnEnter(Screen::Gameplay), clear_drawn_this_match);
+    app.add_systems(
+        Update,
+        (record_plays, record_drawn)
+            .run_if(in_state(Screen::Gameplay).and(in_state(GameResult::Playing))),
+    );
+    app.add_systems(OnEnter(GameResult::Victory), record_victory);
+    app.add_systems(OnEnter(GameResult::Defeat), record_defeat);
+    app.add_systems(
+        Update,
+        (
+            toggle_dashboard.run_if(input_just_pressed(TOGGLE_KEY)),
+            refresh_dashboard,
+        )
+            .chain(),
+    );
+}
+
+/// Aggregated stats for one card, local player only.
+#[derive(Debug, Default, Clone, Copy)]
+struct CardStats {
+    plays: u32,
+    /// Finished matches in which the card reached the hand.
+    games_drawn: u32,
+    wins_drawn: u32,
+    /// Sum of cost left in the bank right after each play.
+    cost_banked: f32,
+}
+
+#[derive(Resource, Default)]
+struct CardTelemetry {
+    cards: HashMap<CardId, CardStats>,
+}
+
+/// Cards that have been in the local hand during the current match.
+#[derive(Resource, Default)]
+struct DrawnThisMatch(HashSet<CardId>);
+
+#[derive(Component)]
+struct TelemetryDashboard;
+
+fn clear_drawn_this_match(mut drawn: ResMut<DrawnThisMatch>) {
+    drawn.0.clear();
+}
+
+fn record_plays(
+    mut played: MessageReader<CardPlayedMessage>,
+    local_query: Query<&Cost, With<LocalPlayer>>,
+    mut telemetry: ResMut<CardTelemetry>,
+) {
+    for message in played.read() {
+        let Ok(cost) = local_query.get(message.player) else {
+            continue;
+        };
+        let stats = telemetry.cards.entry(message.card_id).or_default();
+        stats.plays += 1;
+        stats.cost_banked += cost.current;
+    }
+}
+
+fn record_drawn(
+    hand_query: Query<&Hand, (With<LocalPlayer>, Changed<Hand>)>,
+    mut drawn: ResMut<DrawnThisMatch>,
+) {
+    for hand in &hand_query {
+        drawn.0.extend(hand.cards.iter().copied());
+    }
+}
+
+fn record_victory(drawn: Res<DrawnThisMatch>, telemetry: ResMut<CardTelemetry>) {
+    record_result(&drawn, telemetry, true);
+}
+
+fn record_defeat(drawn: Res<DrawnThisMatch>, telemetry: ResMut<CardTelemetry>) {
+    record_result(&drawn, telemetry, false);
+}
+
+fn record_result(drawn: &DrawnThisMatch, mut telemetry: ResMut<CardTelemetry>, won: bool) {
+    for card in &drawn.0 {
+        let stats = telemetry.cards.entry(*card).or_default();
+        stats.games_drawn += 1;
+        if won {
+            stats.wins_drawn += 1;
+        }
+    }
+}
+
+fn toggle_dashboard(
+    mut commands: Commands,
+    dashboard: Query<Entity, With<TelemetryDashboard>>,
+    mut telemetry: ResMut<CardTelemetry>,
+) {
+    if let Ok(entity) = dashboard.single() {
+        commands.entity(entity).despawn();
+        return;
+    }
+    commands.spawn((
+        Name::new("Telemetry Dashboard"),
+        TelemetryDashboard,
+        Node {
+            position_type: PositionType::Absolute,
+            width: percent(100),
+            height: percent(100),
+            padding: UiRect::all(px(20)),
+            column_gap: px(30),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+        GlobalZIndex(10),
+    ));
+    // Build the charts on the next refresh.
+    telemetry.set_changed();
+}
+
+fn refresh_dashboard(
+    mut commands: Commands,
+    telemetry: Res<CardTelemetry>,
+    registry: Res<CardRegistry>,
+    dashboard: Query<Entity, With<TelemetryDashboard>>,
+) {
+    let Ok(root) = dashboard.single() else {
+        return;
+    };
+    if !telemetry.is_changed() {
+        return;
+    }
+
+    let name = |card: CardId| {
+        registry
+            .get(card)
+            .map_or_else(|| "???".to_string(), |def| def.name.clone())
+    };
+
+    let play_rate: Vec<(String, f32, String)> = top_rows(&telemetry, |stats| {
+        (stats.games_drawn > 0).then(|| stats.plays as f32 / stats.games_drawn as f32)
+    })
+    .into_iter()
+    .map(|(card, rate)| (name(card), rate, format!("{rate:.1}/match")))
+    .collect();
+    let winrate: Vec<(String, f32, String)> = top_rows(&telemetry, |stats| {
+        (stats.games_drawn > 0).then(|| stats.wins_drawn as f32 / stats.games_drawn as f32)
+    })
+    .into_iter()
+    .map(|(card, rate)| (name(card), rate, format!("{:.0}%", rate * 100.0)))
+    .collect();
+    let banked: Vec<(String, f32, String)> = top_rows(&telemetry, |stats| {
+        (stats.plays > 0).then(|| stats.cost_banked / stats.plays as f32)
+    })
+    .into_iter()
+    .map(|(card, cost)| (name(card), cost, format!("{cost:.1}")))
+    .collect();
+
+    commands.entity(root).despawn_children();
+    commands.entity(root).with_children(|parent| {
+        spawn_chart(parent, "Plays per match drawn", &play_rate);
+        spawn_chart(parent, "Winrate when drawn", &winrate);
+        spawn_chart(parent, "Cost banked at play", &banked);
+    });
+}
+
+/// Highest values of a per-card metric, skipping cards it doesn't apply to.
+fn top_rows(
+    telemetry: &CardTelemetry,
+    metric: impl Fn(&CardStats) -> Option<f32>,
+) -> Vec<(CardId, f32)> {
+    let mut rows: Vec<(CardId, f32)> = telemetry
+        .cards
+        .iter()
+        .filter_map(|(card, stats)| metric(stats).map(|value| (*card, value)))
+        .collect();
+    rows.sort_by(|a, b| b.1.total_cmp(&a.1).then((a.0 as u32).cmp(&(b.0 as u32))));
+    rows.truncate(CHART_ROWS);
+    rows
+}
+
+fn spawn_chart(parent: &mut ChildSpawnerCommands, title: &str, rows: &[(String, f32, String)]) {
+    let max = rows
+        .iter()
+        .map(|(_, value, _)| *value)
+        .fold(0.0_f32, f32::max);
+    parent
+        .spawn((
+            Name::new("Telemetry Chart"),
+            Node {
+                flex_direction: FlexDirection::Column,
+                row_gap: px(6),
+                ..default()
+            },
+        ))
+        .with_children(|chart| {
+            chart.spawn(widget::label(title));
+            if rows.is_empty() {
+                chart.spawn(widget::label("No data yet"));
+            }
+            for (name, value, text) in rows {
+                let fraction = if max > 0.0 { value / max } else { 0.0 };
+                chart.spawn(bar_row(name, fraction, text));
+            }
+        });
+}
+
+fn bar_row(name: &str, fraction: f32, text: &str) -> impl Bundle {
+    (
+        Name::new("Telemetry Bar"),
+        Node {
+            column_gap: px(8),
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        children![
+            (
+                Text::new(name),
+                TextFont::from_font_size(14.0),
+                Node {
+                    width: px(120),
+                    ..default()
+                },
+            ),
+            (
+                Node {
+                    width: px(BAR_WIDTH * fraction),
+                    height: px(14),
+                    ..default()
+                },
+                BackgroundColor(BAR_COLOR),
+            ),
+            (Text::new(text), TextFont::from_font_size(14.0)),
+        ],
+    )
+}
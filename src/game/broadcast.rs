@@ -0,0 +1,387 @@
+//! Broadcast layout - top-down camera and face-up hands for streaming matches.
+//!
+//! The camera move itself lives in `camera.rs`, which eases to the top-down
+//! view while [`BroadcastLayout::enabled`] is set.
+//!
+//! Offered offline and while watching a replay, with a `[B]` hint in the
+//! corner: in an online match both hands face-up would leak the opponent's
+//! hand to the local player.
+
+use std::collections::VecDeque;
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+
+use super::{
+    CardPlayedMessage, CardRegistry, Cost, Hand, Health, LocalPlayer, Opponent, is_offline,
+    ui::GameUiRoot,
+};
+use crate::{AppSystems, replay::is_replay, screens::Screen};
+
+/// Number of plays kept in the play-by-play ticker.
+const TICKER_LENGTH: usize = 6;
+
+pub fn plugin(app: &mut App) {
+    app.init_resource::<BroadcastLayout>();
+    app.init_resource::<BroadcastTicker>();
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        (
+            reset_ticker,
+            spawn_broadcast_hint.run_if(is_offline.or(is_replay)),
+        ),
+    );
+    app.add_systems(OnExit(Screen::Gameplay), disable_broadcast_layout);
+    app.add_systems(
+        Update,
+        (
+            toggle_broadcast_layout.run_if(
+                is_offline
+                    .or(is_replay)
+                    .and(input_just_pressed(KeyCode::KeyB)),
+            ),
+            update_broadcast_hint.run_if(resource_changed::<BroadcastLayout>),
+            record_plays,
+            update_broadcast_hands,
+            update_broadcast_bars,
+            update_broadcast_ticker,
+        )
+            .chain()
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// Whether the broadcast layout is active.
+#[derive(Resource, Default)]
+pub struct BroadcastLayout {
+    pub enabled: bool,
+}
+
+/// Recent card plays, newest last.
+#[derive(Resource, Default)]
+struct BroadcastTicker(VecDeque<String>);
+
+/// Marker for the broadcast overlay root.
+#[derive(Component)]
+struct BroadcastRoot;
+
+/// Face-up hand text for one side of the table.
+#[derive(Component)]
+struct BroadcastHand {
+    local: bool,
+}
+
+/// HP bar fill for one side of the table.
+#[derive(Component)]
+struct BroadcastHpBar {
+    local: bool,
+}
+
+/// Cost bar fill for one side of the table.
+#[derive(Component)]
+struct BroadcastCostBar {
+    local: bool,
+}
+
+/// HP and cost readout for one side of the table.
+#[derive(Component)]
+struct BroadcastStats {
+    local: bool,
+}
+
+#[derive(Component)]
+struct BroadcastTickerText;
+
+/// The key hint for toggling the layout.
+#[derive(Component)]
+struct BroadcastHint;
+
+fn reset_ticker(mut ticker: ResMut<BroadcastTicker>) {
+    ticker.0.clear();
+}
+
+fn toggle_broadcast_layout(
+    mut commands: Commands,
+    mut layout: ResMut<BroadcastLayout>,
+    mut game_ui: Query<&mut Visibility, With<GameUiRoot>>,
+    root: Query<Entity, With<BroadcastRoot>>,
+) {
+    layout.enabled = !layout.enabled;
+
+    if layout.enabled {
+        for mut visibility in &mut game_ui {
+            *visibility = Visibility::Hidden;
+        }
+        commands.spawn(broadcast_root());
+    } else {
+        for mut visibility in &mut game_ui {
+            *visibility = Visibility::Inherited;
+        }
+        for entity in &root {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn spawn_broadcast_hint(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Broadcast Hint"),
+        BroadcastHint,
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(12),
+            right: px(16),
+            ..default()
+        },
+        Text::new(hint_text(false)),
+        TextFont::from_font_size(16.0),
+        TextColor(Color::srgba(1.0, 1.0, 1.0, 0.6)),
+        Pickable::IGNORE,
+        DespawnOnExit(Screen::Gameplay),
+    ));
+}
+
+fn update_broadcast_hint(
+    layout: Res<BroadcastLayout>,
+    mut hints: Query<&mut Text, With<BroadcastHint>>,
+) {
+    for mut text in &mut hints {
+        text.0 = hint_text(layout.enabled).to_string();
+    }
+}
+
+fn hint_text(enabled: bool) -> &'static str {
+    if enabled {
+        "[B] Normal view"
+    } else {
+        "[B] Broadcast view"
+    }
+}
+
+fn disable_broadcast_layout(mut layout: ResMut<BroadcastLayout>) {
+    layout.enabled = false;
+}
+
+fn broadcast_root() -> impl Bundle {
+    (
+        Name::new("Broadcast UI"),
+        BroadcastRoot,
+        Node {
+            position_type: PositionType::Absolute,
+            width: percent(100),
+            height: percent(100),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::SpaceBetween,
+            ..default()
+        },
+        Pickable::IGNORE,
+        DespawnOnExit(Screen::Gameplay),
+        children![
+            side_panel("Enemy", false, Color::srgb(0.8, 0.2, 0.2)),
+            (
+                Name::new("Broadcast Ticker"),
+                Node {
+                    padding: UiRect::axes(px(20), px(6)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+                children![(
+                    BroadcastTickerText,
+                    Text::new(""),
+                    TextFont::from_font_size(18.0),
+                    TextColor(Color::srgb(0.9, 0.9, 0.6)),
+                )],
+            ),
+            side_panel("You", true, Color::srgb(0.2, 0.8, 0.2)),
+        ],
+    )
+}
+
+fn side_panel(label: &'static str, local: bool, hp_color: Color) -> impl Bundle {
+    (
+        Name::new(format!("Broadcast Panel {label}")),
+        Node {
+            flex_direction: FlexDirection::Column,
+            row_gap: px(8),
+            padding: UiRect::all(px(16)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.85)),
+        children![
+            (
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: px(16),
+                    ..default()
+                },
+                children![
+                    (
+                        Text::new(label),
+                        TextFont::from_font_size(32.0),
+                        TextColor(Color::WHITE),
+                    ),
+                    (
+                        Name::new("Broadcast HP Bar BG"),
+                        Node {
+                            width: px(480),
+                            height: px(32),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+                        children![(
+                            BroadcastHpBar { local },
+                            Node {
+                                width: percent(100),
+                                height: percent(100),
+                                ..default()
+                            },
+                            BackgroundColor(hp_color),
+                        )],
+                    ),
+                    (
+                        Name::new("Broadcast Cost Bar BG"),
+                        Node {
+                            width: px(240),
+                            height: px(20),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+                        children![(
+                            BroadcastCostBar { local },
+                            Node {
+                                width: percent(0),
+                                height: percent(100),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(1.0, 0.8, 0.2)),
+                        )],
+                    ),
+                    (
+                        BroadcastStats { local },
+                        Text::new(""),
+                        TextFont::from_font_size(24.0),
+                        TextColor(Color::WHITE),
+                    ),
+                ],
+            ),
+            (
+                BroadcastHand { local },
+                Text::new(""),
+                TextFont::from_font_size(20.0),
+                TextColor(Color::srgb(0.8, 0.8, 1.0)),
+            ),
+        ],
+    )
+}
+
+fn record_plays(
+    mut played: MessageReader<CardPlayedMessage>,
+    registry: Res<CardRegistry>,
+    local_query: Query<(), With<LocalPlayer>>,
+    mut ticker: ResMut<BroadcastTicker>,
+) {
+    for message in played.read() {
+        let who = if local_query.contains(message.player) {
+            "You"
+        } else {
+            "Enemy"
+        };
+        let card = registry
+            .get(message.card_id)
+            .map(|def| def.name.as_str())
+            .unwrap_or("???");
+        ticker.0.push_back(format!("{who}: {card}"));
+        if ticker.0.len() > TICKER_LENGTH {
+            ticker.0.pop_front();
+        }
+    }
+}
+
+fn update_broadcast_hands(
+    layout: Res<BroadcastLayout>,
+    registry: Res<CardRegistry>,
+    local_query: Query<&Hand, With<LocalPlayer>>,
+    opponent_query: Query<&Hand, With<Opponent>>,
+    mut texts: Query<(&BroadcastHand, &mut Text)>,
+) {
+    if !layout.enabled {
+        return;
+    }
+
+    for (side, mut text) in &mut texts {
+        let hand = if side.local {
+            local_query.single()
+        } else {
+            opponent_query.single()
+        };
+        let Ok(hand) = hand else {
+            continue;
+        };
+        text.0 = hand
+            .cards
+            .iter()
+            .enumerate()
+            .map(|(index, card_id)| match registry.get(*card_id) {
+                Some(def) => format!("[{}] {} ({:.1})", index + 1, def.name, def.cost),
+                None => format!("[{}] ???", index + 1),
+            })
+            .collect::<Vec<_>>()
+            .join("   ");
+    }
+}
+
+fn update_broadcast_bars(
+    layout: Res<BroadcastLayout>,
+    local_query: Query<(&Health, &Cost), With<LocalPlayer>>,
+    opponent_query: Query<(&Health, &Cost), With<Opponent>>,
+    mut hp_bars: Query<(&BroadcastHpBar, &mut Node), Without<BroadcastCostBar>>,
+    mut cost_bars: Query<(&BroadcastCostBar, &mut Node), Without<BroadcastHpBar>>,
+    mut stats: Query<(&BroadcastStats, &mut Text)>,
+) {
+    if !layout.enabled {
+        return;
+    }
+
+    let side = |local: bool| {
+        if local {
+            local_query.single().ok()
+        } else {
+            opponent_query.single().ok()
+        }
+    };
+
+    for (bar, mut node) in &mut hp_bars {
+        if let Some((health, _)) = side(bar.local) {
+            node.width = Val::Percent(health.percentage() * 100.0);
+        }
+    }
+    for (bar, mut node) in &mut cost_bars {
+        if let Some((_, cost)) = side(bar.local) {
+            node.width = Val::Percent(cost.fill() * 100.0);
+        }
+    }
+    for (readout, mut text) in &mut stats {
+        if let Some((health, cost)) = side(readout.local) {
+            text.0 = format!(
+                "{:.0} / {:.0} HP   Cost {:.1}",
+                health.current, health.max, cost.current
+            );
+        }
+    }
+}
+
+fn update_broadcast_ticker(
+    layout: Res<BroadcastLayout>,
+    ticker: Res<BroadcastTicker>,
+    mut texts: Query<&mut Text, With<BroadcastTickerText>>,
+) {
+    if !layout.enabled {
+        return;
+    }
+
+    let line = ticker.0.iter().cloned().collect::<Vec<_>>().join("  |  ");
+    for mut text in &mut texts {
+        text.0 = line.clone();
+    }
+}
@@ -0,0 +1,188 @@
+//! The gameplay camera: a chosen preset angle, smooth moves between angles
+//! and a slow idle sway so the table never looks frozen.
+//!
+//! The angle is picked in the settings and saved across sessions. The
+//! broadcast layout borrows the camera for its top-down view and hands it
+//! back when turned off; both directions ease like any other angle change.
+
+use bevy::prelude::*;
+
+use super::broadcast::BroadcastLayout;
+use crate::{
+    AppSystems, MainCamera,
+    persist::{self, Persisted, save_on_change},
+    screens::Screen,
+};
+
+const SAVE_KEY: &str = "camera.txt";
+/// How quickly the camera closes the gap to a new angle (per second).
+const TRANSITION_EASE: f32 = 4.0;
+/// Largest sway away from the preset, in radians.
+const SWAY_YAW: f32 = 0.008;
+const SWAY_PITCH: f32 = 0.005;
+/// Spot on the table every preset looks at.
+const TABLE_CENTER: Vec3 = Vec3::ZERO;
+
+pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(CameraSettings::load());
+    app.init_resource::<CameraRig>();
+    app.add_systems(OnEnter(Screen::Gameplay), snap_camera);
+    app.add_systems(OnExit(Screen::Gameplay), reset_camera);
+    app.add_systems(
+        Update,
+        (
+            save_on_change::<CameraSettings>.run_if(resource_changed::<CameraSettings>),
+            drive_camera
+                .in_set(AppSystems::Update)
+                .run_if(in_state(Screen::Gameplay)),
+        ),
+    );
+}
+
+/// Preset gameplay camera angles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CameraAngle {
+    #[default]
+    Default,
+    TopDown,
+    Cinematic,
+}
+
+impl CameraAngle {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::TopDown => "Top-Down",
+            Self::Cinematic => "Cinematic",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Default => Self::TopDown,
+            Self::TopDown => Self::Cinematic,
+            Self::Cinematic => Self::Default,
+        }
+    }
+
+    /// Where the camera sits for this angle.
+    pub fn pose(self) -> Transform {
+        let eye = match self {
+            // Matches the camera spawned in `main.rs`, so menus look the same.
+            Self::Default => Vec3::new(0.0, 12.0, 14.0),
+            Self::TopDown => Vec3::new(0.0, 20.0, 3.0),
+            Self::Cinematic => Vec3::new(0.0, 6.5, 15.5),
+        };
+        Transform::from_translation(eye).looking_at(TABLE_CENTER, Vec3::Y)
+    }
+
+    fn save_name(self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::TopDown => "top_down",
+            Self::Cinematic => "cinematic",
+        }
+    }
+}
+
+/// Camera preferences, persisted across sessions.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct CameraSettings {
+    pub angle: CameraAngle,
+}
+
+impl CameraSettings {
+    fn load() -> Self {
+        let mut settings = Self::default();
+        let Some(contents) = persist::load(SAVE_KEY) else {
+            return settings;
+        };
+        for (key, value) in contents.lines().filter_map(|line| line.split_once('=')) {
+            if key == "angle" {
+                settings.angle = match value {
+                    "top_down" => CameraAngle::TopDown,
+                    "cinematic" => CameraAngle::Cinematic,
+                    _ => CameraAngle::Default,
+                };
+            }
+        }
+        settings
+    }
+}
+
+impl Persisted for CameraSettings {
+    fn save(&self) {
+        persist::store(SAVE_KEY, &format!("angle={}", self.angle.save_name()));
+    }
+}
+
+/// The camera pose before sway, easing toward the wanted angle.
+#[derive(Resource, Default)]
+struct CameraRig {
+    pose: Transform,
+    /// Seconds of sway so far this match.
+    sway_time: f32,
+}
+
+fn target_pose(settings: &CameraSettings, broadcast: &BroadcastLayout) -> Transform {
+    if broadcast.enabled {
+        // Straight down, with the opponent's side at the top of the screen.
+        Transform::from_xyz(0.0, 24.0, 0.0).looking_at(TABLE_CENTER, Vec3::NEG_Z)
+    } else {
+        settings.angle.pose()
+    }
+}
+
+/// A match opens at the chosen angle rather than flying in from the menu pose.
+fn snap_camera(
+    settings: Res<CameraSettings>,
+    broadcast: Res<BroadcastLayout>,
+    mut rig: ResMut<CameraRig>,
+    mut camera: Query<&mut Transform, With<MainCamera>>,
+) {
+    rig.pose = target_pose(&settings, &broadcast);
+    rig.sway_time = 0.0;
+    for mut transform in &mut camera {
+        *transform = rig.pose;
+    }
+}
+
+/// The menus expect the camera where it was spawned.
+fn reset_camera(mut camera: Query<&mut Transform, With<MainCamera>>) {
+    for mut transform in &mut camera {
+        *transform = CameraAngle::Default.pose();
+    }
+}
+
+fn drive_camera(
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    broadcast: Res<BroadcastLayout>,
+    mut rig: ResMut<CameraRig>,
+    mut camera: Query<&mut Transform, With<MainCamera>>,
+) {
+    let dt = time.delta_secs();
+    let target = target_pose(&settings, &broadcast);
+    let blend = 1.0 - (-TRANSITION_EASE * dt).exp();
+    rig.pose.translation = rig.pose.translation.lerp(target.translation, blend);
+    rig.pose.rotation = rig.pose.rotation.slerp(target.rotation, blend);
+
+    // Two slow, unrelated periods so the drift never visibly repeats. The
+    // broadcast view stays still for streaming.
+    rig.sway_time += dt;
+    let sway = if broadcast.enabled {
+        Quat::IDENTITY
+    } else {
+        let t = rig.sway_time;
+        Quat::from_euler(
+            EulerRot::YXZ,
+            SWAY_YAW * (t * 0.21).sin(),
+            SWAY_PITCH * (t * 0.13).sin(),
+            0.0,
+        )
+    };
+    for mut transform in &mut camera {
+        transform.translation = rig.pose.translation;
+        transform.rotation = rig.pose.rotation * sway;
+    }
+}
@@ -0,0 +1,74 @@
+//! Per-card front art.
+//!
+//! Each card may have its own front at `assets/cards/<card>.png` (see
+//! [`CardDef::art_path`]). Art is loaded the first time a card is shown, not
+//! up front. Cards without a file keep their tile in the shared card atlas,
+//! which doubles as the placeholder while art is being drawn.
+
+use bevy::{
+    asset::AssetLoadFailedEvent,
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+
+use super::{CardDef, CardId};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<CardArt>();
+    app.add_systems(Update, mark_missing_card_art);
+}
+
+/// Handles to card art loaded so far, and which cards turned out to have none.
+#[derive(Resource, Default)]
+pub(super) struct CardArt {
+    handles: HashMap<CardId, Handle<Image>>,
+    missing: HashSet<CardId>,
+}
+
+impl CardArt {
+    /// The card's own art, starting the load on first use. `None` once the
+    /// file is known to be missing.
+    pub(super) fn get(
+        &mut self,
+        def: &CardDef,
+        asset_server: &AssetServer,
+    ) -> Option<Handle<Image>> {
+        if self.missing.contains(&def.id) {
+            return None;
+        }
+        let handle = self
+            .handles
+            .entry(def.id)
+            .or_insert_with(|| asset_server.load(def.art_path()));
+        Some(handle.clone())
+    }
+
+    /// Art already requested for the card, without starting a load.
+    pub(super) fn loaded(&self, card_id: CardId) -> Option<Handle<Image>> {
+        if self.missing.contains(&card_id) {
+            return None;
+        }
+        self.handles.get(&card_id).cloned()
+    }
+
+    pub(super) fn is_missing(&self, card_id: CardId) -> bool {
+        self.missing.contains(&card_id)
+    }
+}
+
+fn mark_missing_card_art(
+    mut failed: MessageReader<AssetLoadFailedEvent<Image>>,
+    mut art: ResMut<CardArt>,
+) {
+    for message in failed.read() {
+        let Some(card_id) = art
+            .handles
+            .iter()
+            .find_map(|(card_id, handle)| (handle.id() == message.id).then_some(*card_id))
+        else {
+            continue;
+        };
+        debug!("No art for {card_id:?}, using the atlas");
+        art.missing.insert(card_id);
+    }
+}
@@ -0,0 +1,203 @@
+//! Procedural card frames.
+//!
+//! Every card front is a frame drawn at runtime: a border coloured by
+//! [`CardType`] and a gem coloured by [`CardRarity`], so cards can be told
+//! apart at a glance without hand-authored art for each one. The card's art
+//! is inset just above the frame, leaving the border and gem showing, and the
+//! card text sits on top of both.
+
+use bevy::{
+    asset::RenderAssetUsages,
+    platform::collections::HashMap,
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+
+use super::{CardDef, CardRarity, CardType};
+
+/// Card size on the table, in world units.
+pub(super) const CARD_SIZE: Vec2 = Vec2::new(2.5, 3.5);
+/// Border around the art on the sides and top.
+const BORDER: f32 = 0.1;
+/// Deeper bottom border that holds the rarity gem.
+const BOTTOM_BORDER: f32 = 0.3;
+const GEM_RADIUS: f32 = 0.1;
+/// Dark ring around the gem.
+const GEM_BEZEL: f32 = 0.025;
+/// Frame texture resolution.
+const PIXELS_PER_UNIT: f32 = 40.0;
+/// Keeps the art from z-fighting with the frame under it.
+const ART_LIFT: f32 = 0.0005;
+/// Shows through the art window only while the art is loading.
+const WINDOW_COLOR: [f32; 3] = [0.08, 0.08, 0.1];
+
+/// Frame materials for the current match, one per type and rarity seen so
+/// far, and the inset quad card art is drawn on.
+#[derive(Resource)]
+pub(super) struct CardFrames {
+    art_mesh: Handle<Mesh>,
+    materials: HashMap<(CardType, CardRarity), Handle<StandardMaterial>>,
+}
+
+impl CardFrames {
+    pub(super) fn new(meshes: &mut Assets<Mesh>) -> Self {
+        let art_size = Vec2::new(
+            CARD_SIZE.x - 2.0 * BORDER,
+            CARD_SIZE.y - BORDER - BOTTOM_BORDER,
+        );
+        Self {
+            art_mesh: meshes.add(Plane3d::default().mesh().size(art_size.x, art_size.y)),
+            materials: HashMap::default(),
+        }
+    }
+
+    /// The frame for a card, drawn the first time its type and rarity come up.
+    /// Cards without a definition get a plain status frame.
+    pub(super) fn material(
+        &mut self,
+        def: Option<&CardDef>,
+        images: &mut Assets<Image>,
+        materials: &mut Assets<StandardMaterial>,
+    ) -> Handle<StandardMaterial> {
+        let (card_type, rarity) = def.map_or((CardType::Status, CardRarity::Special), |def| {
+            (def.card_type, def.rarity)
+        });
+        self.materials
+            .entry((card_type, rarity))
+            .or_insert_with(|| {
+                materials.add(StandardMaterial {
+                    base_color_texture: Some(images.add(frame_image(card_type, rarity))),
+                    ..default()
+                })
+            })
+            .clone()
+    }
+
+    /// A card's front: the frame on the full card quad, with the art inset
+    /// above it.
+    pub(super) fn front(
+        &self,
+        card_mesh: Handle<Mesh>,
+        frame: Handle<StandardMaterial>,
+        art: Handle<StandardMaterial>,
+    ) -> impl Bundle {
+        (
+            Name::new("Card Front"),
+            Mesh3d(card_mesh),
+            MeshMaterial3d(frame),
+            children![(
+                Name::new("Card Art"),
+                CardArtQuad,
+                Mesh3d(self.art_mesh.clone()),
+                MeshMaterial3d(art),
+                // The top of the card is -Z; the deeper bottom border moves
+                // the art up.
+                Transform::from_xyz(0.0, ART_LIFT, (BORDER - BOTTOM_BORDER) / 2.0),
+            )],
+        )
+    }
+}
+
+/// The inset quad a card's art is drawn on.
+#[derive(Component)]
+pub(super) struct CardArtQuad;
+
+fn type_color(card_type: CardType) -> [f32; 3] {
+    match card_type {
+        CardType::Attack => [0.7, 0.2, 0.15],
+        CardType::Skill => [0.2, 0.45, 0.7],
+        CardType::Power => [0.8, 0.6, 0.15],
+        CardType::Status => [0.4, 0.4, 0.4],
+    }
+}
+
+fn gem_color(rarity: CardRarity) -> [f32; 3] {
+    match rarity {
+        CardRarity::Starter => [0.5, 0.5, 0.5],
+        CardRarity::Common => [0.9, 0.9, 0.9],
+        CardRarity::Uncommon => [0.3, 0.65, 1.0],
+        CardRarity::Rare => [1.0, 0.8, 0.2],
+        CardRarity::Special => [0.7, 0.3, 0.9],
+    }
+}
+
+/// Draw one frame: a bevelled border, the art window and the gem.
+fn frame_image(card_type: CardType, rarity: CardRarity) -> Image {
+    let size = (CARD_SIZE * PIXELS_PER_UNIT).as_uvec2();
+    let gem_center = Vec2::new(CARD_SIZE.x / 2.0, CARD_SIZE.y - BOTTOM_BORDER / 2.0);
+    let mut data = Vec::with_capacity((size.x * size.y * 4) as usize);
+    for y in 0..size.y {
+        for x in 0..size.x {
+            let point = (Vec2::new(x as f32, y as f32) + 0.5) / PIXELS_PER_UNIT;
+            let [r, g, b] = frame_pixel(point, gem_center, card_type, rarity);
+            data.extend(Srgba::rgb(r, g, b).to_u8_array());
+        }
+    }
+    Image::new(
+        Extent3d {
+            width: size.x,
+            height: size.y,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
+
+/// Frame colour at a point on the card, in world units from the top left.
+fn frame_pixel(point: Vec2, gem_center: Vec2, card_type: CardType, rarity: CardRarity) -> [f32; 3] {
+    let gem_distance = point.distance(gem_center);
+    if gem_distance <= GEM_RADIUS {
+        // Brighter toward the middle, like a cut stone catching the light.
+        return shade(gem_color(rarity), 1.3 - 0.5 * gem_distance / GEM_RADIUS);
+    }
+    if gem_distance <= GEM_RADIUS + GEM_BEZEL {
+        return shade(type_color(card_type), 0.35);
+    }
+    let in_window = point.x > BORDER
+        && point.x < CARD_SIZE.x - BORDER
+        && point.y > BORDER
+        && point.y < CARD_SIZE.y - BOTTOM_BORDER;
+    if in_window {
+        return WINDOW_COLOR;
+    }
+    // Darker at the outer rim, lighter toward the art.
+    let edge = point
+        .x
+        .min(point.y)
+        .min(CARD_SIZE.x - point.x)
+        .min(CARD_SIZE.y - point.y);
+    shade(type_color(card_type), 0.7 + 0.4 * (edge / BORDER).min(1.0))
+}
+
+fn shade([r, g, b]: [f32; 3], factor: f32) -> [f32; 3] {
+    [r, g, b].map(|channel| (channel * factor).min(1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pixel(image: &Image, point: Vec2) -> [u8; 4] {
+        let pixel = (point * PIXELS_PER_UNIT).as_uvec2();
+        let index = ((pixel.y * image.width() + pixel.x) * 4) as usize;
+        let data = image.data.as_ref().expect("frame has pixel data");
+        data[index..index + 4].try_into().unwrap()
+    }
+
+    #[test]
+    fn border_follows_type_and_gem_follows_rarity() {
+        let border = Vec2::new(BORDER / 2.0, CARD_SIZE.y / 2.0);
+        let gem = Vec2::new(CARD_SIZE.x / 2.0, CARD_SIZE.y - BOTTOM_BORDER / 2.0);
+        let attack = frame_image(CardType::Attack, CardRarity::Common);
+        let skill = frame_image(CardType::Skill, CardRarity::Common);
+        let rare_skill = frame_image(CardType::Skill, CardRarity::Rare);
+
+        assert_ne!(pixel(&attack, border), pixel(&skill, border));
+        assert_eq!(pixel(&attack, gem), pixel(&skill, gem));
+        assert_eq!(pixel(&skill, border), pixel(&rare_skill, border));
+        assert_ne!(pixel(&skill, gem), pixel(&rare_skill, gem));
+    }
+}
@@ -0,0 +1,95 @@
+//! Sound feedback for the local hand: hovering a card, drawing cards and
+//! pressing a card that can't be played.
+//!
+//! These play on the effects bus and can be switched off, together with the
+//! button sounds, with the feedback sounds setting. They reuse the shipped
+//! button and footstep sounds; a rejected press is the click played low.
+//! They're in the gameplay asset group.
+
+use bevy::prelude::*;
+use bevy_la_mesa::Hand as MesaHand;
+
+use super::mesa::{HoveredCard, LOCAL_PLAYER_INDEX};
+use crate::{
+    AppSystems,
+    asset_tracking::{AssetGroup, LoadResource},
+    audio::{AudioBus, AudioMix},
+    screens::Screen,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.load_resource_in::<CardSounds>(AssetGroup::Gameplay);
+    app.add_message::<CardRejected>();
+    app.clear_messages_on_exit::<CardRejected>(Screen::Gameplay);
+    app.add_systems(
+        Update,
+        (play_hover_sound, play_draw_sound, play_rejected_sound)
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay).and(resource_exists::<CardSounds>))
+            .run_if(|mix: Res<AudioMix>| mix.feedback),
+    );
+}
+
+/// The local player pressed a hand card that won't be played or queued.
+#[derive(Message)]
+pub(super) struct CardRejected;
+
+/// Playback speed of the click for a rejected press, low enough to read as a
+/// refusal.
+const REJECTED_SPEED: f32 = 0.6;
+
+#[derive(Resource, Asset, Clone, TypePath)]
+struct CardSounds {
+    #[dependency]
+    hover: Handle<AudioSource>,
+    #[dependency]
+    draw: Handle<AudioSource>,
+    #[dependency]
+    rejected: Handle<AudioSource>,
+}
+
+impl FromWorld for CardSounds {
+    fn from_world(world: &mut World) -> Self {
+        let assets = world.resource::<AssetServer>();
+        Self {
+            hover: assets.load("audio/sound_effects/button_hover.ogg"),
+            draw: assets.load("audio/sound_effects/step1.ogg"),
+            rejected: assets.load("audio/sound_effects/button_click.ogg"),
+        }
+    }
+}
+
+fn play_hover_sound(
+    mut commands: Commands,
+    sounds: Res<CardSounds>,
+    hovered: Query<(), Added<HoveredCard>>,
+) {
+    if !hovered.is_empty() {
+        commands.spawn(AudioBus::Sfx.one_shot(sounds.hover.clone()));
+    }
+}
+
+/// One sound per batch, so an opening hand doesn't play five at once.
+fn play_draw_sound(
+    mut commands: Commands,
+    sounds: Res<CardSounds>,
+    drawn: Query<&MesaHand, Added<MesaHand>>,
+) {
+    if drawn.iter().any(|hand| hand.player == LOCAL_PLAYER_INDEX) {
+        commands.spawn(AudioBus::Sfx.one_shot(sounds.draw.clone()));
+    }
+}
+
+fn play_rejected_sound(
+    mut commands: Commands,
+    sounds: Res<CardSounds>,
+    mut rejected: MessageReader<CardRejected>,
+) {
+    if rejected.read().count() > 0 {
+        commands.spawn((
+            AudioPlayer(sounds.rejected.clone()),
+            PlaybackSettings::DESPAWN.with_speed(REJECTED_SPEED),
+            AudioBus::Sfx,
+        ));
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! Card IDs: 1-99
 
-use super::{CardDef, CardEffect, CardId, CardRarity, CardRegistry, CardType};
+use super::{CardAction, CardDef, CardEffect, CardId, CardRarity, CardRegistry, CardType};
 
 pub fn register_attack_cards(registry: &mut CardRegistry) {
     // === STARTER CARDS ===
@@ -11,22 +11,22 @@ pub fn register_attack_cards(registry: &mut CardRegistry) {
     registry.register(CardDef {
         id: CardId::Strike,
         name: "Strike".to_string(),
-        description: "Deal 60 damage.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Starter,
         cost: 1.0,
-        effect: CardEffect::Damage(60.0),
+        effect: CardEffect::on_play([CardAction::Damage(60.0)]),
     });
 
     // 2: Bash - Attack with weak
     registry.register(CardDef {
         id: CardId::Bash,
         name: "Bash".to_string(),
-        description: "Deal 80 damage. Apply 2 Vulnerable.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Starter,
         cost: 2.0,
-        effect: CardEffect::Combo(vec![CardEffect::Damage(80.0), CardEffect::Vulnerable(2.0)]),
+        effect: CardEffect::on_play([CardAction::Damage(80.0), CardAction::Vulnerable(2.0)]),
     });
 
     // === COMMON ATTACKS ===
@@ -35,118 +35,118 @@ pub fn register_attack_cards(registry: &mut CardRegistry) {
     registry.register(CardDef {
         id: CardId::Anger,
         name: "Anger".to_string(),
-        description: "Deal 60 damage. Add a copy to discard pile.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Common,
         cost: 0.5,
-        effect: CardEffect::Damage(60.0), // Copy mechanic handled separately
+        effect: CardEffect::on_play([CardAction::Damage(60.0), CardAction::AddCopyToDiscard]),
     });
 
     // 4: Cleave - Multi-target (in 1v1, just more damage)
     registry.register(CardDef {
         id: CardId::Cleave,
         name: "Cleave".to_string(),
-        description: "Deal 80 damage.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Common,
         cost: 1.0,
-        effect: CardEffect::Damage(80.0),
+        effect: CardEffect::on_play([CardAction::Damage(80.0)]),
     });
 
     // 5: Clothesline - Damage + Weak
     registry.register(CardDef {
         id: CardId::Clothesline,
         name: "Clothesline".to_string(),
-        description: "Deal 120 damage. Apply 2 Weak.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Common,
         cost: 2.0,
-        effect: CardEffect::Combo(vec![CardEffect::Damage(120.0), CardEffect::Weak(2.0)]),
+        effect: CardEffect::on_play([CardAction::Damage(120.0), CardAction::Weak(2.0)]),
     });
 
     // 6: Headbutt - Damage
     registry.register(CardDef {
         id: CardId::Headbutt,
         name: "Headbutt".to_string(),
-        description: "Deal 90 damage.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Common,
         cost: 1.0,
-        effect: CardEffect::Damage(90.0),
+        effect: CardEffect::on_play([CardAction::Damage(90.0)]),
     });
 
     // 7: Iron Wave - Damage + Block
     registry.register(CardDef {
         id: CardId::IronWave,
         name: "Iron Wave".to_string(),
-        description: "Deal 50 damage. Gain 50 Block.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Common,
         cost: 1.0,
-        effect: CardEffect::Combo(vec![CardEffect::Damage(50.0), CardEffect::Block(50.0)]),
+        effect: CardEffect::on_play([CardAction::Damage(50.0), CardAction::Block(50.0)]),
     });
 
     // 8: Pommel Strike - Damage + Draw
     registry.register(CardDef {
         id: CardId::PommelStrike,
         name: "Pommel Strike".to_string(),
-        description: "Deal 90 damage. Draw 1 card.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Common,
         cost: 1.0,
-        effect: CardEffect::Combo(vec![CardEffect::Damage(90.0), CardEffect::Draw(1)]),
+        effect: CardEffect::on_play([CardAction::Damage(90.0), CardAction::Draw(1)]),
     });
 
     // 9: Sword Boomerang - Multi-hit random
     registry.register(CardDef {
         id: CardId::SwordBoomerang,
         name: "Sword Boomerang".to_string(),
-        description: "Deal 30 damage 3 times.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Common,
         cost: 1.0,
-        effect: CardEffect::MultiHit {
+        effect: CardEffect::on_play([CardAction::MultiHit {
             damage: 30.0,
             hits: 3,
-        },
+        }]),
     });
 
     // 10: Thunder Clap - Damage + Vulnerable
     registry.register(CardDef {
         id: CardId::ThunderClap,
         name: "Thunder Clap".to_string(),
-        description: "Deal 40 damage. Apply 1 Vulnerable.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Common,
         cost: 1.0,
-        effect: CardEffect::Combo(vec![CardEffect::Damage(40.0), CardEffect::Vulnerable(1.0)]),
+        effect: CardEffect::on_play([CardAction::Damage(40.0), CardAction::Vulnerable(1.0)]),
     });
 
     // 11: Twin Strike - Two hits
     registry.register(CardDef {
         id: CardId::TwinStrike,
         name: "Twin Strike".to_string(),
-        description: "Deal 50 damage twice.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Common,
         cost: 1.0,
-        effect: CardEffect::MultiHit {
+        effect: CardEffect::on_play([CardAction::MultiHit {
             damage: 50.0,
             hits: 2,
-        },
+        }]),
     });
 
     // 12: Wild Strike - High damage, add wound
     registry.register(CardDef {
         id: CardId::WildStrike,
         name: "Wild Strike".to_string(),
-        description: "Deal 120 damage. Add a Wound to your deck.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Common,
         cost: 1.0,
-        effect: CardEffect::Combo(vec![
-            CardEffect::Damage(120.0),
-            CardEffect::AddStatus(CardId::Wound), // Wound
+        effect: CardEffect::on_play([
+            CardAction::Damage(120.0),
+            CardAction::AddStatus(CardId::Wound), // Wound
         ]),
     });
 
@@ -156,36 +156,36 @@ pub fn register_attack_cards(registry: &mut CardRegistry) {
     registry.register(CardDef {
         id: CardId::BodySlam,
         name: "Body Slam".to_string(),
-        description: "Deal damage equal to your current Block.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Uncommon,
         cost: 1.0,
-        effect: CardEffect::BodySlam,
+        effect: CardEffect::on_play([CardAction::BodySlam]),
     });
 
     // 14: Carnage - High damage
     registry.register(CardDef {
         id: CardId::Carnage,
         name: "Carnage".to_string(),
-        description: "Deal 200 damage.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Uncommon,
         cost: 2.0,
-        effect: CardEffect::Damage(200.0),
+        effect: CardEffect::on_play([CardAction::Damage(200.0)]),
     });
 
     // 15: Dropkick - Draw + cost if vulnerable
     registry.register(CardDef {
         id: CardId::Dropkick,
         name: "Dropkick".to_string(),
-        description: "Deal 50 damage. Draw 1. Gain 1 cost.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Uncommon,
         cost: 1.0,
-        effect: CardEffect::Combo(vec![
-            CardEffect::Damage(50.0),
-            CardEffect::Draw(1),
-            CardEffect::Accelerate {
+        effect: CardEffect::on_play([
+            CardAction::Damage(50.0),
+            CardAction::Draw(1),
+            CardAction::Accelerate {
                 bonus_rate: 0.5,
                 duration: 2.0,
             },
@@ -196,13 +196,13 @@ pub fn register_attack_cards(registry: &mut CardRegistry) {
     registry.register(CardDef {
         id: CardId::Hemokinesis,
         name: "Hemokinesis".to_string(),
-        description: "Lose 20 HP. Deal 150 damage.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Uncommon,
         cost: 1.0,
-        effect: CardEffect::Combo(vec![
-            CardEffect::Bloodletting(-20.0), // Negative = self damage
-            CardEffect::Damage(150.0),
+        effect: CardEffect::on_play([
+            CardAction::Bloodletting(-20.0), // Negative = self damage
+            CardAction::Damage(150.0),
         ]),
     });
 
@@ -210,38 +210,38 @@ pub fn register_attack_cards(registry: &mut CardRegistry) {
     registry.register(CardDef {
         id: CardId::Pummel,
         name: "Pummel".to_string(),
-        description: "Deal 20 damage 4 times.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Uncommon,
         cost: 1.0,
-        effect: CardEffect::MultiHit {
+        effect: CardEffect::on_play([CardAction::MultiHit {
             damage: 20.0,
             hits: 4,
-        },
+        }]),
     });
 
     // 18: Rampage - Increasing damage (simplified: high base)
     registry.register(CardDef {
         id: CardId::Rampage,
         name: "Rampage".to_string(),
-        description: "Deal 80 damage. Damage increases each play.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Uncommon,
         cost: 1.0,
-        effect: CardEffect::Damage(80.0), // Scaling would need tracking
+        effect: CardEffect::on_play([CardAction::Damage(80.0)]), // Scaling would need tracking
     });
 
     // 19: Reckless Charge - High damage, add wound
     registry.register(CardDef {
         id: CardId::RecklessCharge,
         name: "Reckless Charge".to_string(),
-        description: "Deal 70 damage. Add a Wound to your draw pile.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Uncommon,
         cost: 0.5,
-        effect: CardEffect::Combo(vec![
-            CardEffect::Damage(70.0),
-            CardEffect::AddStatus(CardId::Wound), // Wound
+        effect: CardEffect::on_play([
+            CardAction::Damage(70.0),
+            CardAction::AddStatus(CardId::Wound), // Wound
         ]),
     });
 
@@ -249,25 +249,25 @@ pub fn register_attack_cards(registry: &mut CardRegistry) {
     registry.register(CardDef {
         id: CardId::SearingBlow,
         name: "Searing Blow".to_string(),
-        description: "Deal 120 damage. Can be upgraded infinitely.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Uncommon,
         cost: 2.0,
-        effect: CardEffect::Damage(120.0),
+        effect: CardEffect::on_play([CardAction::Damage(120.0)]),
     });
 
     // 21: Uppercut - Damage + Weak + Vulnerable
     registry.register(CardDef {
         id: CardId::Uppercut,
         name: "Uppercut".to_string(),
-        description: "Deal 130 damage. Apply 1 Weak. Apply 1 Vulnerable.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Uncommon,
         cost: 2.0,
-        effect: CardEffect::Combo(vec![
-            CardEffect::Damage(130.0),
-            CardEffect::Weak(1.0),
-            CardEffect::Vulnerable(1.0),
+        effect: CardEffect::on_play([
+            CardAction::Damage(130.0),
+            CardAction::Weak(1.0),
+            CardAction::Vulnerable(1.0),
         ]),
     });
 
@@ -275,14 +275,14 @@ pub fn register_attack_cards(registry: &mut CardRegistry) {
     registry.register(CardDef {
         id: CardId::Whirlwind,
         name: "Whirlwind".to_string(),
-        description: "Deal 50 damage 3 times.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Uncommon,
         cost: 3.0,
-        effect: CardEffect::MultiHit {
+        effect: CardEffect::on_play([CardAction::MultiHit {
             damage: 50.0,
             hits: 3,
-        },
+        }]),
     });
 
     // === RARE ATTACKS ===
@@ -291,46 +291,52 @@ pub fn register_attack_cards(registry: &mut CardRegistry) {
     registry.register(CardDef {
         id: CardId::Bludgeon,
         name: "Bludgeon".to_string(),
-        description: "Deal 320 damage.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Rare,
         cost: 3.0,
-        effect: CardEffect::Damage(320.0),
+        effect: CardEffect::on_play([CardAction::Damage(320.0)]),
     });
 
     // 24: Feed - Heal on kill (simplified: damage + heal)
     registry.register(CardDef {
         id: CardId::Feed,
         name: "Feed".to_string(),
-        description: "Deal 100 damage. Heal 30 HP.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Rare,
         cost: 1.0,
-        effect: CardEffect::Combo(vec![CardEffect::Damage(100.0), CardEffect::Heal(30.0)]),
+        effect: CardEffect::on_play([CardAction::Damage(100.0), CardAction::Heal(30.0)]),
     });
 
-    // 25: Fiend Fire - Exhaust all (simplified: big damage)
+    // 25: Fiend Fire - Exhaust hand, damage per card
     registry.register(CardDef {
         id: CardId::FiendFire,
         name: "Fiend Fire".to_string(),
-        description: "Deal 70 damage for each card in hand.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Rare,
         cost: 2.0,
-        effect: CardEffect::Damage(280.0), // Assuming 4 cards avg
+        effect: CardEffect::on_play([
+            CardAction::ExhaustHand,
+            CardAction::PerCardInHand {
+                damage: 70.0,
+                block: 0.0,
+            },
+        ]),
     });
 
     // 26: Immolate - High damage, add burn
     registry.register(CardDef {
         id: CardId::Immolate,
         name: "Immolate".to_string(),
-        description: "Deal 210 damage. Add a Burn to discard pile.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Rare,
         cost: 2.0,
-        effect: CardEffect::Combo(vec![
-            CardEffect::Damage(210.0),
-            CardEffect::AddStatus(CardId::Burn), // Burn
+        effect: CardEffect::on_play([
+            CardAction::Damage(210.0),
+            CardAction::AddStatus(CardId::Burn), // Burn
         ]),
     });
 
@@ -338,10 +344,10 @@ pub fn register_attack_cards(registry: &mut CardRegistry) {
     registry.register(CardDef {
         id: CardId::Reaper,
         name: "Reaper".to_string(),
-        description: "Deal 40 damage. Heal for unblocked damage.".to_string(),
+        description: None,
         card_type: CardType::Attack,
         rarity: CardRarity::Rare,
         cost: 2.0,
-        effect: CardEffect::Combo(vec![CardEffect::Damage(40.0), CardEffect::Heal(40.0)]),
+        effect: CardEffect::on_play([CardAction::Damage(40.0), CardAction::Heal(40.0)]),
     });
 }
@@ -0,0 +1,393 @@
+//! Card descriptions generated from effects, so the text always matches what
+//! a card does. Each action has a sentence template per [`Language`] with
+//! `{0}`, `{1}`... filled in from its numbers.
+
+use super::{CardAction, CardDef, CardEffect, CardKeyword, CardTrigger, CardType, RAGE_SECS};
+use crate::theme::font::Language;
+
+impl CardDef {
+    /// The hand-written override if there is one, otherwise the text
+    /// generated from the effect.
+    pub fn description(&self, language: Language) -> String {
+        self.description
+            .clone()
+            .unwrap_or_else(|| self.effect.describe(language))
+    }
+}
+
+impl CardEffect {
+    /// One sentence per action: `Unplayable.` first, triggered actions in
+    /// order, `Exhaust.` last.
+    pub fn describe(&self, language: Language) -> String {
+        let mut sentences = Vec::new();
+        if self.is_unplayable() {
+            sentences.push(keyword_text(CardKeyword::Unplayable, language).to_string());
+        }
+        for (trigger, action) in &self.actions {
+            let prefix = match (trigger, language) {
+                (CardTrigger::Play, _) => "",
+                (CardTrigger::Draw, Language::English) => "When drawn: ",
+                (CardTrigger::Draw, Language::Japanese) => "引いた時: ",
+            };
+            for (template, args) in action_templates(action, language) {
+                sentences.push(format!("{prefix}{}", fill(template, &args)));
+            }
+        }
+        if self.exhausts_self() {
+            sentences.push(keyword_text(CardKeyword::Exhaust, language).to_string());
+        }
+        let separator = match language {
+            Language::English => " ",
+            Language::Japanese => "",
+        };
+        sentences.join(separator)
+    }
+}
+
+fn keyword_text(keyword: CardKeyword, language: Language) -> &'static str {
+    match (keyword, language) {
+        (CardKeyword::Exhaust, Language::English) => "Exhaust.",
+        (CardKeyword::Exhaust, Language::Japanese) => "廃棄。",
+        (CardKeyword::Unplayable, Language::English) => "Unplayable.",
+        (CardKeyword::Unplayable, Language::Japanese) => "プレイできない。",
+    }
+}
+
+fn card_type_name(card_type: CardType, language: Language) -> &'static str {
+    match (card_type, language) {
+        (CardType::Attack, Language::English) => "Attack",
+        (CardType::Skill, Language::English) => "Skill",
+        (CardType::Power, Language::English) => "Power",
+        (CardType::Status, Language::English) => "Status",
+        (CardType::Attack, Language::Japanese) => "アタック",
+        (CardType::Skill, Language::Japanese) => "スキル",
+        (CardType::Power, Language::Japanese) => "パワー",
+        (CardType::Status, Language::Japanese) => "状態異常",
+    }
+}
+
+/// Replace `{0}`, `{1}`... in `template` with `args`.
+fn fill(template: &str, args: &[String]) -> String {
+    let mut text = template.to_string();
+    for (index, arg) in args.iter().enumerate() {
+        text = text.replace(&format!("{{{index}}}"), arg);
+    }
+    text
+}
+
+fn number(value: f32) -> String {
+    format!("{value:.0}")
+}
+
+fn count(value: u32) -> String {
+    value.to_string()
+}
+
+/// The sentence templates for an action and the values they're filled with.
+fn action_templates(action: &CardAction, language: Language) -> Vec<(&'static str, Vec<String>)> {
+    let pick = |en: &'static str, ja: &'static str| match language {
+        Language::English => en,
+        Language::Japanese => ja,
+    };
+    let cards = |n: u32, en_one: &'static str, en_many: &'static str, ja: &'static str| {
+        if n == 1 {
+            pick(en_one, ja)
+        } else {
+            pick(en_many, ja)
+        }
+    };
+    let one = |template, args| vec![(template, args)];
+    match action {
+        CardAction::Damage(amount) => one(
+            pick("Deal {0} damage.", "{0}ダメージを与える。"),
+            vec![number(*amount)],
+        ),
+        CardAction::MultiHit { damage, hits } => one(
+            pick("Deal {0} damage {1} times.", "{0}ダメージを{1}回与える。"),
+            vec![number(*damage), count(*hits)],
+        ),
+        CardAction::Heal(amount) => one(
+            pick("Heal {0} HP.", "HPを{0}回復する。"),
+            vec![number(*amount)],
+        ),
+        CardAction::Draw(n) => one(
+            cards(
+                *n,
+                "Draw {0} card.",
+                "Draw {0} cards.",
+                "カードを{0}枚引く。",
+            ),
+            vec![count(*n)],
+        ),
+        CardAction::Block(amount) => one(
+            pick("Gain {0} Block.", "ブロックを{0}得る。"),
+            vec![number(*amount)],
+        ),
+        CardAction::Thorns(amount) => one(
+            pick("Gain {0} Thorns.", "トゲを{0}得る。"),
+            vec![number(*amount)],
+        ),
+        CardAction::Strength(amount) => one(
+            pick("Gain {0} Strength.", "筋力を{0}得る。"),
+            vec![number(*amount)],
+        ),
+        CardAction::Vulnerable(secs) => one(
+            pick(
+                "Apply Vulnerable for {0} seconds.",
+                "相手を{0}秒間脆弱にする。",
+            ),
+            vec![number(*secs)],
+        ),
+        CardAction::SelfVulnerable(secs) => one(
+            pick(
+                "Become Vulnerable for {0} seconds.",
+                "自分が{0}秒間脆弱になる。",
+            ),
+            vec![number(*secs)],
+        ),
+        CardAction::Weak(secs) => one(
+            pick("Apply Weak for {0} seconds.", "相手を{0}秒間脱力にする。"),
+            vec![number(*secs)],
+        ),
+        CardAction::Accelerate {
+            bonus_rate,
+            duration,
+        } => one(
+            pick(
+                "Gain {0} more cost per second for {1} seconds.",
+                "{1}秒間、毎秒のコスト獲得量が{0}増える。",
+            ),
+            vec![format!("{bonus_rate:.1}"), number(*duration)],
+        ),
+        CardAction::RaiseCostCap { amount, duration } => one(
+            pick(
+                "Raise your max cost by {0} for {1} seconds.",
+                "{1}秒間、最大コストが{0}増える。",
+            ),
+            vec![number(*amount), number(*duration)],
+        ),
+        CardAction::BodySlam => one(
+            pick(
+                "Deal damage equal to your Block.",
+                "ブロックと同じ値のダメージを与える。",
+            ),
+            vec![],
+        ),
+        CardAction::Bloodletting(amount) if *amount < 0.0 => {
+            one(pick("Lose {0} HP.", "HPを{0}失う。"), vec![number(-amount)])
+        }
+        CardAction::Bloodletting(amount) => one(
+            pick("Heal {0} HP.", "HPを{0}回復する。"),
+            vec![number(*amount)],
+        ),
+        CardAction::SelfDamage(amount) => one(
+            pick("Take {0} damage.", "{0}ダメージを受ける。"),
+            vec![number(*amount)],
+        ),
+        CardAction::LoseAllCost => one(pick("Lose all cost.", "コストをすべて失う。"), vec![]),
+        CardAction::DoubleBlock => one(pick("Double your Block.", "ブロックを2倍にする。"), vec![]),
+        CardAction::DoubleStrength => {
+            one(pick("Double your Strength.", "筋力を2倍にする。"), vec![])
+        }
+        CardAction::Rage(block) => one(
+            pick(
+                "For {1} seconds, gain {0} Block whenever you play an Attack.",
+                "{1}秒間、アタックをプレイするたびにブロックを{0}得る。",
+            ),
+            vec![number(*block), number(RAGE_SECS)],
+        ),
+        CardAction::Metallicize(block) => one(
+            pick("Gain {0} Block every second.", "毎秒ブロックを{0}得る。"),
+            vec![number(*block)],
+        ),
+        CardAction::Combust {
+            self_damage_per_sec,
+            enemy_damage_per_sec,
+        } => one(
+            pick(
+                "Every second, lose {0} HP and deal {1} damage.",
+                "毎秒HPを{0}失い、{1}ダメージを与える。",
+            ),
+            vec![number(*self_damage_per_sec), number(*enemy_damage_per_sec)],
+        ),
+        CardAction::DemonForm(strength) => one(
+            pick("Gain {0} Strength every second.", "毎秒筋力を{0}得る。"),
+            vec![number(*strength)],
+        ),
+        CardAction::Barricade => one(
+            pick("Block no longer decays.", "ブロックが減らなくなる。"),
+            vec![],
+        ),
+        CardAction::Juggernaut(damage) => one(
+            pick(
+                "Whenever you gain Block, deal {0} damage.",
+                "ブロックを得るたびに{0}ダメージを与える。",
+            ),
+            vec![number(*damage)],
+        ),
+        CardAction::DarkEmbrace { draw } => one(
+            cards(
+                *draw,
+                "Whenever a card is Exhausted, draw {0} card.",
+                "Whenever a card is Exhausted, draw {0} cards.",
+                "カードが廃棄されるたびにカードを{0}枚引く。",
+            ),
+            vec![count(*draw)],
+        ),
+        CardAction::Evolve { draw } => one(
+            cards(
+                *draw,
+                "Whenever you draw a Status, draw {0} card.",
+                "Whenever you draw a Status, draw {0} cards.",
+                "状態異常カードを引くたびにカードを{0}枚引く。",
+            ),
+            vec![count(*draw)],
+        ),
+        CardAction::FeelNoPain { block } => one(
+            pick(
+                "Whenever a card is Exhausted, gain {0} Block.",
+                "カードが廃棄されるたびにブロックを{0}得る。",
+            ),
+            vec![number(*block)],
+        ),
+        CardAction::FireBreathing { damage } => one(
+            pick(
+                "Whenever you draw a Status, deal {0} damage.",
+                "状態異常カードを引くたびに{0}ダメージを与える。",
+            ),
+            vec![number(*damage)],
+        ),
+        CardAction::Rupture { strength } => one(
+            pick(
+                "Whenever you lose HP from a card, gain {0} Strength.",
+                "カードでHPを失うたびに筋力を{0}得る。",
+            ),
+            vec![number(*strength)],
+        ),
+        CardAction::Corruption => one(
+            pick(
+                "Skills cost 0. Whenever you play a Skill, Exhaust it.",
+                "スキルのコストが0になる。スキルをプレイするたびに廃棄する。",
+            ),
+            vec![],
+        ),
+        CardAction::Brutality {
+            self_damage_per_sec,
+            draw,
+            draw_interval,
+        } => vec![
+            (
+                pick("Lose {0} HP every second.", "毎秒HPを{0}失う。"),
+                vec![number(*self_damage_per_sec)],
+            ),
+            (
+                cards(
+                    *draw,
+                    "Draw {0} card every {1} seconds.",
+                    "Draw {0} cards every {1} seconds.",
+                    "{1}秒ごとにカードを{0}枚引く。",
+                ),
+                vec![count(*draw), number(*draw_interval)],
+            ),
+        ],
+        CardAction::Poison(stacks) => one(
+            pick("Apply {0} Poison.", "相手に毒を{0}与える。"),
+            vec![number(*stacks)],
+        ),
+        CardAction::MultiplyPoison(factor) => one(
+            pick(
+                "Multiply the enemy's Poison by {0}.",
+                "相手の毒を{0}倍にする。",
+            ),
+            vec![number(*factor)],
+        ),
+        CardAction::AddStatus(card) => one(
+            pick("Add a {0} to your discard pile.", "捨て札に{0}を加える。"),
+            vec![format!("{card:?}")],
+        ),
+        CardAction::AddCopyToDiscard => one(
+            pick(
+                "Add a copy of this card to your discard pile.",
+                "このカードのコピーを捨て札に加える。",
+            ),
+            vec![],
+        ),
+        CardAction::AddRandomCard {
+            card_type,
+            free_secs,
+        } => one(
+            pick(
+                "Add a random {0} to your hand. It costs 0 for {1} seconds.",
+                "ランダムな{0}を手札に加える。それは{1}秒間コスト0になる。",
+            ),
+            vec![
+                card_type_name(*card_type, language).to_string(),
+                number(*free_secs),
+            ],
+        ),
+        CardAction::ExhaustHand => {
+            one(pick("Exhaust your hand.", "手札をすべて廃棄する。"), vec![])
+        }
+        CardAction::PerCardInHand { damage, block } => {
+            let mut templates = Vec::new();
+            if *damage > 0.0 {
+                templates.push((
+                    pick(
+                        "Deal {0} damage for each other card in your hand.",
+                        "他の手札1枚につき{0}ダメージを与える。",
+                    ),
+                    vec![number(*damage)],
+                ));
+            }
+            if *block > 0.0 {
+                templates.push((
+                    pick(
+                        "Gain {0} Block for each other card in your hand.",
+                        "他の手札1枚につきブロックを{0}得る。",
+                    ),
+                    vec![number(*block)],
+                ));
+            }
+            templates
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::cards::{CardRegistry, register_all_cards};
+
+    #[test]
+    fn descriptions_use_the_effect_numbers() {
+        let bash = CardEffect::on_play([CardAction::Damage(80.0), CardAction::Vulnerable(2.0)]);
+        assert_eq!(
+            bash.describe(Language::English),
+            "Deal 80 damage. Apply Vulnerable for 2 seconds."
+        );
+        assert_eq!(
+            bash.describe(Language::Japanese),
+            "80ダメージを与える。相手を2秒間脆弱にする。"
+        );
+        let burn = CardEffect::default()
+            .keyword(CardKeyword::Unplayable)
+            .on(CardTrigger::Draw, CardAction::SelfDamage(20.0));
+        assert_eq!(
+            burn.describe(Language::English),
+            "Unplayable. When drawn: Take 20 damage."
+        );
+    }
+
+    #[test]
+    fn every_card_has_a_filled_in_description() {
+        let mut registry = CardRegistry::default();
+        register_all_cards(&mut registry);
+        for def in registry.iter() {
+            for language in [Language::English, Language::Japanese] {
+                let text = def.description(language);
+                assert!(!text.is_empty(), "{:?} has no description", def.id);
+                assert!(!text.contains('{'), "{:?}: {text}", def.id);
+            }
+        }
+    }
+}
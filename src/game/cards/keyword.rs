@@ -0,0 +1,380 @@
+//! Card keywords, triggers and actions - the data a card's effect is built from.
+//!
+//! A [`CardEffect`] is a list of [`CardAction`]s, each fired by a
+//! [`CardTrigger`], plus static [`CardKeyword`]s. Actions resolve in one place
+//! (`game/effect.rs`) whatever fires them, and the card face text and color
+//! come from the actions, so a new card made of existing actions is data only.
+
+use super::{CardId, CardType};
+
+/// How long [`CardAction::Rage`] lasts.
+pub const RAGE_SECS: f32 = 10.0;
+
+/// When an action fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardTrigger {
+    /// The card is played from hand
+    Play,
+    /// The card is drawn into hand
+    Draw,
+}
+
+impl CardTrigger {
+    /// Prefix for the card face lines of actions this trigger fires.
+    fn text_prefix(self) -> &'static str {
+        match self {
+            CardTrigger::Play => "",
+            CardTrigger::Draw => "DRAW: ",
+        }
+    }
+}
+
+/// Static rules a card follows, whatever its actions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardKeyword {
+    /// Removed from the deck for the rest of the match when played
+    Exhaust,
+    /// Cannot be played from hand
+    Unplayable,
+}
+
+/// One thing a card does when its trigger fires.
+#[derive(Debug, Clone)]
+pub enum CardAction {
+    /// Deal damage to opponent
+    Damage(f32),
+    /// Deal damage multiple times
+    MultiHit { damage: f32, hits: u32 },
+    /// Heal self
+    Heal(f32),
+    /// Draw cards
+    Draw(u32),
+    /// Gain block (reduces incoming damage)
+    Block(f32),
+    /// Gain thorns (reflect damage when hit)
+    Thorns(f32),
+    /// Gain strength (increases attack damage)
+    Strength(f32),
+    /// Apply vulnerable to opponent (takes more damage)
+    Vulnerable(f32),
+    /// Apply vulnerable to self (takes more damage)
+    SelfVulnerable(f32),
+    /// Apply weak to opponent (deals less damage)
+    Weak(f32),
+    /// Temporarily increase cost generation rate
+    Accelerate { bonus_rate: f32, duration: f32 },
+    /// Temporarily raise the max cost
+    RaiseCostCap { amount: f32, duration: f32 },
+    /// Deal damage equal to current block
+    BodySlam,
+    /// Lose HP (negative) or gain HP (positive) via card effect
+    Bloodletting(f32),
+    /// Take damage from no one (status cards)
+    SelfDamage(f32),
+    /// Drain all of the player's cost
+    LoseAllCost,
+    /// Double current block
+    DoubleBlock,
+    /// Double current strength
+    DoubleStrength,
+    /// Gain Rage (gain block when playing attacks, for [`RAGE_SECS`])
+    Rage(f32),
+    /// Gain Metallicize (gain block continuously)
+    Metallicize(f32),
+    /// Gain Combust (continuous damage to self and enemies)
+    Combust {
+        self_damage_per_sec: f32,
+        enemy_damage_per_sec: f32,
+    },
+    /// Gain Demon Form (gain strength over time)
+    DemonForm(f32),
+    /// Gain Barricade (block doesn't decay)
+    Barricade,
+    /// Gain Juggernaut (deal damage when gaining block)
+    Juggernaut(f32),
+    /// Draw when a card is exhausted
+    DarkEmbrace { draw: u32 },
+    /// Draw when a status card is drawn
+    Evolve { draw: u32 },
+    /// Gain block when a card is exhausted
+    FeelNoPain { block: f32 },
+    /// Deal damage when a status card is drawn
+    FireBreathing { damage: f32 },
+    /// Gain strength when taking self-damage
+    Rupture { strength: f32 },
+    /// Skills cost 0 and exhaust when played
+    Corruption,
+    /// Continuous self damage + periodic draw
+    Brutality {
+        self_damage_per_sec: f32,
+        draw: u32,
+        draw_interval: f32,
+    },
+    /// Apply poison to opponent (damage per second, decaying)
+    Poison(f32),
+    /// Multiply the opponent's poison
+    MultiplyPoison(f32),
+    /// Add a wound/status card to discard pile
+    AddStatus(CardId),
+    /// Add a copy of the played card to discard pile
+    AddCopyToDiscard,
+    /// Add a random playable card of this type from the played card's class
+    /// to hand, free to play for `free_secs`
+    AddRandomCard { card_type: CardType, free_secs: f32 },
+    /// Exhaust the rest of the hand (handled by the deck system)
+    ExhaustHand,
+    /// Damage/block scaled by the cards left in hand at play time
+    PerCardInHand { damage: f32, block: f32 },
+}
+
+/// What an action mostly does, for coloring the card face.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EffectKind {
+    Damage,
+    Heal,
+    Draw,
+    Block,
+    Thorns,
+    Accelerate,
+    Poison,
+}
+
+impl CardAction {
+    /// The kind of this action, or `None` for mixed or special ones.
+    pub fn kind(&self) -> Option<EffectKind> {
+        match self {
+            CardAction::Damage(_)
+            | CardAction::MultiHit { .. }
+            | CardAction::BodySlam
+            | CardAction::FireBreathing { .. } => Some(EffectKind::Damage),
+            CardAction::Heal(_) => Some(EffectKind::Heal),
+            CardAction::Draw(_) | CardAction::DarkEmbrace { .. } | CardAction::Evolve { .. } => {
+                Some(EffectKind::Draw)
+            }
+            CardAction::Block(_) | CardAction::DoubleBlock | CardAction::FeelNoPain { .. } => {
+                Some(EffectKind::Block)
+            }
+            CardAction::Thorns(_) => Some(EffectKind::Thorns),
+            CardAction::Accelerate { .. } | CardAction::RaiseCostCap { .. } => {
+                Some(EffectKind::Accelerate)
+            }
+            CardAction::Poison(_) | CardAction::MultiplyPoison(_) => Some(EffectKind::Poison),
+            CardAction::PerCardInHand { damage, block } => match (*damage > 0.0, *block > 0.0) {
+                (true, false) => Some(EffectKind::Damage),
+                (false, true) => Some(EffectKind::Block),
+                _ => None,
+            },
+            CardAction::Strength(_)
+            | CardAction::DoubleStrength
+            | CardAction::DemonForm(_)
+            | CardAction::Vulnerable(_)
+            | CardAction::SelfVulnerable(_)
+            | CardAction::Weak(_)
+            | CardAction::Bloodletting(_)
+            | CardAction::SelfDamage(_)
+            | CardAction::LoseAllCost
+            | CardAction::Rage(_)
+            | CardAction::Metallicize(_)
+            | CardAction::Combust { .. }
+            | CardAction::Barricade
+            | CardAction::Juggernaut(_)
+            | CardAction::Rupture { .. }
+            | CardAction::Corruption
+            | CardAction::Brutality { .. }
+            | CardAction::AddStatus(_)
+            | CardAction::AddCopyToDiscard
+            | CardAction::AddRandomCard { .. }
+            | CardAction::ExhaustHand => None,
+        }
+    }
+
+    /// Short card face lines for this action.
+    pub fn short_lines(&self, lines: &mut Vec<String>) {
+        let line = match self {
+            CardAction::Damage(amount) => format!("DMG {:.0}", amount),
+            CardAction::MultiHit { damage, hits } => format!("DMG {:.0}x{}", damage, hits),
+            CardAction::Heal(amount) => format!("HEAL {:.0}", amount),
+            CardAction::Draw(count) => format!("DRAW {}", count),
+            CardAction::Block(amount) => format!("BLOCK {:.0}", amount),
+            CardAction::Thorns(amount) => format!("THORNS {:.0}", amount),
+            CardAction::Strength(amount) => format!("STR +{:.0}", amount),
+            CardAction::Vulnerable(duration) => format!("VULN {:.0}s", duration),
+            CardAction::SelfVulnerable(duration) => format!("SELF VULN {:.0}s", duration),
+            CardAction::Weak(duration) => format!("WEAK {:.0}s", duration),
+            CardAction::Poison(stacks) => format!("POISON {:.0}", stacks),
+            CardAction::MultiplyPoison(factor) => format!("POISON x{:.0}", factor),
+            CardAction::Accelerate {
+                bonus_rate,
+                duration,
+            } => format!("ACCEL +{:.1}/s {:.0}s", bonus_rate, duration),
+            CardAction::RaiseCostCap { amount, duration } => {
+                format!("MAX COST +{:.0} {:.0}s", amount, duration)
+            }
+            CardAction::BodySlam => "BODY SLAM".to_string(),
+            CardAction::Bloodletting(amount) if *amount < 0.0 => format!("LOSE {:.0} HP", -amount),
+            CardAction::Bloodletting(amount) => format!("GAIN {:.0} HP", amount),
+            CardAction::SelfDamage(amount) => format!("-{:.0} HP", amount),
+            CardAction::LoseAllCost => "0 COST".to_string(),
+            CardAction::DoubleBlock => "x2 BLOCK".to_string(),
+            CardAction::DoubleStrength => "x2 STR".to_string(),
+            CardAction::Rage(block) => format!("RAGE {:.0}", block),
+            CardAction::Metallicize(block) => format!("METAL {:.0}/s", block),
+            CardAction::Combust {
+                self_damage_per_sec,
+                enemy_damage_per_sec,
+            } => format!(
+                "COMBUST -{:.0}/+{:.0}/s",
+                self_damage_per_sec, enemy_damage_per_sec
+            ),
+            CardAction::DemonForm(str_per_sec) => format!("DEMON +{:.0}STR/s", str_per_sec),
+            CardAction::Barricade => "BARRICADE".to_string(),
+            CardAction::Juggernaut(dmg) => format!("JUGG {:.0}", dmg),
+            CardAction::DarkEmbrace { draw } => format!("EXH DRAW +{}", draw),
+            CardAction::Evolve { draw } => format!("STATUS DRAW +{}", draw),
+            CardAction::FeelNoPain { block } => format!("EXH BLOCK +{:.0}", block),
+            CardAction::FireBreathing { damage } => format!("STATUS DMG {:.0}", damage),
+            CardAction::Rupture { strength } => format!("RUPTURE +{:.0} STR", strength),
+            CardAction::Corruption => "SKILL 0C EXH".to_string(),
+            CardAction::Brutality {
+                self_damage_per_sec,
+                draw,
+                draw_interval,
+            } => {
+                lines.push(format!("LOSE {:.0} HP/s", self_damage_per_sec));
+                format!("DRAW {}/{:.0}s", draw, draw_interval)
+            }
+            CardAction::AddStatus(_) => "+STATUS".to_string(),
+            CardAction::AddCopyToDiscard => "+COPY".to_string(),
+            CardAction::AddRandomCard { free_secs, .. } => format!("+RANDOM 0C {:.0}s", free_secs),
+            CardAction::ExhaustHand => "EXH HAND".to_string(),
+            CardAction::PerCardInHand { damage, block } => {
+                if *damage > 0.0 {
+                    lines.push(format!("DMG {:.0}/CARD", damage));
+                }
+                if *block > 0.0 {
+                    lines.push(format!("BLOCK {:.0}/CARD", block));
+                }
+                return;
+            }
+        };
+        lines.push(line);
+    }
+}
+
+/// What a card does: its keywords and its triggered actions, which resolve
+/// in order.
+#[derive(Debug, Clone, Default)]
+pub struct CardEffect {
+    pub keywords: Vec<CardKeyword>,
+    pub actions: Vec<(CardTrigger, CardAction)>,
+}
+
+impl CardEffect {
+    /// An effect that runs `actions` in order when the card is played.
+    pub fn on_play(actions: impl IntoIterator<Item = CardAction>) -> Self {
+        Self {
+            keywords: Vec::new(),
+            actions: actions
+                .into_iter()
+                .map(|action| (CardTrigger::Play, action))
+                .collect(),
+        }
+    }
+
+    /// Add a keyword.
+    pub fn keyword(mut self, keyword: CardKeyword) -> Self {
+        self.keywords.push(keyword);
+        self
+    }
+
+    /// Add an action fired by `trigger`, after the existing ones.
+    pub fn on(mut self, trigger: CardTrigger, action: CardAction) -> Self {
+        self.actions.push((trigger, action));
+        self
+    }
+
+    pub fn has_keyword(&self, keyword: CardKeyword) -> bool {
+        self.keywords.contains(&keyword)
+    }
+
+    /// The actions `trigger` fires, in order.
+    pub fn actions(&self, trigger: CardTrigger) -> impl Iterator<Item = &CardAction> {
+        self.actions
+            .iter()
+            .filter(move |(fired_by, _)| *fired_by == trigger)
+            .map(|(_, action)| action)
+    }
+
+    /// Whether this effect prevents the card from being played.
+    pub fn is_unplayable(&self) -> bool {
+        self.has_keyword(CardKeyword::Unplayable)
+    }
+
+    /// Whether playing this effect exhausts the card itself.
+    pub fn exhausts_self(&self) -> bool {
+        self.has_keyword(CardKeyword::Exhaust)
+    }
+
+    /// Whether playing this effect exhausts the rest of the hand.
+    pub fn exhausts_hand(&self) -> bool {
+        self.actions(CardTrigger::Play)
+            .any(|action| matches!(action, CardAction::ExhaustHand))
+    }
+
+    /// The kind shared by every action, or `None` if they differ.
+    pub fn kind(&self) -> Option<EffectKind> {
+        let mut kinds = self.actions.iter().map(|(_, action)| action.kind());
+        let first = kinds.next()??;
+        kinds.all(|kind| kind == Some(first)).then_some(first)
+    }
+
+    /// Short card face text, one line per action: `UNPLAYABLE` first,
+    /// triggered actions in order, `EXHAUST` last.
+    pub fn short_lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        if self.is_unplayable() {
+            lines.push("UNPLAYABLE".to_string());
+        }
+        for (trigger, action) in &self.actions {
+            let start = lines.len();
+            action.short_lines(&mut lines);
+            for line in &mut lines[start..] {
+                line.insert_str(0, trigger.text_prefix());
+            }
+        }
+        if self.exhausts_self() {
+            lines.push("EXHAUST".to_string());
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draw_actions_are_prefixed_after_keywords() {
+        let burn = CardEffect::default()
+            .keyword(CardKeyword::Unplayable)
+            .on(CardTrigger::Draw, CardAction::SelfDamage(20.0));
+        assert_eq!(burn.short_lines(), ["UNPLAYABLE", "DRAW: -20 HP"]);
+        assert!(burn.is_unplayable());
+        assert_eq!(burn.actions(CardTrigger::Play).count(), 0);
+    }
+
+    #[test]
+    fn kind_is_shared_by_every_action() {
+        let damage = CardEffect::on_play([
+            CardAction::Damage(60.0),
+            CardAction::MultiHit {
+                damage: 10.0,
+                hits: 2,
+            },
+        ]);
+        assert_eq!(damage.kind(), Some(EffectKind::Damage));
+        let mixed = CardEffect::on_play([CardAction::Damage(60.0), CardAction::Block(50.0)]);
+        assert_eq!(mixed.kind(), None);
+        assert_eq!(CardEffect::default().kind(), None);
+    }
+}
@@ -6,17 +6,26 @@
 //! - Power: Persistent effects that last the whole game
 
 mod attack;
+mod describe;
+mod keyword;
 mod power;
+mod silent;
 mod skill;
 mod status;
 
-use bevy::prelude::*;
+use bevy::{platform::collections::HashMap, prelude::*};
+use serde::{Deserialize, Serialize};
 
 pub use attack::register_attack_cards;
+pub use keyword::{CardAction, CardEffect, CardKeyword, CardTrigger, EffectKind, RAGE_SECS};
 pub use power::register_power_cards;
+pub use silent::register_silent_cards;
 pub use skill::register_skill_cards;
 pub use status::register_status_cards;
 
+/// Asset folder holding one `<card>.png` front per card (see [`CardDef::art_path`]).
+pub const CARD_ART_DIR: &str = "cards/";
+
 pub fn plugin(app: &mut App) {
     app.init_resource::<CardRegistry>();
     app.add_systems(Startup, setup_all_cards);
@@ -51,8 +60,32 @@ pub enum CardRarity {
     Special,
 }
 
+impl CardRarity {
+    /// Relative chance of showing up in a card reward (0 = never offered).
+    pub fn reward_weight(self) -> usize {
+        match self {
+            CardRarity::Common => 6,
+            CardRarity::Uncommon => 3,
+            CardRarity::Rare => 1,
+            CardRarity::Starter | CardRarity::Special => 0,
+        }
+    }
+}
+
+/// Playable character class; each has its own card pool and starter deck.
+#[derive(
+    Resource, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Default, Serialize, Deserialize,
+)]
+pub enum CharacterClass {
+    /// Strength and block (cards 1-299)
+    #[default]
+    Ironclad,
+    /// Poison and card flow (cards 400-499)
+    Silent,
+}
+
 /// Unique identifier for a card type.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
 #[repr(u32)]
 pub enum CardId {
     Unknown = 0,
@@ -111,6 +144,7 @@ pub enum CardId {
     Impervious = 125,
     LimitBreak = 126,
     Offering = 127,
+    Hoard = 128,
     Combust = 200,
     DarkEmbrace = 201,
     Evolve = 202,
@@ -130,6 +164,27 @@ pub enum CardId {
     Burn = 302,
     Slimed = 303,
     Void = 304,
+    Neutralize = 400,
+    Survivor = 401,
+    DeadlyPoison = 402,
+    PoisonedStab = 403,
+    Backflip = 404,
+    DaggerSpray = 405,
+    Acrobatics = 406,
+    BladeDance = 407,
+    Catalyst = 408,
+    Adrenaline = 409,
+}
+
+impl CardId {
+    /// The class this card belongs to, or `None` for shared status cards.
+    pub fn class(self) -> Option<CharacterClass> {
+        match self as u32 {
+            1..=299 => Some(CharacterClass::Ironclad),
+            400..=499 => Some(CharacterClass::Silent),
+            _ => None,
+        }
+    }
 }
 
 /// Definition of a card type (shared data).
@@ -137,111 +192,68 @@ pub enum CardId {
 pub struct CardDef {
     pub id: CardId,
     pub name: String,
-    #[allow(dead_code)]
-    pub description: String,
+    /// Hand-written text shown instead of the generated description, for
+    /// flavor. Its numbers don't follow the effect, so most cards leave it
+    /// `None`.
+    pub description: Option<String>,
     pub card_type: CardType,
-    #[allow(dead_code)]
     pub rarity: CardRarity,
     pub cost: f32,
     pub effect: CardEffect,
 }
 
-/// What a card does when played.
-#[derive(Debug, Clone)]
-pub enum CardEffect {
-    /// Deal damage to opponent
-    Damage(f32),
-    /// Deal damage multiple times
-    MultiHit { damage: f32, hits: u32 },
-    /// Heal self
-    Heal(f32),
-    /// Draw cards
-    Draw(u32),
-    /// Gain block (reduces incoming damage)
-    Block(f32),
-    /// Gain thorns (reflect damage when hit)
-    Thorns(f32),
-    /// Gain strength (increases attack damage)
-    Strength(f32),
-    /// Apply vulnerable to opponent (takes more damage)
-    Vulnerable(f32),
-    /// Apply vulnerable to self (takes more damage)
-    SelfVulnerable(f32),
-    /// Apply weak to opponent (deals less damage)
-    Weak(f32),
-    /// Temporarily increase cost generation rate
-    Accelerate { bonus_rate: f32, duration: f32 },
-    /// Deal damage equal to current block
-    BodySlam,
-    /// Lose HP (negative) or gain HP (positive) via card effect
-    Bloodletting(f32),
-    /// Double current block
-    DoubleBlock,
-    /// Double current strength
-    DoubleStrength,
-    /// Gain Rage (gain block when playing attacks)
-    Rage(f32),
-    /// Gain Metallicize (gain block continuously)
-    Metallicize(f32),
-    /// Gain Combust (continuous damage to self and enemies)
-    Combust {
-        self_damage_per_sec: f32,
-        enemy_damage_per_sec: f32,
-    },
-    /// Gain Demon Form (gain strength over time)
-    DemonForm(f32),
-    /// Gain Barricade (block doesn't decay)
-    Barricade,
-    /// Gain Juggernaut (deal damage when gaining block)
-    Juggernaut(f32),
-    /// Draw when a card is exhausted
-    DarkEmbrace { draw: u32 },
-    /// Draw when a status card is drawn
-    Evolve { draw: u32 },
-    /// Gain block when a card is exhausted
-    FeelNoPain { block: f32 },
-    /// Deal damage when a status card is drawn
-    FireBreathing { damage: f32 },
-    /// Gain strength when taking self-damage
-    Rupture { strength: f32 },
-    /// Skills cost 0 and exhaust when played
-    Corruption,
-    /// Continuous self damage + periodic draw
-    Brutality {
-        self_damage_per_sec: f32,
-        draw: u32,
-        draw_interval: f32,
-    },
-    /// Exhaust this card (removed from deck for this combat)
-    Exhaust,
-    /// Add a wound/status card to discard pile
-    AddStatus(CardId),
-    /// Apply multiple effects in sequence
-    Combo(Vec<CardEffect>),
+impl CardDef {
+    /// Whether this card can be played from hand at all.
+    pub fn is_playable(&self) -> bool {
+        !self.effect.is_unplayable()
+    }
+
+    /// Front art for this card, e.g. `cards/pommel_strike.png` for Pommel Strike.
+    pub fn art_path(&self) -> String {
+        let mut path = CARD_ART_DIR.to_string();
+        for (index, ch) in format!("{:?}", self.id).chars().enumerate() {
+            if ch.is_ascii_uppercase() && index > 0 {
+                path.push('_');
+            }
+            path.push(ch.to_ascii_lowercase());
+        }
+        path.push_str(".png");
+        path
+    }
 }
 
 /// Registry of all card definitions.
+///
+/// Lookups by id and by name are indexed; iteration follows registration
+/// order so listings stay stable.
 #[derive(Resource, Default)]
 pub struct CardRegistry {
     cards: Vec<CardDef>,
+    by_id: HashMap<CardId, usize>,
+    /// Keyed by lowercase name.
+    by_name: HashMap<String, usize>,
 }
 
 impl CardRegistry {
+    /// Add a card. The first definition registered for an id or name wins.
     pub fn register(&mut self, card: CardDef) {
+        let index = self.cards.len();
+        self.by_id.entry(card.id).or_insert(index);
+        self.by_name
+            .entry(card.name.to_lowercase())
+            .or_insert(index);
         self.cards.push(card);
     }
 
     pub fn get(&self, id: CardId) -> Option<&CardDef> {
-        self.cards.iter().find(|c| c.id == id)
+        self.by_id.get(&id).map(|&index| &self.cards[index])
     }
 
     /// Get a card by name (case-insensitive).
-    #[allow(dead_code)]
     pub fn get_by_name(&self, name: &str) -> Option<&CardDef> {
-        let name_lower = name.to_lowercase();
-        self.cards
-            .iter()
-            .find(|c| c.name.to_lowercase() == name_lower)
+        self.by_name
+            .get(&name.to_lowercase())
+            .map(|&index| &self.cards[index])
     }
 
     /// Get a CardId by name (case-insensitive). Panics if not found.
@@ -253,25 +265,23 @@ impl CardRegistry {
             .id
     }
 
-    #[allow(dead_code)]
-    pub fn all(&self) -> &[CardDef] {
-        &self.cards
-    }
-
-    #[allow(dead_code)]
-    pub fn get_by_type(&self, card_type: CardType) -> Vec<&CardDef> {
-        self.cards
-            .iter()
-            .filter(|c| c.card_type == card_type)
-            .collect()
+    /// Every card, in registration order.
+    pub fn iter(&self) -> impl Iterator<Item = &CardDef> {
+        self.cards.iter()
     }
 }
+
 /// Setup all cards at startup.
 fn setup_all_cards(mut registry: ResMut<CardRegistry>) {
-    register_attack_cards(&mut registry);
-    register_skill_cards(&mut registry);
-    register_power_cards(&mut registry);
-    register_status_cards(&mut registry);
+    register_all_cards(&mut registry);
+}
+
+fn register_all_cards(registry: &mut CardRegistry) {
+    register_attack_cards(registry);
+    register_skill_cards(registry);
+    register_power_cards(registry);
+    register_status_cards(registry);
+    register_silent_cards(registry);
 }
 
 // Card ID ranges:
@@ -279,3 +289,38 @@ fn setup_all_cards(mut registry: ResMut<CardRegistry>) {
 // 100-199: Skill cards
 // 200-299: Power cards
 // 300-399: Status cards
+// 400-499: Silent cards
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> CardRegistry {
+        let mut registry = CardRegistry::default();
+        register_all_cards(&mut registry);
+        registry
+    }
+
+    #[test]
+    fn lookups_match_a_linear_scan() {
+        let registry = registry();
+        for card in registry.iter() {
+            let first = registry.iter().find(|c| c.id == card.id).unwrap();
+            assert_eq!(registry.get(card.id).unwrap().name, first.name);
+            let by_name = registry.get_by_name(&card.name.to_uppercase()).unwrap();
+            assert_eq!(by_name.id, card.id);
+        }
+        assert!(registry.get_by_name("no such card").is_none());
+    }
+
+    #[test]
+    fn iteration_keeps_registration_order() {
+        let registry = registry();
+        let mut expected = CardRegistry::default();
+        register_attack_cards(&mut expected);
+        let attacks = expected.iter().count();
+        let ids: Vec<CardId> = registry.iter().take(attacks).map(|c| c.id).collect();
+        let expected_ids: Vec<CardId> = expected.iter().map(|c| c.id).collect();
+        assert_eq!(ids, expected_ids);
+    }
+}
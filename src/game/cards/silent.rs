@@ -0,0 +1,141 @@
+//! Silent cards - poison and card flow.
+//!
+//! Card IDs: 400-499
+
+use super::{
+    CardAction, CardDef, CardEffect, CardId, CardKeyword, CardRarity, CardRegistry, CardType,
+};
+
+pub fn register_silent_cards(registry: &mut CardRegistry) {
+    // === STARTER CARDS ===
+
+    // 400: Neutralize - Cheap damage + weak
+    registry.register(CardDef {
+        id: CardId::Neutralize,
+        name: "Neutralize".to_string(),
+        description: None,
+        card_type: CardType::Attack,
+        rarity: CardRarity::Starter,
+        cost: 0.5,
+        effect: CardEffect::on_play([CardAction::Damage(30.0), CardAction::Weak(1.0)]),
+    });
+
+    // 401: Survivor - Big block
+    registry.register(CardDef {
+        id: CardId::Survivor,
+        name: "Survivor".to_string(),
+        description: None,
+        card_type: CardType::Skill,
+        rarity: CardRarity::Starter,
+        cost: 1.0,
+        effect: CardEffect::on_play([CardAction::Block(80.0)]),
+    });
+
+    // === COMMON CARDS ===
+
+    // 402: Deadly Poison - Apply poison
+    registry.register(CardDef {
+        id: CardId::DeadlyPoison,
+        name: "Deadly Poison".to_string(),
+        description: None,
+        card_type: CardType::Skill,
+        rarity: CardRarity::Common,
+        cost: 1.0,
+        effect: CardEffect::on_play([CardAction::Poison(5.0)]),
+    });
+
+    // 403: Poisoned Stab - Damage + poison
+    registry.register(CardDef {
+        id: CardId::PoisonedStab,
+        name: "Poisoned Stab".to_string(),
+        description: None,
+        card_type: CardType::Attack,
+        rarity: CardRarity::Common,
+        cost: 1.0,
+        effect: CardEffect::on_play([CardAction::Damage(60.0), CardAction::Poison(3.0)]),
+    });
+
+    // 404: Backflip - Block + draw
+    registry.register(CardDef {
+        id: CardId::Backflip,
+        name: "Backflip".to_string(),
+        description: None,
+        card_type: CardType::Skill,
+        rarity: CardRarity::Common,
+        cost: 1.0,
+        effect: CardEffect::on_play([CardAction::Block(50.0), CardAction::Draw(2)]),
+    });
+
+    // 405: Dagger Spray - Hit twice
+    registry.register(CardDef {
+        id: CardId::DaggerSpray,
+        name: "Dagger Spray".to_string(),
+        description: None,
+        card_type: CardType::Attack,
+        rarity: CardRarity::Common,
+        cost: 1.0,
+        effect: CardEffect::on_play([CardAction::MultiHit {
+            damage: 40.0,
+            hits: 2,
+        }]),
+    });
+
+    // === UNCOMMON CARDS ===
+
+    // 406: Acrobatics - Draw
+    registry.register(CardDef {
+        id: CardId::Acrobatics,
+        name: "Acrobatics".to_string(),
+        description: None,
+        card_type: CardType::Skill,
+        rarity: CardRarity::Uncommon,
+        cost: 1.0,
+        effect: CardEffect::on_play([CardAction::Draw(3)]),
+    });
+
+    // 407: Blade Dance - Many small hits
+    registry.register(CardDef {
+        id: CardId::BladeDance,
+        name: "Blade Dance".to_string(),
+        description: None,
+        card_type: CardType::Attack,
+        rarity: CardRarity::Uncommon,
+        cost: 1.5,
+        effect: CardEffect::on_play([CardAction::MultiHit {
+            damage: 40.0,
+            hits: 3,
+        }]),
+    });
+
+    // 408: Catalyst - Double poison
+    registry.register(CardDef {
+        id: CardId::Catalyst,
+        name: "Catalyst".to_string(),
+        description: None,
+        card_type: CardType::Skill,
+        rarity: CardRarity::Uncommon,
+        cost: 1.0,
+        effect: CardEffect::on_play([CardAction::MultiplyPoison(2.0)])
+            .keyword(CardKeyword::Exhaust),
+    });
+
+    // === RARE CARDS ===
+
+    // 409: Adrenaline - Draw + cost acceleration
+    registry.register(CardDef {
+        id: CardId::Adrenaline,
+        name: "Adrenaline".to_string(),
+        description: None,
+        card_type: CardType::Skill,
+        rarity: CardRarity::Rare,
+        cost: 0.0,
+        effect: CardEffect::on_play([
+            CardAction::Draw(2),
+            CardAction::Accelerate {
+                bonus_rate: 1.0,
+                duration: 3.0,
+            },
+        ])
+        .keyword(CardKeyword::Exhaust),
+    });
+}
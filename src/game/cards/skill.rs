@@ -2,7 +2,7 @@
 //!
 //! Card IDs: 100-199
 
-use super::{CardDef, CardEffect, CardId, CardRarity, CardRegistry, CardType};
+use super::{CardAction, CardDef, CardEffect, CardId, CardRarity, CardRegistry, CardType};
 
 pub fn register_skill_cards(registry: &mut CardRegistry) {
     // === STARTER CARDS ===
@@ -11,11 +11,11 @@ pub fn register_skill_cards(registry: &mut CardRegistry) {
     registry.register(CardDef {
         id: CardId::Defend,
         name: "Defend".to_string(),
-        description: "Gain 50 Block.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Starter,
         cost: 1.0,
-        effect: CardEffect::Block(50.0),
+        effect: CardEffect::on_play([CardAction::Block(50.0)]),
     });
 
     // === COMMON SKILLS ===
@@ -24,66 +24,66 @@ pub fn register_skill_cards(registry: &mut CardRegistry) {
     registry.register(CardDef {
         id: CardId::Armaments,
         name: "Armaments".to_string(),
-        description: "Gain 50 Block.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Common,
         cost: 1.0,
-        effect: CardEffect::Block(50.0),
+        effect: CardEffect::on_play([CardAction::Block(50.0)]),
     });
 
     // 102: Flex - Temporary strength
     registry.register(CardDef {
         id: CardId::Flex,
         name: "Flex".to_string(),
-        description: "Gain 2 Strength for 5 seconds.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Common,
         cost: 0.5,
-        effect: CardEffect::Strength(2.0), // Temporary effect tracked separately
+        effect: CardEffect::on_play([CardAction::Strength(2.0)]), // Temporary effect tracked separately
     });
 
     // 103: Havoc - Play top card from deck
     registry.register(CardDef {
         id: CardId::Havoc,
         name: "Havoc".to_string(),
-        description: "Draw and play the top card of your deck.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Common,
         cost: 1.0,
-        effect: CardEffect::Draw(1), // Simplified
+        effect: CardEffect::on_play([CardAction::Draw(1)]), // Simplified
     });
 
     // 104: Shrug It Off - Block + Draw
     registry.register(CardDef {
         id: CardId::ShrugItOff,
         name: "Shrug It Off".to_string(),
-        description: "Gain 80 Block. Draw 1 card.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Common,
         cost: 1.0,
-        effect: CardEffect::Combo(vec![CardEffect::Block(80.0), CardEffect::Draw(1)]),
+        effect: CardEffect::on_play([CardAction::Block(80.0), CardAction::Draw(1)]),
     });
 
     // 105: True Grit - Block, exhaust random
     registry.register(CardDef {
         id: CardId::TrueGrit,
         name: "True Grit".to_string(),
-        description: "Gain 70 Block.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Common,
         cost: 1.0,
-        effect: CardEffect::Block(70.0),
+        effect: CardEffect::on_play([CardAction::Block(70.0)]),
     });
 
     // 106: Warcry - Draw + put card on deck
     registry.register(CardDef {
         id: CardId::Warcry,
         name: "Warcry".to_string(),
-        description: "Draw 2 cards.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Common,
         cost: 0.5,
-        effect: CardEffect::Draw(2),
+        effect: CardEffect::on_play([CardAction::Draw(2)]),
     });
 
     // === UNCOMMON SKILLS ===
@@ -92,24 +92,24 @@ pub fn register_skill_cards(registry: &mut CardRegistry) {
     registry.register(CardDef {
         id: CardId::BattleTrance,
         name: "Battle Trance".to_string(),
-        description: "Draw 3 cards.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Uncommon,
         cost: 0.5,
-        effect: CardEffect::Draw(3),
+        effect: CardEffect::on_play([CardAction::Draw(3)]),
     });
 
     // 108: Bloodletting - Self damage for cost boost
     registry.register(CardDef {
         id: CardId::Bloodletting,
         name: "Bloodletting".to_string(),
-        description: "Lose 30 HP. Gain cost acceleration.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Uncommon,
         cost: 0.5,
-        effect: CardEffect::Combo(vec![
-            CardEffect::Bloodletting(-30.0),
-            CardEffect::Accelerate {
+        effect: CardEffect::on_play([
+            CardAction::Bloodletting(-30.0),
+            CardAction::Accelerate {
                 bonus_rate: 1.0,
                 duration: 5.0,
             },
@@ -120,91 +120,94 @@ pub fn register_skill_cards(registry: &mut CardRegistry) {
     registry.register(CardDef {
         id: CardId::BurningPact,
         name: "Burning Pact".to_string(),
-        description: "Draw 2 cards.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Uncommon,
         cost: 1.0,
-        effect: CardEffect::Draw(2),
+        effect: CardEffect::on_play([CardAction::Draw(2)]),
     });
 
     // 110: Disarm - Remove enemy strength (simplified: apply weak)
     registry.register(CardDef {
         id: CardId::Disarm,
         name: "Disarm".to_string(),
-        description: "Apply 2 Weak.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Uncommon,
         cost: 1.0,
-        effect: CardEffect::Weak(2.0),
+        effect: CardEffect::on_play([CardAction::Weak(2.0)]),
     });
 
     // 111: Entrench - Double block
     registry.register(CardDef {
         id: CardId::Entrench,
         name: "Entrench".to_string(),
-        description: "Double your current Block.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Uncommon,
         cost: 2.0,
-        effect: CardEffect::DoubleBlock,
+        effect: CardEffect::on_play([CardAction::DoubleBlock]),
     });
 
     // 112: Flame Barrier - Block + thorns
     registry.register(CardDef {
         id: CardId::FlameBarrier,
         name: "Flame Barrier".to_string(),
-        description: "Gain 120 Block. Gain 4 Thorns.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Uncommon,
         cost: 2.0,
-        effect: CardEffect::Combo(vec![CardEffect::Block(120.0), CardEffect::Thorns(4.0)]),
+        effect: CardEffect::on_play([CardAction::Block(120.0), CardAction::Thorns(4.0)]),
     });
 
     // 113: Ghostly Armor - Big block
     registry.register(CardDef {
         id: CardId::GhostlyArmor,
         name: "Ghostly Armor".to_string(),
-        description: "Gain 100 Block.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Uncommon,
         cost: 1.0,
-        effect: CardEffect::Block(100.0),
+        effect: CardEffect::on_play([CardAction::Block(100.0)]),
     });
 
     // 114: Infernal Blade - Random attack to hand
     registry.register(CardDef {
         id: CardId::InfernalBlade,
         name: "Infernal Blade".to_string(),
-        description: "Draw 2 cards.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Uncommon,
         cost: 1.0,
-        effect: CardEffect::Draw(2),
+        effect: CardEffect::on_play([CardAction::AddRandomCard {
+            card_type: CardType::Attack,
+            free_secs: 5.0,
+        }]),
     });
 
     // 115: Intimidate - Apply weak to all
     registry.register(CardDef {
         id: CardId::Intimidate,
         name: "Intimidate".to_string(),
-        description: "Apply 1 Weak.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Uncommon,
         cost: 0.5,
-        effect: CardEffect::Weak(1.0),
+        effect: CardEffect::on_play([CardAction::Weak(1.0)]),
     });
 
     // 116: Power Through - Big block, add wounds
     registry.register(CardDef {
         id: CardId::PowerThrough,
         name: "Power Through".to_string(),
-        description: "Gain 150 Block. Add 2 Wounds to your hand.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Uncommon,
         cost: 1.0,
-        effect: CardEffect::Combo(vec![
-            CardEffect::Block(150.0),
-            CardEffect::AddStatus(CardId::Wound),
-            CardEffect::AddStatus(CardId::Wound),
+        effect: CardEffect::on_play([
+            CardAction::Block(150.0),
+            CardAction::AddStatus(CardId::Wound),
+            CardAction::AddStatus(CardId::Wound),
         ]),
     });
 
@@ -212,69 +215,72 @@ pub fn register_skill_cards(registry: &mut CardRegistry) {
     registry.register(CardDef {
         id: CardId::Rage,
         name: "Rage".to_string(),
-        description: "This turn, gain 3 Block when playing Attacks.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Uncommon,
         cost: 0.5,
-        effect: CardEffect::Rage(30.0),
+        effect: CardEffect::on_play([CardAction::Rage(30.0)]),
     });
 
-    // 118: Second Wind - Block for non-attacks
+    // 118: Second Wind - Block per card in hand
     registry.register(CardDef {
         id: CardId::SecondWind,
         name: "Second Wind".to_string(),
-        description: "Gain 50 Block for each card in hand.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Uncommon,
         cost: 1.0,
-        effect: CardEffect::Block(200.0), // Assuming ~4 cards
+        effect: CardEffect::on_play([CardAction::PerCardInHand {
+            damage: 0.0,
+            block: 50.0,
+        }]),
     });
 
     // 119: Seeing Red - Free cost boost
     registry.register(CardDef {
         id: CardId::SeeingRed,
         name: "Seeing Red".to_string(),
-        description: "Gain cost acceleration.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Uncommon,
         cost: 1.0,
-        effect: CardEffect::Accelerate {
+        effect: CardEffect::on_play([CardAction::Accelerate {
             bonus_rate: 1.5,
             duration: 4.0,
-        },
+        }]),
     });
 
     // 120: Sentinel - Block, gain energy on exhaust
     registry.register(CardDef {
         id: CardId::Sentinel,
         name: "Sentinel".to_string(),
-        description: "Gain 50 Block.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Uncommon,
         cost: 1.0,
-        effect: CardEffect::Block(50.0),
+        effect: CardEffect::on_play([CardAction::Block(50.0)]),
     });
 
     // 121: Shockwave - Apply weak and vulnerable
     registry.register(CardDef {
         id: CardId::Shockwave,
         name: "Shockwave".to_string(),
-        description: "Apply 3 Weak. Apply 3 Vulnerable.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Uncommon,
         cost: 2.0,
-        effect: CardEffect::Combo(vec![CardEffect::Weak(3.0), CardEffect::Vulnerable(3.0)]),
+        effect: CardEffect::on_play([CardAction::Weak(3.0), CardAction::Vulnerable(3.0)]),
     });
 
     // 122: Spot Weakness - Gain strength if enemy attacking
     registry.register(CardDef {
         id: CardId::SpotWeakness,
         name: "Spot Weakness".to_string(),
-        description: "Gain 3 Strength.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Uncommon,
         cost: 1.0,
-        effect: CardEffect::Strength(3.0),
+        effect: CardEffect::on_play([CardAction::Strength(3.0)]),
     });
 
     // === RARE SKILLS ===
@@ -283,61 +289,75 @@ pub fn register_skill_cards(registry: &mut CardRegistry) {
     registry.register(CardDef {
         id: CardId::DoubleTap,
         name: "Double Tap".to_string(),
-        description: "Your next Attack is played twice.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Rare,
         cost: 1.0,
-        effect: CardEffect::Draw(1), // Simplified; real effect needs state tracking
+        effect: CardEffect::on_play([CardAction::Draw(1)]), // Simplified; real effect needs state tracking
     });
 
     // 124: Exhume - Get exhausted card
     registry.register(CardDef {
         id: CardId::Exhume,
         name: "Exhume".to_string(),
-        description: "Draw 2 cards.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Rare,
         cost: 1.0,
-        effect: CardEffect::Draw(2),
+        effect: CardEffect::on_play([CardAction::Draw(2)]),
     });
 
     // 125: Impervious - Massive block
     registry.register(CardDef {
         id: CardId::Impervious,
         name: "Impervious".to_string(),
-        description: "Gain 300 Block.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Rare,
         cost: 2.0,
-        effect: CardEffect::Block(300.0),
+        effect: CardEffect::on_play([CardAction::Block(300.0)]),
     });
 
     // 126: Limit Break - Double strength
     registry.register(CardDef {
         id: CardId::LimitBreak,
         name: "Limit Break".to_string(),
-        description: "Double your Strength.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Rare,
         cost: 1.0,
-        effect: CardEffect::DoubleStrength,
+        effect: CardEffect::on_play([CardAction::DoubleStrength]),
     });
 
     // 127: Offering - Self damage for draw + cost
     registry.register(CardDef {
         id: CardId::Offering,
         name: "Offering".to_string(),
-        description: "Lose 60 HP. Gain cost acceleration. Draw 3 cards.".to_string(),
+        description: None,
         card_type: CardType::Skill,
         rarity: CardRarity::Rare,
         cost: 0.5,
-        effect: CardEffect::Combo(vec![
-            CardEffect::Bloodletting(-60.0),
-            CardEffect::Accelerate {
+        effect: CardEffect::on_play([
+            CardAction::Bloodletting(-60.0),
+            CardAction::Accelerate {
                 bonus_rate: 2.0,
                 duration: 5.0,
             },
-            CardEffect::Draw(3),
+            CardAction::Draw(3),
         ]),
     });
+
+    // 128: Hoard - Raise the cost cap
+    registry.register(CardDef {
+        id: CardId::Hoard,
+        name: "Hoard".to_string(),
+        description: None,
+        card_type: CardType::Skill,
+        rarity: CardRarity::Uncommon,
+        cost: 0.5,
+        effect: CardEffect::on_play([CardAction::RaiseCostCap {
+            amount: 5.0,
+            duration: 10.0,
+        }]),
+    });
 }
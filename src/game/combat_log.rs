@@ -0,0 +1,371 @@
+//! Combat log: a timestamped list of what both players did, shown with Tab.
+//!
+//! [`CombatLog`] is recorded inside the simulation like [`super::MatchStats`],
+//! so online it rolls back with the match and re-simulated frames aren't
+//! logged twice. Entries name players by handle and only become "You" or
+//! "Opponent" when drawn. The log keeps the last [`LOG_CAPACITY`] entries.
+
+use std::collections::VecDeque;
+
+use bevy::{ecs::system::SystemParam, input::common_conditions::input_just_pressed, prelude::*};
+
+use super::{
+    AddGameplaySystems, CardId, CardPlayedMessage, CardRegistry, DamageKind, DamageMessage,
+    DeckReshuffledMessage, GainBlockMessage, GameplaySystems, HealMessage, LocalPlayer, MatchClock,
+    PlayerHandle,
+    effect::{ApplyPoisonMessage, ApplyStrengthMessage, ApplyVulnerableMessage, ApplyWeakMessage},
+};
+use crate::{
+    AppSystems,
+    screens::Screen,
+    theme::{scroll::FollowEnd, widget},
+};
+
+/// Entries kept; the oldest is dropped when a new one doesn't fit.
+const LOG_CAPACITY: usize = 200;
+
+/// The panel, toggled with Tab.
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_combat_log_panel);
+    app.add_systems(
+        Update,
+        (
+            toggle_combat_log.run_if(input_just_pressed(KeyCode::Tab)),
+            update_combat_log_text,
+        )
+            .chain()
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// The log itself, fed from the gameplay messages.
+pub(super) fn simulation_plugin(app: &mut App) {
+    app.init_resource::<CombatLog>();
+    app.add_systems(OnEnter(Screen::Gameplay), clear_combat_log);
+    app.add_gameplay_systems(GameplaySystems::Health, record_combat_log);
+}
+
+/// The most recent [`LOG_CAPACITY`] things that happened this match, oldest
+/// first.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct CombatLog {
+    entries: VecDeque<LogEntry>,
+}
+
+impl CombatLog {
+    pub fn entries(&self) -> impl DoubleEndedIterator<Item = &LogEntry> {
+        self.entries.iter()
+    }
+
+    fn push(&mut self, entry: LogEntry) {
+        if self.entries.len() == LOG_CAPACITY {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LogEntry {
+    /// Match time, in seconds.
+    pub time: f32,
+    /// The player it happened to, or who did it.
+    pub handle: usize,
+    pub event: LogEvent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LogEvent {
+    Played(CardId),
+    Damage(f32, DamageKind),
+    Heal(f32),
+    Block(f32),
+    Strength(f32),
+    /// Seconds of Vulnerable applied.
+    Vulnerable(f32),
+    /// Seconds of Weak applied.
+    Weak(f32),
+    Poison(f32),
+    /// The discard pile went back into the deck, this many cards.
+    Reshuffled(usize),
+}
+
+impl LogEntry {
+    /// One line of the panel, e.g. `[1:05] You played Strike`.
+    fn line(&self, local: usize, registry: &CardRegistry) -> String {
+        let secs = self.time.max(0.0) as u32;
+        let who = if self.handle == local {
+            "You"
+        } else {
+            "Opponent"
+        };
+        let what = match self.event {
+            LogEvent::Played(card_id) => {
+                let name = registry
+                    .get(card_id)
+                    .map_or("a card", |def| def.name.as_str());
+                format!("played {name}")
+            }
+            LogEvent::Damage(amount, DamageKind::Attack) => format!("took {amount:.0} damage"),
+            LogEvent::Damage(amount, DamageKind::Power) => {
+                format!("took {amount:.0} power damage")
+            }
+            LogEvent::Damage(amount, DamageKind::Thorns) => {
+                format!("took {amount:.0} Thorns damage")
+            }
+            LogEvent::Heal(amount) => format!("healed {amount:.0}"),
+            LogEvent::Block(amount) => format!("gained {amount:.0} Block"),
+            LogEvent::Strength(amount) => format!("gained {amount:.0} Strength"),
+            LogEvent::Vulnerable(secs) => format!("became Vulnerable for {secs:.1}s"),
+            LogEvent::Weak(secs) => format!("became Weak for {secs:.1}s"),
+            LogEvent::Poison(stacks) => format!("gained {stacks:.0} Poison"),
+            LogEvent::Reshuffled(cards) => format!("reshuffled {cards} cards into the deck"),
+        };
+        format!("[{}:{:02}] {who} {what}", secs / 60, secs % 60)
+    }
+}
+
+fn clear_combat_log(mut log: ResMut<CombatLog>) {
+    *log = CombatLog::default();
+}
+
+/// Every message the log listens to.
+#[derive(SystemParam)]
+struct LogMessages<'w, 's> {
+    played: MessageReader<'w, 's, CardPlayedMessage>,
+    damage: MessageReader<'w, 's, DamageMessage>,
+    heal: MessageReader<'w, 's, HealMessage>,
+    block: MessageReader<'w, 's, GainBlockMessage>,
+    strength: MessageReader<'w, 's, ApplyStrengthMessage>,
+    vulnerable: MessageReader<'w, 's, ApplyVulnerableMessage>,
+    weak: MessageReader<'w, 's, ApplyWeakMessage>,
+    poison: MessageReader<'w, 's, ApplyPoisonMessage>,
+    reshuffled: MessageReader<'w, 's, DeckReshuffledMessage>,
+}
+
+fn record_combat_log(
+    mut messages: LogMessages,
+    players: Query<(&PlayerHandle, &MatchClock)>,
+    mut log: ResMut<CombatLog>,
+) {
+    let time = players
+        .iter()
+        .next()
+        .map_or(0.0, |(_, clock)| clock.elapsed);
+    let mut events = Vec::new();
+    events.extend(
+        messages
+            .played
+            .read()
+            .map(|msg| (msg.player, LogEvent::Played(msg.card_id))),
+    );
+    events.extend(
+        messages
+            .damage
+            .read()
+            .filter(|msg| msg.amount > 0.0)
+            .map(|msg| (msg.target, LogEvent::Damage(msg.amount, msg.kind))),
+    );
+    events.extend(
+        messages
+            .heal
+            .read()
+            .filter(|msg| msg.amount > 0.0)
+            .map(|msg| (msg.target, LogEvent::Heal(msg.amount))),
+    );
+    events.extend(
+        messages
+            .block
+            .read()
+            .filter(|msg| msg.amount > 0.0)
+            .map(|msg| (msg.target, LogEvent::Block(msg.amount))),
+    );
+    events.extend(
+        messages
+            .strength
+            .read()
+            .map(|msg| (msg.target, LogEvent::Strength(msg.amount))),
+    );
+    events.extend(
+        messages
+            .vulnerable
+            .read()
+            .map(|msg| (msg.target, LogEvent::Vulnerable(msg.duration))),
+    );
+    events.extend(
+        messages
+            .weak
+            .read()
+            .map(|msg| (msg.target, LogEvent::Weak(msg.duration))),
+    );
+    events.extend(
+        messages
+            .poison
+            .read()
+            .map(|msg| (msg.target, LogEvent::Poison(msg.stacks))),
+    );
+    events.extend(
+        messages
+            .reshuffled
+            .read()
+            .map(|msg| (msg.player, LogEvent::Reshuffled(msg.deck.len()))),
+    );
+    for (entity, event) in events {
+        if let Ok((handle, _)) = players.get(entity) {
+            log.push(LogEntry {
+                time,
+                handle: handle.0,
+                event,
+            });
+        }
+    }
+}
+
+#[derive(Component)]
+struct CombatLogPanel;
+
+#[derive(Component)]
+struct CombatLogList;
+
+#[derive(Component)]
+struct CombatLogText;
+
+fn spawn_combat_log_panel(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Combat Log"),
+        CombatLogPanel,
+        Node {
+            position_type: PositionType::Absolute,
+            left: px(16),
+            top: px(96),
+            width: px(380),
+            max_height: percent(50),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(px(10)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.85)),
+        Visibility::Hidden,
+        GlobalZIndex(40),
+        DespawnOnExit(Screen::Gameplay),
+        children![widget::scroll_list(
+            Node {
+                min_height: px(0),
+                ..default()
+            },
+            px(0),
+            (
+                CombatLogList,
+                FollowEnd::default(),
+                children![(
+                    CombatLogText,
+                    Text::new(""),
+                    TextFont::from_font_size(16.0),
+                    TextColor(Color::srgb(0.85, 0.85, 0.9)),
+                    Pickable::IGNORE,
+                )],
+            ),
+        )],
+    ));
+}
+
+/// Opening the log jumps back to the newest entry.
+fn toggle_combat_log(
+    mut panel: Query<&mut Visibility, With<CombatLogPanel>>,
+    mut list: Query<&mut FollowEnd, With<CombatLogList>>,
+) {
+    for mut visibility in &mut panel {
+        *visibility = match *visibility {
+            Visibility::Hidden => Visibility::Inherited,
+            _ => Visibility::Hidden,
+        };
+    }
+    for mut follow in &mut list {
+        follow.following = true;
+    }
+}
+
+fn update_combat_log_text(
+    log: Res<CombatLog>,
+    registry: Res<CardRegistry>,
+    local: Query<&PlayerHandle, With<LocalPlayer>>,
+    panel: Query<Ref<Visibility>, With<CombatLogPanel>>,
+    mut text: Query<&mut Text, With<CombatLogText>>,
+) {
+    let Ok(visibility) = panel.single() else {
+        return;
+    };
+    // Only redrawn while open; opening it catches up.
+    if *visibility == Visibility::Hidden || !(log.is_changed() || visibility.is_changed()) {
+        return;
+    }
+    let local = local.single().map_or(0, |handle| handle.0);
+    for mut text in &mut text {
+        text.0 = log
+            .entries()
+            .map(|entry| entry.line(local, &registry))
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        game::{
+            Cost, create_test_deck,
+            test_support::{Harness, MODES},
+        },
+        input::INPUT_CARD_1,
+    };
+
+    #[test]
+    fn full_log_drops_the_oldest_entry() {
+        let mut log = CombatLog::default();
+        for i in 0..=LOG_CAPACITY {
+            log.push(LogEntry {
+                time: i as f32,
+                handle: 0,
+                event: LogEvent::Heal(1.0),
+            });
+        }
+        assert_eq!(log.entries().count(), LOG_CAPACITY);
+        assert_eq!(log.entries().next().map(|entry| entry.time), Some(1.0));
+        assert_eq!(
+            log.entries().next_back().map(|entry| entry.time),
+            Some(LOG_CAPACITY as f32)
+        );
+    }
+
+    #[test]
+    fn log_records_both_sides_of_a_play() {
+        for mode in MODES {
+            let mut harness = Harness::new(mode, create_test_deck(), create_test_deck());
+            let (player, opponent) = (harness.player, harness.opponent);
+            harness.give_card(player, CardId::Strike);
+            harness.get_mut::<Cost>(player).current = 1.0;
+            harness.press(player, INPUT_CARD_1);
+            harness.step();
+
+            let (mine, theirs) = (
+                harness.get::<PlayerHandle>(player).0,
+                harness.get::<PlayerHandle>(opponent).0,
+            );
+            let log = harness.app.world().resource::<CombatLog>();
+            let events: Vec<_> = log
+                .entries()
+                .map(|entry| (entry.handle, entry.event))
+                .collect();
+            assert_eq!(
+                events,
+                [
+                    (mine, LogEvent::Played(CardId::Strike)),
+                    (theirs, LogEvent::Damage(60.0, DamageKind::Attack)),
+                ],
+                "{mode:?}"
+            );
+        }
+    }
+}
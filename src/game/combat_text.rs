@@ -0,0 +1,201 @@
+//! Floating damage and heal numbers above each player's side of the table.
+//!
+//! Numbers are UI text projected from a world anchor, so they always face the
+//! camera. A fixed pool is spawned per match and recycled, oldest first.
+
+use std::fmt::Write;
+
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use super::{DamageKind, DamageMessage, HealMessage, LocalPlayer, Opponent};
+use crate::{AppSystems, MainCamera, screens::Screen};
+
+/// Numbers alive at once; the oldest is reused when all are busy.
+const POOL_SIZE: usize = 24;
+const LIFETIME_SECS: f32 = 1.0;
+/// World units risen per second.
+const DRIFT_SPEED: f32 = 1.2;
+/// Spread between consecutive numbers so simultaneous hits don't overlap.
+const SPREAD: f32 = 0.45;
+/// Above the local hand; the opponent's anchor mirrors it across the table.
+const LOCAL_ANCHOR: Vec3 = Vec3::new(0.0, 2.5, 4.0);
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_number_pool);
+    app.add_systems(
+        Update,
+        (show_damage_numbers, show_heal_numbers, animate_numbers)
+            .chain()
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[derive(Component, Default)]
+struct FloatingNumber {
+    anchor: Vec3,
+    color: Color,
+    /// Seconds since shown, or `None` while parked in the pool.
+    age: Option<f32>,
+}
+
+fn spawn_number_pool(mut commands: Commands) {
+    for _ in 0..POOL_SIZE {
+        commands.spawn((
+            Name::new("Floating Number"),
+            FloatingNumber::default(),
+            Node {
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            Text::new(""),
+            TextFont::from_font_size(28.0),
+            TextColor(Color::WHITE),
+            Visibility::Hidden,
+            Pickable::IGNORE,
+            GlobalZIndex(50),
+            DespawnOnExit(Screen::Gameplay),
+        ));
+    }
+}
+
+fn damage_color(kind: DamageKind) -> Color {
+    match kind {
+        DamageKind::Attack => Color::srgb(1.0, 0.3, 0.25),
+        DamageKind::Power => Color::srgb(0.75, 0.4, 1.0),
+        DamageKind::Thorns => Color::srgb(1.0, 0.6, 0.2),
+    }
+}
+
+fn show_damage_numbers(
+    mut messages: MessageReader<DamageMessage>,
+    local: Query<Entity, With<LocalPlayer>>,
+    opponent: Query<Entity, With<Opponent>>,
+    mut numbers: Query<(&mut FloatingNumber, &mut Text)>,
+) {
+    for msg in messages.read() {
+        if msg.amount <= 0.0 {
+            continue;
+        }
+        let Some(anchor) = anchor_for(msg.target, &local, &opponent) else {
+            continue;
+        };
+        show_number(
+            &mut numbers,
+            anchor,
+            damage_color(msg.kind),
+            '-',
+            msg.amount,
+        );
+    }
+}
+
+fn show_heal_numbers(
+    mut messages: MessageReader<HealMessage>,
+    local: Query<Entity, With<LocalPlayer>>,
+    opponent: Query<Entity, With<Opponent>>,
+    mut numbers: Query<(&mut FloatingNumber, &mut Text)>,
+) {
+    for msg in messages.read() {
+        if msg.amount <= 0.0 {
+            continue;
+        }
+        let Some(anchor) = anchor_for(msg.target, &local, &opponent) else {
+            continue;
+        };
+        show_number(
+            &mut numbers,
+            anchor,
+            Color::srgb(0.3, 1.0, 0.4),
+            '+',
+            msg.amount,
+        );
+    }
+}
+
+pub(super) fn anchor_for(
+    target: Entity,
+    local: &Query<Entity, With<LocalPlayer>>,
+    opponent: &Query<Entity, With<Opponent>>,
+) -> Option<Vec3> {
+    if local.contains(target) {
+        Some(LOCAL_ANCHOR)
+    } else if opponent.contains(target) {
+        Some(Vec3::new(-LOCAL_ANCHOR.x, LOCAL_ANCHOR.y, -LOCAL_ANCHOR.z))
+    } else {
+        None
+    }
+}
+
+/// Take a parked number (or the oldest live one) and show `amount` with it.
+fn show_number(
+    numbers: &mut Query<(&mut FloatingNumber, &mut Text)>,
+    anchor: Vec3,
+    color: Color,
+    sign: char,
+    amount: f32,
+) {
+    let live = numbers
+        .iter()
+        .filter(|(number, _)| number.age.is_some_and(|age| age < LIFETIME_SECS * 0.5))
+        .count();
+    let Some((mut number, mut text)) = numbers.iter_mut().max_by(|(a, _), (b, _)| {
+        let a = a.age.unwrap_or(f32::INFINITY);
+        let b = b.age.unwrap_or(f32::INFINITY);
+        a.total_cmp(&b)
+    }) else {
+        return;
+    };
+
+    let offset = (live % 3) as f32 - 1.0;
+    number.anchor = anchor + Vec3::X * offset * SPREAD;
+    number.color = color;
+    number.age = Some(0.0);
+    // Reuse the text buffer instead of allocating per hit.
+    text.0.clear();
+    let _ = write!(text.0, "{sign}{amount:.0}");
+}
+
+fn animate_numbers(
+    time: Res<Time>,
+    camera: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    mut numbers: Query<(
+        &mut FloatingNumber,
+        &mut Node,
+        &mut TextColor,
+        &mut Visibility,
+    )>,
+) {
+    let Ok((camera, camera_transform)) = camera.single() else {
+        return;
+    };
+    // The camera may render to a smaller offscreen image (render scale).
+    let to_window = camera
+        .logical_viewport_size()
+        .map_or(Vec2::ONE, |size| window.size() / size);
+    for (mut number, mut node, mut color, mut visibility) in &mut numbers {
+        let Some(age) = number.age else {
+            continue;
+        };
+        let age = age + time.delta_secs();
+        if age >= LIFETIME_SECS {
+            number.age = None;
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+        number.age = Some(age);
+
+        let world = number.anchor + Vec3::Y * DRIFT_SPEED * age;
+        let Ok(screen) = camera.world_to_viewport(camera_transform, world) else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+        let screen = screen * to_window;
+        node.left = px(screen.x - 16.0);
+        node.top = px(screen.y - 16.0);
+        let fade = 1.0 - (age / LIFETIME_SECS).powi(2);
+        color.0 = number.color.with_alpha(fade);
+        *visibility = Visibility::Inherited;
+    }
+}
@@ -1,37 +1,23 @@
 //! Cost system - accumulates over time.
 
 use bevy::prelude::*;
-use bevy_ggrs::{GgrsSchedule, GgrsTime};
 
-use crate::{
-    AppSystems,
-    game::{GameResult, GameplaySystems, is_offline, is_online},
-    screens::Screen,
-};
+use crate::game::{AddGameplaySystems, CardId, GameplaySystems, SimTime, per_tick, quantize};
 
 pub fn plugin(app: &mut App) {
-    app.add_systems(
-        Update,
-        (tick_acceleration_offline, accumulate_cost_offline)
-            .chain()
-            .in_set(AppSystems::TickTimers)
-            .run_if(is_offline)
-            .run_if(in_state(Screen::Gameplay))
-            .run_if(in_state(GameResult::Playing)),
-    );
-    app.add_systems(
-        GgrsSchedule,
-        (tick_acceleration_online, accumulate_cost_online)
-            .chain()
-            .in_set(GameplaySystems::Tick)
-            .run_if(is_online)
-            .run_if(in_state(Screen::Gameplay))
-            .run_if(in_state(GameResult::Playing)),
+    app.add_gameplay_systems(
+        GameplaySystems::Tick,
+        (
+            tick_acceleration,
+            tick_cost_cap_boost,
+            accumulate_cost,
+            tick_free_cards,
+        ),
     );
 }
 
 /// Player's accumulated cost resource.
-/// Increases over time and is spent to play cards.
+/// Increases over time up to `max` and is spent to play cards.
 #[derive(Component, Debug, Default, Clone, Reflect)]
 #[reflect(Component)]
 pub struct Cost {
@@ -39,25 +25,49 @@ pub struct Cost {
     pub current: f32,
     /// Rate of cost accumulation per second
     pub rate: f32,
+    /// Maximum cost that can be held; anything above burns off
+    pub max: f32,
 }
 
 impl Cost {
-    pub fn new(rate: f32) -> Self {
-        Self { current: 0.0, rate }
+    pub fn new(rate: f32, max: f32) -> Self {
+        Self {
+            current: 0.0,
+            rate,
+            max,
+        }
+    }
+
+    /// Whether cost is at the cap, so further gain is wasted.
+    pub fn is_capped(&self) -> bool {
+        self.current >= self.max
+    }
+
+    /// Fraction of the cap currently filled (0.0 - 1.0).
+    pub fn fill(&self) -> f32 {
+        if self.max > 0.0 {
+            (self.current / self.max).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
     }
 
     /// Try to spend cost. Returns true if successful.
     pub fn try_spend(&mut self, amount: f32) -> bool {
         if self.current >= amount {
-            self.current -= amount;
+            self.current = quantize(self.current - amount);
             true
         } else {
             false
         }
     }
 
+    /// Gain one frame's worth of cost, up to the cap.
+    pub fn accumulate(&mut self, delta: f32) {
+        self.current = (self.current + per_tick(self.rate, delta)).min(self.max);
+    }
+
     /// Check if we can afford a cost without spending.
-    #[allow(dead_code)]
     pub fn can_afford(&self, amount: f32) -> bool {
         self.current >= amount
     }
@@ -75,52 +85,102 @@ impl Acceleration {
     pub fn new(bonus_rate: f32, duration: f32) -> Self {
         Self {
             bonus_rate,
-            remaining: duration.max(0.0),
+            remaining: quantize(duration.max(0.0)),
         }
     }
 
     pub fn extend(&mut self, bonus_rate: f32, duration: f32) {
         self.bonus_rate += bonus_rate;
-        self.remaining = self.remaining.max(duration);
+        self.remaining = self.remaining.max(quantize(duration));
     }
 }
 
-/// System that accumulates cost over time for all entities with Cost component.
-fn accumulate_cost_offline(time: Res<Time>, query: Query<&mut Cost>) {
-    accumulate_cost_delta(time.delta_secs(), query);
+/// Temporary raise of the cost cap.
+#[derive(Component, Debug, Default, Clone, Reflect)]
+#[reflect(Component)]
+pub struct CostCapBoost {
+    pub amount: f32,
+    pub remaining: f32,
 }
 
-fn accumulate_cost_online(time: Res<Time<GgrsTime>>, query: Query<&mut Cost>) {
-    accumulate_cost_delta(time.delta_secs(), query);
+impl CostCapBoost {
+    pub fn new(amount: f32, duration: f32) -> Self {
+        Self {
+            amount,
+            remaining: quantize(duration.max(0.0)),
+        }
+    }
+
+    pub fn extend(&mut self, amount: f32, duration: f32) {
+        self.amount += amount;
+        self.remaining = self.remaining.max(quantize(duration));
+    }
 }
 
-fn accumulate_cost_delta(delta: f32, mut query: Query<&mut Cost>) {
-    for mut cost in &mut query {
-        cost.current += cost.rate * delta;
+/// Cards that can be played without paying cost until their timer runs out
+/// (cards added by Infernal Blade).
+#[derive(Component, Debug, Default, Clone, Reflect)]
+#[reflect(Component)]
+pub struct FreeCards {
+    pub cards: Vec<FreeCard>,
+}
+
+#[derive(Debug, Clone, Copy, Reflect)]
+pub struct FreeCard {
+    pub card: CardId,
+    pub remaining: f32,
+}
+
+impl FreeCards {
+    /// Make the next play of `card` free for `duration` seconds.
+    pub fn grant(&mut self, card: CardId, duration: f32) {
+        self.cards.push(FreeCard {
+            card,
+            remaining: quantize(duration.max(0.0)),
+        });
+    }
+
+    pub fn is_free(&self, card: CardId) -> bool {
+        self.cards.iter().any(|free| free.card == card)
+    }
+
+    /// Use up a free play of `card`. Returns whether there was one.
+    pub fn take(&mut self, card: CardId) -> bool {
+        let Some(index) = self.cards.iter().position(|free| free.card == card) else {
+            return false;
+        };
+        self.cards.remove(index);
+        true
     }
 }
 
-fn tick_acceleration_offline(
-    time: Res<Time>,
-    commands: Commands,
-    query: Query<(Entity, &mut Cost, &mut Acceleration)>,
-) {
-    tick_acceleration_delta(time.delta_secs(), commands, query);
+/// System that accumulates cost over time for all entities with Cost component.
+fn accumulate_cost(time: SimTime, mut query: Query<&mut Cost>) {
+    let delta = quantize(time.delta_secs());
+    for mut cost in &mut query {
+        cost.accumulate(delta);
+    }
 }
 
-fn tick_acceleration_online(
-    time: Res<Time<GgrsTime>>,
-    commands: Commands,
-    query: Query<(Entity, &mut Cost, &mut Acceleration)>,
-) {
-    tick_acceleration_delta(time.delta_secs(), commands, query);
+fn tick_free_cards(time: SimTime, mut query: Query<&mut FreeCards>) {
+    let delta = quantize(time.delta_secs());
+    for mut free_cards in &mut query {
+        if free_cards.cards.is_empty() {
+            continue;
+        }
+        for free in &mut free_cards.cards {
+            free.remaining -= delta;
+        }
+        free_cards.cards.retain(|free| free.remaining > 0.0);
+    }
 }
 
-fn tick_acceleration_delta(
-    delta: f32,
+fn tick_acceleration(
+    time: SimTime,
     mut commands: Commands,
     mut query: Query<(Entity, &mut Cost, &mut Acceleration)>,
 ) {
+    let delta = quantize(time.delta_secs());
     for (entity, mut cost, mut accel) in &mut query {
         accel.remaining -= delta;
         if accel.remaining <= 0.0 {
@@ -129,3 +189,20 @@ fn tick_acceleration_delta(
         }
     }
 }
+
+fn tick_cost_cap_boost(
+    time: SimTime,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Cost, &mut CostCapBoost)>,
+) {
+    let delta = quantize(time.delta_secs());
+    for (entity, mut cost, mut boost) in &mut query {
+        boost.remaining -= delta;
+        if boost.remaining <= 0.0 {
+            // Cost above the restored cap burns off.
+            cost.max = (cost.max - boost.amount).max(0.0);
+            cost.current = cost.current.min(cost.max);
+            commands.entity(entity).remove::<CostCapBoost>();
+        }
+    }
+}
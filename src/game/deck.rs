@@ -1,16 +1,15 @@
 //! Deck system - deck, hand, and discard pile management.
 
 use bevy::{ecs::message::Message, prelude::*};
-use bevy_ggrs::GgrsSchedule;
+use serde::{Deserialize, Serialize};
 
-use super::{CardEffect, CardId, MAX_HAND_SIZE};
+use super::{CardId, MAX_HAND_SIZE};
 use crate::game::{
     CardRegistry, CardType, CorruptionEffect, DamageKind, DamageMessage, EvolveEffect,
     FireBreathingEffect, PlayerHandle, opponent_entity,
 };
 use crate::{
-    AppSystems,
-    game::{GameResult, GameplaySystems, is_offline, is_online},
+    game::{AddGameplaySystems, GameplaySystems},
     screens::Screen,
 };
 
@@ -18,32 +17,18 @@ pub fn plugin(app: &mut App) {
     app.add_message::<DrawCardsMessage>();
     app.add_message::<PlayCardMessage>();
     app.add_message::<CardPlayedMessage>();
+    app.add_message::<CardDrawnMessage>();
     app.add_message::<CardExhaustedMessage>();
     app.add_message::<DeckReshuffledMessage>();
+    app.add_message::<CardRemovedFromHandMessage>();
     app.clear_messages_on_exit::<DrawCardsMessage>(Screen::Gameplay)
         .clear_messages_on_exit::<PlayCardMessage>(Screen::Gameplay)
         .clear_messages_on_exit::<CardPlayedMessage>(Screen::Gameplay)
+        .clear_messages_on_exit::<CardDrawnMessage>(Screen::Gameplay)
         .clear_messages_on_exit::<CardExhaustedMessage>(Screen::Gameplay)
-        .clear_messages_on_exit::<DeckReshuffledMessage>(Screen::Gameplay);
-    app.add_systems(
-        Update,
-        (handle_draw_cards, handle_play_card)
-            .chain()
-            .in_set(AppSystems::Update)
-            .in_set(GameplaySystems::Deck)
-            .run_if(is_offline)
-            .run_if(in_state(Screen::Gameplay))
-            .run_if(in_state(GameResult::Playing)),
-    );
-    app.add_systems(
-        GgrsSchedule,
-        (handle_draw_cards, handle_play_card)
-            .chain()
-            .in_set(GameplaySystems::Deck)
-            .run_if(is_online)
-            .run_if(in_state(Screen::Gameplay))
-            .run_if(in_state(GameResult::Playing)),
-    );
+        .clear_messages_on_exit::<DeckReshuffledMessage>(Screen::Gameplay)
+        .clear_messages_on_exit::<CardRemovedFromHandMessage>(Screen::Gameplay);
+    app.add_gameplay_systems(GameplaySystems::Deck, (handle_draw_cards, handle_play_card));
 }
 
 /// Message to draw cards from deck to hand.
@@ -65,16 +50,31 @@ pub struct PlayCardMessage {
 pub struct CardPlayedMessage {
     pub player: Entity,
     pub card_id: super::CardId,
+    /// Cards left in hand when this card was played (before any hand exhaust).
+    pub hand_size: usize,
+}
+
+/// Message fired when a card is drawn into hand, for its on-draw actions.
+#[derive(Message)]
+pub struct CardDrawnMessage {
+    pub player: Entity,
+    pub card_id: CardId,
 }
 
 /// Message fired when a card is exhausted.
 #[derive(Message)]
 pub struct CardExhaustedMessage {
     pub player: Entity,
-    #[allow(dead_code)]
     pub card_id: super::CardId,
 }
 
+/// Message fired when a card leaves the hand without being played.
+#[derive(Message)]
+pub struct CardRemovedFromHandMessage {
+    pub player: Entity,
+    pub hand_index: usize,
+}
+
 /// Message fired when a deck is refilled and shuffled from the discard pile.
 #[derive(Message)]
 pub struct DeckReshuffledMessage {
@@ -92,6 +92,14 @@ pub struct Deck {
 
 const DEFAULT_DECK_SEED: u64 = 0x23d3_44d3_6f2a_7c15;
 
+/// Everything needed to rebuild a deck exactly: its cards in draw-pile order
+/// and the position of its RNG. For replays and rejoining a match.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeckSnapshot {
+    pub cards: Vec<CardId>,
+    pub rng_state: u64,
+}
+
 impl Default for Deck {
     fn default() -> Self {
         Self {
@@ -118,6 +126,22 @@ impl Deck {
         }
     }
 
+    /// The deck's current state; [`Deck::restore`] turns it back into a deck
+    /// that shuffles and draws exactly as this one will.
+    pub fn snapshot(&self) -> DeckSnapshot {
+        DeckSnapshot {
+            cards: self.cards.clone(),
+            rng_state: self.rng_state,
+        }
+    }
+
+    pub fn restore(snapshot: DeckSnapshot) -> Self {
+        Self {
+            cards: snapshot.cards,
+            rng_state: snapshot.rng_state,
+        }
+    }
+
     pub fn seed_for_handle(match_seed: u64, handle: usize) -> u64 {
         DEFAULT_DECK_SEED ^ match_seed ^ (handle as u64).wrapping_mul(0x9e3779b97f4a7c15)
     }
@@ -223,6 +247,29 @@ impl DiscardPile {
     }
 }
 
+/// Every card a player has played this match, in order.
+/// Rolled back with the rest of the player, so it stays exact online.
+#[derive(Component, Debug, Default, Clone, Reflect)]
+#[reflect(Component)]
+pub struct PlayLog {
+    pub cards: Vec<CardId>,
+}
+
+impl PlayLog {
+    /// Play count per card, most played first.
+    pub fn counts(&self) -> Vec<(CardId, u32)> {
+        let mut counts: Vec<(CardId, u32)> = Vec::new();
+        for card in &self.cards {
+            match counts.iter_mut().find(|(id, _)| id == card) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((*card, 1)),
+            }
+        }
+        counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        counts
+    }
+}
+
 /// System to handle drawing cards from deck to hand.
 fn handle_draw_cards(
     mut messages: MessageReader<DrawCardsMessage>,
@@ -232,6 +279,7 @@ fn handle_draw_cards(
     players: Query<(Entity, &PlayerHandle)>,
     evolve_query: Query<&EvolveEffect>,
     fire_breathing_query: Query<&FireBreathingEffect>,
+    mut drawn_messages: MessageWriter<CardDrawnMessage>,
     mut damage_messages: MessageWriter<DamageMessage>,
 ) {
     for msg in messages.read() {
@@ -277,12 +325,16 @@ fn handle_draw_cards(
                 break;
             };
             hand.add_card(card_id);
+            drawn_messages.write(CardDrawnMessage {
+                player: msg.player,
+                card_id,
+            });
+
+            let Some(card_def) = card_registry.get(card_id) else {
+                continue;
+            };
 
-            let is_status = card_registry
-                .get(card_id)
-                .map(|def| def.card_type == CardType::Status)
-                .unwrap_or(false);
-            if is_status {
+            if card_def.card_type == CardType::Status {
                 if evolve_bonus > 0 {
                     draws_remaining += evolve_bonus;
                 }
@@ -302,26 +354,30 @@ fn handle_draw_cards(
 /// System to handle playing a card from hand back into the deck.
 fn handle_play_card(
     mut messages: MessageReader<PlayCardMessage>,
-    mut query: Query<(&mut Hand, &mut Deck)>,
+    mut query: Query<(&mut Hand, &mut Deck, &mut PlayLog)>,
     mut card_played_messages: MessageWriter<CardPlayedMessage>,
     mut card_exhausted_messages: MessageWriter<CardExhaustedMessage>,
+    mut removed_messages: MessageWriter<CardRemovedFromHandMessage>,
     card_registry: Res<CardRegistry>,
     corruption_query: Query<&CorruptionEffect>,
 ) {
     for msg in messages.read() {
-        let Ok((mut hand, mut deck)) = query.get_mut(msg.player) else {
+        let Ok((mut hand, mut deck, mut play_log)) = query.get_mut(msg.player) else {
             continue;
         };
 
         if let Some(card_id) = hand.remove_card(msg.hand_index) {
+            play_log.cards.push(card_id);
             let mut return_to_deck = true;
             let mut counts_as_exhaust = false;
+            let mut exhaust_hand = false;
 
             if let Some(card_def) = card_registry.get(card_id) {
+                exhaust_hand = card_def.effect.exhausts_hand();
                 if card_def.card_type == CardType::Power {
                     return_to_deck = false;
                 }
-                if matches!(card_def.effect, CardEffect::Exhaust) {
+                if card_def.effect.exhausts_self() {
                     return_to_deck = false;
                     counts_as_exhaust = true;
                 }
@@ -339,6 +395,7 @@ fn handle_play_card(
             card_played_messages.write(CardPlayedMessage {
                 player: msg.player,
                 card_id,
+                hand_size: hand.len(),
             });
 
             if counts_as_exhaust {
@@ -347,6 +404,140 @@ fn handle_play_card(
                     card_id,
                 });
             }
+
+            // Exhaust the rest of the hand back-to-front so indices stay valid.
+            if exhaust_hand {
+                while let Some(exhausted) = hand.cards.pop() {
+                    removed_messages.write(CardRemovedFromHandMessage {
+                        player: msg.player,
+                        hand_index: hand.len(),
+                    });
+                    card_exhausted_messages.write(CardExhaustedMessage {
+                        player: msg.player,
+                        card_id: exhausted,
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CARDS: [CardId; 6] = [
+        CardId::Strike,
+        CardId::Defend,
+        CardId::Bash,
+        CardId::Anger,
+        CardId::Cleave,
+        CardId::IronWave,
+    ];
+
+    /// Seeds spread over the whole `u64` range (SplitMix64).
+    fn seeds(count: usize) -> impl Iterator<Item = u64> {
+        let mut state = 0u64;
+        std::iter::repeat_with(move || {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        })
+        .take(count)
+    }
+
+    /// A deck of `len` cards cycling through [`CARDS`].
+    fn deck(len: usize, seed: u64) -> Deck {
+        Deck::new_with_seed(CARDS.iter().copied().cycle().take(len).collect(), seed)
+    }
+
+    /// Shuffle, then draw everything, in order.
+    fn shuffle_and_draw_all(mut deck: Deck) -> Vec<CardId> {
+        deck.shuffle();
+        std::iter::from_fn(|| deck.draw()).collect()
+    }
+
+    #[test]
+    fn same_seed_gives_the_same_order() {
+        for (index, seed) in seeds(500).enumerate() {
+            let len = index % 40;
+            assert_eq!(
+                shuffle_and_draw_all(deck(len, seed)),
+                shuffle_and_draw_all(deck(len, seed)),
+                "seed {seed:#x}"
+            );
+        }
+    }
+
+    #[test]
+    fn shuffling_and_drawing_keep_every_card() {
+        for (index, seed) in seeds(500).enumerate() {
+            let original = deck(index % 40, seed);
+            let mut expected = original.cards.clone();
+            let mut drawn = shuffle_and_draw_all(original);
+            expected.sort_by_key(|card| *card as u32);
+            drawn.sort_by_key(|card| *card as u32);
+            assert_eq!(drawn, expected, "seed {seed:#x}");
+        }
+    }
+
+    /// Pins the exact sequence so a change to the RNG, or a platform where
+    /// it behaves differently, fails here instead of desyncing online.
+    #[test]
+    fn known_seed_gives_a_known_order() {
+        let mut deck = Deck::new_with_seed(CARDS.to_vec(), 0x5e75e7);
+        deck.shuffle();
+        assert_eq!(
+            deck.cards,
+            [
+                CardId::Defend,
+                CardId::Anger,
+                CardId::Strike,
+                CardId::Bash,
+                CardId::IronWave,
+                CardId::Cleave,
+            ]
+        );
+        assert_eq!(deck.rng_state, 0x2ad0_7400_9252_9a68);
+
+        let drawn: Vec<CardId> = std::iter::from_fn(|| deck.draw()).collect();
+        assert_eq!(
+            drawn,
+            [
+                CardId::Anger,
+                CardId::Cleave,
+                CardId::Bash,
+                CardId::Strike,
+                CardId::IronWave,
+                CardId::Defend,
+            ]
+        );
+        assert_eq!(deck.rng_state, 0xa374_356c_e450_7742);
+    }
+
+    #[test]
+    fn restored_deck_continues_exactly() {
+        for (index, seed) in seeds(200).enumerate() {
+            let mut original = deck(10 + index % 30, seed);
+            original.shuffle();
+            for _ in 0..index % 8 {
+                original.draw();
+            }
+            let restored = Deck::restore(original.snapshot());
+            assert_eq!(restored.snapshot(), original.snapshot());
+
+            // Reshuffle the discards back in, as a long match would.
+            let continue_with = |mut deck: Deck| {
+                deck.add_cards(CARDS.to_vec());
+                shuffle_and_draw_all(deck)
+            };
+            assert_eq!(
+                continue_with(restored),
+                continue_with(original),
+                "seed {seed:#x}"
+            );
         }
     }
 }
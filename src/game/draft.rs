@@ -0,0 +1,160 @@
+//! Draft: both players build their decks from shared packs before the match.
+//!
+//! Packs of [`PACK_SIZE`] cards are dealt one at a time from the match seed,
+//! so online peers deal identical packs without sending them. The player
+//! who opens a pack picks first and the other picks from what's left; the
+//! last card is discarded. Who opens alternates pack by pack until both
+//! players hold [`DRAFT_DECK_SIZE`] cards.
+
+use bevy::prelude::*;
+
+use super::{CardId, CardRegistry, CardType, CharacterClass, Deck};
+
+/// Cards in each pack.
+pub const PACK_SIZE: usize = 3;
+/// Cards each player drafts.
+pub const DRAFT_DECK_SIZE: usize = 15;
+
+/// Draft in progress, or finished and waiting to be played with.
+#[derive(Resource, Debug, Clone)]
+pub struct Draft {
+    seed: u64,
+    /// Cards the packs are dealt from, weighted by rarity.
+    pool: Vec<CardId>,
+    packs_opened: u64,
+    /// Cards left in the pack on the table.
+    pub pack: Vec<CardId>,
+    /// Cards each player has drafted, indexed by handle.
+    pub picks: [Vec<CardId>; 2],
+    /// Handle of the player to pick next.
+    pub picker: usize,
+}
+
+impl Draft {
+    /// Start a draft over the card pools of the classes in the match.
+    pub fn new(registry: &CardRegistry, classes: &[CharacterClass], seed: u64) -> Self {
+        let pool = registry
+            .iter()
+            .filter(|def| def.id.class().is_some_and(|class| classes.contains(&class)))
+            .filter(|def| def.card_type != CardType::Status && def.is_playable())
+            .flat_map(|def| std::iter::repeat_n(def.id, def.rarity.reward_weight()))
+            .collect();
+        let mut draft = Self {
+            seed,
+            pool,
+            packs_opened: 0,
+            pack: Vec::new(),
+            picks: [Vec::new(), Vec::new()],
+            picker: 0,
+        };
+        draft.open_pack();
+        draft
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.picks.iter().all(|deck| deck.len() >= DRAFT_DECK_SIZE)
+    }
+
+    /// Take the card in `slot` of the pack for `handle`, if it's their turn.
+    pub fn pick(&mut self, handle: usize, slot: usize) -> Option<CardId> {
+        if self.is_complete() || handle != self.picker || slot >= self.pack.len() {
+            return None;
+        }
+        let card = self.pack.remove(slot);
+        self.picks[handle].push(card);
+        // Both players have taken one card from this pack once it's down to
+        // the last card; the opener of the next one is the other player.
+        if self.pack.len() + 2 <= PACK_SIZE {
+            self.open_pack();
+        } else {
+            self.picker = 1 - handle;
+        }
+        Some(card)
+    }
+
+    /// Slot a simple opponent picks: the rarest card, the first one on ties.
+    pub fn preferred_slot(&self, registry: &CardRegistry) -> usize {
+        self.pack
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, card)| {
+                registry
+                    .get(**card)
+                    .map_or(usize::MAX, |def| def.rarity.reward_weight())
+            })
+            .map_or(0, |(slot, _)| slot)
+    }
+
+    fn open_pack(&mut self) {
+        self.pack.clear();
+        if self.is_complete() {
+            return;
+        }
+        let seed = Deck::seed_for_handle(self.seed.rotate_left(16), self.packs_opened as usize);
+        let mut pool = Deck::new_with_seed(self.pool.clone(), seed);
+        while self.pack.len() < PACK_SIZE {
+            let Some(card) = pool.draw() else {
+                break;
+            };
+            if !self.pack.contains(&card) {
+                self.pack.push(card);
+            }
+        }
+        self.picker = (self.packs_opened % 2) as usize;
+        self.packs_opened += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{
+        register_attack_cards, register_power_cards, register_silent_cards, register_skill_cards,
+    };
+
+    fn draft(seed: u64) -> Draft {
+        let mut registry = CardRegistry::default();
+        register_attack_cards(&mut registry);
+        register_skill_cards(&mut registry);
+        register_power_cards(&mut registry);
+        register_silent_cards(&mut registry);
+        Draft::new(
+            &registry,
+            &[CharacterClass::Ironclad, CharacterClass::Silent],
+            seed,
+        )
+    }
+
+    #[test]
+    fn same_seed_deals_the_same_packs() {
+        let mut first = draft(11);
+        let mut second = draft(11);
+        while !first.is_complete() {
+            assert_eq!(first.pack, second.pack);
+            let (picker, slot) = (first.picker, first.pack.len() - 1);
+            assert_eq!(first.pick(picker, slot), second.pick(picker, slot));
+        }
+        assert_eq!(first.picks, second.picks);
+    }
+
+    #[test]
+    fn players_alternate_until_both_decks_are_full() {
+        let mut draft = draft(3);
+        assert_eq!(draft.pack.len(), PACK_SIZE);
+        assert_eq!(draft.picker, 0);
+        assert!(draft.pick(1, 0).is_none(), "not player 1's turn");
+        draft.pick(0, 0).unwrap();
+        assert_eq!(draft.picker, 1);
+        draft.pick(1, 0).unwrap();
+        // Second pack: player 1 opens.
+        assert_eq!(draft.pack.len(), PACK_SIZE);
+        assert_eq!(draft.picker, 1);
+
+        while !draft.is_complete() {
+            draft.pick(draft.picker, 0).unwrap();
+        }
+        assert_eq!(draft.picks[0].len(), DRAFT_DECK_SIZE);
+        assert_eq!(draft.picks[1].len(), DRAFT_DECK_SIZE);
+        assert!(draft.pack.is_empty());
+    }
+}
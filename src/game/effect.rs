@@ -4,19 +4,19 @@ use bevy::{
     ecs::{message::Message, system::SystemParam},
     prelude::*,
 };
-use bevy_ggrs::GgrsSchedule;
 
 use super::{
-    Acceleration, BarricadeEffect, Block, BrutalityEffect, CardEffect, CardExhaustedMessage,
-    CardPlayedMessage, CardRegistry, CardType, CombustEffect, CorruptionEffect, Cost, DamageKind,
-    DamageMessage, DarkEmbraceEffect, DemonFormEffect, DrawCardsMessage, EvolveEffect,
-    FeelNoPainEffect, FireBreathingEffect, GainBlockMessage, GainThornsMessage, HealMessage,
-    JuggernautEffect, MetallicizeEffect, PlayerHandle, RageEffect, RuptureEffect, Strength,
-    Vulnerable, Weak, opponent_entity,
+    Acceleration, BarricadeEffect, Block, BrutalityEffect, CardAction, CardDrawnMessage,
+    CardExhaustedMessage, CardId, CardPlayedMessage, CardRegistry, CardTrigger, CardType,
+    CharacterClass, CombustEffect, CorruptionEffect, Cost, CostCapBoost, DamageKind, DamageMessage,
+    DarkEmbraceEffect, DemonFormEffect, DrawCardsMessage, EvolveEffect, FeelNoPainEffect,
+    FireBreathingEffect, FreeCards, GainBlockMessage, GainThornsMessage, GameRng, Hand,
+    HealMessage, JuggernautEffect, MAX_HAND_SIZE, MetallicizeEffect, PlayerHandle, Poison,
+    RAGE_SECS, RageEffect, RngStream, Rules, RuptureEffect, Strength, Vulnerable, Weak,
+    opponent_entity,
 };
 use crate::{
-    AppSystems,
-    game::{GameResult, GameplaySystems, is_offline, is_online},
+    game::{AddGameplaySystems, GameplaySystems},
     screens::Screen,
 };
 
@@ -24,30 +24,19 @@ pub fn plugin(app: &mut App) {
     app.add_message::<ApplyStrengthMessage>();
     app.add_message::<ApplyVulnerableMessage>();
     app.add_message::<ApplyWeakMessage>();
-    app.add_message::<AddStatusCardMessage>();
+    app.add_message::<ApplyPoisonMessage>();
+    app.add_message::<AddCardToDiscardMessage>();
+    app.add_message::<AddRandomCardMessage>();
     app.clear_messages_on_exit::<ApplyStrengthMessage>(Screen::Gameplay)
         .clear_messages_on_exit::<ApplyVulnerableMessage>(Screen::Gameplay)
         .clear_messages_on_exit::<ApplyWeakMessage>(Screen::Gameplay)
-        .clear_messages_on_exit::<AddStatusCardMessage>(Screen::Gameplay);
+        .clear_messages_on_exit::<ApplyPoisonMessage>(Screen::Gameplay)
+        .clear_messages_on_exit::<AddCardToDiscardMessage>(Screen::Gameplay)
+        .clear_messages_on_exit::<AddRandomCardMessage>(Screen::Gameplay);
 
-    app.add_systems(
-        Update,
-        (apply_card_effects, apply_status_effects)
-            .chain()
-            .in_set(AppSystems::Update)
-            .in_set(GameplaySystems::Effects)
-            .run_if(is_offline)
-            .run_if(in_state(Screen::Gameplay))
-            .run_if(in_state(GameResult::Playing)),
-    );
-    app.add_systems(
-        GgrsSchedule,
-        (apply_card_effects, apply_status_effects)
-            .chain()
-            .in_set(GameplaySystems::Effects)
-            .run_if(is_online)
-            .run_if(in_state(Screen::Gameplay))
-            .run_if(in_state(GameResult::Playing)),
+    app.add_gameplay_systems(
+        GameplaySystems::Effects,
+        (apply_card_effects, apply_status_effects, add_random_cards),
     );
 }
 
@@ -72,11 +61,28 @@ pub struct ApplyWeakMessage {
     pub duration: f32,
 }
 
-/// Message to add a status card to deck/discard.
+/// Message to apply poison stacks to a target.
+#[derive(Message)]
+pub struct ApplyPoisonMessage {
+    pub target: Entity,
+    pub stacks: f32,
+}
+
+/// Message to add a card (status or copy) to the discard pile.
+#[derive(Message)]
+pub struct AddCardToDiscardMessage {
+    pub player: Entity,
+    pub card_id: CardId,
+}
+
+/// Message to add a random card to a player's hand.
 #[derive(Message)]
-pub struct AddStatusCardMessage {
+pub struct AddRandomCardMessage {
     pub player: Entity,
-    pub card_id: super::CardId,
+    pub card_type: CardType,
+    pub class: Option<CharacterClass>,
+    /// How long the card can be played for free.
+    pub free_secs: f32,
 }
 
 #[derive(SystemParam)]
@@ -89,147 +95,164 @@ struct EffectMessages<'w> {
     strength: MessageWriter<'w, ApplyStrengthMessage>,
     vulnerable: MessageWriter<'w, ApplyVulnerableMessage>,
     weak: MessageWriter<'w, ApplyWeakMessage>,
-    add_status: MessageWriter<'w, AddStatusCardMessage>,
+    poison: MessageWriter<'w, ApplyPoisonMessage>,
+    add_to_discard: MessageWriter<'w, AddCardToDiscardMessage>,
+    add_random: MessageWriter<'w, AddRandomCardMessage>,
 }
 
-/// System to apply card effects when a card is played.
+/// Player state actions read or change directly.
+#[derive(SystemParam)]
+struct EffectTargets<'w, 's> {
+    cost: Query<
+        'w,
+        's,
+        (
+            &'static mut Cost,
+            Option<&'static mut Acceleration>,
+            Option<&'static mut CostCapBoost>,
+        ),
+    >,
+    block: Query<'w, 's, &'static Block>,
+    strength: Query<'w, 's, &'static Strength>,
+    weak: Query<'w, 's, &'static Weak>,
+    vulnerable: Query<'w, 's, &'static Vulnerable>,
+    poison: Query<'w, 's, &'static Poison>,
+    rules: Res<'w, Rules>,
+}
+
+/// The card an action belongs to and the players it resolves between.
+struct ActionSource {
+    player: Entity,
+    card_id: CardId,
+    opponent: Option<Entity>,
+    /// The player's strength before any of the card's actions.
+    strength: f32,
+    /// Cards left in hand after playing the card.
+    hand_size: usize,
+}
+
+/// System to resolve card actions: on play for played cards, on draw for
+/// drawn ones.
 fn apply_card_effects(
     mut card_played_messages: MessageReader<CardPlayedMessage>,
+    mut card_drawn_messages: MessageReader<CardDrawnMessage>,
     mut exhausted_messages: MessageReader<CardExhaustedMessage>,
     card_registry: Res<CardRegistry>,
     players: Query<(Entity, &PlayerHandle)>,
     mut messages: EffectMessages,
-    mut cost_query: Query<(&mut Cost, Option<&mut Acceleration>)>,
-    block_query: Query<&Block>,
-    strength_query: Query<&Strength>,
+    mut targets: EffectTargets,
     rage_query: Query<&RageEffect>,
     dark_embrace_query: Query<&DarkEmbraceEffect>,
     feel_no_pain_query: Query<&FeelNoPainEffect>,
-    weak_query: Query<&Weak>,
-    vulnerable_query: Query<&Vulnerable>,
     mut commands: Commands,
 ) {
-    for event in card_played_messages.read() {
-        let Some(card_def) = card_registry.get(event.card_id) else {
+    let fired = card_played_messages
+        .read()
+        .map(|event| {
+            (
+                CardTrigger::Play,
+                event.player,
+                event.card_id,
+                event.hand_size,
+            )
+        })
+        .chain(
+            card_drawn_messages
+                .read()
+                .map(|event| (CardTrigger::Draw, event.player, event.card_id, 0)),
+        );
+    for (trigger, player, card_id, hand_size) in fired {
+        let Some(card_def) = card_registry.get(card_id) else {
             continue;
         };
 
-        if players.get(event.player).is_err() {
+        if players.get(player).is_err() {
             continue;
         }
-        let opponent = opponent_entity(event.player, &players);
-
-        // Get player's strength for damage calculations
-        let player_strength = strength_query
-            .get(event.player)
-            .map(|s| s.amount)
-            .unwrap_or(0.0);
+        let source = ActionSource {
+            player,
+            card_id,
+            opponent: opponent_entity(player, &players),
+            strength: targets
+                .strength
+                .get(player)
+                .map(|s| s.amount)
+                .unwrap_or(0.0),
+            hand_size,
+        };
 
-        apply_card_effect(
-            &card_def.effect,
-            event.player,
-            opponent,
-            player_strength,
-            &mut messages.damage,
-            &mut messages.heal,
-            &mut messages.draw,
-            &mut messages.block,
-            &mut messages.thorns,
-            &mut messages.strength,
-            &mut messages.vulnerable,
-            &mut messages.weak,
-            &mut messages.add_status,
-            &mut cost_query,
-            &block_query,
-            &weak_query,
-            &vulnerable_query,
-            &mut commands,
-        );
+        for action in card_def.effect.actions(trigger) {
+            resolve_action(action, &source, &mut messages, &mut targets, &mut commands);
+        }
 
-        if card_def.card_type == CardType::Attack {
-            if let Ok(rage) = rage_query.get(event.player) {
-                if rage.is_active() {
-                    messages.block.write(GainBlockMessage {
-                        target: event.player,
-                        amount: rage.block_per_attack,
-                    });
-                }
-            }
+        if trigger == CardTrigger::Play
+            && card_def.card_type == CardType::Attack
+            && let Ok(rage) = rage_query.get(player)
+            && rage.is_active()
+        {
+            messages.block.write(GainBlockMessage {
+                target: player,
+                amount: rage.block_per_attack,
+            });
         }
     }
 
     for event in exhausted_messages.read() {
-        if let Ok(effect) = dark_embrace_query.get(event.player) {
-            if effect.draw_on_exhaust > 0 {
-                messages.draw.write(DrawCardsMessage {
-                    player: event.player,
-                    count: effect.draw_on_exhaust as usize,
-                });
-            }
+        if let Ok(effect) = dark_embrace_query.get(event.player)
+            && effect.draw_on_exhaust > 0
+        {
+            messages.draw.write(DrawCardsMessage {
+                player: event.player,
+                count: effect.draw_on_exhaust as usize,
+            });
         }
-        if let Ok(effect) = feel_no_pain_query.get(event.player) {
-            if effect.block_on_exhaust > 0.0 {
-                messages.block.write(GainBlockMessage {
-                    target: event.player,
-                    amount: effect.block_on_exhaust,
-                });
-            }
+        if let Ok(effect) = feel_no_pain_query.get(event.player)
+            && effect.block_on_exhaust > 0.0
+        {
+            messages.block.write(GainBlockMessage {
+                target: event.player,
+                amount: effect.block_on_exhaust,
+            });
         }
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-fn apply_card_effect(
-    effect: &CardEffect,
-    player: Entity,
-    opponent: Option<Entity>,
-    player_strength: f32,
-    damage_messages: &mut MessageWriter<DamageMessage>,
-    heal_messages: &mut MessageWriter<HealMessage>,
-    draw_messages: &mut MessageWriter<DrawCardsMessage>,
-    block_messages: &mut MessageWriter<GainBlockMessage>,
-    thorns_messages: &mut MessageWriter<GainThornsMessage>,
-    strength_messages: &mut MessageWriter<ApplyStrengthMessage>,
-    vulnerable_messages: &mut MessageWriter<ApplyVulnerableMessage>,
-    weak_messages: &mut MessageWriter<ApplyWeakMessage>,
-    add_status_messages: &mut MessageWriter<AddStatusCardMessage>,
-    cost_query: &mut Query<(&mut Cost, Option<&mut Acceleration>)>,
-    block_query: &Query<&Block>,
-    weak_query: &Query<&Weak>,
-    vulnerable_query: &Query<&Vulnerable>,
+/// Resolve one action, whatever fired it.
+fn resolve_action(
+    action: &CardAction,
+    source: &ActionSource,
+    messages: &mut EffectMessages,
+    targets: &mut EffectTargets,
     commands: &mut Commands,
 ) {
-    match effect {
-        CardEffect::Damage(amount) => {
+    let player = source.player;
+    let opponent = source.opponent;
+    let attack = |base: f32, targets: &EffectTargets| {
+        attack_damage(
+            base,
+            player,
+            opponent,
+            source.strength * targets.rules.strength_damage,
+            &targets.weak,
+            &targets.vulnerable,
+        )
+    };
+    match action {
+        CardAction::Damage(amount) => {
             if let Some(opponent) = opponent {
-                let total_damage = attack_damage(
-                    *amount,
-                    player,
-                    Some(opponent),
-                    player_strength,
-                    weak_query,
-                    vulnerable_query,
-                );
-                damage_messages.write(DamageMessage {
+                messages.damage.write(DamageMessage {
                     target: opponent,
-                    amount: total_damage,
+                    amount: attack(*amount, targets),
                     source: Some(player),
                     kind: DamageKind::Attack,
                 });
             }
         }
-        CardEffect::MultiHit { damage, hits } => {
+        CardAction::MultiHit { damage, hits } => {
             if let Some(opponent) = opponent {
-                let total_damage = attack_damage(
-                    *damage,
-                    player,
-                    Some(opponent),
-                    player_strength,
-                    weak_query,
-                    vulnerable_query,
-                );
+                let total_damage = attack(*damage, targets);
                 for _ in 0..*hits {
-                    damage_messages.write(DamageMessage {
+                    messages.damage.write(DamageMessage {
                         target: opponent,
                         amount: total_damage,
                         source: Some(player),
@@ -238,63 +261,81 @@ fn apply_card_effect(
                 }
             }
         }
-        CardEffect::Heal(amount) => {
-            heal_messages.write(HealMessage {
+        CardAction::Heal(amount) => {
+            messages.heal.write(HealMessage {
                 target: player,
                 amount: *amount,
             });
         }
-        CardEffect::Draw(count) => {
-            draw_messages.write(DrawCardsMessage {
+        CardAction::Draw(count) => {
+            messages.draw.write(DrawCardsMessage {
                 player,
                 count: *count as usize,
             });
         }
-        CardEffect::Block(amount) => {
-            block_messages.write(GainBlockMessage {
+        CardAction::Block(amount) => {
+            messages.block.write(GainBlockMessage {
                 target: player,
                 amount: *amount,
             });
         }
-        CardEffect::Thorns(amount) => {
-            thorns_messages.write(GainThornsMessage {
+        CardAction::Thorns(amount) => {
+            messages.thorns.write(GainThornsMessage {
                 target: player,
                 amount: *amount,
             });
         }
-        CardEffect::Strength(amount) => {
-            strength_messages.write(ApplyStrengthMessage {
+        CardAction::Strength(amount) => {
+            messages.strength.write(ApplyStrengthMessage {
                 target: player,
                 amount: *amount,
             });
         }
-        CardEffect::Vulnerable(duration) => {
+        CardAction::Vulnerable(duration) => {
             if let Some(opponent) = opponent {
-                vulnerable_messages.write(ApplyVulnerableMessage {
+                messages.vulnerable.write(ApplyVulnerableMessage {
                     target: opponent,
                     duration: *duration,
                 });
             }
         }
-        CardEffect::SelfVulnerable(duration) => {
-            vulnerable_messages.write(ApplyVulnerableMessage {
+        CardAction::SelfVulnerable(duration) => {
+            messages.vulnerable.write(ApplyVulnerableMessage {
                 target: player,
                 duration: *duration,
             });
         }
-        CardEffect::Weak(duration) => {
+        CardAction::Weak(duration) => {
             if let Some(opponent) = opponent {
-                weak_messages.write(ApplyWeakMessage {
+                messages.weak.write(ApplyWeakMessage {
                     target: opponent,
                     duration: *duration,
                 });
             }
         }
-        CardEffect::Accelerate {
+        CardAction::Poison(stacks) => {
+            if let Some(opponent) = opponent {
+                messages.poison.write(ApplyPoisonMessage {
+                    target: opponent,
+                    stacks: *stacks,
+                });
+            }
+        }
+        CardAction::MultiplyPoison(factor) => {
+            if let Some(opponent) = opponent
+                && let Ok(poison) = targets.poison.get(opponent)
+            {
+                messages.poison.write(ApplyPoisonMessage {
+                    target: opponent,
+                    stacks: poison.stacks * (factor - 1.0),
+                });
+            }
+        }
+        CardAction::Accelerate {
             bonus_rate,
             duration,
         } => {
-            if let Ok((mut cost, accel)) = cost_query.get_mut(player) {
+            if let Ok((mut cost, accel, _)) = targets.cost.get_mut(player) {
                 cost.rate += *bonus_rate;
                 if let Some(mut accel) = accel {
                     accel.extend(*bonus_rate, *duration);
@@ -305,68 +346,85 @@ fn apply_card_effect(
                 }
             }
         }
-        CardEffect::BodySlam => {
-            // Deal damage equal to current block
-            if let Some(opponent) = opponent {
-                if let Ok(block) = block_query.get(player) {
-                    let total_damage = attack_damage(
-                        block.current,
-                        player,
-                        Some(opponent),
-                        player_strength,
-                        weak_query,
-                        vulnerable_query,
-                    );
-                    damage_messages.write(DamageMessage {
-                        target: opponent,
-                        amount: total_damage,
-                        source: Some(player),
-                        kind: DamageKind::Attack,
-                    });
+        CardAction::RaiseCostCap { amount, duration } => {
+            if let Ok((mut cost, _, boost)) = targets.cost.get_mut(player) {
+                cost.max += *amount;
+                if let Some(mut boost) = boost {
+                    boost.extend(*amount, *duration);
+                } else {
+                    commands
+                        .entity(player)
+                        .insert(CostCapBoost::new(*amount, *duration));
                 }
             }
         }
-        CardEffect::Bloodletting(amount) => {
+        CardAction::BodySlam => {
+            // Deal damage equal to current block
+            if let Some(opponent) = opponent
+                && let Ok(block) = targets.block.get(player)
+            {
+                messages.damage.write(DamageMessage {
+                    target: opponent,
+                    amount: attack(block.current, targets),
+                    source: Some(player),
+                    kind: DamageKind::Attack,
+                });
+            }
+        }
+        CardAction::Bloodletting(amount) => {
             // If negative, it's self-damage. If positive, it would be healing from blood.
             if *amount < 0.0 {
-                damage_messages.write(DamageMessage {
+                messages.damage.write(DamageMessage {
                     target: player,
                     amount: -amount,
                     source: Some(player),
                     kind: DamageKind::Power,
                 });
             } else {
-                heal_messages.write(HealMessage {
+                messages.heal.write(HealMessage {
                     target: player,
                     amount: *amount,
                 });
             }
         }
-        CardEffect::DoubleBlock => {
-            if let Ok(block) = block_query.get(player) {
-                block_messages.write(GainBlockMessage {
+        CardAction::SelfDamage(amount) => {
+            messages.damage.write(DamageMessage {
+                target: player,
+                amount: *amount,
+                source: None,
+                kind: DamageKind::Power,
+            });
+        }
+        CardAction::LoseAllCost => {
+            if let Ok((mut cost, _, _)) = targets.cost.get_mut(player) {
+                cost.current = 0.0;
+            }
+        }
+        CardAction::DoubleBlock => {
+            if let Ok(block) = targets.block.get(player) {
+                messages.block.write(GainBlockMessage {
                     target: player,
                     amount: block.current,
                 });
             }
         }
-        CardEffect::DoubleStrength => {
-            strength_messages.write(ApplyStrengthMessage {
+        CardAction::DoubleStrength => {
+            messages.strength.write(ApplyStrengthMessage {
                 target: player,
-                amount: player_strength, // Double current strength
+                amount: source.strength, // Double current strength
             });
         }
-        CardEffect::Rage(block_per_attack) => {
+        CardAction::Rage(block_per_attack) => {
             commands
                 .entity(player)
-                .insert(RageEffect::new(*block_per_attack, 10.0));
+                .insert(RageEffect::new(*block_per_attack, RAGE_SECS));
         }
-        CardEffect::Metallicize(block_per_second) => {
+        CardAction::Metallicize(block_per_second) => {
             commands
                 .entity(player)
                 .insert(MetallicizeEffect::new(*block_per_second));
         }
-        CardEffect::Combust {
+        CardAction::Combust {
             self_damage_per_sec,
             enemy_damage_per_sec,
         } => {
@@ -375,46 +433,46 @@ fn apply_card_effect(
                 *enemy_damage_per_sec,
             ));
         }
-        CardEffect::DemonForm(strength_per_second) => {
+        CardAction::DemonForm(strength_per_second) => {
             commands
                 .entity(player)
                 .insert(DemonFormEffect::new(*strength_per_second));
         }
-        CardEffect::Barricade => {
+        CardAction::Barricade => {
             commands.entity(player).insert(BarricadeEffect);
         }
-        CardEffect::Juggernaut(damage_on_block) => {
+        CardAction::Juggernaut(damage_on_block) => {
             commands
                 .entity(player)
                 .insert(JuggernautEffect::new(*damage_on_block));
         }
-        CardEffect::DarkEmbrace { draw } => {
+        CardAction::DarkEmbrace { draw } => {
             commands
                 .entity(player)
                 .insert(DarkEmbraceEffect::new(*draw));
         }
-        CardEffect::Evolve { draw } => {
+        CardAction::Evolve { draw } => {
             commands.entity(player).insert(EvolveEffect::new(*draw));
         }
-        CardEffect::FeelNoPain { block } => {
+        CardAction::FeelNoPain { block } => {
             commands
                 .entity(player)
                 .insert(FeelNoPainEffect::new(*block));
         }
-        CardEffect::FireBreathing { damage } => {
+        CardAction::FireBreathing { damage } => {
             commands
                 .entity(player)
                 .insert(FireBreathingEffect::new(*damage));
         }
-        CardEffect::Rupture { strength } => {
+        CardAction::Rupture { strength } => {
             commands
                 .entity(player)
                 .insert(RuptureEffect::new(*strength));
         }
-        CardEffect::Corruption => {
+        CardAction::Corruption => {
             commands.entity(player).insert(CorruptionEffect);
         }
-        CardEffect::Brutality {
+        CardAction::Brutality {
             self_damage_per_sec,
             draw,
             draw_interval,
@@ -425,37 +483,51 @@ fn apply_card_effect(
                 *draw_interval,
             ));
         }
-        CardEffect::Exhaust => {
-            // Card is exhausted (removed from combat) - handled by deck system
-        }
-        CardEffect::AddStatus(card_id) => {
-            add_status_messages.write(AddStatusCardMessage {
+        CardAction::AddStatus(card_id) => {
+            messages.add_to_discard.write(AddCardToDiscardMessage {
                 player,
                 card_id: *card_id,
             });
         }
-        CardEffect::Combo(effects) => {
-            for effect in effects {
-                apply_card_effect(
-                    effect,
-                    player,
-                    opponent,
-                    player_strength,
-                    damage_messages,
-                    heal_messages,
-                    draw_messages,
-                    block_messages,
-                    thorns_messages,
-                    strength_messages,
-                    vulnerable_messages,
-                    weak_messages,
-                    add_status_messages,
-                    cost_query,
-                    block_query,
-                    weak_query,
-                    vulnerable_query,
-                    commands,
-                );
+        CardAction::AddCopyToDiscard => {
+            messages.add_to_discard.write(AddCardToDiscardMessage {
+                player,
+                card_id: source.card_id,
+            });
+        }
+        CardAction::AddRandomCard {
+            card_type,
+            free_secs,
+        } => {
+            messages.add_random.write(AddRandomCardMessage {
+                player,
+                card_type: *card_type,
+                class: source.card_id.class(),
+                free_secs: *free_secs,
+            });
+        }
+        CardAction::ExhaustHand => {
+            // The rest of the hand is exhausted by the deck system
+        }
+        CardAction::PerCardInHand { damage, block } => {
+            if *damage > 0.0
+                && let Some(opponent) = opponent
+            {
+                let per_hit = attack(*damage, targets);
+                for _ in 0..source.hand_size {
+                    messages.damage.write(DamageMessage {
+                        target: opponent,
+                        amount: per_hit,
+                        source: Some(player),
+                        kind: DamageKind::Attack,
+                    });
+                }
+            }
+            if *block > 0.0 && source.hand_size > 0 {
+                messages.block.write(GainBlockMessage {
+                    target: player,
+                    amount: *block * source.hand_size as f32,
+                });
             }
         }
     }
@@ -465,31 +537,47 @@ fn attack_damage(
     base: f32,
     player: Entity,
     opponent: Option<Entity>,
-    player_strength: f32,
+    strength_bonus: f32,
     weak_query: &Query<&Weak>,
     vulnerable_query: &Query<&Vulnerable>,
 ) -> f32 {
-    let mut damage = base + player_strength * 10.0;
-    if let Ok(weak) = weak_query.get(player) {
+    modified_attack_damage(
+        base,
+        strength_bonus,
+        weak_query.get(player).ok(),
+        opponent.and_then(|opponent| vulnerable_query.get(opponent).ok()),
+    )
+}
+
+/// Attack damage after Strength, the attacker's Weak and the target's Vulnerable.
+/// `strength_bonus` is the damage the attacker's Strength adds.
+pub(super) fn modified_attack_damage(
+    base: f32,
+    strength_bonus: f32,
+    weak: Option<&Weak>,
+    vulnerable: Option<&Vulnerable>,
+) -> f32 {
+    let mut damage = base + strength_bonus;
+    if let Some(weak) = weak {
         damage = weak.modify_outgoing_damage(damage);
     }
-    if let Some(opponent) = opponent {
-        if let Ok(vulnerable) = vulnerable_query.get(opponent) {
-            damage = vulnerable.modify_incoming_damage(damage);
-        }
+    if let Some(vulnerable) = vulnerable {
+        damage = vulnerable.modify_incoming_damage(damage);
     }
     damage.max(0.0)
 }
 
-/// System to apply status effect messages (strength, vulnerable, weak).
+/// System to apply status effect messages (strength, vulnerable, weak, poison).
 fn apply_status_effects(
     mut strength_messages: MessageReader<ApplyStrengthMessage>,
     mut vulnerable_messages: MessageReader<ApplyVulnerableMessage>,
     mut weak_messages: MessageReader<ApplyWeakMessage>,
-    mut add_status_messages: MessageReader<AddStatusCardMessage>,
+    mut poison_messages: MessageReader<ApplyPoisonMessage>,
+    mut discard_messages: MessageReader<AddCardToDiscardMessage>,
     mut strength_query: Query<&mut Strength>,
     mut vulnerable_query: Query<&mut Vulnerable>,
     mut weak_query: Query<&mut Weak>,
+    mut poison_query: Query<&mut Poison>,
     mut discard_query: Query<&mut super::DiscardPile>,
 ) {
     for msg in strength_messages.read() {
@@ -510,9 +598,46 @@ fn apply_status_effects(
         }
     }
 
-    for msg in add_status_messages.read() {
+    for msg in poison_messages.read() {
+        if let Ok(mut poison) = poison_query.get_mut(msg.target) {
+            poison.apply(msg.stacks);
+        }
+    }
+
+    for msg in discard_messages.read() {
         if let Ok(mut discard) = discard_query.get_mut(msg.player) {
             discard.add_card(msg.card_id);
         }
     }
 }
+
+/// Pick each random card from the registry with the shared [`GameRng`], so
+/// both peers add the same one.
+fn add_random_cards(
+    mut messages: MessageReader<AddRandomCardMessage>,
+    registry: Res<CardRegistry>,
+    mut rng: ResMut<GameRng>,
+    mut players: Query<(&mut Hand, &mut FreeCards)>,
+) {
+    for msg in messages.read() {
+        let Ok((mut hand, mut free_cards)) = players.get_mut(msg.player) else {
+            continue;
+        };
+        let pool: Vec<CardId> = registry
+            .iter()
+            .filter(|def| def.card_type == msg.card_type)
+            .filter(|def| def.is_playable() && def.id.class() == msg.class)
+            .map(|def| def.id)
+            .collect();
+        let Some(card) = rng.pick(RngStream::CardGeneration, &pool).copied() else {
+            continue;
+        };
+        // No room in hand: the card is lost. It's still rolled, so the stream
+        // doesn't depend on hand sizes.
+        if hand.len() >= MAX_HAND_SIZE {
+            continue;
+        }
+        hand.add_card(card);
+        free_cards.grant(card, msg.free_secs);
+    }
+}
@@ -7,13 +7,12 @@ use super::{
     JuggernautEffect, LocalPlayer, Opponent, PlayerHandle, RuptureEffect, Strength, opponent_entity,
 };
 use crate::{
-    AppSystems,
-    game::{GameplaySystems, is_offline, is_online},
+    game::{AddGameplaySystems, GameplaySystems, is_offline, is_online, quantize},
     screens::Screen,
 };
 
 /// Game result state.
-#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
 pub enum GameResult {
     #[default]
     Playing,
@@ -35,8 +34,8 @@ pub fn plugin(app: &mut App) {
         .clear_messages_on_exit::<GainThornsMessage>(Screen::Gameplay)
         .clear_messages_on_exit::<ThornsDamageMessage>(Screen::Gameplay)
         .clear_messages_on_exit::<DeathMessage>(Screen::Gameplay);
-    app.add_systems(
-        Update,
+    app.add_gameplay_systems(
+        GameplaySystems::Health,
         (
             handle_gain_block,
             handle_juggernaut,
@@ -44,38 +43,17 @@ pub fn plugin(app: &mut App) {
             handle_damage,
             handle_thorns_damage,
             handle_heal,
-        )
-            .chain()
-            .in_set(AppSystems::Update)
-            .in_set(GameplaySystems::Health)
-            .run_if(is_offline)
-            .run_if(in_state(Screen::Gameplay))
-            .run_if(in_state(GameResult::Playing)),
+        ),
     );
     app.add_systems(
-        Update,
+        FixedUpdate,
         (check_death, handle_game_over)
             .chain()
-            .in_set(AppSystems::Update)
+            .after(GameplaySystems::Health)
+            .after(GameplaySystems::Tick)
             .run_if(is_offline)
             .run_if(in_state(Screen::Gameplay)),
     );
-    app.add_systems(
-        GgrsSchedule,
-        (
-            handle_gain_block,
-            handle_juggernaut,
-            handle_gain_thorns,
-            handle_damage,
-            handle_thorns_damage,
-            handle_heal,
-        )
-            .chain()
-            .in_set(GameplaySystems::Health)
-            .run_if(is_online)
-            .run_if(in_state(Screen::Gameplay))
-            .run_if(in_state(GameResult::Playing)),
-    );
     app.add_systems(
         GgrsSchedule,
         (check_death, handle_game_over)
@@ -102,11 +80,11 @@ impl Health {
     }
 
     pub fn take_damage(&mut self, amount: f32) {
-        self.current = (self.current - amount).max(0.0);
+        self.current = quantize((self.current - amount).max(0.0));
     }
 
     pub fn heal(&mut self, amount: f32) {
-        self.current = (self.current + amount).min(self.max);
+        self.current = quantize((self.current + amount).min(self.max));
     }
 
     pub fn is_dead(&self) -> bool {
@@ -133,7 +111,12 @@ pub struct Block {
 
 impl Block {
     pub fn gain(&mut self, amount: f32) {
-        self.current = (self.current + amount).max(0.0);
+        self.current = quantize((self.current + amount).max(0.0));
+    }
+
+    /// Lose block without it going negative.
+    pub fn decay(&mut self, amount: f32) {
+        self.current = quantize((self.current - amount).max(0.0));
     }
 }
 
@@ -232,10 +215,10 @@ fn handle_damage(
             continue;
         };
 
-        let mut remaining = msg.amount.max(0.0);
+        let mut remaining = quantize(msg.amount.max(0.0));
         if let Some(mut block) = block {
             let absorbed = remaining.min(block.current);
-            block.current = (block.current - absorbed).max(0.0);
+            block.decay(absorbed);
             remaining -= absorbed;
         }
 
@@ -243,24 +226,26 @@ fn handle_damage(
             health.take_damage(remaining);
         }
 
-        if msg.kind == DamageKind::Attack {
-            if let (Some(thorns), Some(source)) = (thorns, msg.source) {
-                if source != msg.target && thorns.damage > 0.0 && msg.amount > 0.0 {
-                    thorns_messages.write(ThornsDamageMessage {
-                        target: source,
-                        amount: thorns.damage,
-                        source: msg.target,
-                    });
-                }
-            }
+        if msg.kind == DamageKind::Attack
+            && let (Some(thorns), Some(source)) = (thorns, msg.source)
+            && source != msg.target
+            && thorns.damage > 0.0
+            && msg.amount > 0.0
+        {
+            thorns_messages.write(ThornsDamageMessage {
+                target: source,
+                amount: thorns.damage,
+                source: msg.target,
+            });
         }
 
-        if msg.kind == DamageKind::Power && msg.amount > 0.0 && msg.source == Some(msg.target) {
-            if let Ok(rupture) = rupture_query.get(msg.target) {
-                if let Ok(mut strength) = strength_query.get_mut(msg.target) {
-                    strength.gain(rupture.strength_on_self_damage);
-                }
-            }
+        if msg.kind == DamageKind::Power
+            && msg.amount > 0.0
+            && msg.source == Some(msg.target)
+            && let Ok(rupture) = rupture_query.get(msg.target)
+            && let Ok(mut strength) = strength_query.get_mut(msg.target)
+        {
+            strength.gain(rupture.strength_on_self_damage);
         }
     }
 }
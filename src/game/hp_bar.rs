@@ -0,0 +1,202 @@
+//! Animated HP bars for both players.
+//!
+//! The fill eases toward the real HP instead of jumping. Damage leaves a
+//! white trail where the fill used to be, which holds briefly and then
+//! drains, so the size of each hit stays readable. Big hits also flash the
+//! fill.
+
+use bevy::prelude::*;
+
+use super::{GameResult, Health, LocalPlayer, Opponent};
+use crate::{AppSystems, screens::Screen};
+
+/// How quickly the fill closes the gap to the real HP (per second).
+const FILL_EASE: f32 = 12.0;
+/// Seconds the damage trail holds before draining.
+const TRAIL_DELAY_SECS: f32 = 0.5;
+/// Fraction of the bar the trail drains per second.
+const TRAIL_DRAIN: f32 = 0.6;
+/// Hits taking at least this fraction of max HP flash the bar.
+const BIG_HIT: f32 = 0.1;
+const FLASH_SECS: f32 = 0.25;
+const TRAIL_COLOR: Color = Color::srgb(0.95, 0.95, 0.95);
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        animate_hp_bars
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay))
+            .run_if(in_state(GameResult::Playing)),
+    );
+}
+
+/// A 200×20 HP bar for one player: background, damage trail and fill.
+pub(super) fn hp_bar(local: bool, background: Color, fill: Color) -> impl Bundle {
+    (
+        Name::new(if local {
+            "Player HP Bar BG"
+        } else {
+            "Opponent HP Bar BG"
+        }),
+        HpBar::new(local, fill),
+        Node {
+            width: px(200),
+            height: px(20),
+            ..default()
+        },
+        BackgroundColor(background),
+        children![
+            (
+                Name::new("HP Bar Trail"),
+                HpBarTrail,
+                bar_segment(),
+                BackgroundColor(TRAIL_COLOR),
+            ),
+            (
+                Name::new("HP Bar Fill"),
+                HpBarFill,
+                bar_segment(),
+                BackgroundColor(fill),
+            ),
+        ],
+    )
+}
+
+fn bar_segment() -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        left: px(0),
+        width: percent(100),
+        height: percent(100),
+        ..default()
+    }
+}
+
+/// What an HP bar is showing, as fractions of max HP.
+#[derive(Component, Debug)]
+struct HpBar {
+    local: bool,
+    fill_color: Color,
+    /// HP the bar is heading toward; `None` until the first update.
+    target: Option<f32>,
+    fill: f32,
+    trail: f32,
+    /// Seconds before the trail starts draining.
+    hold: f32,
+    flash: f32,
+}
+
+impl HpBar {
+    fn new(local: bool, fill_color: Color) -> Self {
+        Self {
+            local,
+            fill_color,
+            target: None,
+            fill: 1.0,
+            trail: 1.0,
+            hold: 0.0,
+            flash: 0.0,
+        }
+    }
+
+    fn update(&mut self, target: f32, dt: f32) {
+        let Some(previous) = self.target else {
+            // Matches can start below full HP; don't animate into it.
+            *self = Self {
+                target: Some(target),
+                fill: target,
+                trail: target,
+                ..*self
+            };
+            return;
+        };
+        if target < previous {
+            self.hold = TRAIL_DELAY_SECS;
+            if previous - target >= BIG_HIT {
+                self.flash = FLASH_SECS;
+            }
+        }
+        self.target = Some(target);
+        self.fill += (target - self.fill) * (1.0 - (-FILL_EASE * dt).exp());
+        if self.hold > 0.0 {
+            self.hold -= dt;
+        } else {
+            self.trail -= TRAIL_DRAIN * dt;
+        }
+        // Healing pushes the trail up with the fill.
+        self.trail = self.trail.max(self.fill);
+        self.flash = (self.flash - dt).max(0.0);
+    }
+
+    fn fill_color(&self) -> Color {
+        self.fill_color.mix(&Color::WHITE, self.flash / FLASH_SECS)
+    }
+}
+
+#[derive(Component)]
+struct HpBarTrail;
+
+#[derive(Component)]
+struct HpBarFill;
+
+fn animate_hp_bars(
+    time: Res<Time>,
+    local: Query<&Health, With<LocalPlayer>>,
+    opponent: Query<&Health, With<Opponent>>,
+    mut bars: Query<(&mut HpBar, &Children)>,
+    mut trails: Query<&mut Node, (With<HpBarTrail>, Without<HpBarFill>)>,
+    mut fills: Query<(&mut Node, &mut BackgroundColor), With<HpBarFill>>,
+) {
+    for (mut bar, children) in &mut bars {
+        let health = if bar.local {
+            local.single()
+        } else {
+            opponent.single()
+        };
+        let Ok(health) = health else {
+            continue;
+        };
+        bar.update(health.percentage().clamp(0.0, 1.0), time.delta_secs());
+        for child in children.iter() {
+            if let Ok(mut node) = trails.get_mut(child) {
+                node.width = percent(bar.trail * 100.0);
+            }
+            if let Ok((mut node, mut color)) = fills.get_mut(child) {
+                node.width = percent(bar.fill * 100.0);
+                color.0 = bar.fill_color();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trail_holds_then_drains_to_the_fill() {
+        let mut bar = HpBar::new(true, Color::BLACK);
+        bar.update(1.0, 0.0);
+        bar.update(0.5, 0.1);
+        assert!(bar.flash > 0.0);
+        assert!(bar.fill < 1.0 && bar.fill > 0.5);
+        assert_eq!(bar.trail, 1.0);
+
+        for _ in 0..60 {
+            bar.update(0.5, 0.05);
+        }
+        assert!((bar.fill - 0.5).abs() < 0.01);
+        assert_eq!(bar.trail, bar.fill);
+        assert_eq!(bar.flash, 0.0);
+    }
+
+    #[test]
+    fn small_hits_do_not_flash() {
+        let mut bar = HpBar::new(true, Color::BLACK);
+        bar.update(0.8, 0.0);
+        bar.update(0.75, 0.1);
+        assert_eq!(bar.flash, 0.0);
+        assert!(bar.trail > bar.fill);
+    }
+}
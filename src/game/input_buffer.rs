@@ -3,16 +3,96 @@
 use bevy::prelude::*;
 
 use crate::{
-    AppSystems,
-    input::{INPUT_DRAW, card_flag, flags_from_keyboard},
+    AppSystems, PausableSystems,
+    input::{
+        INPUT_CANCEL_QUEUE, INPUT_CARDS, INPUT_CONCEDE, INPUT_DRAW, INPUT_QUEUE_WAIT, card_flag,
+        flags_from_keyboard,
+    },
+    persist::{self, Persisted, save_on_change},
+    replay::{ReplayRecorder, is_replay},
+    sandbox::picker_open,
 };
 
 use super::{
-    CardRegistry, CardType, CorruptionEffect, Cost, DRAW_COUNT, DrawCardsMessage, GameResult,
-    GameplaySystems, Hand, LocalPlayer, MAX_HAND_SIZE, PlayCardMessage, is_offline,
+    CardId, CardRegistry, CardType, CorruptionEffect, Cost, DeathMessage, DrawCardsMessage,
+    FreeCards, GameResult, GameplaySystems, Hand, LocalPlayer, MAX_HAND_SIZE, PlayCardMessage,
+    PlayerHandle, Rules, is_inspecting, is_offline,
 };
 use crate::screens::Screen;
 
+const SAVE_KEY: &str = "input.txt";
+
+/// Cards pressed but not played yet, oldest first.
+///
+/// At most one card is played per frame, so presses that arrive together (or
+/// are replayed together after a rollback) wait here and resolve on following
+/// frames. Cards are queued by id rather than hand slot, since slots shift as
+/// other cards leave the hand.
+#[derive(Component, Debug, Default, Clone, Reflect)]
+#[reflect(Component)]
+pub struct PlayQueue {
+    pub cards: Vec<QueuedPlay>,
+}
+
+impl PlayQueue {
+    /// Whether a card is waiting in the queue for enough cost.
+    pub fn is_waiting(&self, card_id: CardId) -> bool {
+        self.cards
+            .iter()
+            .any(|queued| queued.wait && queued.card == card_id)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Reflect)]
+pub struct QueuedPlay {
+    pub card: CardId,
+    /// Stay queued while unaffordable instead of being dropped.
+    pub wait: bool,
+}
+
+/// Local input preferences, persisted across sessions.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct InputSettings {
+    /// Cards pressed without enough cost wait and play once they can be paid for.
+    pub queue_until_affordable: bool,
+}
+
+impl InputSettings {
+    /// Mark card presses to wait for cost when the player asked for that.
+    ///
+    /// The mode travels with the input rather than being looked up when it is
+    /// applied, so both clients of an online match resolve the same queue.
+    pub fn tag_flags(&self, flags: u16) -> u16 {
+        if self.queue_until_affordable && flags & INPUT_CARDS != 0 {
+            flags | INPUT_QUEUE_WAIT
+        } else {
+            flags
+        }
+    }
+
+    fn load() -> Self {
+        let mut settings = Self::default();
+        let Some(contents) = persist::load(SAVE_KEY) else {
+            return settings;
+        };
+        for (key, value) in contents.lines().filter_map(|line| line.split_once('=')) {
+            if key == "queue_until_affordable" {
+                settings.queue_until_affordable = value == "true";
+            }
+        }
+        settings
+    }
+}
+
+impl Persisted for InputSettings {
+    fn save(&self) {
+        persist::store(
+            SAVE_KEY,
+            &format!("queue_until_affordable={}", self.queue_until_affordable),
+        );
+    }
+}
+
 /// One-frame input buffer that UI and keyboard systems can write into.
 #[derive(Resource, Default)]
 pub struct PendingInput {
@@ -33,20 +113,31 @@ impl PendingInput {
 
 pub(super) fn plugin(app: &mut App) {
     app.init_resource::<PendingInput>();
+    app.insert_resource(InputSettings::load());
+    app.add_systems(
+        Update,
+        save_on_change::<InputSettings>.run_if(resource_changed::<InputSettings>),
+    );
     app.add_systems(
         Update,
         capture_keyboard_input
             .in_set(AppSystems::RecordInput)
+            .in_set(PausableSystems)
             .run_if(is_offline)
             .run_if(in_state(Screen::Gameplay))
-            .run_if(in_state(GameResult::Playing)),
+            .run_if(in_state(GameResult::Playing))
+            // Typing a card name into the sandbox picker shouldn't draw or play cards.
+            .run_if(not(picker_open))
+            .run_if(not(is_inspecting)),
     );
+    // Keys pressed between ticks pile up in `PendingInput` until the next one.
     app.add_systems(
-        Update,
+        FixedUpdate,
         apply_pending_inputs
-            .in_set(AppSystems::Update)
             .in_set(GameplaySystems::Input)
             .run_if(is_offline)
+            // A replay brings its own inputs.
+            .run_if(not(is_replay))
             .run_if(in_state(Screen::Gameplay))
             .run_if(in_state(GameResult::Playing)),
     );
@@ -58,76 +149,153 @@ fn capture_keyboard_input(keyboard: Res<ButtonInput<KeyCode>>, mut pending: ResM
 
 fn apply_pending_inputs(
     mut pending: ResMut<PendingInput>,
+    settings: Res<InputSettings>,
     mut player_query: Query<
-        (Entity, &Hand, &mut Cost, Option<&CorruptionEffect>),
+        (
+            Entity,
+            &Hand,
+            &mut PlayQueue,
+            &mut Cost,
+            &mut FreeCards,
+            &PlayerHandle,
+            Option<&CorruptionEffect>,
+        ),
         With<LocalPlayer>,
     >,
     card_registry: Res<CardRegistry>,
+    rules: Res<Rules>,
+    mut recorder: ResMut<ReplayRecorder>,
     mut play_messages: MessageWriter<PlayCardMessage>,
     mut draw_messages: MessageWriter<DrawCardsMessage>,
+    mut death_messages: MessageWriter<DeathMessage>,
 ) {
-    let flags = pending.take_flags();
-    if flags == 0 {
-        return;
-    }
-
-    let Ok((player_entity, hand, mut cost, corruption)) = player_query.single_mut() else {
+    // Runs even without new input so queued cards keep resolving.
+    let flags = settings.tag_flags(pending.take_flags());
+    let Ok((player_entity, hand, mut queue, mut cost, mut free_cards, handle, corruption)) =
+        player_query.single_mut()
+    else {
         return;
     };
+    // The opponent never sends input offline.
+    let mut inputs = [0; 2];
+    inputs[handle.0] = flags;
+    recorder.record(None, inputs);
 
-    apply_local_input_flags(
+    apply_input_flags(
         flags,
         player_entity,
         hand,
+        &mut queue,
         &mut cost,
+        &mut free_cards,
         corruption.is_some(),
         &card_registry,
+        &rules,
         &mut draw_messages,
         &mut play_messages,
+        &mut death_messages,
     );
 }
 
-pub(crate) fn apply_local_input_flags(
+/// Turn one player's input flags into draw and play messages.
+///
+/// Shared by offline play and the rollback schedule, so both follow the same
+/// rules; only where the flags come from differs.
+pub(crate) fn apply_input_flags(
     flags: u16,
     player_entity: Entity,
     hand: &Hand,
+    queue: &mut PlayQueue,
     cost: &mut Cost,
+    free_cards: &mut FreeCards,
     corruption_active: bool,
     card_registry: &CardRegistry,
+    rules: &Rules,
     draw_messages: &mut MessageWriter<DrawCardsMessage>,
     play_messages: &mut MessageWriter<PlayCardMessage>,
+    death_messages: &mut MessageWriter<DeathMessage>,
 ) {
-    // Draw cost = current hand size (0 cards = free draw)
-    let draw_cost = hand.len() as f32;
+    // Conceding counts as a death, so both clients resolve the same result.
+    if flags & INPUT_CONCEDE != 0 {
+        death_messages.write(DeathMessage {
+            entity: player_entity,
+        });
+        return;
+    }
+
+    // Draw cost grows with the hand (0 cards = free draw)
+    let draw_cost = rules.draw_cost(hand.len());
     if flags & INPUT_DRAW != 0 && cost.try_spend(draw_cost) {
         draw_messages.write(DrawCardsMessage {
             player: player_entity,
-            count: DRAW_COUNT,
+            count: rules.draw_count,
         });
     }
 
+    if flags & INPUT_CANCEL_QUEUE != 0 {
+        queue.cards.clear();
+    }
+
+    // Queue every card pressed this frame, in hand order. Pressing a card
+    // that is already waiting for cost takes it back out instead.
+    let wait = flags & INPUT_QUEUE_WAIT != 0;
     for i in 0..MAX_HAND_SIZE {
         let Some(flag) = card_flag(i) else {
             continue;
         };
-        if flags & flag != 0 {
-            if let Some(card_id) = hand.cards.get(i).copied() {
-                if let Some(card_def) = card_registry.get(card_id) {
-                    let effective_cost =
-                        if corruption_active && card_def.card_type == CardType::Skill {
-                            0.0
-                        } else {
-                            card_def.cost
-                        };
-                    if cost.try_spend(effective_cost) {
-                        play_messages.write(PlayCardMessage {
-                            player: player_entity,
-                            hand_index: i,
-                        });
-                    }
-                }
-            }
+        if flags & flag == 0 {
+            continue;
+        }
+        let Some(card_id) = hand.cards.get(i).copied() else {
+            continue;
+        };
+        if let Some(waiting) = queue
+            .cards
+            .iter()
+            .position(|queued| queued.wait && queued.card == card_id)
+        {
+            queue.cards.remove(waiting);
+        } else if queue.cards.len() < MAX_HAND_SIZE {
+            queue.cards.push(QueuedPlay {
+                card: card_id,
+                wait,
+            });
+        }
+    }
+
+    // Play the oldest queued card that can be paid for. Ones that left the
+    // hand or can't be played are dropped without using up the frame; ones
+    // that can't be afforded yet stay if they are waiting for cost.
+    let mut index = 0;
+    while index < queue.cards.len() {
+        let queued = queue.cards[index];
+        let Some(hand_index) = hand.cards.iter().position(|card| *card == queued.card) else {
+            queue.cards.remove(index);
+            continue;
+        };
+        let Some(card_def) = card_registry
+            .get(queued.card)
+            .filter(|card_def| card_def.is_playable())
+        else {
+            queue.cards.remove(index);
+            continue;
+        };
+        let free = free_cards.is_free(queued.card)
+            || (corruption_active && card_def.card_type == CardType::Skill);
+        let effective_cost = if free { 0.0 } else { card_def.cost };
+        if cost.try_spend(effective_cost) {
+            free_cards.take(queued.card);
+            queue.cards.remove(index);
+            play_messages.write(PlayCardMessage {
+                player: player_entity,
+                hand_index,
+            });
             break;
         }
+        if queued.wait {
+            index += 1;
+        } else {
+            queue.cards.remove(index);
+        }
     }
 }
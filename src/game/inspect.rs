@@ -0,0 +1,362 @@
+//! Card inspection: right-click (or long-press) a card to zoom it up close.
+//!
+//! Works on the local hand and on cards being played by either side. The card
+//! grows from where it sits on the table to the middle of the screen, with the
+//! art and the full tooltip text in crisp UI text, over a dimmed backdrop.
+//! Gameplay input stays blocked until the view is dismissed with a click or
+//! Escape.
+
+use bevy::{
+    ecs::system::SystemParam, picking::pointer::PointerId, prelude::*, window::PrimaryWindow,
+};
+use bevy_la_mesa::{Card as MesaCardComponent, Hand as MesaHand};
+
+use super::{
+    CardId, CardRegistry, CorruptionEffect, LocalPlayer, Opponent, Rules, Strength, Vulnerable,
+    Weak,
+    card_art::CardArt,
+    mesa::{LOCAL_PLAYER_INDEX, MesaCard, PlayedCard, card_art},
+    tooltip::tooltip_text,
+};
+use crate::{AppSystems, MainCamera, screens::Screen, theme::font::Language};
+
+/// How long a card must be held down to inspect it (touch screens).
+const LONG_PRESS_SECS: f32 = 0.5;
+const ZOOM_SECS: f32 = 0.2;
+/// Card scale at the start of the zoom, relative to the inspected size.
+const ZOOM_START_SCALE: f32 = 0.2;
+const BACKDROP_ALPHA: f32 = 0.75;
+const ART_WIDTH: f32 = 250.0;
+const ART_HEIGHT: f32 = 350.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<CardInspect>();
+    app.add_systems(OnExit(Screen::Gameplay), reset_inspect);
+    app.add_systems(
+        Update,
+        (
+            (track_long_press, open_on_right_click, open_on_long_press).run_if(not(is_inspecting)),
+            animate_zoom,
+        )
+            .chain()
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+    // After `Update`, so the Escape that closes the view doesn't also pause.
+    app.add_systems(
+        PostUpdate,
+        close_on_escape.run_if(in_state(Screen::Gameplay).and(is_inspecting)),
+    );
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct CardInspect {
+    open: bool,
+    long_press: Option<LongPress>,
+}
+
+#[derive(Debug)]
+struct LongPress {
+    pointer: PointerId,
+    card: Entity,
+    held_secs: f32,
+}
+
+impl CardInspect {
+    /// Whether a card is zoomed in; gameplay input is ignored meanwhile.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+}
+
+pub fn is_inspecting(inspect: Res<CardInspect>) -> bool {
+    inspect.is_open()
+}
+
+fn reset_inspect(mut inspect: ResMut<CardInspect>) {
+    *inspect = CardInspect::default();
+}
+
+/// Backdrop dimming the scene behind the inspected card.
+#[derive(Component)]
+struct InspectBackdrop;
+
+#[derive(Component)]
+struct InspectZoom {
+    /// Screen offset from the middle of the window to where the card started.
+    from: Vec2,
+    elapsed: f32,
+}
+
+type InspectableCards<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static MesaCardComponent<MesaCard>,
+        Option<&'static MesaHand>,
+        Has<PlayedCard>,
+    ),
+>;
+
+/// The card entity a picked mesh belongs to, if its face is visible to the
+/// local player: their own hand, or a card being played by either side.
+fn inspectable_card(
+    entity: Entity,
+    cards: &InspectableCards,
+    parents: &Query<&ChildOf>,
+) -> Option<(Entity, CardId)> {
+    let mut current = entity;
+    loop {
+        if let Ok((card, hand, played)) = cards.get(current) {
+            let own_hand = hand.is_some_and(|hand| hand.player == LOCAL_PLAYER_INDEX);
+            let visible = (own_hand || played) && card.data.card_id != CardId::Unknown;
+            return visible.then_some((current, card.data.card_id));
+        }
+        current = parents.get(current).ok()?.parent();
+    }
+}
+
+fn track_long_press(
+    time: Res<Time>,
+    mut inspect: ResMut<CardInspect>,
+    mut presses: MessageReader<Pointer<Press>>,
+    mut releases: MessageReader<Pointer<Release>>,
+    mut outs: MessageReader<Pointer<Out>>,
+    cards: InspectableCards,
+    parents: Query<&ChildOf>,
+) {
+    for press in presses.read() {
+        if press.button != PointerButton::Primary {
+            continue;
+        }
+        if let Some((card, _)) = inspectable_card(press.entity, &cards, &parents) {
+            inspect.long_press = Some(LongPress {
+                pointer: press.pointer_id,
+                card,
+                held_secs: 0.0,
+            });
+        }
+    }
+    // Letting go or sliding off the card cancels the press.
+    let released = releases.read().any(|release| {
+        inspect
+            .long_press
+            .as_ref()
+            .is_some_and(|press| press.pointer == release.pointer_id)
+    });
+    let left = outs.read().any(|out| {
+        inspect.long_press.as_ref().is_some_and(|press| {
+            inspectable_card(out.entity, &cards, &parents).map(|(card, _)| card) == Some(press.card)
+        })
+    });
+    if released || left {
+        inspect.long_press = None;
+    }
+    if let Some(press) = &mut inspect.long_press {
+        press.held_secs += time.delta_secs();
+    }
+}
+
+fn open_on_right_click(
+    mut commands: Commands,
+    mut inspect: ResMut<CardInspect>,
+    mut clicks: MessageReader<Pointer<Click>>,
+    cards: InspectableCards,
+    parents: Query<&ChildOf>,
+    view: InspectView,
+) {
+    let Some((card, card_id)) = clicks
+        .read()
+        .filter(|click| click.button == PointerButton::Secondary)
+        .find_map(|click| inspectable_card(click.entity, &cards, &parents))
+    else {
+        return;
+    };
+    inspect.long_press = None;
+    inspect.open = view.spawn(&mut commands, card, card_id);
+}
+
+fn open_on_long_press(
+    mut commands: Commands,
+    mut inspect: ResMut<CardInspect>,
+    cards: InspectableCards,
+    view: InspectView,
+) {
+    let Some(card) = inspect
+        .long_press
+        .as_ref()
+        .filter(|press| press.held_secs >= LONG_PRESS_SECS)
+        .map(|press| press.card)
+    else {
+        return;
+    };
+    inspect.long_press = None;
+    let Ok((mesa_card, _, _)) = cards.get(card) else {
+        return;
+    };
+    inspect.open = view.spawn(&mut commands, card, mesa_card.data.card_id);
+}
+
+/// Everything needed to build the zoomed-in card.
+#[derive(SystemParam)]
+struct InspectView<'w, 's> {
+    asset_server: Res<'w, AssetServer>,
+    images: Res<'w, Assets<Image>>,
+    art: Res<'w, CardArt>,
+    registry: Res<'w, CardRegistry>,
+    language: Res<'w, Language>,
+    rules: Res<'w, Rules>,
+    window: Single<'w, 's, &'static Window, With<PrimaryWindow>>,
+    camera: Query<'w, 's, (&'static Camera, &'static GlobalTransform), With<MainCamera>>,
+    transforms: Query<'w, 's, &'static GlobalTransform>,
+    player: Query<
+        'w,
+        's,
+        (
+            Option<&'static Strength>,
+            Option<&'static Weak>,
+            Option<&'static CorruptionEffect>,
+        ),
+        With<LocalPlayer>,
+    >,
+    opponent: Query<'w, 's, Option<&'static Vulnerable>, With<Opponent>>,
+}
+
+impl InspectView<'_, '_> {
+    /// Spawn the zoomed-in card; false if the card has no definition.
+    fn spawn(&self, commands: &mut Commands, card: Entity, card_id: CardId) -> bool {
+        let Some(def) = self.registry.get(card_id) else {
+            return false;
+        };
+        let (strength, weak, corruption) = self.player.single().unwrap_or_default();
+        let vulnerable = self.opponent.single().ok().flatten();
+        let text = tooltip_text(
+            def,
+            *self.language,
+            &self.rules,
+            strength.map_or(0.0, |strength| strength.amount),
+            weak,
+            vulnerable,
+            corruption.is_some(),
+        );
+
+        commands
+            .spawn((
+                Name::new("Card Inspect"),
+                InspectBackdrop,
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: percent(100),
+                    height: percent(100),
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                BackgroundColor(Color::BLACK.with_alpha(0.0)),
+                GlobalZIndex(30),
+                DespawnOnExit(Screen::Gameplay),
+            ))
+            .observe(close_on_click)
+            .with_children(|backdrop| {
+                backdrop.spawn((
+                    Name::new("Inspected Card"),
+                    InspectZoom {
+                        from: self.card_offset(card),
+                        elapsed: 0.0,
+                    },
+                    Node {
+                        width: px(ART_WIDTH + 40.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: px(12),
+                        padding: UiRect::all(px(20)),
+                        border_radius: BorderRadius::all(px(12)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 0.95)),
+                    UiTransform::from_scale(Vec2::splat(ZOOM_START_SCALE)),
+                    Pickable::IGNORE,
+                    children![
+                        (
+                            Node {
+                                width: px(ART_WIDTH),
+                                height: px(ART_HEIGHT),
+                                ..default()
+                            },
+                            card_art(card_id, &self.art, &self.asset_server, &self.images),
+                            Pickable::IGNORE,
+                        ),
+                        (
+                            Text::new(text),
+                            TextFont::from_font_size(18.0),
+                            TextColor(Color::WHITE),
+                            Pickable::IGNORE,
+                        ),
+                    ],
+                ));
+            });
+        true
+    }
+
+    /// Where the card sits on screen, relative to the middle of the window.
+    fn card_offset(&self, card: Entity) -> Vec2 {
+        let Ok((camera, camera_transform)) = self.camera.single() else {
+            return Vec2::ZERO;
+        };
+        let Ok(transform) = self.transforms.get(card) else {
+            return Vec2::ZERO;
+        };
+        let Ok(screen) = camera.world_to_viewport(camera_transform, transform.translation()) else {
+            return Vec2::ZERO;
+        };
+        // The camera may render to a smaller offscreen image (render scale).
+        let to_window = camera
+            .logical_viewport_size()
+            .map_or(Vec2::ONE, |size| self.window.size() / size);
+        screen * to_window - self.window.size() / 2.0
+    }
+}
+
+fn animate_zoom(
+    time: Res<Time>,
+    mut zooms: Query<(&mut InspectZoom, &mut UiTransform, &ChildOf)>,
+    mut backdrops: Query<&mut BackgroundColor, With<InspectBackdrop>>,
+) {
+    for (mut zoom, mut transform, child_of) in &mut zooms {
+        if zoom.elapsed >= ZOOM_SECS {
+            continue;
+        }
+        zoom.elapsed = (zoom.elapsed + time.delta_secs()).min(ZOOM_SECS);
+        let t = EaseFunction::CubicOut.sample_clamped(zoom.elapsed / ZOOM_SECS);
+        transform.scale = Vec2::splat(ZOOM_START_SCALE.lerp(1.0, t));
+        let offset = zoom.from * (1.0 - t);
+        transform.translation = Val2::px(offset.x, offset.y);
+        if let Ok(mut backdrop) = backdrops.get_mut(child_of.parent()) {
+            backdrop.0 = Color::BLACK.with_alpha(BACKDROP_ALPHA * t);
+        }
+    }
+}
+
+fn close_on_click(
+    click: On<Pointer<Click>>,
+    mut commands: Commands,
+    mut inspect: ResMut<CardInspect>,
+) {
+    commands.entity(click.entity).despawn();
+    inspect.open = false;
+}
+
+fn close_on_escape(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut inspect: ResMut<CardInspect>,
+    backdrops: Query<Entity, With<InspectBackdrop>>,
+) {
+    if !keyboard.just_pressed(KeyCode::Escape) {
+        return;
+    }
+    for entity in &backdrops {
+        commands.entity(entity).despawn();
+    }
+    inspect.open = false;
+}
@@ -0,0 +1,187 @@
+//! Per-match statistics for the result overlay.
+//!
+//! [`MatchStats`] is fed from the gameplay messages inside the simulation, so
+//! online it rolls back with the players and re-simulated frames aren't
+//! counted twice. It starts over whenever a match does.
+
+use bevy::prelude::*;
+
+use super::{
+    AddGameplaySystems, CardId, CardPlayedMessage, CardRegistry, DamageMessage, GainBlockMessage,
+    GameplaySystems, PlayerHandle,
+};
+use crate::screens::Screen;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<MatchStats>();
+    app.add_systems(OnEnter(Screen::Gameplay), reset_match_stats);
+    app.add_gameplay_systems(GameplaySystems::Health, record_match_stats);
+}
+
+/// What each player has done this match, by player handle.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct MatchStats {
+    players: [PlayerStats; 2],
+}
+
+impl MatchStats {
+    pub fn player(&self, handle: usize) -> &PlayerStats {
+        &self.players[handle]
+    }
+}
+
+/// One player's numbers. Damage is counted as dealt, before Block absorbs it.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PlayerStats {
+    /// Damage dealt to the opponent.
+    pub damage_dealt: f32,
+    /// Damage taken from any source, self-inflicted included.
+    pub damage_taken: f32,
+    /// The largest single hit dealt to the opponent.
+    pub biggest_hit: f32,
+    pub block_gained: f32,
+    pub cards_played: u32,
+    /// Plays per card, in the order each was first played.
+    plays: Vec<(CardId, u32)>,
+}
+
+impl PlayerStats {
+    /// The card played most and how often; the one played first wins ties.
+    pub fn most_played(&self) -> Option<(CardId, u32)> {
+        self.plays
+            .iter()
+            .copied()
+            .reduce(|best, play| if play.1 > best.1 { play } else { best })
+    }
+
+    fn record_play(&mut self, card_id: CardId) {
+        self.cards_played += 1;
+        match self.plays.iter_mut().find(|(id, _)| *id == card_id) {
+            Some((_, count)) => *count += 1,
+            None => self.plays.push((card_id, 1)),
+        }
+    }
+
+    /// Lines for the result overlay, for a match that lasted `elapsed` seconds.
+    pub fn summary(&self, elapsed: f32, registry: &CardRegistry) -> String {
+        let secs = elapsed.max(0.0) as u32;
+        let most_played = self
+            .most_played()
+            .and_then(|(card_id, count)| {
+                registry
+                    .get(card_id)
+                    .map(|def| format!("{} ({count})", def.name))
+            })
+            .unwrap_or_else(|| "-".to_string());
+        [
+            format!("Duration: {}:{:02}", secs / 60, secs % 60),
+            format!(
+                "Damage dealt: {:.0}   Damage taken: {:.0}",
+                self.damage_dealt, self.damage_taken
+            ),
+            format!(
+                "Cards played: {}   Most played: {most_played}",
+                self.cards_played
+            ),
+            format!(
+                "Biggest hit: {:.0}   Block gained: {:.0}",
+                self.biggest_hit, self.block_gained
+            ),
+        ]
+        .join("\n")
+    }
+}
+
+fn reset_match_stats(mut stats: ResMut<MatchStats>) {
+    *stats = MatchStats::default();
+}
+
+fn record_match_stats(
+    mut damage_messages: MessageReader<DamageMessage>,
+    mut block_messages: MessageReader<GainBlockMessage>,
+    mut played_messages: MessageReader<CardPlayedMessage>,
+    handles: Query<&PlayerHandle>,
+    mut stats: ResMut<MatchStats>,
+) {
+    for msg in damage_messages.read() {
+        if msg.amount <= 0.0 {
+            continue;
+        }
+        if let Ok(target) = handles.get(msg.target) {
+            stats.players[target.0].damage_taken += msg.amount;
+        }
+        let Some(source) = msg.source.filter(|source| *source != msg.target) else {
+            continue;
+        };
+        if let Ok(source) = handles.get(source) {
+            let dealer = &mut stats.players[source.0];
+            dealer.damage_dealt += msg.amount;
+            dealer.biggest_hit = dealer.biggest_hit.max(msg.amount);
+        }
+    }
+    for msg in block_messages.read() {
+        if let Ok(target) = handles.get(msg.target) {
+            stats.players[target.0].block_gained += msg.amount.max(0.0);
+        }
+    }
+    for msg in played_messages.read() {
+        if let Ok(player) = handles.get(msg.player) {
+            stats.players[player.0].record_play(msg.card_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        game::{
+            Cost, PlayerHandle, create_test_deck,
+            test_support::{Harness, MODES},
+        },
+        input::{INPUT_CARD_1, INPUT_CARD_2},
+    };
+
+    #[test]
+    fn most_played_prefers_the_first_card_on_ties() {
+        let mut stats = PlayerStats::default();
+        assert_eq!(stats.most_played(), None);
+        for card_id in [
+            CardId::Defend,
+            CardId::Strike,
+            CardId::Strike,
+            CardId::Defend,
+        ] {
+            stats.record_play(card_id);
+        }
+        assert_eq!(stats.cards_played, 4);
+        assert_eq!(stats.most_played(), Some((CardId::Defend, 2)));
+        stats.record_play(CardId::Strike);
+        assert_eq!(stats.most_played(), Some((CardId::Strike, 3)));
+    }
+
+    #[test]
+    fn stats_follow_the_plays() {
+        for mode in MODES {
+            let mut harness = Harness::new(mode, create_test_deck(), create_test_deck());
+            let player = harness.player;
+            harness.give_card(player, CardId::Strike);
+            harness.give_card(player, CardId::Defend);
+            harness.get_mut::<Cost>(player).current = 2.0;
+            // Both are queued; one is played per frame.
+            harness.press(player, INPUT_CARD_1 | INPUT_CARD_2);
+            harness.step();
+            harness.step();
+
+            let handle = harness.get::<PlayerHandle>(player).0;
+            let stats = harness.app.world().resource::<MatchStats>();
+            let (mine, theirs) = (stats.player(handle), stats.player(1 - handle));
+            assert_eq!(mine.cards_played, 2, "{mode:?}");
+            assert_eq!(mine.damage_dealt, 60.0, "{mode:?}");
+            assert_eq!(mine.biggest_hit, 60.0, "{mode:?}");
+            assert_eq!(mine.block_gained, 50.0, "{mode:?}");
+            assert_eq!(theirs.damage_taken, 60.0, "{mode:?}");
+            assert_eq!(theirs.cards_played, 0, "{mode:?}");
+        }
+    }
+}
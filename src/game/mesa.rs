@@ -1,25 +1,42 @@
 //! 3D card table rendering with bevy_la_mesa.
 
 use bevy::{
-    color::Srgba, math::Affine2, prelude::*, render::alpha::AlphaMode,
-    transform::TransformSystems,
+    color::Srgba, math::Affine2, platform::collections::HashMap, prelude::*,
+    render::alpha::AlphaMode, transform::TransformSystems,
 };
 use bevy_la_mesa::events::{
     AlignCardsInHand, CardHover, CardOut, CardPress, DiscardCardToDeck, RenderDeck,
+    handle_discard_card_to_deck,
 };
 use bevy_la_mesa::{
     Card as MesaCardComponent, CardMetadata, Deck as MesaDeck, DeckArea, Hand as MesaHand,
     HandArea, LaMesaPlugin, LaMesaPluginSettings, PlayArea,
 };
 use bevy_rich_text3d::{Text3d, Text3dStyling, TextAlign, TextAnchor, TextAtlas};
-use bevy_tweening::TweenAnim;
-use std::{cmp::Ordering, num::NonZeroU32};
+use bevy_tweening::{
+    Tween, TweenAnim,
+    lens::{TransformPositionLens, TransformRotationLens},
+};
+use std::{cmp::Ordering, num::NonZeroU32, time::Duration};
 
 use super::{
-    CardEffect, CardId, CardRegistry, CardType, Deck, DeckReshuffledMessage, GameResult, Hand,
-    LocalPlayer, Opponent, PendingInput, PlayCardMessage,
+    CardEffect, CardExhaustedMessage, CardId, CardRegistry, CardRemovedFromHandMessage, CardType,
+    CharacterClass, CorruptionEffect, Cost, Deck, DeckReshuffledMessage, EffectKind, FreeCards,
+    GameResult, Hand, InputSettings, LocalPlayer, Opponent, PendingInput, PlayCardMessage,
+    PlayQueue,
+    card_art::CardArt,
+    card_frame::{CARD_SIZE, CardArtQuad, CardFrames},
+    card_sounds::CardRejected,
+    effect::AddCardToDiscardMessage,
+    shaders::GlowColors,
+};
+use crate::{
+    AppSystems, MainCamera,
+    asset_tracking::{AssetGroup, LoadResource},
+    input::card_flag,
+    logging::hot_log,
+    screens::Screen,
 };
-use crate::{AppSystems, input::card_flag, screens::Screen};
 
 /// Marker for the glow overlay mesh attached to cards.
 #[derive(Component)]
@@ -29,29 +46,144 @@ struct CardGlowOverlay;
 #[derive(Component)]
 struct HasGlowOverlay;
 
-/// Marker component for cards that have effect text added.
+/// The card whose name, type, effect and cost the card's text lines show.
 #[derive(Component)]
-struct CardEffectTextAdded;
+struct CardText(CardId);
+
+/// Which of a card's text lines this is, so a recycled card can rewrite it.
+#[derive(Component, Clone, Copy)]
+enum CardTextLine {
+    Name,
+    Type,
+    Effect,
+    Cost,
+}
 
-#[derive(Resource, Clone)]
-struct CardTextMaterial(Handle<StandardMaterial>);
+/// The card's front (frame, art and text). Face-down cards get it when they
+/// are first revealed.
+#[derive(Component)]
+struct CardFront(Entity);
+
+/// Most cards kept for reuse; any more leaving the table are despawned.
+const CARD_POOL_LIMIT: usize = 24;
 
+/// Cards that have left the table, kept with their back, front and text so
+/// drawing a card swaps its data instead of building a new hierarchy.
+#[derive(Resource, Default)]
+struct CardPool(Vec<(Entity, Option<Entity>)>);
+
+/// A card on its way into the [`CardPool`].
+#[derive(Component)]
+struct ParkCard;
+
+/// Unlit material for `Text3d` on the table, shared by every card and label.
 #[derive(Resource, Clone)]
-struct GlowAssets {
-    mesh: Handle<Mesh>,
-    material: Handle<StandardMaterial>,
+pub(super) struct CardTextMaterial(pub(super) Handle<StandardMaterial>);
+
+/// Meshes and materials shared by every card on the table, created once per
+/// match. Fronts are cached per card and tint, so drawing, hovering and
+/// retinting cards never adds assets.
+#[derive(Resource)]
+struct MesaAssets {
+    card_mesh: Handle<Mesh>,
+    back_material: Handle<StandardMaterial>,
+    glow_mesh: Handle<Mesh>,
+    hover_material: Handle<StandardMaterial>,
+    /// Edge shown behind local hand cards that can be played right now.
+    playable_material: Handle<StandardMaterial>,
+    /// Edge shown behind local hand cards queued until they are affordable.
+    queued_material: Handle<StandardMaterial>,
+    /// Keyed by card, whether it shows its own art, and whether it is greyed
+    /// out as unaffordable.
+    faces: HashMap<(CardId, bool, bool), Handle<StandardMaterial>>,
+}
+
+impl MesaAssets {
+    /// The front material for a card, created the first time it is needed.
+    fn face(
+        &mut self,
+        card: &MesaCard,
+        unaffordable: bool,
+        asset_server: &AssetServer,
+        materials: &mut Assets<StandardMaterial>,
+    ) -> Handle<StandardMaterial> {
+        self.faces
+            .entry((card.card_id, card.art.is_some(), unaffordable))
+            .or_insert_with(|| {
+                let mut face = StandardMaterial::default();
+                set_face_texture(&mut face, card, asset_server);
+                if unaffordable {
+                    face.base_color = GlowColors::UNPLAYABLE.into();
+                }
+                materials.add(face)
+            })
+            .clone()
+    }
+}
+
+/// The card's current front material, drawn on its art quad.
+#[derive(Component)]
+struct CardFace(Handle<StandardMaterial>);
+
+/// The shared frame material drawn under the card's front.
+#[derive(Component)]
+struct CardFrame(Handle<StandardMaterial>);
+
+/// Affordability tint currently applied to a hand card.
+#[derive(Component, Default, Clone, Copy)]
+enum AffordTint {
+    #[default]
+    None,
+    /// Holds the edge overlay entity.
+    Playable(Entity),
+    /// Waiting in the play queue for enough cost; holds the edge overlay entity.
+    Queued(Entity),
+    Unaffordable,
 }
 
+impl AffordTint {
+    fn same_kind(self, other: Self) -> bool {
+        std::mem::discriminant(&self) == std::mem::discriminant(&other)
+    }
+}
+
+#[derive(Component)]
+pub(super) struct HoveredCard;
+
+/// An opponent's hand card showing only its back. The face mesh and text are
+/// added when the card is played, so nothing about it is on the table before.
+#[derive(Component)]
+struct FaceDown;
+
+/// A played card showing in the play area before it goes to the discard pile.
 #[derive(Component)]
-struct HoveredCard;
+pub(super) struct PlayedCard {
+    card_id: CardId,
+    deck_entity: Option<Entity>,
+    /// Seconds left until it slides to the discard pile.
+    remaining: f32,
+    /// Burn away instead of going to the discard pile.
+    exhausted: bool,
+}
 
-const LOCAL_PLAYER_INDEX: usize = 1;
-const OPPONENT_PLAYER_INDEX: usize = 2;
+/// An exhausted card burning away before it despawns.
+///
+/// Emissive burn and shrink stand in for a dissolve shader until custom
+/// materials work again (see `shaders.rs`).
+#[derive(Component, Default)]
+struct Burning {
+    elapsed: f32,
+}
+
+pub(super) const LOCAL_PLAYER_INDEX: usize = 1;
+pub(super) const OPPONENT_PLAYER_INDEX: usize = 2;
 
 const CARD_BACK_IMAGE: &str = "images/splash.png";
 const CARD_ATLAS_IMAGE: &str = "images/cards.png";
 const ATLAS_COLS: usize = 6;
 const ATLAS_ROWS: usize = 2;
+/// Distance from a deck's center to its count label.
+const DECK_COUNT_OFFSET: f32 = CARD_SIZE.y / 2.0 + 0.4;
 const HAND_FAN_RADIUS: f32 = 12.0;
 const HAND_FAN_MAX_SPAN: f32 = 10.0;
 const HAND_FAN_BASE_STEP: f32 = 0.15;
@@ -60,12 +192,25 @@ const HAND_LAYER_STEP: f32 = 0.01;
 const HAND_TILT_STEP: f32 = 0.004;
 const HAND_HOVER_LIFT: f32 = 0.35;
 const CARD_TEXT_LIFT: f32 = 0.002;
+/// Played cards rise above the play area, flip face-up, then settle.
+const PLAY_LIFT: f32 = 0.8;
+const PLAY_MOVE_MILLIS: u64 = 180;
+const PLAY_FLIP_MILLIS: u64 = 120;
+const PLAY_SETTLE_MILLIS: u64 = 100;
+const BURN_SECS: f32 = 0.6;
+const BURN_COLOR: LinearRgba = LinearRgba::new(1.0, 0.45, 0.1, 1.0);
+/// How long a played card stays face-up once it has landed.
+const PLAY_HOLD_SECS: f32 = 0.5;
+/// Opponent plays stay up longer so the local player can read them.
+pub(super) const OPPONENT_REVEAL_SECS: f32 = 2.0;
 
 #[derive(Clone, Debug)]
-struct MesaCard {
-    card_id: CardId,
+pub(super) struct MesaCard {
+    pub(super) card_id: CardId,
     /// Index into the card atlas (0..ATLAS_COLS*ATLAS_ROWS).
     atlas_index: usize,
+    /// The card's own front, if it has one; otherwise its atlas tile is shown.
+    art: Option<Handle<Image>>,
     back: String,
 }
 
@@ -74,6 +219,7 @@ impl Default for MesaCard {
         Self {
             card_id: CardId::Unknown,
             atlas_index: 0,
+            art: None,
             back: CARD_BACK_IMAGE.to_string(),
         }
     }
@@ -83,7 +229,10 @@ impl CardMetadata for MesaCard {
     type Output = CardId;
 
     fn front_image_filename(&self) -> String {
-        CARD_ATLAS_IMAGE.to_string()
+        self.art
+            .as_ref()
+            .and_then(|art| art.path())
+            .map_or_else(|| CARD_ATLAS_IMAGE.to_string(), |path| path.to_string())
     }
 
     fn back_image_filename(&self) -> String {
@@ -96,10 +245,40 @@ fn atlas_uv_transform(index: usize) -> Affine2 {
     let col = index % ATLAS_COLS;
     let row = index / ATLAS_COLS;
     let scale = Vec2::new(1.0 / ATLAS_COLS as f32, 1.0 / ATLAS_ROWS as f32);
-    let translation = Vec2::new(col as f32 / ATLAS_COLS as f32, row as f32 / ATLAS_ROWS as f32);
+    let translation = Vec2::new(
+        col as f32 / ATLAS_COLS as f32,
+        row as f32 / ATLAS_ROWS as f32,
+    );
     Affine2::from_scale_angle_translation(scale, 0.0, translation)
 }
 
+/// The card's front art as a UI image: its own art if loaded, otherwise cut
+/// out of the atlas.
+pub(super) fn card_art(
+    card_id: CardId,
+    art: &CardArt,
+    asset_server: &AssetServer,
+    images: &Assets<Image>,
+) -> ImageNode {
+    if let Some(image) = art.loaded(card_id) {
+        return ImageNode::new(image);
+    }
+    let atlas: Handle<Image> = asset_server.load(CARD_ATLAS_IMAGE);
+    let index = atlas_index_for_card(card_id);
+    // Until the atlas is loaded its size is unknown; show the whole image.
+    let rect = images.get(&atlas).map(|image| {
+        let tile = image.size_f32() / Vec2::new(ATLAS_COLS as f32, ATLAS_ROWS as f32);
+        let min = Vec2::new((index % ATLAS_COLS) as f32, (index / ATLAS_COLS) as f32) * tile;
+        Rect::from_corners(min, min + tile)
+    });
+    ImageNode {
+        image: atlas,
+        rect,
+        color: class_tint(card_id),
+        ..default()
+    }
+}
+
 #[derive(Resource, Default)]
 struct MesaScene {
     local_deck: Option<Entity>,
@@ -127,6 +306,12 @@ impl MesaScene {
     }
 }
 
+/// Shows how many cards are left in a player's draw pile.
+#[derive(Component)]
+struct DeckCountLabel {
+    player: usize,
+}
+
 #[derive(Resource, Default)]
 struct MesaDecksRendered {
     local: bool,
@@ -189,6 +374,8 @@ pub fn plugin(app: &mut App) {
     app.init_resource::<MesaDecksRendered>();
     app.init_resource::<MesaHandMap>();
     app.init_resource::<PreviousHandSizes>();
+    app.init_resource::<CardPool>();
+    app.load_resource_in::<CardTextures>(AssetGroup::Gameplay);
     app.clear_messages_on_exit::<CardPress>(Screen::Gameplay)
         .clear_messages_on_exit::<RenderDeck<MesaCard>>(Screen::Gameplay)
         .clear_messages_on_exit::<DiscardCardToDeck>(Screen::Gameplay)
@@ -198,23 +385,42 @@ pub fn plugin(app: &mut App) {
         OnEnter(Screen::Gameplay),
         (reset_mesa_state, spawn_mesa_scene).chain(),
     );
-    app.add_systems(OnExit(Screen::Gameplay), reset_mesa_state);
+    app.add_systems(
+        OnExit(Screen::Gameplay),
+        (reset_mesa_state, release_match_assets),
+    );
 
     app.add_systems(
         Update,
         (
             render_initial_decks,
             handle_deck_reshuffle,
+            update_deck_counts,
             sync_hand_to_mesa,
             sync_played_cards,
+            mark_exhausted_played_cards,
+            sync_removed_hand_cards,
+            burn_exhausted_cards,
+            park_cards,
             add_effect_text_to_cards,
             track_hand_hover,
             update_card_glow_on_hover,
+            update_card_affordability,
+            fall_back_to_atlas.run_if(resource_changed::<CardArt>),
+            sync_face_materials,
         )
             .chain()
             .in_set(AppSystems::Update)
             .run_if(in_state(Screen::Gameplay)),
     );
+    // Cards must be ready before la_mesa animates them onto the deck.
+    app.add_systems(
+        Update,
+        (sync_added_discard_cards, finish_played_cards)
+            .before(handle_discard_card_to_deck::<MesaCard>)
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
     app.add_systems(
         PostUpdate,
         fan_hand_layout
@@ -242,11 +448,43 @@ fn reset_mesa_state(
     mut rendered: ResMut<MesaDecksRendered>,
     mut hand_map: ResMut<MesaHandMap>,
     mut prev_sizes: ResMut<PreviousHandSizes>,
+    mut pool: ResMut<CardPool>,
 ) {
     *scene = MesaScene::default();
     *rendered = MesaDecksRendered::default();
     *hand_map = MesaHandMap::default();
     *prev_sizes = PreviousHandSizes::default();
+    // Pooled cards despawn with the rest of the match.
+    *pool = CardPool::default();
+}
+
+/// Drop match-scoped handles so the shared card meshes and materials are freed
+/// as soon as a match ends instead of lingering until the next one.
+fn release_match_assets(mut commands: Commands) {
+    commands.remove_resource::<MesaAssets>();
+    commands.remove_resource::<CardTextMaterial>();
+    commands.remove_resource::<CardFrames>();
+}
+
+/// Shared card textures, loaded with the gameplay asset group so cards don't
+/// appear blank at the start of a match. Dropped with the group; materials
+/// still in use keep their textures until the match is despawned.
+#[derive(Resource, Asset, Clone, TypePath)]
+struct CardTextures {
+    #[dependency]
+    atlas: Handle<Image>,
+    #[dependency]
+    back: Handle<Image>,
+}
+
+impl FromWorld for CardTextures {
+    fn from_world(world: &mut World) -> Self {
+        let assets = world.resource::<AssetServer>();
+        Self {
+            atlas: assets.load(CARD_ATLAS_IMAGE),
+            back: assets.load(CARD_BACK_IMAGE),
+        }
+    }
 }
 
 fn rotate_around_origin_y(transform: Transform) -> Transform {
@@ -263,22 +501,47 @@ fn spawn_mesa_scene(
     mut scene: ResMut<MesaScene>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    asset_server: Res<AssetServer>,
 ) {
-    info!("spawn_mesa_scene called!");
-
-    // Pre-allocate glow assets (reused for all card hover effects).
     let glow_mesh = meshes.add(Plane3d::default().mesh().size(2.7, 3.7).subdivisions(2));
-    let glow_material = materials.add(StandardMaterial {
+    let hover_material = materials.add(StandardMaterial {
         base_color: Color::srgba(1.0, 0.9, 0.3, 0.3),
         emissive: LinearRgba::new(1.0, 0.8, 0.2, 1.0) * 2.0,
         alpha_mode: AlphaMode::Blend,
         unlit: true,
         ..default()
     });
-    commands.insert_resource(GlowAssets {
-        mesh: glow_mesh,
-        material: glow_material,
+    let playable_material = materials.add(StandardMaterial {
+        base_color: GlowColors::PLAYABLE.into(),
+        emissive: GlowColors::PLAYABLE * 1.5,
+        unlit: true,
+        ..default()
+    });
+    let queued_material = materials.add(StandardMaterial {
+        base_color: GlowColors::SELECTED.into(),
+        emissive: GlowColors::SELECTED * 1.5,
+        unlit: true,
+        ..default()
+    });
+    commands.insert_resource(MesaAssets {
+        card_mesh: meshes.add(
+            Plane3d::default()
+                .mesh()
+                .size(CARD_SIZE.x, CARD_SIZE.y)
+                .subdivisions(2),
+        ),
+        // Every card shares the same back.
+        back_material: materials.add(StandardMaterial {
+            base_color_texture: Some(asset_server.load(CARD_BACK_IMAGE)),
+            ..default()
+        }),
+        glow_mesh,
+        hover_material,
+        playable_material,
+        queued_material,
+        faces: HashMap::default(),
     });
+    commands.insert_resource(CardFrames::new(&mut meshes));
 
     commands.insert_resource(GlobalAmbientLight {
         color: Color::WHITE,
@@ -316,7 +579,7 @@ fn spawn_mesa_scene(
         unlit: true,
         ..default()
     });
-    commands.insert_resource(CardTextMaterial(text_material));
+    commands.insert_resource(CardTextMaterial(text_material.clone()));
 
     let local_deck_transform = Transform::from_translation(Vec3::new(-6.0, 0.01, 2.5));
     let opponent_deck_transform = rotate_around_origin_y(local_deck_transform);
@@ -398,6 +661,34 @@ fn spawn_mesa_scene(
         DespawnOnExit(Screen::Gameplay),
     ));
 
+    for (player, deck_transform) in [
+        (LOCAL_PLAYER_INDEX, local_deck_transform),
+        (OPPONENT_PLAYER_INDEX, opponent_deck_transform),
+    ] {
+        // Just past the edge of each deck facing its owner.
+        let offset = deck_transform.rotation * Vec3::new(0.0, 0.01, DECK_COUNT_OFFSET);
+        commands.spawn((
+            Name::new("Deck Count"),
+            DeckCountLabel { player },
+            Text3d::new(""),
+            Text3dStyling {
+                size: 18.0,
+                color: Srgba::WHITE,
+                stroke: NonZeroU32::new(2),
+                stroke_color: Srgba::BLACK,
+                align: TextAlign::Center,
+                anchor: TextAnchor::CENTER,
+                world_scale: Some(Vec2::splat(0.3)),
+                ..default()
+            },
+            Mesh3d::default(),
+            MeshMaterial3d(text_material.clone()),
+            Transform::from_translation(deck_transform.translation + offset)
+                .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+            DespawnOnExit(Screen::Gameplay),
+        ));
+    }
+
     scene.local_deck = Some(local_deck);
     scene.opponent_deck = Some(opponent_deck);
     scene.local_play_marker = Some(LOCAL_PLAYER_INDEX);
@@ -408,31 +699,35 @@ fn render_initial_decks(
     scene: Res<MesaScene>,
     mut rendered: ResMut<MesaDecksRendered>,
     registry: Res<CardRegistry>,
+    mut card_art: ResMut<CardArt>,
+    asset_server: Res<AssetServer>,
     local_query: Query<&Deck, With<LocalPlayer>>,
     opponent_query: Query<&Deck, With<Opponent>>,
-    deck_cards: Query<(Entity, &MesaDeck)>,
+    deck_cards: Query<(Entity, &MesaDeck, Has<CardFace>)>,
     children_query: Query<&Children>,
     mut render_deck: MessageWriter<RenderDeck<MesaCard>>,
     mut commands: Commands,
 ) {
     if !rendered.local {
         let Some(deck_entity) = scene.local_deck else {
-            info!("render_initial_decks: no local deck entity");
+            hot_log!("render_initial_decks: no local deck entity");
             return;
         };
         let Ok(deck) = local_query.single() else {
-            info!("render_initial_decks: no local player deck component");
+            hot_log!("render_initial_decks: no local player deck component");
             return;
         };
-        info!(
+        debug!(
             "render_initial_decks: rendering local deck with {} cards",
             deck.cards.len()
         );
-        rebuild_deck_visual(
+        refresh_deck_visual(
             deck_entity,
             LOCAL_PLAYER_INDEX,
             &deck.cards,
             &registry,
+            &mut card_art,
+            &asset_server,
             &deck_cards,
             &children_query,
             &mut commands,
@@ -448,11 +743,13 @@ fn render_initial_decks(
         let Ok(deck) = opponent_query.single() else {
             return;
         };
-        rebuild_deck_visual(
+        refresh_deck_visual(
             deck_entity,
             OPPONENT_PLAYER_INDEX,
             &deck.cards,
             &registry,
+            &mut card_art,
+            &asset_server,
             &deck_cards,
             &children_query,
             &mut commands,
@@ -466,7 +763,9 @@ fn handle_deck_reshuffle(
     mut reshuffle: MessageReader<DeckReshuffledMessage>,
     scene: Res<MesaScene>,
     registry: Res<CardRegistry>,
-    deck_cards: Query<(Entity, &MesaDeck)>,
+    mut card_art: ResMut<CardArt>,
+    asset_server: Res<AssetServer>,
+    deck_cards: Query<(Entity, &MesaDeck, Has<CardFace>)>,
     children_query: Query<&Children>,
     local_query: Query<Entity, With<LocalPlayer>>,
     opponent_query: Query<Entity, With<Opponent>>,
@@ -483,11 +782,13 @@ fn handle_deck_reshuffle(
             continue;
         };
 
-        rebuild_deck_visual(
+        refresh_deck_visual(
             deck_entity,
             player_index,
             &message.deck,
             &registry,
+            &mut card_art,
+            &asset_server,
             &deck_cards,
             &children_query,
             &mut commands,
@@ -496,6 +797,29 @@ fn handle_deck_reshuffle(
     }
 }
 
+fn update_deck_counts(
+    decks: Query<
+        (&Deck, Has<LocalPlayer>),
+        (Or<(With<LocalPlayer>, With<Opponent>)>, Changed<Deck>),
+    >,
+    mut labels: Query<(&DeckCountLabel, &mut Text3d)>,
+) {
+    for (deck, local) in &decks {
+        let player = if local {
+            LOCAL_PLAYER_INDEX
+        } else {
+            OPPONENT_PLAYER_INDEX
+        };
+        let count = deck.cards.len().to_string();
+        for (label, mut text) in &mut labels {
+            // Rollback restores the deck every frame; only relayout real changes.
+            if label.player == player && text.get_single() != Some(count.as_str()) {
+                *text = Text3d::new(count.clone());
+            }
+        }
+    }
+}
+
 /// Sync the game logic Hand component to 3D mesa rendering.
 /// This watches for changes in Hand.cards.len() and triggers draws accordingly.
 fn sync_hand_to_mesa(
@@ -503,11 +827,15 @@ fn sync_hand_to_mesa(
     opponent_player: Query<&Hand, With<Opponent>>,
     hand_areas: Query<(&HandArea, &Transform)>,
     registry: Res<CardRegistry>,
+    mut card_art: ResMut<CardArt>,
     mut hand_map: ResMut<MesaHandMap>,
     mut prev_sizes: ResMut<PreviousHandSizes>,
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
+    mut pool: ResMut<CardPool>,
+    mut mesa_assets: ResMut<MesaAssets>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+    mut frames: ResMut<CardFrames>,
     asset_server: Res<AssetServer>,
 ) {
     let players: [(usize, Option<&Hand>); 2] = [
@@ -527,28 +855,37 @@ fn sync_hand_to_mesa(
             let hand_transform = hand_areas
                 .iter()
                 .find(|(area, _)| area.player == player_index)
-                .map(|(_, transform)| transform.clone())
+                .map(|(_, transform)| *transform)
                 .unwrap_or_default();
 
             if let Some(mesa_hand) = hand_map.hand_mut(player_index) {
                 for card_id in new_cards.iter().take(cards_to_draw) {
-                    let mesa_card = mesa_card_from_id(*card_id, &registry);
+                    let mesa_card =
+                        mesa_card_from_id(*card_id, &registry, &mut card_art, &asset_server);
                     let card_entity = spawn_hand_card(
                         &mut commands,
                         &mesa_card,
                         player_index,
                         hand_transform,
-                        &mut meshes,
+                        player_index == OPPONENT_PLAYER_INDEX,
+                        &registry,
+                        &mut pool,
+                        &mut mesa_assets,
+                        &mut frames,
                         &mut materials,
+                        &mut images,
                         &asset_server,
                     );
                     mesa_hand.push(card_entity);
                 }
             }
 
-            info!(
+            hot_log!(
                 "sync_hand_to_mesa: player {} drew {} cards (hand: {} -> {})",
-                player_index, cards_to_draw, previous_size, current_size
+                player_index,
+                cards_to_draw,
+                previous_size,
+                current_size
             );
         }
 
@@ -563,9 +900,11 @@ fn sync_played_cards(
     opponent_query: Query<Entity, With<Opponent>>,
     scene: Res<MesaScene>,
     mut hand_map: ResMut<MesaHandMap>,
-    mut discard_card: MessageWriter<DiscardCardToDeck>,
+    play_areas: Query<(&PlayArea, &Transform)>,
+    cards: Query<(&Transform, &MesaCardComponent<MesaCard>)>,
+    face_down: Query<(&Mesh3d, &CardFace, &CardFrame, Option<&CardFront>), With<FaceDown>>,
+    frames: Res<CardFrames>,
     mut align_hand: MessageWriter<AlignCardsInHand>,
-    children_query: Query<&Children>,
     mut commands: Commands,
 ) {
     for message in play_messages.read() {
@@ -583,14 +922,143 @@ fn sync_played_cards(
         }
 
         let card_entity = hand.remove(message.hand_index);
-        if let Some(deck_entity) = scene.deck_for(player_index) {
+        let (start, card_id) = cards.get(card_entity).map_or(
+            (Transform::default(), CardId::Unknown),
+            |(transform, card)| (*transform, card.data.card_id),
+        );
+        let play_transform = play_areas
+            .iter()
+            .find(|(area, _)| area.player == player_index)
+            .map(|(_, transform)| *transform)
+            .unwrap_or_default();
+        let lifted = play_transform.translation + Vec3::Y * PLAY_LIFT;
+        // The opponent's play area faces them; turn their cards so the local
+        // player can read the name and effect text.
+        let (reveal_rotation, hold_secs) = if player_index == OPPONENT_PLAYER_INDEX {
+            (
+                play_transform.rotation * Quat::from_rotation_y(std::f32::consts::PI),
+                OPPONENT_REVEAL_SECS,
+            )
+        } else {
+            (play_transform.rotation, PLAY_HOLD_SECS)
+        };
+
+        // Hand -> above the play area -> flip face-up -> settle onto the table.
+        let animation = Tween::new(
+            EaseFunction::QuadraticOut,
+            Duration::from_millis(PLAY_MOVE_MILLIS),
+            TransformPositionLens {
+                start: start.translation,
+                end: lifted,
+            },
+        )
+        .then(Tween::new(
+            EaseFunction::QuadraticInOut,
+            Duration::from_millis(PLAY_FLIP_MILLIS),
+            TransformRotationLens {
+                start: start.rotation,
+                end: reveal_rotation,
+            },
+        ))
+        .then(Tween::new(
+            EaseFunction::QuadraticIn,
+            Duration::from_millis(PLAY_SETTLE_MILLIS),
+            TransformPositionLens {
+                start: lifted,
+                end: play_transform.translation,
+            },
+        ));
+        let animation_secs =
+            (PLAY_MOVE_MILLIS + PLAY_FLIP_MILLIS + PLAY_SETTLE_MILLIS) as f32 / 1000.0;
+
+        // Give an opponent's face-down card its face before the flip shows it.
+        if let Ok((mesh, face, frame, front)) = face_down.get(card_entity) {
+            commands.entity(card_entity).remove::<FaceDown>();
+            show_front(
+                &mut commands,
+                card_entity,
+                front.map(|front| front.0),
+                &frames,
+                mesh.0.clone(),
+                frame.0.clone(),
+                face.0.clone(),
+            );
+        }
+
+        commands
+            .entity(card_entity)
+            .remove::<HoveredCard>()
+            .insert((
+                PlayedCard {
+                    card_id,
+                    deck_entity: scene.deck_for(player_index),
+                    remaining: animation_secs + hold_secs,
+                    exhausted: false,
+                },
+                TweenAnim::new(animation),
+            ));
+
+        align_hand.write(AlignCardsInHand {
+            player: player_index,
+        });
+    }
+}
+
+/// Send played cards to the discard pile once they have been shown.
+fn finish_played_cards(
+    time: Res<Time>,
+    mut played: Query<(Entity, &mut PlayedCard)>,
+    mut discard_card: MessageWriter<DiscardCardToDeck>,
+    mut commands: Commands,
+) {
+    for (card_entity, mut card) in &mut played {
+        card.remaining -= time.delta_secs();
+        if card.remaining > 0.0 {
+            continue;
+        }
+
+        commands.entity(card_entity).remove::<PlayedCard>();
+        if card.exhausted {
+            commands.entity(card_entity).insert(Burning::default());
+        } else if let Some(deck_entity) = card.deck_entity {
             discard_card.write(DiscardCardToDeck {
                 card_entity,
                 deck_entity,
             });
         } else {
-            despawn_entity_recursive(card_entity, &children_query, &mut commands);
+            park_card(card_entity, &mut commands);
         }
+    }
+}
+
+/// Burn away hand cards that left the hand without being played (e.g. exhausted).
+fn sync_removed_hand_cards(
+    mut removed_messages: MessageReader<CardRemovedFromHandMessage>,
+    local_query: Query<Entity, With<LocalPlayer>>,
+    opponent_query: Query<Entity, With<Opponent>>,
+    mut hand_map: ResMut<MesaHandMap>,
+    mut align_hand: MessageWriter<AlignCardsInHand>,
+    mut commands: Commands,
+) {
+    for message in removed_messages.read() {
+        let Some(player_index) =
+            player_index_for_entity(message.player, &local_query, &opponent_query)
+        else {
+            continue;
+        };
+
+        let Some(hand) = hand_map.hand_mut(player_index) else {
+            continue;
+        };
+        if message.hand_index >= hand.len() {
+            continue;
+        }
+
+        let card_entity = hand.remove(message.hand_index);
+        commands
+            .entity(card_entity)
+            .remove::<(HoveredCard, TweenAnim)>()
+            .insert(Burning::default());
 
         align_hand.write(AlignCardsInHand {
             player: player_index,
@@ -598,25 +1066,139 @@ fn sync_played_cards(
     }
 }
 
+/// Flag played cards that were exhausted so they burn instead of discarding.
+fn mark_exhausted_played_cards(
+    mut exhausted: MessageReader<CardExhaustedMessage>,
+    local_query: Query<Entity, With<LocalPlayer>>,
+    opponent_query: Query<Entity, With<Opponent>>,
+    mut played: Query<(&MesaHand, &mut PlayedCard)>,
+) {
+    for message in exhausted.read() {
+        let Some(player_index) =
+            player_index_for_entity(message.player, &local_query, &opponent_query)
+        else {
+            continue;
+        };
+        // Cards exhausted straight from the hand have no played copy; they
+        // burn through `sync_removed_hand_cards`.
+        if let Some((_, mut card)) = played.iter_mut().find(|(hand, card)| {
+            hand.player == player_index && card.card_id == message.card_id && !card.exhausted
+        }) {
+            card.exhausted = true;
+        }
+    }
+}
+
+fn burn_exhausted_cards(
+    time: Res<Time>,
+    mut cards: Query<(Entity, &mut Burning, &mut Transform, Option<&mut CardFace>)>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    for (entity, mut burning, mut transform, mut face) in &mut cards {
+        // Fronts are shared; burn a copy so other copies of the card stay put.
+        let copy = face
+            .as_ref()
+            .filter(|_| burning.elapsed == 0.0)
+            .and_then(|face| materials.get(&face.0).cloned());
+        if let (Some(face), Some(copy)) = (face.as_mut(), copy) {
+            face.0 = materials.add(copy);
+        }
+        burning.elapsed += time.delta_secs();
+        let progress = (burning.elapsed / BURN_SECS).min(1.0);
+        if progress >= 1.0 {
+            park_card(entity, &mut commands);
+            continue;
+        }
+
+        // Flare up, then shrink away while drifting upward.
+        if let Some(material) = face.and_then(|face| materials.get_mut(&face.0)) {
+            material.emissive = BURN_COLOR * (4.0 * (progress * std::f32::consts::PI).sin());
+        }
+        transform.scale = Vec3::splat((1.0 - progress).max(0.01));
+        transform.translation.y += time.delta_secs() * 0.5;
+    }
+}
+
+/// Show cards added straight to the discard pile (status cards, copies) landing on the deck.
+fn sync_added_discard_cards(
+    mut discard_messages: MessageReader<AddCardToDiscardMessage>,
+    local_query: Query<Entity, With<LocalPlayer>>,
+    opponent_query: Query<Entity, With<Opponent>>,
+    play_areas: Query<(&PlayArea, &Transform)>,
+    scene: Res<MesaScene>,
+    registry: Res<CardRegistry>,
+    mut card_art: ResMut<CardArt>,
+    mut discard_card: MessageWriter<DiscardCardToDeck>,
+    mut commands: Commands,
+    mut pool: ResMut<CardPool>,
+    mut mesa_assets: ResMut<MesaAssets>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+    mut frames: ResMut<CardFrames>,
+    asset_server: Res<AssetServer>,
+) {
+    for message in discard_messages.read() {
+        let Some(player_index) =
+            player_index_for_entity(message.player, &local_query, &opponent_query)
+        else {
+            continue;
+        };
+        let Some(deck_entity) = scene.deck_for(player_index) else {
+            continue;
+        };
+
+        let play_transform = play_areas
+            .iter()
+            .find(|(area, _)| area.player == player_index)
+            .map(|(_, transform)| *transform)
+            .unwrap_or_default();
+
+        let mesa_card = mesa_card_from_id(message.card_id, &registry, &mut card_art, &asset_server);
+        let card_entity = spawn_hand_card(
+            &mut commands,
+            &mesa_card,
+            player_index,
+            play_transform,
+            false,
+            &registry,
+            &mut pool,
+            &mut mesa_assets,
+            &mut frames,
+            &mut materials,
+            &mut images,
+            &asset_server,
+        );
+
+        discard_card.write(DiscardCardToDeck {
+            card_entity,
+            deck_entity,
+        });
+    }
+}
+
 fn handle_card_press_input(
     mut card_press: MessageReader<CardPress>,
     cards_in_hand: Query<(Entity, &MesaHand, &MesaCardComponent<MesaCard>, &Transform)>,
     parents: Query<&ChildOf>,
     local_hand_query: Query<&Hand, With<LocalPlayer>>,
     hand_map: Res<MesaHandMap>,
+    tints: Query<&AffordTint>,
+    input_settings: Res<InputSettings>,
     mut pending: ResMut<PendingInput>,
+    mut rejected: MessageWriter<CardRejected>,
 ) {
     for press in card_press.read() {
-        info!("CardPress received for entity {:?}", press.entity);
+        hot_log!("CardPress received for entity {:?}", press.entity);
 
         // Check if the pressed entity is in the local player's hand
         let Some(card_entity) = resolve_pressed_card_entity(press.entity, &cards_in_hand, &parents)
         else {
-            info!("  -> No matching Mesa card entity found for press target");
+            hot_log!("  -> No matching Mesa card entity found for press target");
             continue;
         };
         let Ok((_, hand, _card, _transform)) = cards_in_hand.get(card_entity) else {
-            info!("  -> Resolved entity missing MesaHand component");
+            hot_log!("  -> Resolved entity missing MesaHand component");
             continue;
         };
 
@@ -627,7 +1209,7 @@ fn handle_card_press_input(
 
         // Get the game logic hand to verify index is valid
         let Ok(game_hand) = local_hand_query.single() else {
-            info!("  -> No game logic Hand found for local player");
+            hot_log!("  -> No game logic Hand found for local player");
             continue;
         };
 
@@ -642,7 +1224,7 @@ fn handle_card_press_input(
 
         // Verify index is within game logic hand bounds
         if index >= game_hand.cards.len() {
-            info!(
+            hot_log!(
                 "  -> Card index {} is out of bounds (game hand has {} cards)",
                 index,
                 game_hand.cards.len()
@@ -650,10 +1232,18 @@ fn handle_card_press_input(
             continue;
         }
 
+        // The simulation drops it; let the player hear that it didn't go through.
+        let unaffordable = matches!(tints.get(card_entity), Ok(AffordTint::Unaffordable));
+        if unaffordable && !input_settings.queue_until_affordable {
+            rejected.write(CardRejected);
+        }
+
         if let Some(flag) = card_flag(index) {
-            info!(
+            hot_log!(
                 "  -> Card at index {} pressed, pushing flag {} (game hand: {:?})",
-                index, flag, game_hand.cards
+                index,
+                flag,
+                game_hand.cards
             );
             pending.push_flags(flag);
         }
@@ -684,12 +1274,17 @@ fn track_hand_hover(
     mut out: MessageReader<CardOut>,
     cards_in_hand: Query<(Entity, &MesaHand, &MesaCardComponent<MesaCard>, &Transform)>,
     parents: Query<&ChildOf>,
+    played: Query<(), Or<(With<PlayedCard>, With<Burning>)>>,
 ) {
     for event in hover.read() {
         let Some(card_entity) = resolve_pressed_card_entity(event.entity, &cards_in_hand, &parents)
         else {
             continue;
         };
+        // Hover would cancel the play animation.
+        if played.contains(card_entity) {
+            continue;
+        }
         let Ok((_, hand, _, _)) = cards_in_hand.get(card_entity) else {
             continue;
         };
@@ -708,6 +1303,9 @@ fn track_hand_hover(
         else {
             continue;
         };
+        if played.contains(card_entity) {
+            continue;
+        }
         commands
             .entity(card_entity)
             .remove::<HoveredCard>()
@@ -716,31 +1314,52 @@ fn track_hand_hover(
 }
 
 /// Max cards to actually render in a deck pile. Only the top few are visible.
+/// Most face-down cards stacked on a deck; the count label covers the rest.
 const DECK_VISUAL_LIMIT: usize = 3;
 
-fn rebuild_deck_visual(
+/// Clear discarded cards off a deck and top up its face-down stack.
+///
+/// Stack cards never show their faces, so which cards they stand for doesn't
+/// matter: a reshuffle only re-renders them when there are too few.
+fn refresh_deck_visual(
     deck_entity: Entity,
     marker: usize,
     deck_cards: &[CardId],
     registry: &CardRegistry,
-    existing_cards: &Query<(Entity, &MesaDeck)>,
+    card_art: &mut CardArt,
+    asset_server: &AssetServer,
+    existing_cards: &Query<(Entity, &MesaDeck, Has<CardFace>)>,
     children_query: &Query<&Children>,
     commands: &mut Commands,
     render_deck: &mut MessageWriter<RenderDeck<MesaCard>>,
 ) {
-    for (entity, deck) in existing_cards.iter() {
-        if deck.marker == marker {
-            despawn_entity_recursive(entity, children_query, commands);
+    let mut stacked = Vec::new();
+    for (entity, deck, ours) in existing_cards.iter() {
+        if deck.marker != marker {
+            continue;
+        }
+        // Discarded cards on the pile go back to the pool.
+        if ours {
+            park_card(entity, commands);
+        } else {
+            stacked.push(entity);
         }
     }
 
-    // Only render the top few cards visually — the rest are data-only.
+    let wanted = deck_cards.len().min(DECK_VISUAL_LIMIT);
+    if stacked.len() >= wanted {
+        return;
+    }
+    // Cards la_mesa rendered itself have nothing worth keeping.
+    for entity in stacked {
+        despawn_entity_recursive(entity, children_query, commands);
+    }
     let visual_cards: Vec<MesaCard> = deck_cards
         .iter()
         .rev()
         .take(DECK_VISUAL_LIMIT)
         .rev()
-        .map(|card_id| mesa_card_from_id(*card_id, registry))
+        .map(|card_id| mesa_card_from_id(*card_id, registry, card_art, asset_server))
         .collect();
 
     render_deck.write(RenderDeck::<MesaCard> {
@@ -762,6 +1381,53 @@ fn despawn_entity_recursive(
     commands.entity(entity).despawn();
 }
 
+/// Take one of our cards off the table; [`park_cards`] keeps it for reuse.
+fn park_card(entity: Entity, commands: &mut Commands) {
+    commands
+        .entity(entity)
+        .remove::<(
+            MesaCardComponent<MesaCard>,
+            MesaHand,
+            MesaDeck,
+            PlayedCard,
+            Burning,
+            HoveredCard,
+            FaceDown,
+            TweenAnim,
+        )>()
+        .insert((ParkCard, Visibility::Hidden));
+}
+
+/// Strip parked cards of their overlays and add them to the pool, or despawn
+/// them once the pool is full.
+fn park_cards(
+    mut commands: Commands,
+    mut pool: ResMut<CardPool>,
+    mut parked: Query<(Entity, &mut AffordTint, Option<&CardFront>), Added<ParkCard>>,
+    children_query: Query<&Children>,
+    glow_overlays: Query<(), With<CardGlowOverlay>>,
+) {
+    for (entity, mut tint, front) in &mut parked {
+        if pool.0.len() >= CARD_POOL_LIMIT {
+            despawn_entity_recursive(entity, &children_query, &mut commands);
+            continue;
+        }
+        if let AffordTint::Playable(overlay) | AffordTint::Queued(overlay) = *tint {
+            commands.entity(overlay).despawn();
+        }
+        *tint = AffordTint::None;
+        if let Ok(children) = children_query.get(entity) {
+            for child in children.iter() {
+                if glow_overlays.contains(child) {
+                    commands.entity(child).despawn();
+                }
+            }
+        }
+        commands.entity(entity).remove::<HasGlowOverlay>();
+        pool.0.push((entity, front.map(|front| front.0)));
+    }
+}
+
 /// Map a CardId to an atlas index (0..11).
 /// Atlas layout: 2 rows x 6 columns, left-to-right, top-to-bottom.
 fn atlas_index_for_card(card_id: CardId) -> usize {
@@ -783,10 +1449,18 @@ fn atlas_index_for_card(card_id: CardId) -> usize {
     }
 }
 
-fn mesa_card_from_id(card_id: CardId, _registry: &CardRegistry) -> MesaCard {
+fn mesa_card_from_id(
+    card_id: CardId,
+    registry: &CardRegistry,
+    art: &mut CardArt,
+    asset_server: &AssetServer,
+) -> MesaCard {
     MesaCard {
         card_id,
         atlas_index: atlas_index_for_card(card_id),
+        art: registry
+            .get(card_id)
+            .and_then(|def| art.get(def, asset_server)),
         back: CARD_BACK_IMAGE.to_string(),
     }
 }
@@ -796,57 +1470,187 @@ fn spawn_hand_card(
     card: &MesaCard,
     player_index: usize,
     hand_transform: Transform,
-    meshes: &mut Assets<Mesh>,
+    face_down: bool,
+    registry: &CardRegistry,
+    pool: &mut CardPool,
+    mesa_assets: &mut MesaAssets,
+    frames: &mut CardFrames,
     materials: &mut Assets<StandardMaterial>,
+    images: &mut Assets<Image>,
     asset_server: &AssetServer,
 ) -> Entity {
-    let back_texture = asset_server.load(card.back.clone());
-    let back_material = materials.add(StandardMaterial {
-        base_color_texture: Some(back_texture),
-        ..default()
-    });
-
-    let face_texture: Handle<Image> = asset_server.load(CARD_ATLAS_IMAGE);
-    let face_material: Handle<StandardMaterial> = materials.add(StandardMaterial {
-        base_color_texture: Some(face_texture),
-        uv_transform: atlas_uv_transform(card.atlas_index),
-        ..default()
-    });
-
-    let card_mesh = meshes.add(Plane3d::default().mesh().size(2.5, 3.5).subdivisions(2));
+    let face_material = mesa_assets.face(card, false, asset_server, materials);
+    let frame_material = frames.material(registry.get(card.card_id), images, materials);
+    let card_mesh = mesa_assets.card_mesh.clone();
     let transform = Transform::from_translation(hand_transform.translation)
         .with_rotation(hand_transform.rotation);
+    let card_bundle = (
+        MesaCardComponent {
+            pickable: true,
+            transform: None,
+            data: card.clone(),
+        },
+        MesaHand {
+            player: player_index,
+        },
+        transform,
+        CardFace(face_material.clone()),
+        CardFrame(frame_material.clone()),
+        AffordTint::default(),
+    );
 
-    commands
-        .spawn((
-            Name::new("Card"),
-            MesaCardComponent {
-                pickable: true,
-                transform: None,
-                data: card.clone(),
-            },
-            MesaHand {
-                player: player_index,
-            },
-            Pickable::default(),
-            Mesh3d(card_mesh.clone()),
-            transform,
-        ))
-        .observe(on_hand_card_over)
-        .observe(on_hand_card_out)
-        .observe(on_hand_card_click)
-        .with_children(|parent| {
-            parent.spawn((Mesh3d(card_mesh.clone()), MeshMaterial3d(face_material)));
-            parent.spawn((
-                Mesh3d(card_mesh),
-                MeshMaterial3d(back_material),
-                Transform::IDENTITY.with_rotation(Quat::from_rotation_z(std::f32::consts::PI)),
-            ));
-        })
-        .id()
+    // A recycled card keeps its observers, back and front; its materials and
+    // text follow the new data through `sync_face_materials` and
+    // `add_effect_text_to_cards`.
+    let (entity, front) = match pool.0.pop() {
+        Some((entity, front)) => {
+            commands
+                .entity(entity)
+                .remove::<ParkCard>()
+                .insert((card_bundle, Visibility::Inherited));
+            (entity, front)
+        }
+        None => {
+            let entity = commands
+                .spawn((
+                    Name::new("Card"),
+                    card_bundle,
+                    Pickable::default(),
+                    Mesh3d(card_mesh.clone()),
+                ))
+                .observe(on_hand_card_over)
+                .observe(on_hand_card_out)
+                .observe(on_hand_card_click)
+                .with_child((
+                    Mesh3d(card_mesh.clone()),
+                    MeshMaterial3d(mesa_assets.back_material.clone()),
+                    Transform::IDENTITY.with_rotation(Quat::from_rotation_z(std::f32::consts::PI)),
+                ))
+                .id();
+            (entity, None)
+        }
+    };
+    if face_down {
+        commands.entity(entity).insert(FaceDown);
+        if let Some(front) = front {
+            commands.entity(front).insert(Visibility::Hidden);
+        }
+    } else {
+        show_front(
+            commands,
+            entity,
+            front,
+            frames,
+            card_mesh,
+            frame_material,
+            face_material,
+        );
+    }
+    entity
+}
+
+/// Show a card's front, building it the first time the card is face-up.
+fn show_front(
+    commands: &mut Commands,
+    card_entity: Entity,
+    front: Option<Entity>,
+    frames: &CardFrames,
+    card_mesh: Handle<Mesh>,
+    frame: Handle<StandardMaterial>,
+    face: Handle<StandardMaterial>,
+) {
+    match front {
+        Some(front) => {
+            commands.entity(front).insert(Visibility::Inherited);
+        }
+        None => {
+            let front = commands.spawn(frames.front(card_mesh, frame, face)).id();
+            commands
+                .entity(card_entity)
+                .add_child(front)
+                .insert(CardFront(front));
+        }
+    }
+}
+
+/// Point a front material at the card's own art, or at its atlas tile.
+fn set_face_texture(material: &mut StandardMaterial, card: &MesaCard, asset_server: &AssetServer) {
+    material.base_color = face_tint(card);
+    match &card.art {
+        Some(art) => {
+            material.base_color_texture = Some(art.clone());
+            material.uv_transform = Affine2::IDENTITY;
+        }
+        None => {
+            material.base_color_texture = Some(asset_server.load(CARD_ATLAS_IMAGE));
+            material.uv_transform = atlas_uv_transform(card.atlas_index);
+        }
+    }
+}
+
+/// Untinted for real art; atlas tiles are shared, so the class tint tells
+/// them apart.
+fn face_tint(card: &MesaCard) -> Color {
+    if card.art.is_some() {
+        Color::WHITE
+    } else {
+        class_tint(card.card_id)
+    }
+}
+
+/// Cards spawned before their art failed to load go back to the atlas.
+fn fall_back_to_atlas(
+    art: Res<CardArt>,
+    asset_server: Res<AssetServer>,
+    mut mesa_assets: ResMut<MesaAssets>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut cards: Query<(&mut MesaCardComponent<MesaCard>, &mut CardFace, &AffordTint)>,
+) {
+    for (mut card, mut face, tint) in &mut cards {
+        if card.data.art.is_none() || !art.is_missing(card.data.card_id) {
+            continue;
+        }
+        card.data.art = None;
+        let unaffordable = matches!(tint, AffordTint::Unaffordable);
+        face.0 = mesa_assets.face(&card.data, unaffordable, &asset_server, &mut materials);
+    }
+}
+
+/// Point each card's front at its current frame, and its art quad at its
+/// current front material.
+fn sync_face_materials(
+    cards: Query<
+        (&CardFace, &CardFrame, &CardFront),
+        Or<(Changed<CardFace>, Changed<CardFrame>, Changed<CardFront>)>,
+    >,
+    children_query: Query<&Children>,
+    mut quads: Query<(&mut MeshMaterial3d<StandardMaterial>, Has<CardArtQuad>)>,
+) {
+    for (face, frame, front) in &cards {
+        if let Ok((mut material, _)) = quads.get_mut(front.0) {
+            material.0 = frame.0.clone();
+        }
+        for descendant in children_query.iter_descendants(front.0) {
+            if let Ok((mut material, true)) = quads.get_mut(descendant) {
+                material.0 = face.0.clone();
+            }
+        }
+    }
+}
+
+/// Per-class tint multiplied over the card front.
+fn class_tint(card_id: CardId) -> Color {
+    match card_id.class() {
+        Some(CharacterClass::Silent) => Color::srgb(0.75, 1.0, 0.8),
+        Some(CharacterClass::Ironclad) | None => Color::WHITE,
+    }
 }
 
 fn on_hand_card_click(click: On<Pointer<Click>>, mut ew_card: MessageWriter<CardPress>) {
+    // Other buttons inspect the card instead (see `inspect.rs`).
+    if click.button != PointerButton::Primary {
+        return;
+    }
     ew_card.write(CardPress {
         entity: click.event().entity,
     });
@@ -878,139 +1682,15 @@ fn player_index_for_entity(
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum EffectKind {
-    Damage,
-    Heal,
-    Draw,
-    Block,
-    Thorns,
-    Accelerate,
-}
-
-fn unified_effect_kind(effect: &CardEffect) -> Option<EffectKind> {
-    match effect {
-        CardEffect::Damage(_) | CardEffect::MultiHit { .. } | CardEffect::BodySlam => {
-            Some(EffectKind::Damage)
-        }
-        CardEffect::Heal(_) => Some(EffectKind::Heal),
-        CardEffect::Draw(_) => Some(EffectKind::Draw),
-        CardEffect::Block(_) | CardEffect::DoubleBlock => Some(EffectKind::Block),
-        CardEffect::Thorns(_) => Some(EffectKind::Thorns),
-        CardEffect::Accelerate { .. } => Some(EffectKind::Accelerate),
-        CardEffect::DarkEmbrace { .. } | CardEffect::Evolve { .. } => Some(EffectKind::Draw),
-        CardEffect::FeelNoPain { .. } => Some(EffectKind::Block),
-        CardEffect::FireBreathing { .. } => Some(EffectKind::Damage),
-        CardEffect::Strength(_)
-        | CardEffect::DoubleStrength
-        | CardEffect::DemonForm(_)
-        | CardEffect::Vulnerable(_)
-        | CardEffect::SelfVulnerable(_)
-        | CardEffect::Weak(_)
-        | CardEffect::Bloodletting(_)
-        | CardEffect::Rage(_)
-        | CardEffect::Metallicize(_)
-        | CardEffect::Combust { .. }
-        | CardEffect::Barricade
-        | CardEffect::Juggernaut(_)
-        | CardEffect::Rupture { .. }
-        | CardEffect::Corruption
-        | CardEffect::Brutality { .. }
-        | CardEffect::Exhaust
-        | CardEffect::AddStatus(_) => None,
-        CardEffect::Combo(effects) => {
-            let mut kind = None;
-            for effect in effects {
-                let next = unified_effect_kind(effect);
-                if next.is_none() {
-                    return None;
-                }
-                let next = next.unwrap();
-                if let Some(existing) = kind {
-                    if existing != next {
-                        return None;
-                    }
-                } else {
-                    kind = Some(next);
-                }
-            }
-            kind
-        }
-    }
-}
-
-fn effect_lines(effect: &CardEffect, lines: &mut Vec<String>) {
-    match effect {
-        CardEffect::Damage(amount) => lines.push(format!("DMG {:.0}", amount)),
-        CardEffect::MultiHit { damage, hits } => lines.push(format!("DMG {:.0}x{}", damage, hits)),
-        CardEffect::Heal(amount) => lines.push(format!("HEAL {:.0}", amount)),
-        CardEffect::Draw(count) => lines.push(format!("DRAW {}", count)),
-        CardEffect::Block(amount) => lines.push(format!("BLOCK {:.0}", amount)),
-        CardEffect::Thorns(amount) => lines.push(format!("THORNS {:.0}", amount)),
-        CardEffect::Strength(amount) => lines.push(format!("STR +{:.0}", amount)),
-        CardEffect::Vulnerable(duration) => lines.push(format!("VULN {:.0}s", duration)),
-        CardEffect::SelfVulnerable(duration) => {
-            lines.push(format!("SELF VULN {:.0}s", duration));
-        }
-        CardEffect::Weak(duration) => lines.push(format!("WEAK {:.0}s", duration)),
-        CardEffect::Accelerate {
-            bonus_rate,
-            duration,
-        } => lines.push(format!("ACCEL +{:.1}/s {:.0}s", bonus_rate, duration)),
-        CardEffect::BodySlam => lines.push("BODY SLAM".to_string()),
-        CardEffect::Bloodletting(amount) => {
-            if *amount < 0.0 {
-                lines.push(format!("LOSE {:.0} HP", -amount));
-            } else {
-                lines.push(format!("GAIN {:.0} HP", amount));
-            }
-        }
-        CardEffect::DoubleBlock => lines.push("x2 BLOCK".to_string()),
-        CardEffect::DoubleStrength => lines.push("x2 STR".to_string()),
-        CardEffect::Rage(block) => lines.push(format!("RAGE {:.0}", block)),
-        CardEffect::Metallicize(block) => lines.push(format!("METAL {:.0}/s", block)),
-        CardEffect::Combust {
-            self_damage_per_sec,
-            enemy_damage_per_sec,
-        } => lines.push(format!(
-            "COMBUST -{:.0}/+{:.0}/s",
-            self_damage_per_sec, enemy_damage_per_sec
-        )),
-        CardEffect::DemonForm(str_per_sec) => lines.push(format!("DEMON +{:.0}STR/s", str_per_sec)),
-        CardEffect::Barricade => lines.push("BARRICADE".to_string()),
-        CardEffect::Juggernaut(dmg) => lines.push(format!("JUGG {:.0}", dmg)),
-        CardEffect::DarkEmbrace { draw } => lines.push(format!("EXH DRAW +{}", draw)),
-        CardEffect::Evolve { draw } => lines.push(format!("STATUS DRAW +{}", draw)),
-        CardEffect::FeelNoPain { block } => lines.push(format!("EXH BLOCK +{:.0}", block)),
-        CardEffect::FireBreathing { damage } => lines.push(format!("STATUS DMG {:.0}", damage)),
-        CardEffect::Rupture { strength } => lines.push(format!("RUPTURE +{:.0} STR", strength)),
-        CardEffect::Corruption => lines.push("SKILL 0C EXH".to_string()),
-        CardEffect::Brutality {
-            self_damage_per_sec,
-            draw,
-            draw_interval,
-        } => {
-            lines.push(format!("LOSE {:.0} HP/s", self_damage_per_sec));
-            lines.push(format!("DRAW {}/{:.0}s", draw, draw_interval));
-        }
-        CardEffect::Exhaust => lines.push("EXHAUST".to_string()),
-        CardEffect::AddStatus(_) => lines.push("+STATUS".to_string()),
-        CardEffect::Combo(effects) => {
-            for effect in effects {
-                effect_lines(effect, lines);
-            }
-        }
-    }
-}
-
 fn effect_color(effect: &CardEffect) -> Srgba {
-    match unified_effect_kind(effect) {
+    match effect.kind() {
         Some(EffectKind::Damage) => Srgba::rgb(1.0, 0.3, 0.3),
         Some(EffectKind::Heal) => Srgba::rgb(0.3, 1.0, 0.3),
         Some(EffectKind::Draw) => Srgba::rgb(0.3, 0.5, 1.0),
         Some(EffectKind::Block) => Srgba::rgb(0.3, 0.8, 1.0),
         Some(EffectKind::Thorns) => Srgba::rgb(1.0, 0.6, 0.2),
         Some(EffectKind::Accelerate) => Srgba::rgb(1.0, 0.9, 0.2),
+        Some(EffectKind::Poison) => Srgba::rgb(0.5, 0.9, 0.2),
         None => Srgba::rgb(0.9, 0.9, 0.9),
     }
 }
@@ -1025,57 +1705,99 @@ fn card_type_display(card_type: CardType) -> (&'static str, Srgba) {
     }
 }
 
-/// Add effect text to cards that don't have it yet.
+/// Text and color for each [`CardTextLine`], in declaration order. Cards
+/// missing from the registry get blank lines.
+fn card_text(card_id: CardId, registry: &CardRegistry) -> [(String, Srgba); 4] {
+    let Some(card_def) = registry.get(card_id) else {
+        return std::array::from_fn(|_| (String::new(), Srgba::WHITE));
+    };
+
+    // Build effect text (short version for card display)
+    let lines = card_def.effect.short_lines();
+    let effect_text = if lines.is_empty() {
+        "???".to_string()
+    } else {
+        lines.join("\n")
+    };
+
+    let cost_text = if card_def.is_playable() {
+        format!("{:.1}", card_def.cost)
+    } else {
+        "-".to_string()
+    };
+
+    // Get card type display
+    let (type_text, type_color) = card_type_display(card_def.card_type);
+
+    [
+        (card_def.name.clone(), Srgba::WHITE),
+        (type_text.to_string(), type_color),
+        (effect_text, effect_color(&card_def.effect)),
+        (cost_text, Srgba::rgb(1.0, 0.9, 0.2)),
+    ]
+}
+
+/// Write each face-up card's text onto its front: the lines are added the
+/// first time, and rewritten in place when a recycled card shows another card.
 fn add_effect_text_to_cards(
     mut commands: Commands,
     registry: Res<CardRegistry>,
     text_material: Option<Res<CardTextMaterial>>,
-    cards_without_text: Query<
-        (Entity, &MesaCardComponent<MesaCard>),
-        (Without<CardEffectTextAdded>, With<MesaHand>),
+    cards: Query<
+        (
+            Entity,
+            &MesaCardComponent<MesaCard>,
+            &CardFront,
+            Option<&CardText>,
+        ),
+        (With<MesaHand>, Without<FaceDown>),
     >,
+    children_query: Query<&Children>,
+    mut text_lines: Query<(&CardTextLine, &mut Text3d, &mut Text3dStyling)>,
 ) {
     let Some(text_material) = text_material else {
         return;
     };
     let text_material = text_material.0.clone();
 
-    for (entity, card) in cards_without_text.iter() {
+    for (entity, card, front, shown) in cards.iter() {
         let card_id = card.data.card_id;
-        let Some(card_def) = registry.get(card_id) else {
-            commands.entity(entity).insert(CardEffectTextAdded);
+        if shown.is_some_and(|shown| shown.0 == card_id) {
             continue;
-        };
-
-        // Build effect text (short version for card display)
-        let mut lines = Vec::new();
-        effect_lines(&card_def.effect, &mut lines);
-        let effect_text = if lines.is_empty() {
-            "???".to_string()
-        } else {
-            lines.join("\n")
-        };
-
-        let cost_text = format!("{:.1}", card_def.cost);
-        let name_text = card_def.name.clone();
-
-        // Determine color based on effect type
-        let effect_color = effect_color(&card_def.effect);
-
-        // Get card type display
-        let (type_text, type_color) = card_type_display(card_def.card_type);
+        }
+        let [name, card_type, effect, cost] = card_text(card_id, &registry);
+        commands.entity(entity).insert(CardText(card_id));
 
-        commands.entity(entity).insert(CardEffectTextAdded);
+        if shown.is_some() {
+            let Ok(children) = children_query.get(front.0) else {
+                continue;
+            };
+            for child in children.iter() {
+                let Ok((line, mut text, mut styling)) = text_lines.get_mut(child) else {
+                    continue;
+                };
+                let (content, color) = match line {
+                    CardTextLine::Name => &name,
+                    CardTextLine::Type => &card_type,
+                    CardTextLine::Effect => &effect,
+                    CardTextLine::Cost => &cost,
+                };
+                *text = Text3d::new(content.clone());
+                styling.color = *color;
+            }
+            continue;
+        }
 
-        // Add effect text as a child entity (positioned on top of the card front face)
-        commands.entity(entity).with_children(|parent| {
+        // Add the text on top of the card's front face
+        commands.entity(front.0).with_children(|parent| {
             // Card name (top of card)
             parent.spawn((
                 Name::new("Card Name Text"),
-                Text3d::new(name_text),
+                CardTextLine::Name,
+                Text3d::new(name.0),
                 Text3dStyling {
                     size: 16.0,
-                    color: Srgba::WHITE,
+                    color: name.1,
                     stroke: NonZeroU32::new(2),
                     stroke_color: Srgba::BLACK,
                     align: TextAlign::Center,
@@ -1093,10 +1815,11 @@ fn add_effect_text_to_cards(
             // Card type (top right corner)
             parent.spawn((
                 Name::new("Card Type Text"),
-                Text3d::new(type_text),
+                CardTextLine::Type,
+                Text3d::new(card_type.0),
                 Text3dStyling {
                     size: 12.0,
-                    color: type_color,
+                    color: card_type.1,
                     stroke: NonZeroU32::new(2),
                     stroke_color: Srgba::BLACK,
                     align: TextAlign::Center,
@@ -1114,10 +1837,11 @@ fn add_effect_text_to_cards(
             // Effect text (center of card) - smaller font
             parent.spawn((
                 Name::new("Card Effect Text"),
-                Text3d::new(effect_text),
+                CardTextLine::Effect,
+                Text3d::new(effect.0),
                 Text3dStyling {
                     size: 14.0,
-                    color: effect_color,
+                    color: effect.1,
                     stroke: NonZeroU32::new(2),
                     stroke_color: Srgba::BLACK,
                     align: TextAlign::Center,
@@ -1136,10 +1860,11 @@ fn add_effect_text_to_cards(
             // Cost text (top left corner) - smaller
             parent.spawn((
                 Name::new("Card Cost Text"),
-                Text3d::new(cost_text),
+                CardTextLine::Cost,
+                Text3d::new(cost.0),
                 Text3dStyling {
                     size: 18.0,
-                    color: Srgba::rgb(1.0, 0.9, 0.2),
+                    color: cost.1,
                     stroke: NonZeroU32::new(2),
                     stroke_color: Srgba::BLACK,
                     align: TextAlign::Center,
@@ -1172,7 +1897,7 @@ fn tag_mesa_cards_for_cleanup(
 
 fn fan_hand_layout(
     hand_areas: Query<(&HandArea, &Transform)>,
-    camera_query: Query<&GlobalTransform, With<Camera3d>>,
+    camera_query: Query<&GlobalTransform, With<MainCamera>>,
     hand_map: Res<MesaHandMap>,
     mut cards: ParamSet<(
         Query<(Entity, &MesaHand, &Transform), Without<HandArea>>,
@@ -1298,7 +2023,7 @@ fn update_card_glow_on_hover(
     has_glow_query: Query<&HasGlowOverlay>,
     children_query: Query<&Children>,
     glow_overlays: Query<Entity, With<CardGlowOverlay>>,
-    glow_assets: Res<GlowAssets>,
+    mesa_assets: Res<MesaAssets>,
 ) {
     // Add glow to hovered cards
     for (entity, hand) in hovered_cards.iter() {
@@ -1313,8 +2038,8 @@ fn update_card_glow_on_hover(
             .spawn((
                 Name::new("Card Glow Overlay"),
                 CardGlowOverlay,
-                Mesh3d(glow_assets.mesh.clone()),
-                MeshMaterial3d(glow_assets.material.clone()),
+                Mesh3d(mesa_assets.glow_mesh.clone()),
+                MeshMaterial3d(mesa_assets.hover_material.clone()),
                 Transform::from_xyz(0.0, 0.002, 0.0),
             ))
             .id();
@@ -1343,3 +2068,330 @@ fn update_card_glow_on_hover(
         commands.entity(entity).remove::<HasGlowOverlay>();
     }
 }
+
+/// Green edge on local hand cards that are affordable, gray face otherwise.
+/// Cards already on their way to the play area lose the tint.
+fn update_card_affordability(
+    mut commands: Commands,
+    player: Query<(&Cost, &PlayQueue, &FreeCards, Has<CorruptionEffect>), With<LocalPlayer>>,
+    registry: Res<CardRegistry>,
+    mut mesa_assets: ResMut<MesaAssets>,
+    asset_server: Res<AssetServer>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut cards: Query<(
+        Entity,
+        &MesaHand,
+        &MesaCardComponent<MesaCard>,
+        &mut CardFace,
+        &mut AffordTint,
+        Has<PlayedCard>,
+        Has<Burning>,
+    )>,
+) {
+    let Ok((cost, queue, free_cards, corruption_active)) = player.single() else {
+        return;
+    };
+
+    for (entity, hand, card, mut face, mut tint, played, burning) in &mut cards {
+        if hand.player != LOCAL_PLAYER_INDEX {
+            continue;
+        }
+        let affordable = registry.get(card.data.card_id).is_some_and(|def| {
+            let free = free_cards.is_free(def.id)
+                || (corruption_active && def.card_type == CardType::Skill);
+            let effective_cost = if free { 0.0 } else { def.cost };
+            def.is_playable() && cost.can_afford(effective_cost)
+        });
+        // Queues hold card ids, so every copy of a queued card lights up.
+        let queued = queue.is_waiting(card.data.card_id);
+        let target = match (played || burning, queued, affordable) {
+            (true, _, _) => AffordTint::None,
+            (false, true, _) => AffordTint::Queued(Entity::PLACEHOLDER),
+            (false, false, true) => AffordTint::Playable(Entity::PLACEHOLDER),
+            (false, false, false) => AffordTint::Unaffordable,
+        };
+        if tint.same_kind(target) {
+            continue;
+        }
+
+        if let AffordTint::Playable(overlay) | AffordTint::Queued(overlay) = *tint {
+            commands.entity(overlay).despawn();
+        }
+        let unaffordable = matches!(target, AffordTint::Unaffordable);
+        face.0 = mesa_assets.face(&card.data, unaffordable, &asset_server, &mut materials);
+        let mut spawn_edge = |material: &Handle<StandardMaterial>| {
+            let overlay = commands
+                .spawn((
+                    Name::new("Card Edge"),
+                    Mesh3d(mesa_assets.glow_mesh.clone()),
+                    MeshMaterial3d(material.clone()),
+                    // Just under the card so only the rim shows.
+                    Transform::from_xyz(0.0, -0.002, 0.0),
+                ))
+                .id();
+            commands.entity(entity).add_child(overlay);
+            overlay
+        };
+        *tint = match target {
+            AffordTint::Playable(_) => {
+                AffordTint::Playable(spawn_edge(&mesa_assets.playable_material))
+            }
+            AffordTint::Queued(_) => AffordTint::Queued(spawn_edge(&mesa_assets.queued_material)),
+            other => other,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{asset::AssetPlugin, ecs::system::RunSystemOnce, state::app::StatesPlugin};
+
+    use super::*;
+    use crate::{
+        asset_tracking::ResourceHandles,
+        game::{
+            GameMode, Health, Rules, card_art, cards, create_test_deck,
+            test_support::{Harness, go_to},
+        },
+        input::INPUT_DRAW,
+    };
+
+    const MATCHES: usize = 50;
+    /// Low enough that each match is over in a few seconds of play.
+    const OPPONENT_HP: f32 = 300.0;
+    const MAX_MATCH_FRAMES: usize = 60 * 60;
+
+    fn asset_counts(app: &App) -> (usize, usize) {
+        let world = app.world();
+        (
+            world.resource::<Assets<Mesh>>().len(),
+            world.resource::<Assets<StandardMaterial>>().len(),
+        )
+    }
+
+    /// Table presentation for [`Harness::with_plugins`].
+    fn table_plugins(app: &mut App) {
+        app.add_plugins(AssetPlugin::default());
+        app.init_asset::<Mesh>();
+        app.init_asset::<StandardMaterial>();
+        app.init_asset::<Image>();
+        app.init_resource::<ResourceHandles>();
+        // Sent on rejected card presses; the card sounds play it.
+        app.add_message::<CardRejected>();
+        app.add_plugins((card_art::plugin, plugin));
+    }
+
+    /// A simple AI for the local player: plays the first card it can pay for,
+    /// otherwise draws once it can afford to.
+    fn ai_input(harness: &Harness) -> u16 {
+        let world = harness.app.world();
+        let (registry, rules) = (world.resource::<CardRegistry>(), world.resource::<Rules>());
+        let hand = &harness.get::<Hand>(harness.player).cards;
+        let cost = harness.get::<Cost>(harness.player).current;
+        let playable = hand.iter().position(|card| {
+            registry
+                .get(*card)
+                .is_some_and(|def| def.is_playable() && def.cost <= cost)
+        });
+        match playable {
+            Some(index) => card_flag(index).unwrap_or_default(),
+            None if cost >= rules.draw_cost(hand.len()) => INPUT_DRAW,
+            None => 0,
+        }
+    }
+
+    fn play_out(harness: &mut Harness) {
+        for _ in 0..MAX_MATCH_FRAMES {
+            if harness.result() != GameResult::Playing {
+                return;
+            }
+            let input = ai_input(harness);
+            if input != 0 {
+                harness.press(harness.player, input);
+            }
+            harness.step();
+        }
+        panic!("the match wasn't decided in {MAX_MATCH_FRAMES} frames");
+    }
+
+    #[test]
+    fn consecutive_matches_leave_no_assets_behind() {
+        let mut harness = Harness::with_plugins(
+            GameMode::Offline,
+            create_test_deck(),
+            create_test_deck(),
+            table_plugins,
+        );
+        let mut after_first = None;
+        let mut during_second = None;
+        for played in 1..=MATCHES {
+            let opponent = harness.opponent;
+            harness.get_mut::<Health>(opponent).current = OPPONENT_HP;
+            play_out(&mut harness);
+            assert_eq!(harness.result(), GameResult::Victory);
+            let decided = asset_counts(&harness.app);
+            harness.leave();
+            let left = asset_counts(&harness.app);
+            assert!(left.0 < decided.0 && left.1 < decided.1);
+            assert!(!harness.app.world().contains_resource::<MesaAssets>());
+            assert!(!harness.app.world().contains_resource::<CardTextMaterial>());
+
+            assert_eq!(
+                left,
+                *after_first.get_or_insert(left),
+                "after match {played}"
+            );
+            // The first match also builds faces for art that turns out to be
+            // missing; later ones know to use the atlas from the start.
+            if played >= 2 {
+                assert_eq!(
+                    decided,
+                    *during_second.get_or_insert(decided),
+                    "during match {played}"
+                );
+            }
+            if played < MATCHES {
+                harness.rematch();
+            }
+        }
+    }
+
+    /// Draw a card into the local hand the way `sync_hand_to_mesa` does.
+    fn draw(app: &mut App, card_id: CardId) -> Entity {
+        app.world_mut()
+            .run_system_once(
+                move |mut commands: Commands,
+                      registry: Res<CardRegistry>,
+                      mut card_art: ResMut<CardArt>,
+                      asset_server: Res<AssetServer>,
+                      mut pool: ResMut<CardPool>,
+                      mut mesa_assets: ResMut<MesaAssets>,
+                      mut frames: ResMut<CardFrames>,
+                      mut materials: ResMut<Assets<StandardMaterial>>,
+                      mut images: ResMut<Assets<Image>>| {
+                    let card = mesa_card_from_id(card_id, &registry, &mut card_art, &asset_server);
+                    spawn_hand_card(
+                        &mut commands,
+                        &card,
+                        LOCAL_PLAYER_INDEX,
+                        Transform::default(),
+                        false,
+                        &registry,
+                        &mut pool,
+                        &mut mesa_assets,
+                        &mut frames,
+                        &mut materials,
+                        &mut images,
+                        &asset_server,
+                    )
+                },
+            )
+            .unwrap()
+    }
+
+    fn text_line(app: &mut App, card: Entity, line: CardTextLine) -> String {
+        let front = app.world().get::<CardFront>(card).unwrap().0;
+        let children = app.world().get::<Children>(front).unwrap().to_vec();
+        let mut lines = app.world_mut().query::<(&CardTextLine, &Text3d)>();
+        let texts: Vec<_> = children
+            .iter()
+            .filter_map(|child| lines.get(app.world(), *child).ok())
+            .filter(|(kind, _)| std::mem::discriminant(*kind) == std::mem::discriminant(&line))
+            .map(|(_, text)| text.get_single().unwrap().to_string())
+            .collect();
+        assert_eq!(texts.len(), 1, "one line of each kind");
+        texts[0].clone()
+    }
+
+    #[test]
+    fn recycled_card_shows_the_new_card() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default(), StatesPlugin));
+        app.init_asset::<Mesh>();
+        app.init_asset::<StandardMaterial>();
+        app.init_asset::<Image>();
+        app.init_state::<Screen>();
+        app.add_plugins(cards::plugin);
+        app.init_resource::<CardArt>();
+        app.init_resource::<MesaScene>();
+        app.init_resource::<MesaDecksRendered>();
+        app.init_resource::<MesaHandMap>();
+        app.init_resource::<PreviousHandSizes>();
+        app.init_resource::<CardPool>();
+        app.add_systems(
+            OnEnter(Screen::Gameplay),
+            (reset_mesa_state, spawn_mesa_scene).chain(),
+        );
+        app.add_systems(
+            Update,
+            (
+                park_cards,
+                add_effect_text_to_cards,
+                update_card_affordability,
+                sync_face_materials,
+            )
+                .chain(),
+        );
+        go_to(&mut app, Screen::Gameplay);
+        // Enough for Strike (1.0) but not for Bash (2.0).
+        app.world_mut().spawn((
+            LocalPlayer,
+            Cost {
+                current: 1.5,
+                rate: 0.0,
+                max: 10.0,
+            },
+            PlayQueue::default(),
+            FreeCards::default(),
+        ));
+
+        let strike = draw(&mut app, CardId::Strike);
+        app.update();
+        assert_eq!(text_line(&mut app, strike, CardTextLine::Name), "Strike");
+        assert!(matches!(
+            app.world().get::<AffordTint>(strike),
+            Some(AffordTint::Playable(_))
+        ));
+
+        app.world_mut()
+            .run_system_once(move |mut commands: Commands| park_card(strike, &mut commands))
+            .unwrap();
+        app.update();
+        assert_eq!(app.world().resource::<CardPool>().0.len(), 1);
+
+        let bash = draw(&mut app, CardId::Bash);
+        assert_eq!(bash, strike, "the parked card is reused");
+        app.update();
+
+        assert!(app.world().resource::<CardPool>().0.is_empty());
+        assert_eq!(text_line(&mut app, bash, CardTextLine::Name), "Bash");
+        assert_eq!(text_line(&mut app, bash, CardTextLine::Cost), "2.0");
+        assert!(matches!(
+            app.world().get::<AffordTint>(bash),
+            Some(AffordTint::Unaffordable)
+        ));
+        let face = app.world().get::<CardFace>(bash).unwrap().0.clone();
+        let greyed_bash = app
+            .world()
+            .resource::<MesaAssets>()
+            .faces
+            .iter()
+            .find(|((card_id, _, unaffordable), _)| *card_id == CardId::Bash && *unaffordable)
+            .map(|(_, handle)| handle.clone());
+        assert_eq!(Some(face.clone()), greyed_bash);
+        let front = app.world().get::<CardFront>(bash).unwrap().0;
+        let art = app
+            .world()
+            .entity(front)
+            .get::<Children>()
+            .unwrap()
+            .iter()
+            .find(|child| app.world().get::<CardArtQuad>(*child).is_some())
+            .unwrap();
+        let art_material = app
+            .world()
+            .get::<MeshMaterial3d<StandardMaterial>>(art)
+            .unwrap();
+        assert_eq!(art_material.0, face);
+    }
+}
@@ -1,40 +1,95 @@
 //! Core game systems for Sensen card game.
 
+mod broadcast;
+mod camera;
+mod card_art;
+mod card_frame;
+mod card_sounds;
 mod cards;
+mod combat_log;
+mod combat_text;
 mod cost;
 mod deck;
+mod draft;
 mod effect;
 mod health;
+mod hp_bar;
 mod input_buffer;
+mod inspect;
+mod match_stats;
 mod mesa;
+mod mutator;
+mod particles;
+mod pile_labels;
+mod pip;
 mod player;
+mod reward;
+mod rng;
 mod rules;
+mod series;
 mod shaders;
+mod sim_math;
 mod status;
+mod status_bar;
+mod sudden_death;
+#[cfg(test)]
+pub(crate) mod test_support;
+mod tooltip;
 mod ui;
 
+pub use camera::CameraSettings;
 pub use cards::*;
+pub use combat_log::CombatLog;
 pub use cost::*;
 pub use deck::*;
+pub use draft::*;
 pub use health::*;
 pub use input_buffer::*;
+pub use inspect::{CardInspect, is_inspecting};
+pub use match_stats::MatchStats;
+pub use mutator::*;
 pub use player::*;
+pub use reward::*;
+pub use rng::{GameRng, RngStream};
 pub use rules::*;
+pub use series::*;
+pub use sim_math::*;
 pub use status::*;
+pub use sudden_death::{MatchClock, MatchTimeLimit, MatchTimer};
+pub use ui::OpponentNameLabel;
 
-use bevy::prelude::*;
-use bevy_ggrs::GgrsSchedule;
+use std::time::Duration;
 
-use crate::AppSystems;
+use bevy::{
+    ecs::{
+        schedule::ScheduleConfigs,
+        system::{ScheduleSystem, SystemParam},
+    },
+    prelude::*,
+};
+use bevy_ggrs::{GgrsSchedule, GgrsTime};
+
+use crate::screens::Screen;
 
 /// Whether the game is running offline or via rollback networking.
-#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
 pub enum GameMode {
     #[default]
     Offline,
     Online,
 }
 
+/// Which deck players start a match with.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeckMode {
+    #[default]
+    Starter,
+    /// Legal random deck generated from the registry, seeded by `MatchSeed`.
+    Random,
+    /// Decks drafted from shared packs on the Draft screen.
+    Draft,
+}
+
 /// Per-match deterministic seed (shared across peers in online matches).
 #[derive(Resource, Debug, Clone, Copy)]
 pub struct MatchSeed(pub u64);
@@ -47,6 +102,14 @@ impl Default for MatchSeed {
     }
 }
 
+/// Simulation ticks per second, offline and online. Online this is the GGRS
+/// rollback rate; offline it's the `FixedUpdate` rate, so a match plays at the
+/// same speed whatever the frame rate.
+pub const SIM_TICK_RATE: usize = 60;
+
+/// Length of one simulation tick.
+pub const SIM_TICK: Duration = Duration::from_nanos(1_000_000_000 / SIM_TICK_RATE as u64);
+
 pub fn is_online(mode: Res<GameMode>) -> bool {
     *mode == GameMode::Online
 }
@@ -55,6 +118,24 @@ pub fn is_offline(mode: Res<GameMode>) -> bool {
     *mode == GameMode::Offline
 }
 
+/// Seconds of match time advanced this tick: the rollback tick online, the
+/// fixed timestep offline. Both are [`SIM_TICK`].
+#[derive(SystemParam)]
+pub struct SimTime<'w> {
+    mode: Res<'w, GameMode>,
+    time: Res<'w, Time>,
+    ggrs_time: Option<Res<'w, Time<GgrsTime>>>,
+}
+
+impl SimTime<'_> {
+    pub fn delta_secs(&self) -> f32 {
+        match (*self.mode, &self.ggrs_time) {
+            (GameMode::Online, Some(ggrs_time)) => ggrs_time.delta_secs(),
+            _ => self.time.delta_secs(),
+        }
+    }
+}
+
 /// Execution order for gameplay logic (offline and rollback).
 #[derive(SystemSet, Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
 pub enum GameplaySystems {
@@ -65,42 +146,97 @@ pub enum GameplaySystems {
     Health,
 }
 
+/// Registers match simulation systems once for both game loops.
+pub(crate) trait AddGameplaySystems {
+    /// Run `systems`, in the order given, in `set` while a match is being
+    /// played: in `FixedUpdate` offline, and in the rollback `GgrsSchedule`
+    /// online. Both tick at [`SIM_TICK_RATE`].
+    fn add_gameplay_systems<M>(
+        &mut self,
+        set: GameplaySystems,
+        systems: impl IntoScheduleConfigs<ScheduleSystem, M> + Clone,
+    ) -> &mut Self;
+}
+
+impl AddGameplaySystems for App {
+    fn add_gameplay_systems<M>(
+        &mut self,
+        set: GameplaySystems,
+        systems: impl IntoScheduleConfigs<ScheduleSystem, M> + Clone,
+    ) -> &mut Self {
+        let configure = |systems: ScheduleConfigs<ScheduleSystem>| {
+            systems
+                .in_set(set)
+                .run_if(in_state(Screen::Gameplay))
+                .run_if(in_state(GameResult::Playing))
+        };
+        self.add_systems(
+            FixedUpdate,
+            configure(systems.clone().chain()).run_if(is_offline),
+        );
+        self.add_systems(GgrsSchedule, configure(systems.chain()).run_if(is_online))
+    }
+}
+
 pub fn plugin(app: &mut App) {
-    app.init_resource::<GameMode>();
+    app.init_resource::<DeckMode>();
+    app.init_resource::<CharacterClass>();
+    app.init_resource::<MatchMutator>();
     app.init_resource::<MatchSeed>();
-    app.configure_sets(
-        GgrsSchedule,
-        (
-            GameplaySystems::Tick,
-            GameplaySystems::Input,
-            GameplaySystems::Deck,
-            GameplaySystems::Effects,
-            GameplaySystems::Health,
-        )
-            .chain(),
-    );
-    app.configure_sets(
-        Update,
+    app.add_plugins((
+        simulation_plugin,
+        broadcast::plugin,
+        camera::plugin,
+        card_art::plugin,
+        mesa::plugin,
+        reward::plugin,
+        rules::plugin,
+        series::plugin,
+        shaders::plugin,
         (
-            GameplaySystems::Input,
-            GameplaySystems::Deck,
-            GameplaySystems::Effects,
-            GameplaySystems::Health,
-        )
-            .chain()
-            .in_set(AppSystems::Update),
+            card_sounds::plugin,
+            combat_log::plugin,
+            combat_text::plugin,
+            hp_bar::plugin,
+            inspect::plugin,
+            particles::plugin,
+            pile_labels::plugin,
+            pip::plugin,
+            status_bar::plugin,
+            tooltip::plugin,
+            ui::plugin,
+        ),
+    ));
+}
+
+/// The match rules on their own: decks, cost, card effects, health, statuses
+/// and input, with nothing drawn or played back.
+fn simulation_plugin(app: &mut App) {
+    app.init_resource::<GameMode>();
+    app.init_resource::<GameRng>();
+    app.init_resource::<Rules>();
+    // Pausing stops virtual time, which stops the fixed timestep with it.
+    app.insert_resource(Time::<Fixed>::from_duration(SIM_TICK));
+    let order = (
+        GameplaySystems::Tick,
+        GameplaySystems::Input,
+        GameplaySystems::Deck,
+        GameplaySystems::Effects,
+        GameplaySystems::Health,
     );
+    app.configure_sets(FixedUpdate, order.chain());
+    app.configure_sets(GgrsSchedule, order.chain());
     app.add_plugins((
         cost::plugin,
         cards::plugin,
+        combat_log::simulation_plugin,
         deck::plugin,
         effect::plugin,
         health::plugin,
         input_buffer::plugin,
-        mesa::plugin,
+        match_stats::plugin,
         player::plugin,
-        shaders::plugin,
         status::plugin,
-        ui::plugin,
+        sudden_death::plugin,
     ));
 }
@@ -0,0 +1,119 @@
+//! Match mutators - one rule tweak per online match, picked by veto in the lobby.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{Block, Cost, GameRng, Health, RngStream, Strength};
+
+/// Number of mutators offered for a veto vote.
+pub const MUTATOR_OFFER_COUNT: usize = 3;
+
+/// A rule tweak applied to both players for a whole match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum Mutator {
+    /// Cost accumulates 50% faster
+    Overclock,
+    /// Both players start at half HP
+    GlassCannon,
+    /// Max cost raised by 5
+    DeepPockets,
+    /// Both players start with 100 Block
+    Fortified,
+    /// Opening hand of 7 cards
+    BigHands,
+    /// Both players start with 1 Strength
+    Bloodthirst,
+}
+
+impl Mutator {
+    pub const ALL: [Mutator; 6] = [
+        Mutator::Overclock,
+        Mutator::GlassCannon,
+        Mutator::DeepPockets,
+        Mutator::Fortified,
+        Mutator::BigHands,
+        Mutator::Bloodthirst,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Mutator::Overclock => "Overclock",
+            Mutator::GlassCannon => "Glass Cannon",
+            Mutator::DeepPockets => "Deep Pockets",
+            Mutator::Fortified => "Fortified",
+            Mutator::BigHands => "Big Hands",
+            Mutator::Bloodthirst => "Bloodthirst",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Mutator::Overclock => "Cost accumulates 50% faster.",
+            Mutator::GlassCannon => "Both players start at half HP.",
+            Mutator::DeepPockets => "Max cost raised by 5.",
+            Mutator::Fortified => "Both players start with 100 Block.",
+            Mutator::BigHands => "Opening hand of 7 cards.",
+            Mutator::Bloodthirst => "Both players start with 1 Strength.",
+        }
+    }
+
+    /// Pick the offered mutators for a match. Same seed, same offer on every peer.
+    pub fn roll(seed: u64) -> [Mutator; MUTATOR_OFFER_COUNT] {
+        let mut pool = Mutator::ALL;
+        let mut rng = GameRng::new(seed);
+        // Partial Fisher-Yates: only the first slots are needed.
+        for i in 0..MUTATOR_OFFER_COUNT {
+            let j = i + rng.below(RngStream::Mutators, pool.len() - i);
+            pool.swap(i, j);
+        }
+        [pool[0], pool[1], pool[2]]
+    }
+
+    /// Drop every vetoed offer and pick one survivor deterministically.
+    pub fn resolve(offers: [Mutator; MUTATOR_OFFER_COUNT], vetoes: &[usize], seed: u64) -> Mutator {
+        let survivors: Vec<Mutator> = offers
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !vetoes.contains(index))
+            .map(|(_, mutator)| *mutator)
+            .collect();
+        if survivors.is_empty() {
+            return offers[0];
+        }
+        let mut rng = GameRng::new(seed.rotate_left(16));
+        survivors[rng.below(RngStream::Mutators, survivors.len())]
+    }
+
+    /// Apply the match-start tweaks to a freshly spawned player.
+    pub fn apply(
+        self,
+        health: &mut Health,
+        block: &mut Block,
+        cost: &mut Cost,
+        strength: &mut Strength,
+    ) {
+        match self {
+            Mutator::Overclock => cost.rate *= 1.5,
+            Mutator::GlassCannon => {
+                health.max *= 0.5;
+                health.current = health.max;
+            }
+            Mutator::DeepPockets => cost.max += 5.0,
+            Mutator::Fortified => block.gain(100.0),
+            Mutator::BigHands => {}
+            Mutator::Bloodthirst => strength.gain(1.0),
+        }
+    }
+
+    /// Number of cards in the opening hand.
+    pub fn opening_hand(self) -> usize {
+        match self {
+            Mutator::BigHands => 7,
+            _ => 5,
+        }
+    }
+}
+
+/// The mutator active for the current match (`None` offline).
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct MatchMutator(pub Option<Mutator>);
@@ -0,0 +1,247 @@
+//! Particle bursts on card impacts: sparks on damage, green motes on heal and
+//! a blue shimmer on block gain, at the target player's side of the table.
+//!
+//! Particles are small camera-facing quads from a fixed pool spawned per
+//! match and recycled oldest first, like the floating numbers in
+//! `combat_text.rs`. Each kind shares one additive material, so particles
+//! fade out by shrinking.
+
+use bevy::{prelude::*, render::alpha::AlphaMode};
+
+use super::{
+    DamageMessage, GainBlockMessage, HealMessage, LocalPlayer, Opponent, combat_text::anchor_for,
+};
+use crate::{AppSystems, MainCamera, screens::Screen};
+
+/// Particles alive at once; the oldest are reused when all are busy.
+const POOL_SIZE: usize = 160;
+const PARTICLE_SIZE: f32 = 0.12;
+/// Bursts start this far below the floating numbers, down at the board.
+const BURST_DROP: f32 = 1.5;
+/// Pulls sparks back down to the table.
+const GRAVITY: f32 = 6.0;
+/// Spreads a burst evenly around a circle without an RNG.
+const GOLDEN_ANGLE: f32 = 2.399_963;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_particle_pool);
+    app.add_systems(OnExit(Screen::Gameplay), release_particle_materials);
+    app.add_systems(
+        Update,
+        (emit_bursts, animate_particles)
+            .chain()
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Burst {
+    #[default]
+    Sparks,
+    Motes,
+    Shimmer,
+}
+
+impl Burst {
+    fn count(self) -> usize {
+        match self {
+            Burst::Sparks => 12,
+            Burst::Motes => 8,
+            Burst::Shimmer => 14,
+        }
+    }
+
+    fn lifetime(self) -> f32 {
+        match self {
+            Burst::Sparks => 0.45,
+            Burst::Motes => 1.0,
+            Burst::Shimmer => 0.7,
+        }
+    }
+
+    /// Start offset from the anchor and velocity of a burst's `index`th
+    /// particle.
+    fn launch(self, index: usize) -> (Vec3, Vec3) {
+        let angle = index as f32 * GOLDEN_ANGLE;
+        let outward = Vec3::new(angle.cos(), 0.0, angle.sin());
+        let jitter = (index as f32 * 0.618).fract();
+        match self {
+            Burst::Sparks => (
+                Vec3::ZERO,
+                outward * (2.5 + 2.0 * jitter) + Vec3::Y * (2.0 + 2.0 * jitter),
+            ),
+            Burst::Motes => (outward * 0.6 * jitter, Vec3::Y * (0.8 + 0.6 * jitter)),
+            // A loose ring that drifts up around the player's side.
+            Burst::Shimmer => (outward * 0.9 + Vec3::Y * 0.3 * jitter, Vec3::Y * 0.4),
+        }
+    }
+}
+
+#[derive(Component, Default)]
+struct Particle {
+    burst: Burst,
+    velocity: Vec3,
+    /// Seconds since emitted, or `None` while parked in the pool.
+    age: Option<f32>,
+}
+
+impl Particle {
+    /// How far through its life the particle is; parked ones count as done.
+    fn progress(&self) -> f32 {
+        self.age
+            .map_or(f32::INFINITY, |age| age / self.burst.lifetime())
+    }
+}
+
+#[derive(Resource)]
+struct ParticleMaterials {
+    sparks: Handle<StandardMaterial>,
+    motes: Handle<StandardMaterial>,
+    shimmer: Handle<StandardMaterial>,
+}
+
+impl ParticleMaterials {
+    fn get(&self, burst: Burst) -> Handle<StandardMaterial> {
+        match burst {
+            Burst::Sparks => self.sparks.clone(),
+            Burst::Motes => self.motes.clone(),
+            Burst::Shimmer => self.shimmer.clone(),
+        }
+    }
+}
+
+fn glow_material(color: Color) -> StandardMaterial {
+    StandardMaterial {
+        base_color: color,
+        alpha_mode: AlphaMode::Add,
+        unlit: true,
+        ..default()
+    }
+}
+
+fn spawn_particle_pool(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let particle_materials = ParticleMaterials {
+        sparks: materials.add(glow_material(Color::srgb(1.0, 0.7, 0.3))),
+        motes: materials.add(glow_material(Color::srgb(0.3, 1.0, 0.4))),
+        shimmer: materials.add(glow_material(Color::srgb(0.5, 0.75, 1.0))),
+    };
+    let mesh = meshes.add(Rectangle::new(PARTICLE_SIZE, PARTICLE_SIZE));
+    for _ in 0..POOL_SIZE {
+        commands.spawn((
+            Name::new("Particle"),
+            Particle::default(),
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(particle_materials.sparks.clone()),
+            Transform::default(),
+            Visibility::Hidden,
+            Pickable::IGNORE,
+            DespawnOnExit(Screen::Gameplay),
+        ));
+    }
+    commands.insert_resource(particle_materials);
+}
+
+fn release_particle_materials(mut commands: Commands) {
+    commands.remove_resource::<ParticleMaterials>();
+}
+
+fn emit_bursts(
+    mut damage: MessageReader<DamageMessage>,
+    mut heal: MessageReader<HealMessage>,
+    mut block: MessageReader<GainBlockMessage>,
+    local: Query<Entity, With<LocalPlayer>>,
+    opponent: Query<Entity, With<Opponent>>,
+    materials: Res<ParticleMaterials>,
+    mut particles: Query<(
+        &mut Particle,
+        &mut Transform,
+        &mut MeshMaterial3d<StandardMaterial>,
+        &mut Visibility,
+    )>,
+) {
+    let bursts: Vec<(Entity, Burst)> = damage
+        .read()
+        .filter(|msg| msg.amount > 0.0)
+        .map(|msg| (msg.target, Burst::Sparks))
+        .chain(
+            heal.read()
+                .filter(|msg| msg.amount > 0.0)
+                .map(|msg| (msg.target, Burst::Motes)),
+        )
+        .chain(
+            block
+                .read()
+                .filter(|msg| msg.amount > 0.0)
+                .map(|msg| (msg.target, Burst::Shimmer)),
+        )
+        .collect();
+    if bursts.is_empty() {
+        return;
+    }
+
+    // Parked particles first, then the ones closest to fading out.
+    let mut pool: Vec<_> = particles.iter_mut().collect();
+    pool.sort_by(|(a, ..), (b, ..)| b.progress().total_cmp(&a.progress()));
+    let mut pool = pool.into_iter();
+    for (target, burst) in bursts {
+        let Some(anchor) = anchor_for(target, &local, &opponent) else {
+            continue;
+        };
+        let origin = anchor - Vec3::Y * BURST_DROP;
+        for index in 0..burst.count() {
+            let Some((mut particle, mut transform, mut material, mut visibility)) = pool.next()
+            else {
+                return;
+            };
+            let (offset, velocity) = burst.launch(index);
+            *particle = Particle {
+                burst,
+                velocity,
+                age: Some(0.0),
+            };
+            transform.translation = origin + offset;
+            material.0 = materials.get(burst);
+            *visibility = Visibility::Inherited;
+        }
+    }
+}
+
+fn animate_particles(
+    time: Res<Time>,
+    camera: Query<&GlobalTransform, With<MainCamera>>,
+    mut particles: Query<(&mut Particle, &mut Transform, &mut Visibility)>,
+) {
+    let dt = time.delta_secs();
+    let facing = camera
+        .single()
+        .map_or(Quat::IDENTITY, |camera| camera.compute_transform().rotation);
+    for (mut particle, mut transform, mut visibility) in &mut particles {
+        let Some(age) = particle.age else {
+            continue;
+        };
+        let age = age + dt;
+        let lifetime = particle.burst.lifetime();
+        if age >= lifetime {
+            particle.age = None;
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+        particle.age = Some(age);
+        if particle.burst == Burst::Sparks {
+            particle.velocity.y -= GRAVITY * dt;
+        }
+        transform.translation += particle.velocity * dt;
+        transform.rotation = facing;
+        let mut scale = 1.0 - age / lifetime;
+        if particle.burst == Burst::Shimmer {
+            // Twinkle, each fleck out of step with its neighbours.
+            scale *= 0.5 + 0.5 * (age * 25.0 + transform.translation.x * 7.0).sin().abs();
+        }
+        transform.scale = Vec3::splat(scale);
+    }
+}
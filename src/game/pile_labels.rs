@@ -0,0 +1,142 @@
+//! Floating draw and discard pile counts over each deck on the table.
+//!
+//! Discarded cards land on the same table spot as the draw pile, so each
+//! deck gets two stacked labels, turned to face the camera.
+
+use std::num::NonZeroU32;
+
+use bevy::{color::Srgba, prelude::*};
+use bevy_la_mesa::DeckArea;
+use bevy_rich_text3d::{Text3d, Text3dStyling, TextAlign, TextAnchor};
+
+use super::{
+    Deck, DiscardPile, LocalPlayer, Opponent,
+    mesa::{CardTextMaterial, LOCAL_PLAYER_INDEX, OPPONENT_PLAYER_INDEX},
+};
+use crate::{AppSystems, MainCamera, screens::Screen};
+
+/// Height of the draw pile label above the deck area.
+const DRAW_LABEL_HEIGHT: f32 = 1.5;
+/// Height of the discard pile label, just under the draw pile label.
+const DISCARD_LABEL_HEIGHT: f32 = 1.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            spawn_pile_labels.run_if(resource_exists::<CardTextMaterial>),
+            update_pile_labels,
+            face_camera,
+        )
+            .chain()
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Pile {
+    Draw,
+    Discard,
+}
+
+impl Pile {
+    fn label(self, count: usize) -> String {
+        match self {
+            Self::Draw => format!("Draw {count}"),
+            Self::Discard => format!("Discard {count}"),
+        }
+    }
+}
+
+#[derive(Component)]
+struct PileLabel {
+    player: usize,
+    pile: Pile,
+    /// Count currently shown, to only rebuild the text when it changes.
+    shown: Option<usize>,
+}
+
+fn spawn_pile_labels(
+    mut commands: Commands,
+    decks: Query<(&DeckArea, &Transform), Added<DeckArea>>,
+    text_material: Res<CardTextMaterial>,
+) {
+    for (area, transform) in &decks {
+        for (pile, height, color) in [
+            (Pile::Draw, DRAW_LABEL_HEIGHT, Srgba::WHITE),
+            (
+                Pile::Discard,
+                DISCARD_LABEL_HEIGHT,
+                Srgba::rgb(0.75, 0.75, 0.8),
+            ),
+        ] {
+            commands.spawn((
+                Name::new("Pile Label"),
+                PileLabel {
+                    player: area.marker,
+                    pile,
+                    shown: None,
+                },
+                Text3d::new(pile.label(0)),
+                Text3dStyling {
+                    size: 24.0,
+                    color,
+                    stroke: NonZeroU32::new(2),
+                    stroke_color: Srgba::BLACK,
+                    align: TextAlign::Center,
+                    anchor: TextAnchor::CENTER,
+                    world_scale: Some(Vec2::splat(0.4)),
+                    ..default()
+                },
+                Mesh3d::default(),
+                MeshMaterial3d(text_material.0.clone()),
+                Transform::from_translation(transform.translation + Vec3::Y * height),
+                DespawnOnExit(Screen::Gameplay),
+            ));
+        }
+    }
+}
+
+fn update_pile_labels(
+    mut commands: Commands,
+    local: Query<(&Deck, &DiscardPile), With<LocalPlayer>>,
+    opponent: Query<(&Deck, &DiscardPile), With<Opponent>>,
+    mut labels: Query<(Entity, &mut PileLabel)>,
+) {
+    for (entity, mut label) in &mut labels {
+        let piles = match label.player {
+            LOCAL_PLAYER_INDEX => local.single(),
+            OPPONENT_PLAYER_INDEX => opponent.single(),
+            _ => continue,
+        };
+        let Ok((deck, discard)) = piles else {
+            continue;
+        };
+        let count = match label.pile {
+            Pile::Draw => deck.cards.len(),
+            Pile::Discard => discard.cards.len(),
+        };
+        if label.shown != Some(count) {
+            label.shown = Some(count);
+            commands
+                .entity(entity)
+                .insert(Text3d::new(label.pile.label(count)));
+        }
+    }
+}
+
+/// Turn the labels toward the camera so both players' counts stay readable.
+fn face_camera(
+    camera: Query<&GlobalTransform, With<MainCamera>>,
+    mut labels: Query<&mut Transform, With<PileLabel>>,
+) {
+    let Ok(camera) = camera.single() else {
+        return;
+    };
+    for mut transform in &mut labels {
+        // Text faces +Z, so point -Z away from the camera.
+        let away = transform.translation - camera.translation();
+        transform.look_to(away, Vec3::Y);
+    }
+}
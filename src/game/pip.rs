@@ -0,0 +1,130 @@
+//! Picture-in-picture close-up of the opponent's play area.
+//!
+//! A second 3D camera renders the opponent's side of the table into a small
+//! image shown in the corner of the screen, so the card they just played can
+//! be read without moving the main camera. The close-up only renders while
+//! an opponent's card is on show.
+
+use bevy::{camera::RenderTarget, prelude::*, render::render_resource::TextureFormat};
+use bevy_la_mesa::PlayArea;
+
+use super::{
+    CardPlayedMessage, Opponent,
+    broadcast::BroadcastLayout,
+    mesa::{OPPONENT_PLAYER_INDEX, OPPONENT_REVEAL_SECS},
+};
+use crate::{AppSystems, screens::Screen};
+
+/// Close-up size on screen, in logical pixels.
+const PIP_SIZE: UVec2 = UVec2::new(320, 200);
+/// The render target is drawn at twice the on-screen size to keep card text sharp.
+const PIP_RESOLUTION: u32 = 2;
+/// Camera position relative to the opponent's play area.
+const PIP_OFFSET: Vec3 = Vec3::new(0.0, 5.0, 3.5);
+/// Covers the card's flight from the hand before its reveal starts.
+const FLIGHT_SECS: f32 = 0.5;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_pip);
+    app.add_systems(
+        Update,
+        (aim_pip_camera, show_pip_on_opponent_play)
+            .chain()
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[derive(Component)]
+struct PipCamera {
+    /// Seconds left showing the close-up.
+    remaining: f32,
+}
+
+/// The corner panel showing the close-up.
+#[derive(Component)]
+struct PipPanel;
+
+fn spawn_pip(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    let size = PIP_SIZE * PIP_RESOLUTION;
+    let image = images.add(Image::new_target_texture(
+        size.x,
+        size.y,
+        TextureFormat::Rgba8UnormSrgb,
+        None,
+    ));
+    commands.spawn((
+        Name::new("Opponent Close-up Camera"),
+        PipCamera { remaining: 0.0 },
+        Camera3d::default(),
+        Camera {
+            // Render before the main camera; idle until the opponent plays.
+            order: -1,
+            is_active: false,
+            clear_color: ClearColorConfig::Custom(Color::srgb(0.05, 0.05, 0.07)),
+            ..default()
+        },
+        RenderTarget::Image(image.clone().into()),
+        Msaa::Off,
+        Transform::default(),
+        DespawnOnExit(Screen::Gameplay),
+    ));
+    commands.spawn((
+        Name::new("Opponent Close-up"),
+        PipPanel,
+        Node {
+            position_type: PositionType::Absolute,
+            right: px(16),
+            top: percent(30),
+            width: px(PIP_SIZE.x),
+            height: px(PIP_SIZE.y),
+            border: UiRect::all(px(2)),
+            ..default()
+        },
+        BorderColor::all(Color::srgb(0.8, 0.2, 0.2)),
+        ImageNode::new(image),
+        Visibility::Hidden,
+        Pickable::IGNORE,
+        DespawnOnExit(Screen::Gameplay),
+    ));
+}
+
+/// Keep the close-up over the opponent's play area.
+fn aim_pip_camera(
+    play_areas: Query<(&PlayArea, &GlobalTransform)>,
+    mut camera: Single<&mut Transform, With<PipCamera>>,
+) {
+    let Some((_, area)) = play_areas
+        .iter()
+        .find(|(area, _)| area.player == OPPONENT_PLAYER_INDEX)
+    else {
+        return;
+    };
+    let target = area.translation();
+    **camera = Transform::from_translation(target + PIP_OFFSET).looking_at(target, Vec3::Y);
+}
+
+fn show_pip_on_opponent_play(
+    time: Res<Time>,
+    broadcast: Res<BroadcastLayout>,
+    mut played: MessageReader<CardPlayedMessage>,
+    opponent: Query<(), With<Opponent>>,
+    mut camera: Single<(&mut PipCamera, &mut Camera)>,
+    mut panel: Single<&mut Visibility, With<PipPanel>>,
+) {
+    let (pip, camera) = &mut *camera;
+    if played.read().any(|msg| opponent.contains(msg.player)) {
+        pip.remaining = FLIGHT_SECS + OPPONENT_REVEAL_SECS;
+    }
+    pip.remaining = (pip.remaining - time.delta_secs()).max(0.0);
+    // The broadcast layout already shows both hands face-up from above.
+    let show = pip.remaining > 0.0 && !broadcast.enabled;
+    if camera.is_active != show {
+        camera.is_active = show;
+    }
+    panel.set_if_neq(if show {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    });
+}
@@ -3,8 +3,9 @@
 use bevy::prelude::*;
 
 use super::{
-    Block, CardId, Cost, Deck, DiscardPile, Hand, Health, INITIAL_HP, Strength, Thorns, Vulnerable,
-    Weak,
+    Block, CardId, CardRegistry, CardType, CharacterClass, Cost, Deck, DeckMode, DiscardPile,
+    FreeCards, Hand, Health, MatchClock, PlayLog, PlayQueue, Poison, RANDOM_DECK_COPY_LIMIT,
+    RANDOM_DECK_SIZE, REWARD_CHOICES, Strength, Thorns, Vulnerable, Weak,
 };
 
 pub fn plugin(_app: &mut App) {
@@ -38,30 +39,46 @@ pub struct PlayerBundle {
     pub strength: Strength,
     pub vulnerable: Vulnerable,
     pub weak: Weak,
+    pub poison: Poison,
     pub cost: Cost,
     pub deck: Deck,
     pub hand: Hand,
     pub discard_pile: DiscardPile,
+    pub play_log: PlayLog,
+    pub play_queue: PlayQueue,
+    pub free_cards: FreeCards,
+    pub clock: MatchClock,
 }
 
 impl PlayerBundle {
-    pub fn new(handle: usize, cost_rate: f32, initial_deck: Vec<CardId>, match_seed: u64) -> Self {
+    pub fn new(
+        handle: usize,
+        cost: Cost,
+        hp: f32,
+        initial_deck: Vec<CardId>,
+        match_seed: u64,
+    ) -> Self {
         let deck = Deck::new_with_seed(initial_deck, Deck::seed_for_handle(match_seed, handle));
 
         Self {
             name: Name::new("Player"),
             local_player: LocalPlayer,
             handle: PlayerHandle(handle),
-            health: Health::new(INITIAL_HP),
+            health: Health::new(hp),
             block: Block::default(),
             thorns: Thorns::default(),
             strength: Strength::default(),
             vulnerable: Vulnerable::default(),
             weak: Weak::default(),
-            cost: Cost::new(cost_rate),
+            poison: Poison::default(),
+            cost,
             deck,
             hand: Hand::default(),
             discard_pile: DiscardPile::default(),
+            play_log: PlayLog::default(),
+            play_queue: PlayQueue::default(),
+            free_cards: FreeCards::default(),
+            clock: MatchClock::default(),
         }
     }
 }
@@ -78,30 +95,46 @@ pub struct OpponentBundle {
     pub strength: Strength,
     pub vulnerable: Vulnerable,
     pub weak: Weak,
+    pub poison: Poison,
     pub cost: Cost,
     pub deck: Deck,
     pub hand: Hand,
     pub discard_pile: DiscardPile,
+    pub play_log: PlayLog,
+    pub play_queue: PlayQueue,
+    pub free_cards: FreeCards,
+    pub clock: MatchClock,
 }
 
 impl OpponentBundle {
-    pub fn new(handle: usize, cost_rate: f32, initial_deck: Vec<CardId>, match_seed: u64) -> Self {
+    pub fn new(
+        handle: usize,
+        cost: Cost,
+        hp: f32,
+        initial_deck: Vec<CardId>,
+        match_seed: u64,
+    ) -> Self {
         let deck = Deck::new_with_seed(initial_deck, Deck::seed_for_handle(match_seed, handle));
 
         Self {
             name: Name::new("Opponent"),
             opponent: Opponent,
             handle: PlayerHandle(handle),
-            health: Health::new(INITIAL_HP),
+            health: Health::new(hp),
             block: Block::default(),
             thorns: Thorns::default(),
             strength: Strength::default(),
             vulnerable: Vulnerable::default(),
             weak: Weak::default(),
-            cost: Cost::new(cost_rate),
+            poison: Poison::default(),
+            cost,
             deck,
             hand: Hand::default(),
             discard_pile: DiscardPile::default(),
+            play_log: PlayLog::default(),
+            play_queue: PlayQueue::default(),
+            free_cards: FreeCards::default(),
+            clock: MatchClock::default(),
         }
     }
 }
@@ -159,6 +192,101 @@ pub fn create_test_deck() -> Vec<CardId> {
     deck
 }
 
+/// Create the Silent starter deck (poison and card flow).
+pub fn create_silent_deck() -> Vec<CardId> {
+    vec![
+        // === STARTER ===
+        CardId::Neutralize,
+        CardId::Neutralize, // 2x Neutralize - 30 damage + 1 weak
+        CardId::Survivor,
+        CardId::Survivor,
+        CardId::Survivor, // 3x Survivor - 80 block
+        // === COMMON ===
+        CardId::DeadlyPoison,
+        CardId::DeadlyPoison, // 2x Deadly Poison - 5 poison
+        CardId::PoisonedStab,
+        CardId::PoisonedStab, // 2x Poisoned Stab - 60 damage + 3 poison
+        CardId::Backflip,
+        CardId::Backflip, // 2x Backflip - 50 block + draw 2
+        CardId::DaggerSpray,
+        CardId::DaggerSpray, // 2x Dagger Spray - 40 damage x2
+        // === UNCOMMON ===
+        CardId::Acrobatics, // draw 3
+        CardId::BladeDance, // 40 damage x3
+        CardId::Catalyst,   // double poison
+        // === RARE ===
+        CardId::Adrenaline, // draw 2 + cost acceleration
+    ]
+}
+
+/// Create the starter deck for a character class.
+pub fn create_starter_deck(class: CharacterClass) -> Vec<CardId> {
+    match class {
+        CharacterClass::Ironclad => create_test_deck(),
+        CharacterClass::Silent => create_silent_deck(),
+    }
+}
+
+/// Build a player's starting deck for the selected deck mode and class.
+pub fn starting_deck(
+    mode: DeckMode,
+    class: CharacterClass,
+    registry: &CardRegistry,
+    match_seed: u64,
+    handle: usize,
+) -> Vec<CardId> {
+    match mode {
+        DeckMode::Starter => create_starter_deck(class),
+        DeckMode::Random => {
+            // Rotate the seed so deck generation doesn't replay the shuffle sequence.
+            let seed = Deck::seed_for_handle(match_seed.rotate_left(32), handle);
+            create_random_deck(registry, class, seed)
+        }
+        // The Draft screen deals drafted decks; this is only the fallback.
+        DeckMode::Draft => create_starter_deck(class),
+    }
+}
+
+/// Create a legal random deck from every playable card of a class.
+/// Deterministic for a given seed, so online peers generate identical decks.
+pub fn create_random_deck(
+    registry: &CardRegistry,
+    class: CharacterClass,
+    seed: u64,
+) -> Vec<CardId> {
+    let pool: Vec<CardId> = registry
+        .iter()
+        .filter(|def| def.id.class() == Some(class))
+        .filter(|def| def.card_type != CardType::Status && def.is_playable())
+        .flat_map(|def| std::iter::repeat_n(def.id, RANDOM_DECK_COPY_LIMIT))
+        .collect();
+
+    let mut pool = Deck::new_with_seed(pool, seed);
+    (0..RANDOM_DECK_SIZE).map_while(|_| pool.draw()).collect()
+}
+
+/// Roll distinct reward cards from a class pool, weighted by rarity.
+pub fn roll_card_reward(registry: &CardRegistry, class: CharacterClass, seed: u64) -> Vec<CardId> {
+    let pool: Vec<CardId> = registry
+        .iter()
+        .filter(|def| def.id.class() == Some(class))
+        .filter(|def| def.card_type != CardType::Status && def.is_playable())
+        .flat_map(|def| std::iter::repeat_n(def.id, def.rarity.reward_weight()))
+        .collect();
+
+    let mut pool = Deck::new_with_seed(pool, seed);
+    let mut picks = Vec::with_capacity(REWARD_CHOICES);
+    while picks.len() < REWARD_CHOICES {
+        let Some(card) = pool.draw() else {
+            break;
+        };
+        if !picks.contains(&card) {
+            picks.push(card);
+        }
+    }
+    picks
+}
+
 pub fn opponent_entity(player: Entity, players: &Query<(Entity, &PlayerHandle)>) -> Option<Entity> {
     let Ok((_, handle)) = players.get(player) else {
         return None;
@@ -0,0 +1,182 @@
+//! Card reward after a Solo victory. Picked cards join the saved deck for the
+//! class, which Solo matches then start with.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use super::{
+    CardId, CardRegistry, CharacterClass, DeckMode, GameResult, MatchSeed, create_starter_deck,
+    is_offline, roll_card_reward,
+};
+use crate::{
+    persist, replay::is_replay, run::RunState, sandbox::Sandbox, screens::Screen, theme::widget,
+    tutorial::Tutorial,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<SavedDecks>();
+    app.init_resource::<SoloReward>();
+    app.add_systems(PostStartup, load_saved_decks);
+    app.add_systems(
+        OnEnter(GameResult::Victory),
+        // Watching a replay earns nothing.
+        offer_solo_reward.run_if(is_offline.and(not(is_replay))),
+    );
+    app.add_systems(OnExit(Screen::Gameplay), clear_solo_reward);
+}
+
+/// Solo decks grown by card rewards, per class.
+#[derive(Resource, Default)]
+pub struct SavedDecks(HashMap<CharacterClass, Vec<CardId>>);
+
+impl SavedDecks {
+    /// The saved deck for a class, or its starter deck if nothing is saved.
+    pub fn deck(&self, class: CharacterClass) -> Vec<CardId> {
+        self.0
+            .get(&class)
+            .cloned()
+            .unwrap_or_else(|| create_starter_deck(class))
+    }
+
+    /// Saved decks store one card name per line.
+    pub(crate) fn load(registry: &CardRegistry) -> Self {
+        let mut saved = Self::default();
+        for class in [CharacterClass::Ironclad, CharacterClass::Silent] {
+            let Some(contents) = persist::load(&save_key(class)) else {
+                continue;
+            };
+            let deck: Vec<CardId> = contents
+                .lines()
+                .filter_map(|name| registry.get_by_name(name.trim()))
+                .map(|def| def.id)
+                .collect();
+            if !deck.is_empty() {
+                saved.0.insert(class, deck);
+            }
+        }
+        saved
+    }
+
+    fn add(&mut self, class: CharacterClass, card: CardId, registry: &CardRegistry) {
+        let mut deck = self.deck(class);
+        deck.push(card);
+        let contents: Vec<&str> = deck
+            .iter()
+            .filter_map(|card| registry.get(*card))
+            .map(|def| def.name.as_str())
+            .collect();
+        persist::store(&save_key(class), &contents.join("\n"));
+        self.0.insert(class, deck);
+    }
+}
+
+/// Reward cards waiting for a pick after a Solo victory.
+#[derive(Resource, Default)]
+pub struct SoloReward(Option<(CharacterClass, Vec<CardId>)>);
+
+impl SoloReward {
+    pub fn is_pending(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+#[derive(Component)]
+struct RewardPanel;
+
+fn save_key(class: CharacterClass) -> String {
+    format!("deck_{}.txt", format!("{class:?}").to_lowercase())
+}
+
+fn load_saved_decks(mut saved: ResMut<SavedDecks>, registry: Res<CardRegistry>) {
+    *saved = SavedDecks::load(&registry);
+}
+
+fn offer_solo_reward(
+    mut commands: Commands,
+    mut reward: ResMut<SoloReward>,
+    class: Res<CharacterClass>,
+    deck_mode: Res<DeckMode>,
+    run: Res<RunState>,
+    tutorial: Res<Tutorial>,
+    sandbox: Res<Sandbox>,
+    match_seed: Res<MatchSeed>,
+    registry: Res<CardRegistry>,
+) {
+    // Runs have their own rewards, random decks aren't saved, and the tutorial
+    // and sandbox shouldn't change the saved deck.
+    if run.is_active() || *deck_mode != DeckMode::Starter || tutorial.active || sandbox.active {
+        return;
+    }
+    let cards = roll_card_reward(&registry, *class, match_seed.0.rotate_left(48));
+    if cards.is_empty() {
+        return;
+    }
+
+    let panel = commands
+        .spawn((
+            Name::new("Reward Panel"),
+            RewardPanel,
+            Node {
+                position_type: PositionType::Absolute,
+                width: percent(100),
+                bottom: percent(8),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: px(12),
+                ..default()
+            },
+            GlobalZIndex(3),
+            DespawnOnExit(Screen::Gameplay),
+        ))
+        .id();
+    commands.entity(panel).with_children(|parent| {
+        parent.spawn(widget::label("Add a card to your deck"));
+        parent
+            .spawn(Node {
+                column_gap: px(12),
+                ..default()
+            })
+            .with_children(|row| {
+                for (index, card) in cards.iter().enumerate() {
+                    let Some(def) = registry.get(*card) else {
+                        continue;
+                    };
+                    row.spawn(widget::button(
+                        format!("{} ({:?})", def.name, def.rarity),
+                        pick_reward(Some(index)),
+                    ));
+                }
+            });
+        parent.spawn(widget::button("Skip", pick_reward(None)));
+    });
+
+    reward.0 = Some((*class, cards));
+}
+
+fn pick_reward(
+    index: Option<usize>,
+) -> impl FnMut(
+    On<Pointer<Click>>,
+    Commands,
+    ResMut<SoloReward>,
+    ResMut<SavedDecks>,
+    Res<CardRegistry>,
+    Query<Entity, With<RewardPanel>>,
+) {
+    move |_, mut commands, mut reward, mut saved, registry, panel| {
+        let Some((class, cards)) = reward.0.take() else {
+            return;
+        };
+        if let Some(card) = index.and_then(|index| cards.get(index)) {
+            saved.add(class, *card, &registry);
+        }
+        for entity in &panel {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn clear_solo_reward(mut reward: ResMut<SoloReward>) {
+    reward.0 = None;
+}
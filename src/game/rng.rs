@@ -0,0 +1,142 @@
+//! Shared randomness for card effects and everything else that rolls dice.
+//!
+//! [`GameRng`] is seeded from the [`MatchSeed`](super::MatchSeed) when a match
+//! starts, so both peers roll the same numbers. Each subsystem draws from its
+//! own [`RngStream`]: a new random effect in one stream never shifts the rolls
+//! of another, so adding one can't desync matches that don't use it. It rolls
+//! back with the players, so a re-simulated frame rolls exactly as before.
+//!
+//! Code that rolls outside a match (the mutator offer, the run map, bots)
+//! builds a separate `GameRng` from its own seed and draws from its stream.
+//! Deck shuffles and draws keep their own generator in [`Deck`](super::Deck).
+
+use bevy::prelude::*;
+
+/// A subsystem with its own sequence of random numbers. Add a variant (and
+/// list it in [`RngStream::ALL`]) for each new kind of random effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RngStream {
+    /// Cards created by effects (Infernal Blade).
+    CardGeneration,
+    /// Which of several targets an effect hits.
+    Targeting,
+    /// The mutator offer and the veto tie-break in the lobby.
+    Mutators,
+    /// Node layout of a run's map.
+    RunMap,
+    /// Which card a `--bot` client plays next.
+    Bot,
+}
+
+impl RngStream {
+    pub const ALL: [Self; 5] = [
+        Self::CardGeneration,
+        Self::Targeting,
+        Self::Mutators,
+        Self::RunMap,
+        Self::Bot,
+    ];
+
+    /// Mixed into the match seed, so streams start far apart. Never change an
+    /// existing salt: both peers must derive the same streams.
+    fn salt(self) -> u64 {
+        match self {
+            Self::CardGeneration => 0x6361_7264_6765_6e31,
+            Self::Targeting => 0x7461_7267_6574_7331,
+            Self::Mutators => 0x6d75_7461_746f_7231,
+            Self::RunMap => 0x7275_6e6d_6170_3031,
+            Self::Bot => 0x626f_7470_6c61_7931,
+        }
+    }
+}
+
+/// One generator per [`RngStream`], all derived from the match seed.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct GameRng {
+    states: [u64; RngStream::ALL.len()],
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl GameRng {
+    pub fn new(match_seed: u64) -> Self {
+        Self {
+            states: RngStream::ALL.map(|stream| splitmix(match_seed ^ stream.salt())),
+        }
+    }
+
+    /// The next number in `stream` (SplitMix64).
+    pub fn next_u64(&mut self, stream: RngStream) -> u64 {
+        let state = &mut self.states[stream as usize];
+        *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        splitmix(*state)
+    }
+
+    /// A number in `0..bound`. `bound` must not be zero.
+    pub fn below(&mut self, stream: RngStream, bound: usize) -> usize {
+        (self.next_u64(stream) % bound as u64) as usize
+    }
+
+    /// A random item of `items`, or `None` if it's empty.
+    pub fn pick<'a, T>(&mut self, stream: RngStream, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            return None;
+        }
+        Some(&items[self.below(stream, items.len())])
+    }
+}
+
+/// The SplitMix64 output function.
+fn splitmix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rolls(rng: &mut GameRng, stream: RngStream) -> Vec<u64> {
+        (0..16).map(|_| rng.next_u64(stream)).collect()
+    }
+
+    #[test]
+    fn same_seed_rolls_the_same() {
+        let mut a = GameRng::new(42);
+        let mut b = GameRng::new(42);
+        for stream in RngStream::ALL {
+            assert_eq!(rolls(&mut a, stream), rolls(&mut b, stream));
+        }
+        assert_ne!(
+            rolls(&mut GameRng::new(43), RngStream::Targeting),
+            rolls(&mut GameRng::new(42), RngStream::Targeting)
+        );
+    }
+
+    #[test]
+    fn streams_are_independent() {
+        let mut quiet = GameRng::new(7);
+        let mut busy = GameRng::new(7);
+        // Rolling one stream leaves the others where they were.
+        rolls(&mut busy, RngStream::Targeting);
+        assert_eq!(
+            rolls(&mut quiet, RngStream::CardGeneration),
+            rolls(&mut busy, RngStream::CardGeneration)
+        );
+    }
+
+    #[test]
+    fn pick_stays_in_bounds() {
+        let mut rng = GameRng::new(1);
+        let items = [1, 2, 3];
+        for _ in 0..100 {
+            assert!(items.contains(rng.pick(RngStream::Targeting, &items).unwrap()));
+        }
+        assert_eq!(rng.pick::<u8>(RngStream::Targeting, &[]), None);
+    }
+}
@@ -1,8 +1,238 @@
-/// Initial HP for all players.
-pub const INITIAL_HP: f32 = 1000.0;
-/// Number of cards to draw.
-pub const DRAW_COUNT: usize = 1;
+//! Match rules: the tuned balance numbers in [`Rules`], and fixed limits.
+//!
+//! [`Rules`] comes from `assets/data/game.balance.ron`, so a balance pass
+//! doesn't need a recompile. Native dev builds reload it when the file is
+//! saved. Online, peers compare [`Rules::fingerprint`] in the lobby and
+//! refuse to pair if their files differ.
+
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+
+use super::GameMode;
+use crate::asset_tracking::LoadResource;
+
+const RULES_PATH: &str = "data/game.balance.ron";
+
 /// Maximum number of cards supported by input flags.
 pub const MAX_HAND_SIZE: usize = 10;
-/// Block decay rate per second.
-pub const BLOCK_DECAY_RATE: f32 = 20.0;
+/// HP lost per poison stack on each poison tick.
+pub const POISON_DAMAGE_PER_STACK: f32 = 10.0;
+/// Seconds between poison ticks.
+pub const POISON_TICK_INTERVAL: f32 = 1.0;
+/// Number of cards in a generated random deck.
+pub const RANDOM_DECK_SIZE: usize = 20;
+/// Maximum copies of a single card in a generated random deck.
+pub const RANDOM_DECK_COPY_LIMIT: usize = 2;
+/// Cards offered by a card reward.
+pub const REWARD_CHOICES: usize = 3;
+/// Seconds between sudden death hits once the match timer runs out.
+pub const SUDDEN_DEATH_INTERVAL: f32 = 1.0;
+/// HP lost on the first sudden death hit; each later hit adds this much again.
+pub const SUDDEN_DEATH_DAMAGE: f32 = 5.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_asset::<Rules>();
+    app.register_asset_loader(RulesLoader);
+    app.load_resource::<RulesAssets>();
+    app.add_systems(Update, apply_loaded_rules);
+}
+
+/// Balance numbers read from the balance file. Starts at the defaults, which
+/// match the shipped file, until it has loaded.
+#[derive(Resource, Asset, TypePath, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Rules {
+    /// HP both players start a match with.
+    pub initial_hp: f32,
+    /// Cards drawn by one draw.
+    pub draw_count: usize,
+    /// Cost of a draw for each card already in hand; an empty hand draws free.
+    pub draw_cost_per_card: f32,
+    /// Cost a player can hold before extra gain burns off.
+    pub max_cost: f32,
+    /// Block lost per second.
+    pub block_decay_rate: f32,
+    /// Attack damage added by each point of Strength.
+    pub strength_damage: f32,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            initial_hp: 1000.0,
+            draw_count: 1,
+            draw_cost_per_card: 1.0,
+            max_cost: 10.0,
+            block_decay_rate: 20.0,
+            strength_damage: 10.0,
+        }
+    }
+}
+
+impl Rules {
+    /// Cost of drawing with `hand_size` cards in hand.
+    pub fn draw_cost(&self, hand_size: usize) -> f32 {
+        hand_size as f32 * self.draw_cost_per_card
+    }
+
+    /// Why these numbers can't be played with, if they can't.
+    fn validate(&self) -> Result<(), String> {
+        let non_negative = [
+            ("draw_cost_per_card", self.draw_cost_per_card),
+            ("block_decay_rate", self.block_decay_rate),
+            ("strength_damage", self.strength_damage),
+        ];
+        for (name, value) in non_negative {
+            if !value.is_finite() || value < 0.0 {
+                return Err(format!("{name} must be a non-negative number, got {value}"));
+            }
+        }
+        let positive = [("initial_hp", self.initial_hp), ("max_cost", self.max_cost)];
+        for (name, value) in positive {
+            if !value.is_finite() || value <= 0.0 {
+                return Err(format!("{name} must be positive, got {value}"));
+            }
+        }
+        if !(1..=MAX_HAND_SIZE).contains(&self.draw_count) {
+            return Err(format!(
+                "draw_count must be between 1 and {MAX_HAND_SIZE}, got {}",
+                self.draw_count
+            ));
+        }
+        Ok(())
+    }
+
+    /// A hash of every number (FNV-1a over the RON form), equal on two
+    /// machines exactly when their rules are.
+    pub fn fingerprint(&self) -> u64 {
+        let text = ron::to_string(self).unwrap_or_default();
+        text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+    }
+}
+
+#[derive(Default, TypePath)]
+struct RulesLoader;
+
+impl AssetLoader for RulesLoader {
+    type Asset = Rules;
+    type Settings = ();
+    type Error = BevyError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let rules: Rules = ron::de::from_bytes(&bytes)?;
+        rules.validate()?;
+        Ok(rules)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["balance.ron"]
+    }
+}
+
+/// Keeps the balance file loaded, so edits to it are picked up.
+#[derive(Resource, Asset, Clone, Reflect)]
+#[reflect(Resource)]
+struct RulesAssets {
+    #[dependency]
+    rules: Handle<Rules>,
+}
+
+impl FromWorld for RulesAssets {
+    fn from_world(world: &mut World) -> Self {
+        let assets = world.resource::<AssetServer>();
+        Self {
+            rules: assets.load(RULES_PATH),
+        }
+    }
+}
+
+/// Copy the balance file into [`Rules`] when it loads, and again whenever it
+/// is edited. A file that fails validation is logged and leaves the rules as
+/// they were.
+fn apply_loaded_rules(
+    mut events: MessageReader<AssetEvent<Rules>>,
+    loaded: Res<Assets<Rules>>,
+    mode: Res<GameMode>,
+    mut rules: ResMut<Rules>,
+) {
+    for event in events.read() {
+        let (AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id }) = *event
+        else {
+            continue;
+        };
+        let Some(new_rules) = loaded.get(id) else {
+            continue;
+        };
+        if *new_rules == *rules {
+            continue;
+        }
+        if *mode == GameMode::Online {
+            warn!("Balance file changed during an online match; the peers will desync");
+        }
+        info!("Loaded balance rules {:016x}", new_rules.fingerprint());
+        *rules = new_rules.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shipped_balance_file_matches_the_defaults() {
+        let text = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/assets/data/game.balance.ron"
+        ))
+        .unwrap();
+        let rules: Rules = ron::from_str(&text).unwrap();
+        assert_eq!(rules.validate(), Ok(()));
+        assert_eq!(rules, Rules::default());
+    }
+
+    #[test]
+    fn fingerprint_changes_with_any_number() {
+        let rules = Rules::default();
+        assert_eq!(rules.fingerprint(), Rules::default().fingerprint());
+        let tweaked = Rules {
+            strength_damage: 11.0,
+            ..default()
+        };
+        assert_ne!(tweaked.fingerprint(), rules.fingerprint());
+        let deeper = Rules {
+            max_cost: 12.0,
+            ..default()
+        };
+        assert_ne!(deeper.fingerprint(), rules.fingerprint());
+    }
+
+    #[test]
+    fn validation_rejects_unplayable_numbers() {
+        let no_draw = Rules {
+            draw_count: 0,
+            ..default()
+        };
+        assert!(no_draw.validate().is_err());
+        let dead_on_arrival = Rules {
+            initial_hp: 0.0,
+            ..default()
+        };
+        assert!(dead_on_arrival.validate().is_err());
+        let broke = Rules {
+            max_cost: 0.0,
+            ..default()
+        };
+        assert!(broke.validate().is_err());
+    }
+}
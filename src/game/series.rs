@@ -0,0 +1,141 @@
+//! Online series: a single game or best of three.
+//!
+//! Both peers record every game from their own side, so the score stays in
+//! step without being sent. Later games reuse the GGRS session and start from
+//! a seed derived from the first game's, so both decks reshuffle the same way.
+
+use bevy::prelude::*;
+
+use super::{GameResult, MatchSeed, is_online};
+use crate::screens::Screen;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<MatchFormat>();
+    app.init_resource::<Series>();
+    app.add_systems(OnEnter(Screen::Gameplay), start_series_game);
+    app.add_systems(OnEnter(GameResult::Victory), record_win.run_if(is_online));
+    app.add_systems(OnEnter(GameResult::Defeat), record_loss.run_if(is_online));
+}
+
+/// How many games an online match lasts; picked in the lobby.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MatchFormat {
+    #[default]
+    Single,
+    BestOfThree,
+}
+
+impl MatchFormat {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Single => "Single Game",
+            Self::BestOfThree => "Best of Three",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Single => Self::BestOfThree,
+            Self::BestOfThree => Self::Single,
+        }
+    }
+
+    /// Games a player has to win to take the series.
+    pub fn wins_needed(self) -> u8 {
+        match self {
+            Self::Single => 1,
+            Self::BestOfThree => 2,
+        }
+    }
+}
+
+/// Score of the current online series, from the local player's side.
+#[derive(Resource, Debug, Default)]
+pub struct Series {
+    pub wins: u8,
+    pub losses: u8,
+    /// Seed of the first game; later games derive theirs from it.
+    first_seed: u64,
+    /// The local player asked for the next game.
+    pub ready: bool,
+    /// Gameplay is being re-entered for the next game rather than left.
+    pub advancing: bool,
+}
+
+impl Series {
+    pub fn games_played(&self) -> u8 {
+        self.wins + self.losses
+    }
+
+    pub fn is_decided(&self, format: MatchFormat) -> bool {
+        self.wins.max(self.losses) >= format.wins_needed()
+    }
+
+    /// Seed for the next game, the same on both peers.
+    pub fn next_seed(&self) -> u64 {
+        let game = self.games_played() as u64;
+        self.first_seed ^ game.wrapping_mul(0x9e3779b97f4a7c15).rotate_left(17)
+    }
+}
+
+/// Start a fresh series unless this is the next game of the current one.
+fn start_series_game(mut series: ResMut<Series>) {
+    if series.advancing {
+        series.advancing = false;
+        series.ready = false;
+    } else {
+        *series = Series::default();
+    }
+}
+
+fn record_win(seed: Res<MatchSeed>, mut series: ResMut<Series>) {
+    record_game(&seed, &mut series, true);
+}
+
+fn record_loss(seed: Res<MatchSeed>, mut series: ResMut<Series>) {
+    record_game(&seed, &mut series, false);
+}
+
+fn record_game(seed: &MatchSeed, series: &mut Series, won: bool) {
+    if series.games_played() == 0 {
+        series.first_seed = seed.0;
+    }
+    if won {
+        series.wins += 1;
+    } else {
+        series.losses += 1;
+    }
+    info!("Series {}-{}", series.wins, series.losses);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn best_of_three_needs_two_wins() {
+        let mut series = Series::default();
+        record_game(&MatchSeed(7), &mut series, true);
+        assert!(series.is_decided(MatchFormat::Single));
+        assert!(!series.is_decided(MatchFormat::BestOfThree));
+        record_game(&MatchSeed(99), &mut series, false);
+        assert!(!series.is_decided(MatchFormat::BestOfThree));
+        record_game(&MatchSeed(99), &mut series, true);
+        assert!(series.is_decided(MatchFormat::BestOfThree));
+    }
+
+    #[test]
+    fn each_game_gets_its_own_seed_from_the_first() {
+        let mut series = Series::default();
+        record_game(&MatchSeed(7), &mut series, true);
+        let second = series.next_seed();
+        record_game(&MatchSeed(second), &mut series, false);
+        let third = series.next_seed();
+        assert_ne!(second, 7);
+        assert_ne!(second, third);
+
+        let mut mirrored = Series::default();
+        record_game(&MatchSeed(7), &mut mirrored, false);
+        assert_eq!(mirrored.next_seed(), second);
+    }
+}
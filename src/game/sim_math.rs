@@ -0,0 +1,193 @@
+//! Deterministic arithmetic for rolled-back game state.
+//!
+//! Health, Cost, Block and the status timers are `f32`s nudged a little every
+//! frame. Every change to them is snapped onto a grid of 1/16384, so the
+//! per-frame steps add up exactly: the result doesn't depend on rounding,
+//! on the order steps are applied in, or on how many frames a rollback
+//! resimulates at once.
+//!
+//! Sums on the grid are only exact below 1024 in magnitude. HP starts at
+//! 1000 and block can stack far past it (Entrench, Second Wind), and there
+//! `f32` spacing is coarser than the grid, so a step rounds to the nearest
+//! representable value instead. That rounding is the same on every machine:
+//! IEEE-754 addition is correctly rounded and every peer applies the same
+//! steps in the same order, so large values stay bit-identical between runs
+//! and after rollbacks; they just aren't exact sums any more.
+
+/// Finest step a rolled-back value can take.
+pub const SIM_QUANTUM: f32 = 1.0 / 16384.0;
+
+/// Snap a value onto the simulation grid.
+pub fn quantize(value: f32) -> f32 {
+    (value / SIM_QUANTUM).round() * SIM_QUANTUM
+}
+
+/// How much a per-second rate moves a value over one frame.
+pub fn per_tick(rate: f32, delta: f32) -> f32 {
+    quantize(rate * delta)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{Block, Cost, DemonFormEffect, Health, Poison, Rules, Strength};
+
+    /// The online tick length.
+    const DELTA: f32 = 1.0 / 60.0;
+    const FRAMES: usize = 60 * 60;
+    /// Ten seconds in, before the scripted self-damage empties the HP.
+    const GOLDEN_FRAMES: usize = 10 * 60;
+    /// [`Sim::new`] after [`GOLDEN_FRAMES`], as recorded on the current grid.
+    /// A change to [`SIM_QUANTUM`], the rounding or the step order shows up
+    /// here; update the values only when that change is intended.
+    const GOLDEN_BITS: [u32; 7] = [
+        0x400ca600, 0x44388e14, 0x40023900, 0x40800000, 0x3ebab000, 0x3b400000, 0x40400000,
+    ];
+    /// [`Sim::large`] after [`FRAMES`].
+    const GOLDEN_LARGE_BITS: [u32; 7] = [
+        0x410dddc0, 0x472e11a2, 0x4718b18f, 0x41800000, 0x3eb47000, 0x3c900000, 0x41900000,
+    ];
+
+    #[derive(Clone)]
+    struct Sim {
+        cost: Cost,
+        health: Health,
+        block: Block,
+        poison: Poison,
+        demon: DemonFormEffect,
+        strength: Strength,
+    }
+
+    impl Sim {
+        fn new() -> Self {
+            Self {
+                cost: Cost::new(1.0, Rules::default().max_cost),
+                health: Health::new(Rules::default().initial_hp),
+                block: Block::default(),
+                poison: Poison::default(),
+                demon: DemonFormEffect::new(0.3),
+                strength: Strength::default(),
+            }
+        }
+
+        /// A long match: HP and block both past the exactly summed range.
+        fn large() -> Self {
+            let mut sim = Self::new();
+            sim.health = Health::new(Rules::default().initial_hp * 50.0);
+            sim.block.gain(40_000.0);
+            sim
+        }
+
+        /// One frame of the timed systems, plus a scripted card every 97 frames.
+        fn step(&mut self, frame: usize) {
+            let delta = quantize(DELTA);
+            self.cost.accumulate(delta);
+            self.demon.tick(delta, &mut self.strength);
+            self.poison.tick(delta, &mut self.health);
+            self.block
+                .decay(per_tick(Rules::default().block_decay_rate, delta));
+            if frame.is_multiple_of(97) && self.cost.try_spend(1.3) {
+                self.block.gain(7.7);
+                self.poison.apply(2.0);
+                self.health
+                    .take_damage(3.3 * (1.0 + self.strength.amount * 0.1));
+            }
+        }
+
+        fn bits(&self) -> [u32; 7] {
+            [
+                self.cost.current.to_bits(),
+                self.health.current.to_bits(),
+                self.block.current.to_bits(),
+                self.poison.stacks.to_bits(),
+                self.poison.timer.to_bits(),
+                self.demon.accumulated.to_bits(),
+                self.strength.amount.to_bits(),
+            ]
+        }
+    }
+
+    fn run(frames: std::ops::Range<usize>, sim: &mut Sim) {
+        for frame in frames {
+            sim.step(frame);
+        }
+    }
+
+    #[test]
+    fn quantize_is_idempotent() {
+        for value in [0.0, 1.0 / 3.0, -2.7, 999.9, DELTA, 10.0] {
+            let once = quantize(value);
+            assert_eq!(quantize(once).to_bits(), once.to_bits());
+        }
+    }
+
+    #[test]
+    fn grid_sums_are_exact() {
+        let step = per_tick(1.0, DELTA);
+        let mut total = 0.0_f32;
+        for _ in 0..FRAMES {
+            total += step;
+        }
+        assert_eq!(total.to_bits(), (step * FRAMES as f32).to_bits());
+    }
+
+    #[test]
+    fn per_tick_steps_match_golden_bits() {
+        let delta = quantize(DELTA);
+        assert_eq!(delta.to_bits(), 0x3c888000);
+        let step = per_tick(20.0, delta);
+        assert_eq!(step.to_bits(), 0x3eaaa000);
+        let mut total = 0.0_f32;
+        for _ in 0..FRAMES {
+            total += step;
+        }
+        assert_eq!(total.to_bits(), 0x4495f6a0);
+    }
+
+    #[test]
+    fn simulation_matches_golden_bits() {
+        let mut sim = Sim::new();
+        run(0..GOLDEN_FRAMES, &mut sim);
+        assert_eq!(sim.bits(), GOLDEN_BITS);
+    }
+
+    #[test]
+    fn resimulating_after_rollback_matches_golden_bits() {
+        let mut sim = Sim::new();
+        run(0..GOLDEN_FRAMES / 2, &mut sim);
+        let mut resimulated = sim.clone();
+        run(GOLDEN_FRAMES / 2..GOLDEN_FRAMES, &mut sim);
+        run(GOLDEN_FRAMES / 2..GOLDEN_FRAMES, &mut resimulated);
+        assert_eq!(sim.bits(), GOLDEN_BITS);
+        assert_eq!(resimulated.bits(), GOLDEN_BITS);
+    }
+
+    #[test]
+    fn state_stays_on_the_grid() {
+        let mut sim = Sim::new();
+        for frame in 0..FRAMES {
+            sim.step(frame);
+            for value in [
+                sim.cost.current,
+                sim.health.current,
+                sim.block.current,
+                sim.poison.timer,
+                sim.demon.accumulated,
+            ] {
+                assert_eq!(quantize(value).to_bits(), value.to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn large_values_match_golden_bits() {
+        let mut sim = Sim::large();
+        run(0..FRAMES / 2, &mut sim);
+        let mut resimulated = sim.clone();
+        run(FRAMES / 2..FRAMES, &mut sim);
+        run(FRAMES / 2..FRAMES, &mut resimulated);
+        assert!(sim.health.current > 1024.0 && sim.block.current > 1024.0);
+        assert_eq!(sim.bits(), GOLDEN_LARGE_BITS);
+        assert_eq!(resimulated.bits(), GOLDEN_LARGE_BITS);
+    }
+}
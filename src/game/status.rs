@@ -1,43 +1,16 @@
 //! Status effects system - buffs, debuffs, and persistent effects.
 
 use bevy::prelude::*;
-use bevy_ggrs::{GgrsSchedule, GgrsTime};
-
-use crate::{
-    AppSystems,
-    game::{
-        BLOCK_DECAY_RATE, GameResult, GameplaySystems, PlayerHandle, is_offline, is_online,
-        opponent_entity,
-    },
-    screens::Screen,
+
+use crate::game::{
+    AddGameplaySystems, GameplaySystems, POISON_DAMAGE_PER_STACK, POISON_TICK_INTERVAL,
+    PlayerHandle, Rules, SimTime, opponent_entity, per_tick, quantize,
 };
 
 pub fn plugin(app: &mut App) {
-    app.add_systems(
-        Update,
-        (
-            tick_status_effects_offline,
-            tick_power_effects_offline,
-            tick_block_decay_offline,
-        )
-            .chain()
-            .in_set(AppSystems::TickTimers)
-            .run_if(is_offline)
-            .run_if(in_state(Screen::Gameplay))
-            .run_if(in_state(GameResult::Playing)),
-    );
-    app.add_systems(
-        GgrsSchedule,
-        (
-            tick_status_effects_online,
-            tick_power_effects_online,
-            tick_block_decay_online,
-        )
-            .chain()
-            .in_set(GameplaySystems::Tick)
-            .run_if(is_online)
-            .run_if(in_state(Screen::Gameplay))
-            .run_if(in_state(GameResult::Playing)),
+    app.add_gameplay_systems(
+        GameplaySystems::Tick,
+        (tick_status_effects, tick_power_effects, tick_block_decay),
     );
 }
 
@@ -52,11 +25,6 @@ impl Strength {
     pub fn gain(&mut self, amount: f32) {
         self.amount += amount;
     }
-
-    #[allow(dead_code)]
-    pub fn modify_damage(&self, base_damage: f32) -> f32 {
-        (base_damage + self.amount * 10.0).max(0.0)
-    }
 }
 
 /// Vulnerable - takes 50% more damage. Duration in seconds.
@@ -68,7 +36,7 @@ pub struct Vulnerable {
 
 impl Vulnerable {
     pub fn apply(&mut self, duration: f32) {
-        self.duration = (self.duration + duration).max(0.0);
+        self.duration = quantize((self.duration + duration).max(0.0));
     }
 
     pub fn is_active(&self) -> bool {
@@ -93,7 +61,7 @@ pub struct Weak {
 
 impl Weak {
     pub fn apply(&mut self, duration: f32) {
-        self.duration = (self.duration + duration).max(0.0);
+        self.duration = quantize((self.duration + duration).max(0.0));
     }
 
     pub fn is_active(&self) -> bool {
@@ -109,6 +77,39 @@ impl Weak {
     }
 }
 
+/// Poison - lose HP every tick (ignoring block), then lose one stack.
+#[derive(Component, Debug, Default, Clone, Reflect)]
+#[reflect(Component)]
+pub struct Poison {
+    pub stacks: f32,
+    pub timer: f32,
+}
+
+impl Poison {
+    pub fn apply(&mut self, stacks: f32) {
+        self.stacks = (self.stacks + stacks).max(0.0);
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.stacks > 0.0
+    }
+
+    /// Advance the tick timer by one frame, dealing damage for each full
+    /// interval that passed.
+    pub fn tick(&mut self, delta: f32, health: &mut super::Health) {
+        if !self.is_active() {
+            self.timer = 0.0;
+            return;
+        }
+        self.timer += delta;
+        while self.timer >= POISON_TICK_INTERVAL && self.is_active() {
+            self.timer -= POISON_TICK_INTERVAL;
+            health.take_damage(self.stacks * POISON_DAMAGE_PER_STACK);
+            self.stacks = (self.stacks - 1.0).max(0.0);
+        }
+    }
+}
+
 /// Rage - gain block when playing attacks.
 #[derive(Component, Debug, Default, Clone, Reflect)]
 #[reflect(Component)]
@@ -121,7 +122,7 @@ impl RageEffect {
     pub fn new(block_per_attack: f32, duration: f32) -> Self {
         Self {
             block_per_attack,
-            duration,
+            duration: quantize(duration),
         }
     }
 
@@ -158,6 +159,16 @@ impl DemonFormEffect {
             accumulated: 0.0,
         }
     }
+
+    /// Build up one frame of strength, granting it in whole points.
+    pub fn tick(&mut self, delta: f32, strength: &mut Strength) {
+        self.accumulated += per_tick(self.strength_per_second, delta);
+        if self.accumulated >= 1.0 {
+            let gain = self.accumulated.floor();
+            strength.gain(gain);
+            self.accumulated -= gain;
+        }
+    }
 }
 
 /// Barricade - block doesn't decay.
@@ -284,52 +295,22 @@ impl BrutalityEffect {
         Self {
             self_damage_per_sec,
             draw,
-            draw_interval,
+            draw_interval: quantize(draw_interval),
             draw_timer: 0.0,
         }
     }
 }
 
 /// System to tick down status effect durations.
-fn tick_status_effects_offline(
-    time: Res<Time>,
-    vulnerable_query: Query<&mut Vulnerable>,
-    weak_query: Query<&mut Weak>,
-    rage_query: Query<&mut RageEffect>,
-    demon_query: Query<(&mut DemonFormEffect, &mut Strength)>,
-) {
-    tick_status_effects_delta(
-        time.delta_secs(),
-        vulnerable_query,
-        weak_query,
-        rage_query,
-        demon_query,
-    );
-}
-
-fn tick_status_effects_online(
-    time: Res<Time<GgrsTime>>,
-    vulnerable_query: Query<&mut Vulnerable>,
-    weak_query: Query<&mut Weak>,
-    rage_query: Query<&mut RageEffect>,
-    demon_query: Query<(&mut DemonFormEffect, &mut Strength)>,
-) {
-    tick_status_effects_delta(
-        time.delta_secs(),
-        vulnerable_query,
-        weak_query,
-        rage_query,
-        demon_query,
-    );
-}
-
-fn tick_status_effects_delta(
-    delta: f32,
+fn tick_status_effects(
+    time: SimTime,
     mut vulnerable_query: Query<&mut Vulnerable>,
     mut weak_query: Query<&mut Weak>,
     mut rage_query: Query<&mut RageEffect>,
     mut demon_query: Query<(&mut DemonFormEffect, &mut Strength)>,
+    mut poison_query: Query<(&mut Poison, &mut super::Health)>,
 ) {
+    let delta = quantize(time.delta_secs());
     for mut vulnerable in &mut vulnerable_query {
         if vulnerable.duration > 0.0 {
             vulnerable.duration = (vulnerable.duration - delta).max(0.0);
@@ -350,57 +331,17 @@ fn tick_status_effects_delta(
 
     // Demon Form: gain strength over time
     for (mut demon, mut strength) in &mut demon_query {
-        demon.accumulated += demon.strength_per_second * delta;
-        if demon.accumulated >= 1.0 {
-            let gain = demon.accumulated.floor();
-            strength.gain(gain);
-            demon.accumulated -= gain;
-        }
+        demon.tick(delta, &mut strength);
     }
-}
-
-fn tick_power_effects_offline(
-    time: Res<Time>,
-    players: Query<(Entity, &PlayerHandle)>,
-    metallicize_query: Query<(Entity, &MetallicizeEffect)>,
-    combust_query: Query<(Entity, &mut CombustEffect)>,
-    brutality_query: Query<(Entity, &mut BrutalityEffect)>,
-    health_block_query: Query<(&mut super::Health, &mut super::Block)>,
-    deck_query: Query<(&mut super::Deck, &mut super::Hand, &mut super::DiscardPile)>,
-) {
-    tick_power_effects_delta(
-        time.delta_secs(),
-        players,
-        metallicize_query,
-        combust_query,
-        brutality_query,
-        health_block_query,
-        deck_query,
-    );
-}
 
-fn tick_power_effects_online(
-    time: Res<Time<GgrsTime>>,
-    players: Query<(Entity, &PlayerHandle)>,
-    metallicize_query: Query<(Entity, &MetallicizeEffect)>,
-    combust_query: Query<(Entity, &mut CombustEffect)>,
-    brutality_query: Query<(Entity, &mut BrutalityEffect)>,
-    health_block_query: Query<(&mut super::Health, &mut super::Block)>,
-    deck_query: Query<(&mut super::Deck, &mut super::Hand, &mut super::DiscardPile)>,
-) {
-    tick_power_effects_delta(
-        time.delta_secs(),
-        players,
-        metallicize_query,
-        combust_query,
-        brutality_query,
-        health_block_query,
-        deck_query,
-    );
+    // Poison: discrete ticks that bypass block and decay by one stack
+    for (mut poison, mut health) in &mut poison_query {
+        poison.tick(delta, &mut health);
+    }
 }
 
-fn tick_power_effects_delta(
-    delta: f32,
+fn tick_power_effects(
+    time: SimTime,
     players: Query<(Entity, &PlayerHandle)>,
     metallicize_query: Query<(Entity, &MetallicizeEffect)>,
     mut combust_query: Query<(Entity, &mut CombustEffect)>,
@@ -408,36 +349,36 @@ fn tick_power_effects_delta(
     mut health_block_query: Query<(&mut super::Health, &mut super::Block)>,
     mut deck_query: Query<(&mut super::Deck, &mut super::Hand, &mut super::DiscardPile)>,
 ) {
+    let delta = quantize(time.delta_secs());
     // Metallicize: directly add block
     for (entity, metallicize) in &metallicize_query {
-        if metallicize.block_per_second > 0.0 {
-            if let Ok((_, mut block)) = health_block_query.get_mut(entity) {
-                block.gain(metallicize.block_per_second * delta);
-            }
+        if metallicize.block_per_second > 0.0
+            && let Ok((_, mut block)) = health_block_query.get_mut(entity)
+        {
+            block.gain(per_tick(metallicize.block_per_second, delta));
         }
     }
 
     // Combust: continuous damage (applied every frame)
     for (entity, combust) in &mut combust_query {
         // Self damage (power damage bypasses block for self-inflicted)
-        if combust.self_damage_per_sec > 0.0 {
-            if let Ok((mut health, _)) = health_block_query.get_mut(entity) {
-                health.take_damage(combust.self_damage_per_sec * delta);
-            }
+        if combust.self_damage_per_sec > 0.0
+            && let Ok((mut health, _)) = health_block_query.get_mut(entity)
+        {
+            health.take_damage(per_tick(combust.self_damage_per_sec, delta));
         }
 
         // Enemy damage (goes through block)
-        if combust.enemy_damage_per_sec > 0.0 {
-            if let Some(opponent) = opponent_entity(entity, &players) {
-                if let Ok((mut health, mut block)) = health_block_query.get_mut(opponent) {
-                    let mut remaining = combust.enemy_damage_per_sec * delta;
-                    let absorbed = remaining.min(block.current);
-                    block.current = (block.current - absorbed).max(0.0);
-                    remaining -= absorbed;
-                    if remaining > 0.0 {
-                        health.take_damage(remaining);
-                    }
-                }
+        if combust.enemy_damage_per_sec > 0.0
+            && let Some(opponent) = opponent_entity(entity, &players)
+            && let Ok((mut health, mut block)) = health_block_query.get_mut(opponent)
+        {
+            let mut remaining = per_tick(combust.enemy_damage_per_sec, delta);
+            let absorbed = remaining.min(block.current);
+            block.decay(absorbed);
+            remaining -= absorbed;
+            if remaining > 0.0 {
+                health.take_damage(remaining);
             }
         }
     }
@@ -445,10 +386,10 @@ fn tick_power_effects_delta(
     // Brutality: continuous self damage + periodic draw
     for (entity, mut brutality) in &mut brutality_query {
         // Continuous self damage
-        if brutality.self_damage_per_sec > 0.0 {
-            if let Ok((mut health, _)) = health_block_query.get_mut(entity) {
-                health.take_damage(brutality.self_damage_per_sec * delta);
-            }
+        if brutality.self_damage_per_sec > 0.0
+            && let Ok((mut health, _)) = health_block_query.get_mut(entity)
+        {
+            health.take_damage(per_tick(brutality.self_damage_per_sec, delta));
         }
 
         // Periodic draw (discrete event, keeps timer)
@@ -475,27 +416,15 @@ fn tick_power_effects_delta(
 }
 
 /// System to decay block over time (unless Barricade is active).
-fn tick_block_decay_offline(
-    time: Res<Time>,
-    query: Query<&mut super::Block, Without<BarricadeEffect>>,
-) {
-    tick_block_decay_delta(time.delta_secs(), query);
-}
-
-fn tick_block_decay_online(
-    time: Res<Time<GgrsTime>>,
-    query: Query<&mut super::Block, Without<BarricadeEffect>>,
-) {
-    tick_block_decay_delta(time.delta_secs(), query);
-}
-
-fn tick_block_decay_delta(
-    delta: f32,
+fn tick_block_decay(
+    time: SimTime,
+    rules: Res<Rules>,
     mut query: Query<&mut super::Block, Without<BarricadeEffect>>,
 ) {
+    let decay = per_tick(rules.block_decay_rate, quantize(time.delta_secs()));
     for mut block in &mut query {
         if block.current > 0.0 {
-            block.current = (block.current - BLOCK_DECAY_RATE * delta).max(0.0);
+            block.decay(decay);
         }
     }
 }
@@ -0,0 +1,592 @@
+//! Status effect icon bars under each HP bar.
+//!
+//! One badge per active effect with its stack count and a bar for the time
+//! left on timed effects. Hovering a badge explains the effect.
+
+use bevy::{ecs::query::QueryData, prelude::*, window::PrimaryWindow};
+
+use super::{
+    Acceleration, BarricadeEffect, BrutalityEffect, CombustEffect, CorruptionEffect,
+    DarkEmbraceEffect, DemonFormEffect, EvolveEffect, FeelNoPainEffect, FireBreathingEffect,
+    GameResult, JuggernautEffect, LocalPlayer, MetallicizeEffect, Opponent, Poison, RageEffect,
+    RuptureEffect, Strength, Vulnerable, Weak,
+    ui::{OpponentStatusDisplay, PlayerStatusDisplay, StatusSummary},
+};
+use crate::{AppSystems, screens::Screen};
+
+const ICON_WIDTH: f32 = 52.0;
+const TOOLTIP_WIDTH: f32 = 260.0;
+const CURSOR_OFFSET: f32 = 16.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_status_tooltip);
+    app.add_systems(
+        Update,
+        (update_status_bars, update_status_tooltip)
+            .chain()
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay))
+            .run_if(in_state(GameResult::Playing)),
+    );
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum StatusKind {
+    Strength,
+    Vulnerable,
+    Weak,
+    Poison,
+    Acceleration,
+    Rage,
+    Metallicize,
+    DemonForm,
+    Barricade,
+    Combust,
+    Corruption,
+    Brutality,
+    DarkEmbrace,
+    Evolve,
+    FeelNoPain,
+    FireBreathing,
+    Rupture,
+    Juggernaut,
+}
+
+impl StatusKind {
+    fn abbreviation(self) -> &'static str {
+        match self {
+            Self::Strength => "STR",
+            Self::Vulnerable => "VUL",
+            Self::Weak => "WEAK",
+            Self::Poison => "PSN",
+            Self::Acceleration => "ACC",
+            Self::Rage => "RAGE",
+            Self::Metallicize => "MTL",
+            Self::DemonForm => "DMN",
+            Self::Barricade => "BAR",
+            Self::Combust => "CMB",
+            Self::Corruption => "COR",
+            Self::Brutality => "BRU",
+            Self::DarkEmbrace => "DKE",
+            Self::Evolve => "EVO",
+            Self::FeelNoPain => "FNP",
+            Self::FireBreathing => "FBR",
+            Self::Rupture => "RUP",
+            Self::Juggernaut => "JUG",
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Strength => "Strength",
+            Self::Vulnerable => "Vulnerable",
+            Self::Weak => "Weak",
+            Self::Poison => "Poison",
+            Self::Acceleration => "Acceleration",
+            Self::Rage => "Rage",
+            Self::Metallicize => "Metallicize",
+            Self::DemonForm => "Demon Form",
+            Self::Barricade => "Barricade",
+            Self::Combust => "Combust",
+            Self::Corruption => "Corruption",
+            Self::Brutality => "Brutality",
+            Self::DarkEmbrace => "Dark Embrace",
+            Self::Evolve => "Evolve",
+            Self::FeelNoPain => "Feel No Pain",
+            Self::FireBreathing => "Fire Breathing",
+            Self::Rupture => "Rupture",
+            Self::Juggernaut => "Juggernaut",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::Strength => "Each point adds damage to attacks.",
+            Self::Vulnerable => "Takes 50% more damage.",
+            Self::Weak => "Deals 25% less damage.",
+            Self::Poison => "Loses HP every tick, then loses a stack.",
+            Self::Acceleration => "Cost regenerates faster.",
+            Self::Rage => "Gain Block whenever you play an attack.",
+            Self::Metallicize => "Gain Block every second.",
+            Self::DemonForm => "Gain Strength every second.",
+            Self::Barricade => "Block no longer decays.",
+            Self::Combust => "Lose HP and damage the opponent every second.",
+            Self::Corruption => "Skills cost 0 and are exhausted when played.",
+            Self::Brutality => "Lose HP and draw cards over time.",
+            Self::DarkEmbrace => "Draw whenever a card is exhausted.",
+            Self::Evolve => "Draw whenever a status card is drawn.",
+            Self::FeelNoPain => "Gain Block whenever a card is exhausted.",
+            Self::FireBreathing => "Damage the opponent whenever a status card is drawn.",
+            Self::Rupture => "Gain Strength when your own cards cost you HP.",
+            Self::Juggernaut => "Damage the opponent whenever you gain Block.",
+        }
+    }
+
+    fn is_debuff(self) -> bool {
+        matches!(self, Self::Vulnerable | Self::Weak | Self::Poison)
+    }
+}
+
+/// One active effect as shown in the bar.
+struct StatusEntry {
+    kind: StatusKind,
+    /// Stack count or magnitude printed on the badge.
+    count: String,
+    /// Seconds left on timed effects.
+    remaining: Option<f32>,
+    /// Compact text form for the BRP status summary.
+    summary: String,
+}
+
+impl StatusEntry {
+    fn new(kind: StatusKind, count: String, remaining: Option<f32>, summary: String) -> Self {
+        Self {
+            kind,
+            count,
+            remaining,
+            summary,
+        }
+    }
+}
+
+/// A badge in a status bar.
+#[derive(Component)]
+struct StatusIcon {
+    kind: StatusKind,
+    /// Longest time left seen since the badge appeared, for the timer bar.
+    longest_remaining: f32,
+    tooltip: String,
+}
+
+#[derive(Component)]
+struct StatusIconLabel;
+
+#[derive(Component)]
+struct StatusIconTimer;
+
+#[derive(Component)]
+struct StatusTooltip;
+
+fn spawn_status_tooltip(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Status Tooltip"),
+        StatusTooltip,
+        Node {
+            position_type: PositionType::Absolute,
+            width: px(TOOLTIP_WIDTH),
+            padding: UiRect::all(px(8)),
+            ..default()
+        },
+        Text::new(""),
+        TextFont::from_font_size(14.0),
+        TextColor(Color::WHITE),
+        BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 0.92)),
+        Visibility::Hidden,
+        GlobalZIndex(1),
+        Pickable::IGNORE,
+        DespawnOnExit(Screen::Gameplay),
+    ));
+}
+
+fn update_status_bars(
+    mut commands: Commands,
+    player_query: Query<StatusQuery, (With<LocalPlayer>, Without<Opponent>)>,
+    opponent_query: Query<StatusQuery, (With<Opponent>, Without<LocalPlayer>)>,
+    player_bar: Query<Entity, With<PlayerStatusDisplay>>,
+    opponent_bar: Query<Entity, With<OpponentStatusDisplay>>,
+    children: Query<&Children>,
+    mut icons: Query<(&mut StatusIcon, &Children)>,
+    mut labels: Query<&mut Text, With<StatusIconLabel>>,
+    mut timers: Query<&mut Node, With<StatusIconTimer>>,
+    mut summary: ResMut<StatusSummary>,
+) {
+    if let (Ok(status), Ok(bar)) = (player_query.single(), player_bar.single()) {
+        let entries = status_entries(status);
+        let text = summary_string(&entries);
+        if summary.player != text {
+            summary.player = text;
+        }
+        sync_bar(
+            &mut commands,
+            bar,
+            &entries,
+            &children,
+            &mut icons,
+            &mut labels,
+            &mut timers,
+        );
+    }
+
+    if let (Ok(status), Ok(bar)) = (opponent_query.single(), opponent_bar.single()) {
+        let entries = status_entries(status);
+        let text = summary_string(&entries);
+        if summary.opponent != text {
+            summary.opponent = text;
+        }
+        sync_bar(
+            &mut commands,
+            bar,
+            &entries,
+            &children,
+            &mut icons,
+            &mut labels,
+            &mut timers,
+        );
+    }
+}
+
+fn summary_string(entries: &[StatusEntry]) -> String {
+    entries
+        .iter()
+        .map(|entry| entry.summary.as_str())
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+/// Update badges in place, or rebuild the bar when the set of effects changed.
+fn sync_bar(
+    commands: &mut Commands,
+    bar: Entity,
+    entries: &[StatusEntry],
+    children: &Query<&Children>,
+    icons: &mut Query<(&mut StatusIcon, &Children)>,
+    labels: &mut Query<&mut Text, With<StatusIconLabel>>,
+    timers: &mut Query<&mut Node, With<StatusIconTimer>>,
+) {
+    let existing: Vec<Entity> = children
+        .get(bar)
+        .map(|children| children.iter().collect())
+        .unwrap_or_default();
+    let unchanged = existing.len() == entries.len()
+        && existing.iter().zip(entries).all(|(icon, entry)| {
+            icons
+                .get(*icon)
+                .is_ok_and(|(icon, _)| icon.kind == entry.kind)
+        });
+
+    if !unchanged {
+        commands.entity(bar).despawn_children();
+        commands.entity(bar).with_children(|parent| {
+            for entry in entries {
+                parent.spawn(status_icon(entry));
+            }
+        });
+        return;
+    }
+
+    for (icon_entity, entry) in existing.into_iter().zip(entries) {
+        let Ok((mut icon, icon_children)) = icons.get_mut(icon_entity) else {
+            continue;
+        };
+        icon.tooltip = tooltip_text(entry);
+        let fraction = entry.remaining.map(|remaining| {
+            icon.longest_remaining = icon.longest_remaining.max(remaining);
+            remaining / icon.longest_remaining.max(f32::EPSILON)
+        });
+        for child in icon_children.iter() {
+            if let Ok(mut label) = labels.get_mut(child) {
+                let text = badge_text(entry);
+                if label.0 != text {
+                    label.0 = text;
+                }
+            }
+            if let (Ok(mut timer), Some(fraction)) = (timers.get_mut(child), fraction) {
+                let width = percent(fraction.clamp(0.0, 1.0) * 100.0);
+                if timer.width != width {
+                    timer.width = width;
+                }
+            }
+        }
+    }
+}
+
+fn badge_text(entry: &StatusEntry) -> String {
+    if entry.count.is_empty() {
+        entry.kind.abbreviation().to_string()
+    } else {
+        format!("{}\n{}", entry.kind.abbreviation(), entry.count)
+    }
+}
+
+fn tooltip_text(entry: &StatusEntry) -> String {
+    let mut text = format!("{}\n{}", entry.kind.name(), entry.kind.description());
+    if let Some(remaining) = entry.remaining {
+        text.push_str(&format!("\n{remaining:.1}s left"));
+    }
+    text
+}
+
+fn status_icon(entry: &StatusEntry) -> impl Bundle {
+    let color = if entry.kind.is_debuff() {
+        Color::srgb(0.45, 0.15, 0.2)
+    } else {
+        Color::srgb(0.15, 0.3, 0.45)
+    };
+    let timer_width = if entry.remaining.is_some() {
+        percent(100)
+    } else {
+        px(0)
+    };
+    (
+        Name::new(format!("Status Icon {:?}", entry.kind)),
+        StatusIcon {
+            kind: entry.kind,
+            longest_remaining: entry.remaining.unwrap_or_default(),
+            tooltip: tooltip_text(entry),
+        },
+        Interaction::None,
+        Node {
+            width: px(ICON_WIDTH),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            padding: UiRect::all(px(2)),
+            ..default()
+        },
+        BackgroundColor(color),
+        children![
+            (
+                StatusIconLabel,
+                Text::new(badge_text(entry)),
+                TextFont::from_font_size(12.0),
+                TextColor(Color::WHITE),
+            ),
+            (
+                StatusIconTimer,
+                Node {
+                    width: timer_width,
+                    height: px(3),
+                    align_self: AlignSelf::Start,
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.9, 0.9, 0.5)),
+            ),
+        ],
+    )
+}
+
+fn update_status_tooltip(
+    icons: Query<(&Interaction, &StatusIcon)>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    mut tooltip: Single<(&mut Node, &mut Text, &mut Visibility), With<StatusTooltip>>,
+) {
+    let (node, text, visibility) = &mut *tooltip;
+    let hovered = icons
+        .iter()
+        .find(|(interaction, _)| **interaction == Interaction::Hovered);
+    let (Some((_, icon)), Some(cursor)) = (hovered, window.cursor_position()) else {
+        **visibility = Visibility::Hidden;
+        return;
+    };
+
+    // Open away from the screen edge the bar sits on.
+    node.left = px(cursor.x + CURSOR_OFFSET);
+    if cursor.y > window.height() * 0.5 {
+        node.top = Val::Auto;
+        node.bottom = px(window.height() - cursor.y + CURSOR_OFFSET);
+    } else {
+        node.top = px(cursor.y + CURSOR_OFFSET);
+        node.bottom = Val::Auto;
+    }
+    if text.0 != icon.tooltip {
+        text.0.clone_from(&icon.tooltip);
+    }
+    **visibility = Visibility::Inherited;
+}
+
+/// Every status effect a player can carry.
+#[derive(QueryData)]
+struct StatusQuery {
+    strength: &'static Strength,
+    vulnerable: &'static Vulnerable,
+    weak: &'static Weak,
+    poison: Option<&'static Poison>,
+    acceleration: Option<&'static Acceleration>,
+    rage: Option<&'static RageEffect>,
+    metallicize: Option<&'static MetallicizeEffect>,
+    demon_form: Option<&'static DemonFormEffect>,
+    barricade: Option<&'static BarricadeEffect>,
+    combust: Option<&'static CombustEffect>,
+    corruption: Option<&'static CorruptionEffect>,
+    brutality: Option<&'static BrutalityEffect>,
+    dark_embrace: Option<&'static DarkEmbraceEffect>,
+    evolve: Option<&'static EvolveEffect>,
+    feel_no_pain: Option<&'static FeelNoPainEffect>,
+    fire_breathing: Option<&'static FireBreathingEffect>,
+    rupture: Option<&'static RuptureEffect>,
+    juggernaut: Option<&'static JuggernautEffect>,
+}
+
+fn status_entries(status: StatusQueryItem) -> Vec<StatusEntry> {
+    use StatusKind as K;
+    let StatusQueryItem {
+        strength,
+        vulnerable,
+        weak,
+        ..
+    } = status;
+
+    let mut entries = Vec::new();
+
+    if strength.amount > 0.0 {
+        entries.push(StatusEntry::new(
+            K::Strength,
+            format!("+{:.0}", strength.amount),
+            None,
+            format!("Str+{:.0}", strength.amount),
+        ));
+    } else if strength.amount < 0.0 {
+        entries.push(StatusEntry::new(
+            K::Strength,
+            format!("{:.0}", strength.amount),
+            None,
+            format!("Str{:.0}", strength.amount),
+        ));
+    }
+    if vulnerable.is_active() {
+        entries.push(StatusEntry::new(
+            K::Vulnerable,
+            String::new(),
+            Some(vulnerable.duration),
+            format!("Vuln({:.1}s)", vulnerable.duration),
+        ));
+    }
+    if weak.is_active() {
+        entries.push(StatusEntry::new(
+            K::Weak,
+            String::new(),
+            Some(weak.duration),
+            format!("Weak({:.1}s)", weak.duration),
+        ));
+    }
+    if let Some(p) = status.poison
+        && p.is_active()
+    {
+        entries.push(StatusEntry::new(
+            K::Poison,
+            format!("{:.0}", p.stacks),
+            None,
+            format!("Poison({:.0})", p.stacks),
+        ));
+    }
+    if let Some(a) = status.acceleration
+        && a.remaining > 0.0
+    {
+        entries.push(StatusEntry::new(
+            K::Acceleration,
+            format!("+{:.1}", a.bonus_rate),
+            Some(a.remaining),
+            format!("Accel+{:.1}({:.1}s)", a.bonus_rate, a.remaining),
+        ));
+    }
+    if let Some(r) = status.rage
+        && r.is_active()
+    {
+        entries.push(StatusEntry::new(
+            K::Rage,
+            format!("{:.0}", r.block_per_attack),
+            Some(r.duration),
+            format!("Rage({:.1}s)", r.duration),
+        ));
+    }
+    if let Some(m) = status.metallicize {
+        entries.push(StatusEntry::new(
+            K::Metallicize,
+            format!("{:.0}/s", m.block_per_second),
+            None,
+            format!("Metal+{:.0}/s", m.block_per_second),
+        ));
+    }
+    if let Some(d) = status.demon_form {
+        entries.push(StatusEntry::new(
+            K::DemonForm,
+            format!("{:.1}/s", d.strength_per_second),
+            None,
+            format!("Demon+{:.1}str/s", d.strength_per_second),
+        ));
+    }
+    if status.barricade.is_some() {
+        entries.push(StatusEntry::new(
+            K::Barricade,
+            String::new(),
+            None,
+            "Barricade".to_string(),
+        ));
+    }
+    if let Some(c) = status.combust {
+        entries.push(StatusEntry::new(
+            K::Combust,
+            format!("{:.0}/s", c.enemy_damage_per_sec),
+            None,
+            format!(
+                "Combust({:.0}/{:.0}/s)",
+                c.self_damage_per_sec, c.enemy_damage_per_sec
+            ),
+        ));
+    }
+    if status.corruption.is_some() {
+        entries.push(StatusEntry::new(
+            K::Corruption,
+            String::new(),
+            None,
+            "Corrupt".to_string(),
+        ));
+    }
+    if let Some(b) = status.brutality {
+        entries.push(StatusEntry::new(
+            K::Brutality,
+            format!("+{}", b.draw),
+            None,
+            format!("Brutal({:.0}/s +{})", b.self_damage_per_sec, b.draw),
+        ));
+    }
+    if let Some(de) = status.dark_embrace {
+        entries.push(StatusEntry::new(
+            K::DarkEmbrace,
+            format!("+{}", de.draw_on_exhaust),
+            None,
+            format!("DkEmb+{}", de.draw_on_exhaust),
+        ));
+    }
+    if let Some(ev) = status.evolve {
+        entries.push(StatusEntry::new(
+            K::Evolve,
+            format!("+{}", ev.draw_on_status),
+            None,
+            format!("Evolve+{}", ev.draw_on_status),
+        ));
+    }
+    if let Some(fnp) = status.feel_no_pain {
+        entries.push(StatusEntry::new(
+            K::FeelNoPain,
+            format!("+{:.0}", fnp.block_on_exhaust),
+            None,
+            format!("FNP+{:.0}", fnp.block_on_exhaust),
+        ));
+    }
+    if let Some(fb) = status.fire_breathing {
+        entries.push(StatusEntry::new(
+            K::FireBreathing,
+            format!("{:.0}", fb.damage_on_status_draw),
+            None,
+            format!("FBrea+{:.0}", fb.damage_on_status_draw),
+        ));
+    }
+    if let Some(r) = status.rupture {
+        entries.push(StatusEntry::new(
+            K::Rupture,
+            format!("+{:.0}", r.strength_on_self_damage),
+            None,
+            format!("Rupt+{:.0}", r.strength_on_self_damage),
+        ));
+    }
+    if let Some(j) = status.juggernaut {
+        entries.push(StatusEntry::new(
+            K::Juggernaut,
+            format!("{:.0}", j.damage_on_block),
+            None,
+            format!("Jugg+{:.0}", j.damage_on_block),
+        ));
+    }
+
+    entries
+}
@@ -0,0 +1,182 @@
+//! Match timer and sudden death.
+//!
+//! With a time limit set, each player carries a [`MatchClock`]. Once the
+//! limit runs out both players lose HP every [`SUDDEN_DEATH_INTERVAL`],
+//! ignoring block, and each hit is bigger than the last, so even a match
+//! where nobody attacks ends within seconds. The clock ticks with the rest
+//! of the simulation, so online it is rolled back and stays in step.
+
+use bevy::prelude::*;
+
+use super::{
+    AddGameplaySystems, GameplaySystems, Health, SUDDEN_DEATH_DAMAGE, SUDDEN_DEATH_INTERVAL,
+    SimTime, quantize,
+};
+use crate::persist;
+
+const SAVE_KEY: &str = "timer.txt";
+/// Time limits offered in the settings, in minutes; 0 turns the timer off.
+const TIMER_OPTIONS: [u8; 4] = [0, 3, 5, 8];
+
+pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(MatchTimer::load());
+    app.init_resource::<MatchTimeLimit>();
+    app.add_systems(
+        Update,
+        save_match_timer
+            .run_if(resource_changed::<MatchTimer>.and(not(resource_added::<MatchTimer>))),
+    );
+    app.add_gameplay_systems(GameplaySystems::Tick, tick_match_clocks);
+}
+
+/// The time limit the local player asks for, in minutes (0 = no limit).
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchTimer {
+    pub minutes: u8,
+}
+
+impl Default for MatchTimer {
+    fn default() -> Self {
+        Self { minutes: 5 }
+    }
+}
+
+impl MatchTimer {
+    pub fn label(self) -> String {
+        match self.minutes {
+            0 => "Off".to_string(),
+            minutes => format!("{minutes} min"),
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = TIMER_OPTIONS
+            .iter()
+            .position(|minutes| *minutes == self.minutes)
+            .map_or(0, |index| (index + 1) % TIMER_OPTIONS.len());
+        Self {
+            minutes: TIMER_OPTIONS[index],
+        }
+    }
+
+    /// Limit two players agree on: the shorter one, unless either has the
+    /// timer off, in which case the other's.
+    pub fn agree(self, other: Self) -> Self {
+        match (self.minutes, other.minutes) {
+            (0, _) => other,
+            (_, 0) => self,
+            (ours, theirs) => Self {
+                minutes: ours.min(theirs),
+            },
+        }
+    }
+
+    fn load() -> Self {
+        persist::load(SAVE_KEY)
+            .and_then(|contents| {
+                contents
+                    .lines()
+                    .find_map(|line| line.strip_prefix("minutes="))
+                    .and_then(|value| value.parse().ok())
+                    .filter(|minutes| TIMER_OPTIONS.contains(minutes))
+            })
+            .map(|minutes| Self { minutes })
+            .unwrap_or_default()
+    }
+
+    fn save(&self) {
+        persist::store(SAVE_KEY, &format!("minutes={}", self.minutes));
+    }
+}
+
+/// The time limit for the current match; agreed in the lobby online.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct MatchTimeLimit(pub MatchTimer);
+
+/// Match time on one player, and the sudden death damage it has dealt them.
+#[derive(Component, Debug, Default, Clone, Reflect)]
+#[reflect(Component)]
+pub struct MatchClock {
+    /// Seconds before sudden death; 0 for no limit.
+    pub limit: f32,
+    pub elapsed: f32,
+    /// Sudden death hits taken so far.
+    pub hits: u32,
+}
+
+impl MatchClock {
+    pub fn new(limit: MatchTimer) -> Self {
+        Self {
+            limit: f32::from(limit.minutes) * 60.0,
+            ..default()
+        }
+    }
+
+    /// Seconds left before sudden death, or `None` without a limit.
+    pub fn remaining(&self) -> Option<f32> {
+        (self.limit > 0.0).then(|| (self.limit - self.elapsed).max(0.0))
+    }
+
+    /// Advance the clock by one frame, dealing a hit for each full interval
+    /// spent in sudden death.
+    pub fn tick(&mut self, delta: f32, health: &mut Health) {
+        if self.limit <= 0.0 {
+            return;
+        }
+        self.elapsed += delta;
+        while self.elapsed >= self.limit + (self.hits + 1) as f32 * SUDDEN_DEATH_INTERVAL {
+            self.hits += 1;
+            health.take_damage(self.hits as f32 * SUDDEN_DEATH_DAMAGE);
+        }
+    }
+}
+
+fn tick_match_clocks(time: SimTime, mut clocks: Query<(&mut MatchClock, &mut Health)>) {
+    let delta = quantize(time.delta_secs());
+    for (mut clock, mut health) in &mut clocks {
+        clock.tick(delta, &mut health);
+    }
+}
+
+fn save_match_timer(timer: Res<MatchTimer>) {
+    timer.save();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sudden_death_hits_harder_each_interval() {
+        let mut clock = MatchClock::new(MatchTimer { minutes: 3 });
+        let mut health = Health::new(1000.0);
+        clock.tick(180.0, &mut health);
+        assert_eq!(clock.remaining(), Some(0.0));
+        assert_eq!(health.current, 1000.0);
+
+        clock.tick(SUDDEN_DEATH_INTERVAL, &mut health);
+        clock.tick(SUDDEN_DEATH_INTERVAL, &mut health);
+        assert_eq!(clock.hits, 2);
+        assert_eq!(health.current, 1000.0 - 3.0 * SUDDEN_DEATH_DAMAGE);
+    }
+
+    #[test]
+    fn no_limit_never_hurts() {
+        let mut clock = MatchClock::new(MatchTimer { minutes: 0 });
+        let mut health = Health::new(1000.0);
+        clock.tick(3600.0, &mut health);
+        assert_eq!(clock.remaining(), None);
+        assert_eq!(health.current, 1000.0);
+    }
+
+    #[test]
+    fn players_agree_on_the_shorter_limit() {
+        let off = MatchTimer { minutes: 0 };
+        let short = MatchTimer { minutes: 3 };
+        let long = MatchTimer { minutes: 8 };
+        assert_eq!(long.agree(short), short);
+        assert_eq!(short.agree(long), short);
+        assert_eq!(off.agree(long), long);
+        assert_eq!(off.agree(off), off);
+    }
+}
@@ -0,0 +1,446 @@
+//! Headless match harness for tests.
+//!
+//! [`Harness`] runs the match simulation alone, with no window, rendering,
+//! audio or UI, for two players with fixed decks and a fixed seed. Offline it
+//! steps the app one `FixedUpdate` tick per frame and only the local player has input, as
+//! in a Solo match. Online it advances `GgrsSchedule` one rollback tick at a
+//! time and takes input for both handles, standing in for the GGRS session.
+
+use std::time::Duration;
+
+use bevy::{
+    app::Plugins,
+    ecs::component::Mutable,
+    prelude::*,
+    state::{app::StatesPlugin, state::StateTransition},
+    time::TimeUpdateStrategy,
+};
+use bevy_ggrs::{GgrsSchedule, GgrsTime};
+
+use super::{
+    CardId, CardInspect, CardRegistry, CorruptionEffect, Cost, DeathMessage, DrawCardsMessage,
+    FreeCards, GameMode, GameResult, GameRng, GameplaySystems, Hand, OpponentBundle, PendingInput,
+    PlayCardMessage, PlayQueue, PlayerBundle, PlayerHandle, Rules, SIM_TICK, apply_input_flags,
+    simulation_plugin,
+};
+use crate::{
+    AppSystems,
+    replay::{ReplayPlayback, ReplayRecorder},
+    sandbox::Sandbox,
+    screens::Screen,
+};
+
+/// Both modes, for tests that should behave the same offline and online.
+pub const MODES: [GameMode; 2] = [GameMode::Offline, GameMode::Online];
+/// Length of one frame, offline and online: exactly one simulation tick.
+pub const TICK: Duration = SIM_TICK;
+const MATCH_SEED: u64 = 0x5e75e7;
+/// Frames [`go_to`] runs: one for the transition, then a few so loads it
+/// starts land and handles it drops reach their asset storage.
+const SETTLE_FRAMES: usize = 4;
+
+/// A match between two players, stepped by hand.
+pub struct Harness {
+    pub app: App,
+    pub player: Entity,
+    pub opponent: Entity,
+    decks: [Vec<CardId>; 2],
+}
+
+/// Input flags for the next online tick, by player handle.
+#[derive(Resource, Default)]
+struct TickInputs([u16; 2]);
+
+impl Harness {
+    pub fn new(mode: GameMode, player_deck: Vec<CardId>, opponent_deck: Vec<CardId>) -> Self {
+        Self::with_plugins(mode, player_deck, opponent_deck, ())
+    }
+
+    /// A match that also runs `plugins` alongside the simulation, such as the
+    /// table presentation.
+    pub fn with_plugins<M>(
+        mode: GameMode,
+        player_deck: Vec<CardId>,
+        opponent_deck: Vec<CardId>,
+        plugins: impl Plugins<M>,
+    ) -> Self {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(TICK));
+        app.insert_resource(Time::new_with(GgrsTime));
+        app.insert_resource(mode);
+        // Read by the keyboard input systems' run conditions.
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<Sandbox>();
+        app.init_resource::<CardInspect>();
+        app.init_resource::<TickInputs>();
+        // Offline input is recorded for replays, unless it comes from one.
+        app.init_resource::<ReplayPlayback>();
+        app.init_resource::<ReplayRecorder>();
+        app.configure_sets(
+            Update,
+            (
+                AppSystems::TickTimers,
+                AppSystems::RecordInput,
+                AppSystems::Update,
+            )
+                .chain(),
+        );
+        app.insert_state(Screen::Gameplay);
+        app.add_plugins(simulation_plugin);
+        app.add_plugins(plugins);
+        app.add_systems(
+            GgrsSchedule,
+            apply_tick_inputs.in_set(GameplaySystems::Input),
+        );
+
+        // Fill the card registry and enter the match states.
+        app.update();
+
+        let mut harness = Self {
+            app,
+            player: Entity::PLACEHOLDER,
+            opponent: Entity::PLACEHOLDER,
+            decks: [player_deck, opponent_deck],
+        };
+        harness.deal();
+        harness
+    }
+
+    /// Spawn both players with fresh decks and reseed the match.
+    fn deal(&mut self) {
+        let world = self.app.world_mut();
+        world.insert_resource(GameRng::new(MATCH_SEED));
+        let rules = world.resource::<Rules>();
+        let hp = rules.initial_hp;
+        let cost = Cost::new(1.0, rules.max_cost);
+        let [player_deck, opponent_deck] = self.decks.clone();
+        self.player = world
+            .spawn(PlayerBundle::new(
+                0,
+                cost.clone(),
+                hp,
+                player_deck,
+                MATCH_SEED,
+            ))
+            .id();
+        self.opponent = world
+            .spawn(OpponentBundle::new(1, cost, hp, opponent_deck, MATCH_SEED))
+            .id();
+    }
+
+    /// Leave the match for the title screen.
+    pub fn leave(&mut self) {
+        go_to(&mut self.app, Screen::Title);
+        self.app.world_mut().despawn(self.player);
+        self.app.world_mut().despawn(self.opponent);
+    }
+
+    /// Start the same match again in the same app after [`Self::leave`], as
+    /// a player going back for another game would.
+    pub fn rematch(&mut self) {
+        go_to(&mut self.app, Screen::Gameplay);
+        self.deal();
+    }
+
+    fn mode(&self) -> GameMode {
+        *self.app.world().resource::<GameMode>()
+    }
+
+    /// Queue input flags for a player's next frame. Offline, only the local
+    /// player can be given input.
+    pub fn press(&mut self, player: Entity, flags: u16) {
+        match self.mode() {
+            GameMode::Offline => {
+                assert_eq!(
+                    player, self.player,
+                    "offline, only the local player has input"
+                );
+                self.app
+                    .world_mut()
+                    .resource_mut::<PendingInput>()
+                    .push_flags(flags);
+            }
+            GameMode::Online => {
+                let handle = self.get::<PlayerHandle>(player).0;
+                self.app.world_mut().resource_mut::<TickInputs>().0[handle] |= flags;
+            }
+        }
+    }
+
+    /// Advance the match by one frame.
+    pub fn step(&mut self) {
+        match self.mode() {
+            GameMode::Offline => self.app.update(),
+            GameMode::Online => {
+                let world = self.app.world_mut();
+                world.resource_mut::<Time<GgrsTime>>().advance_by(TICK);
+                world.run_schedule(GgrsSchedule);
+                world.run_schedule(StateTransition);
+            }
+        }
+    }
+
+    pub fn run_frames(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.step();
+        }
+    }
+
+    pub fn get<C: Component>(&self, entity: Entity) -> &C {
+        self.app
+            .world()
+            .get::<C>(entity)
+            .expect("player is missing the component")
+    }
+
+    pub fn get_mut<C: Component<Mutability = Mutable>>(&mut self, entity: Entity) -> Mut<'_, C> {
+        self.app
+            .world_mut()
+            .get_mut::<C>(entity)
+            .expect("player is missing the component")
+    }
+
+    /// Put a card straight into a player's hand.
+    pub fn give_card(&mut self, player: Entity, card: CardId) {
+        self.get_mut::<Hand>(player).add_card(card);
+    }
+
+    pub fn result(&self) -> GameResult {
+        *self.app.world().resource::<State<GameResult>>().get()
+    }
+}
+
+/// Switch `app` to `screen` and let it settle.
+pub fn go_to(app: &mut App, screen: Screen) {
+    app.world_mut()
+        .resource_mut::<NextState<Screen>>()
+        .set(screen);
+    for _ in 0..SETTLE_FRAMES {
+        app.update();
+    }
+}
+
+/// Online counterpart of the offline pending input: hand each player the
+/// flags queued for their handle, as the GGRS input system does.
+fn apply_tick_inputs(
+    mut inputs: ResMut<TickInputs>,
+    card_registry: Res<CardRegistry>,
+    rules: Res<Rules>,
+    mut players: Query<(
+        Entity,
+        &PlayerHandle,
+        &Hand,
+        &mut PlayQueue,
+        &mut Cost,
+        &mut FreeCards,
+        Has<CorruptionEffect>,
+    )>,
+    mut draw_messages: MessageWriter<DrawCardsMessage>,
+    mut play_messages: MessageWriter<PlayCardMessage>,
+    mut death_messages: MessageWriter<DeathMessage>,
+) {
+    let inputs = std::mem::take(&mut inputs.0);
+    for (entity, handle, hand, mut queue, mut cost, mut free_cards, corruption) in &mut players {
+        apply_input_flags(
+            inputs[handle.0],
+            entity,
+            hand,
+            &mut queue,
+            &mut cost,
+            &mut free_cards,
+            corruption,
+            &card_registry,
+            &rules,
+            &mut draw_messages,
+            &mut play_messages,
+            &mut death_messages,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        game::{Block, CardType, Deck, DiscardPile, Health, PlayLog, create_test_deck},
+        input::{INPUT_CARD_1, INPUT_DRAW},
+    };
+
+    fn harness(mode: GameMode) -> Harness {
+        Harness::new(mode, create_test_deck(), create_test_deck())
+    }
+
+    #[test]
+    fn strike_damages_the_opponent() {
+        for mode in MODES {
+            let mut harness = harness(mode);
+            let (player, opponent) = (harness.player, harness.opponent);
+            let deck_size = harness.get::<Deck>(player).len();
+            harness.give_card(player, CardId::Strike);
+            harness.get_mut::<Cost>(player).current = 1.0;
+            harness.press(player, INPUT_CARD_1);
+            harness.step();
+
+            assert!(harness.get::<Hand>(player).is_empty(), "{mode:?}");
+            assert_eq!(
+                harness.get::<PlayLog>(player).cards,
+                [CardId::Strike],
+                "{mode:?}"
+            );
+            // Played cards go back into the deck.
+            assert_eq!(harness.get::<Deck>(player).len(), deck_size + 1, "{mode:?}");
+            assert_eq!(
+                harness.get::<Health>(opponent).current,
+                Rules::default().initial_hp - 60.0,
+                "{mode:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn unaffordable_card_stays_in_hand() {
+        for mode in MODES {
+            let mut harness = harness(mode);
+            let (player, opponent) = (harness.player, harness.opponent);
+            harness.give_card(player, CardId::Strike);
+            harness.press(player, INPUT_CARD_1);
+            harness.step();
+
+            assert_eq!(
+                harness.get::<Hand>(player).cards,
+                [CardId::Strike],
+                "{mode:?}"
+            );
+            assert_eq!(
+                harness.get::<Health>(opponent).current,
+                Rules::default().initial_hp,
+                "{mode:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn drawing_moves_the_top_card_into_the_hand() {
+        for mode in MODES {
+            let mut harness = harness(mode);
+            let player = harness.player;
+            let deck_size = harness.get::<Deck>(player).len();
+            // An empty hand draws for free.
+            harness.press(player, INPUT_DRAW);
+            harness.step();
+
+            assert_eq!(harness.get::<Hand>(player).len(), 1, "{mode:?}");
+            assert_eq!(harness.get::<Deck>(player).len(), deck_size - 1, "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn lethal_damage_wins_the_match() {
+        for mode in MODES {
+            let mut harness = harness(mode);
+            let (player, opponent) = (harness.player, harness.opponent);
+            harness.get_mut::<Health>(opponent).current = 10.0;
+            harness.give_card(player, CardId::Strike);
+            harness.get_mut::<Cost>(player).current = 1.0;
+            harness.press(player, INPUT_CARD_1);
+            harness.run_frames(3);
+
+            assert_eq!(harness.result(), GameResult::Victory, "{mode:?}");
+        }
+    }
+
+    /// Play Infernal Blade and return the card it added.
+    fn play_infernal_blade(harness: &mut Harness) -> CardId {
+        let player = harness.player;
+        harness.give_card(player, CardId::InfernalBlade);
+        harness.get_mut::<Cost>(player).current = 1.0;
+        harness.press(player, INPUT_CARD_1);
+        harness.step();
+        let hand = &harness.get::<Hand>(player).cards;
+        assert_eq!(hand.len(), 1, "Infernal Blade adds one card");
+        hand[0]
+    }
+
+    #[test]
+    fn infernal_blade_adds_a_free_attack() {
+        let mut added = Vec::new();
+        for mode in MODES {
+            let mut harness = harness(mode);
+            let player = harness.player;
+            let card = play_infernal_blade(&mut harness);
+            let registry = harness.app.world().resource::<CardRegistry>();
+            let def = registry.get(card).unwrap();
+            assert_eq!(def.card_type, CardType::Attack, "{mode:?}");
+            assert_eq!(card.class(), CardId::InfernalBlade.class(), "{mode:?}");
+            added.push(card);
+
+            // Played with no cost at all.
+            harness.get_mut::<Cost>(player).current = 0.0;
+            harness.press(player, INPUT_CARD_1);
+            harness.step();
+            assert!(harness.get::<Hand>(player).is_empty(), "{mode:?}");
+            assert_eq!(harness.get::<PlayLog>(player).cards.len(), 2, "{mode:?}");
+            assert!(
+                harness.get::<FreeCards>(player).cards.is_empty(),
+                "{mode:?}"
+            );
+        }
+        // The same seed picks the same card offline and online.
+        assert_eq!(added[0], added[1]);
+    }
+
+    #[test]
+    fn infernal_blade_card_costs_again_after_the_window() {
+        let mut harness = harness(GameMode::Offline);
+        let player = harness.player;
+        let card = play_infernal_blade(&mut harness);
+        assert!(harness.get::<FreeCards>(player).is_free(card));
+        harness.run_frames(5 * 60 + 1);
+        assert!(!harness.get::<FreeCards>(player).is_free(card));
+    }
+
+    /// Health, block and cost bits, hand, discard pile and deck size.
+    type PlayerState = (u32, u32, u32, Vec<CardId>, Vec<CardId>, usize);
+
+    /// Both players' state, bit for bit.
+    fn snapshot(harness: &Harness) -> Vec<PlayerState> {
+        [harness.player, harness.opponent]
+            .into_iter()
+            .map(|player| {
+                (
+                    harness.get::<Health>(player).current.to_bits(),
+                    harness.get::<Block>(player).current.to_bits(),
+                    harness.get::<Cost>(player).current.to_bits(),
+                    harness.get::<Hand>(player).cards.clone(),
+                    harness.get::<DiscardPile>(player).cards.clone(),
+                    harness.get::<Deck>(player).len(),
+                )
+            })
+            .collect()
+    }
+
+    /// Both players draw and play from the front of their hands on a fixed
+    /// rhythm for 20 seconds.
+    fn scripted_online_match() -> Vec<PlayerState> {
+        let mut harness = harness(GameMode::Online);
+        let players = [harness.player, harness.opponent];
+        for frame in 0..20 * 60 {
+            for (offset, player) in players.into_iter().enumerate() {
+                if (frame + offset * 7) % 40 == 0 {
+                    harness.press(player, INPUT_DRAW);
+                }
+                if (frame + offset * 11) % 55 == 0 {
+                    harness.press(player, INPUT_CARD_1);
+                }
+            }
+            harness.step();
+        }
+        snapshot(&harness)
+    }
+
+    #[test]
+    fn online_matches_are_bit_identical() {
+        assert_eq!(scripted_online_match(), scripted_online_match());
+    }
+}
@@ -0,0 +1,262 @@
+//! Hover tooltip for hand cards.
+//!
+//! Card faces only fit terse text ("EXH DRAW +1"), so resting the cursor on a
+//! hand card shows the full description, the keywords it uses and its damage
+//! with the current Strength, Weak and Vulnerable applied.
+
+use bevy::{prelude::*, window::PrimaryWindow};
+use bevy_la_mesa::Card as MesaCardComponent;
+
+use super::{
+    CardAction, CardDef, CardEffect, CardRegistry, CardTrigger, CardType, CorruptionEffect,
+    LocalPlayer, Opponent, Rules, Strength, Vulnerable, Weak,
+    effect::modified_attack_damage,
+    mesa::{HoveredCard, MesaCard},
+};
+use crate::{AppSystems, screens::Screen, theme::font::Language};
+
+/// How long a card must stay hovered before its tooltip appears.
+const TOOLTIP_DELAY_SECS: f32 = 0.4;
+const TOOLTIP_WIDTH: f32 = 320.0;
+/// Gap between the cursor and the tooltip's bottom-left corner.
+const CURSOR_OFFSET: f32 = 16.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<TooltipHover>();
+    app.add_systems(
+        Update,
+        update_card_tooltip
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// The hand card under the cursor and how long it has been there.
+#[derive(Resource, Default)]
+struct TooltipHover {
+    card: Option<Entity>,
+    hovered_secs: f32,
+}
+
+#[derive(Component)]
+struct CardTooltip;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Keyword {
+    Exhaust,
+    Block,
+    Strength,
+    Vulnerable,
+    Weak,
+    Poison,
+}
+
+impl Keyword {
+    fn explanation(self, rules: &Rules) -> String {
+        match self {
+            Self::Exhaust => "Exhaust: removed for the rest of the match.".to_string(),
+            Self::Block => format!(
+                "Block: absorbs damage before HP. Decays by {:.0} per second.",
+                rules.block_decay_rate
+            ),
+            Self::Strength => format!(
+                "Strength: each point adds {:.0} damage to attacks.",
+                rules.strength_damage
+            ),
+            Self::Vulnerable => "Vulnerable: takes 50% more damage while it lasts.".to_string(),
+            Self::Weak => "Weak: deals 25% less damage while it lasts.".to_string(),
+            Self::Poison => "Poison: loses HP every tick, then loses a stack.".to_string(),
+        }
+    }
+}
+
+fn update_card_tooltip(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut hover: ResMut<TooltipHover>,
+    hovered: Query<(Entity, &MesaCardComponent<MesaCard>), With<HoveredCard>>,
+    registry: Res<CardRegistry>,
+    language: Res<Language>,
+    rules: Res<Rules>,
+    player: Query<(Option<&Strength>, Option<&Weak>, Option<&CorruptionEffect>), With<LocalPlayer>>,
+    opponent: Query<Option<&Vulnerable>, With<Opponent>>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    mut tooltips: Query<(Entity, &mut Node, &mut Text), With<CardTooltip>>,
+) {
+    let current = hovered.iter().next();
+    if current.map(|(entity, _)| entity) != hover.card {
+        hover.card = current.map(|(entity, _)| entity);
+        hover.hovered_secs = 0.0;
+        for (entity, _, _) in &tooltips {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+    let Some((_, card)) = current else {
+        return;
+    };
+    hover.hovered_secs += time.delta_secs();
+    if hover.hovered_secs < TOOLTIP_DELAY_SECS {
+        return;
+    }
+    let (Some(def), Some(cursor)) = (registry.get(card.data.card_id), window.cursor_position())
+    else {
+        return;
+    };
+
+    let (strength, weak, corruption) = player.single().unwrap_or_default();
+    let vulnerable = opponent.single().ok().flatten();
+    let text = tooltip_text(
+        def,
+        *language,
+        &rules,
+        strength.map_or(0.0, |strength| strength.amount),
+        weak,
+        vulnerable,
+        corruption.is_some(),
+    );
+    let left = px(cursor.x + CURSOR_OFFSET);
+    let bottom = px(window.height() - cursor.y + CURSOR_OFFSET);
+
+    if let Ok((_, mut node, mut tooltip_text)) = tooltips.single_mut() {
+        node.left = left;
+        node.bottom = bottom;
+        if tooltip_text.0 != text {
+            tooltip_text.0 = text;
+        }
+        return;
+    }
+    commands.spawn((
+        Name::new("Card Tooltip"),
+        CardTooltip,
+        Node {
+            position_type: PositionType::Absolute,
+            left,
+            bottom,
+            width: px(TOOLTIP_WIDTH),
+            padding: UiRect::all(px(10)),
+            ..default()
+        },
+        Text::new(text),
+        TextFont::from_font_size(16.0),
+        TextColor(Color::WHITE),
+        BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 0.92)),
+        GlobalZIndex(1),
+        Pickable::IGNORE,
+        DespawnOnExit(Screen::Gameplay),
+    ));
+}
+
+/// Name, cost, description, current damage and keyword explanations for a card.
+pub(super) fn tooltip_text(
+    def: &CardDef,
+    language: Language,
+    rules: &Rules,
+    strength: f32,
+    weak: Option<&Weak>,
+    vulnerable: Option<&Vulnerable>,
+    corruption_active: bool,
+) -> String {
+    let cost = if corruption_active && def.card_type == CardType::Skill {
+        0.0
+    } else {
+        def.cost
+    };
+    let mut lines = vec![
+        format!("{} ({:?}, cost {cost:.0})", def.name, def.card_type),
+        def.description(language),
+    ];
+
+    let mut values = Vec::new();
+    let strength_bonus = strength * rules.strength_damage;
+    value_lines(&def.effect, strength_bonus, weak, vulnerable, &mut values);
+    if !values.is_empty() {
+        lines.push(String::new());
+        lines.extend(values);
+    }
+
+    let mut keywords = Vec::new();
+    collect_keywords(&def.effect, &mut keywords);
+    if !keywords.is_empty() {
+        lines.push(String::new());
+        lines.extend(
+            keywords
+                .into_iter()
+                .map(|keyword| keyword.explanation(rules)),
+        );
+    }
+    lines.join("\n")
+}
+
+/// Damage the card would deal right now.
+fn value_lines(
+    effect: &CardEffect,
+    strength_bonus: f32,
+    weak: Option<&Weak>,
+    vulnerable: Option<&Vulnerable>,
+    lines: &mut Vec<String>,
+) {
+    let damage = |base: f32| {
+        let modified = modified_attack_damage(base, strength_bonus, weak, vulnerable);
+        if modified == base {
+            format!("{modified:.0}")
+        } else {
+            format!("{modified:.0} (base {base:.0})")
+        }
+    };
+    for action in effect.actions(CardTrigger::Play) {
+        match action {
+            CardAction::Damage(amount) => lines.push(format!("Damage now: {}", damage(*amount))),
+            CardAction::MultiHit {
+                damage: amount,
+                hits,
+            } => {
+                lines.push(format!("Damage now: {} x{hits}", damage(*amount)));
+            }
+            CardAction::PerCardInHand { damage: amount, .. } if *amount > 0.0 => {
+                lines.push(format!("Damage now: {} per card in hand", damage(*amount)));
+            }
+            _ => {}
+        }
+    }
+}
+
+fn collect_keywords(effect: &CardEffect, keywords: &mut Vec<Keyword>) {
+    let mut add = |keyword| {
+        if !keywords.contains(&keyword) {
+            keywords.push(keyword);
+        }
+    };
+    if effect.exhausts_self() {
+        add(Keyword::Exhaust);
+    }
+    for (_, action) in &effect.actions {
+        match action {
+            CardAction::ExhaustHand | CardAction::DarkEmbrace { .. } | CardAction::Corruption => {
+                add(Keyword::Exhaust);
+            }
+            CardAction::FeelNoPain { .. } => {
+                add(Keyword::Exhaust);
+                add(Keyword::Block);
+            }
+            CardAction::PerCardInHand { block, .. } if *block > 0.0 => add(Keyword::Block),
+            CardAction::Block(_)
+            | CardAction::DoubleBlock
+            | CardAction::BodySlam
+            | CardAction::Rage(_)
+            | CardAction::Metallicize(_)
+            | CardAction::Barricade
+            | CardAction::Juggernaut(_) => add(Keyword::Block),
+            CardAction::Strength(_)
+            | CardAction::DoubleStrength
+            | CardAction::DemonForm(_)
+            | CardAction::Rupture { .. } => add(Keyword::Strength),
+            CardAction::Vulnerable(_) | CardAction::SelfVulnerable(_) => {
+                add(Keyword::Vulnerable);
+            }
+            CardAction::Weak(_) => add(Keyword::Weak),
+            CardAction::Poison(_) | CardAction::MultiplyPoison(_) => add(Keyword::Poison),
+            _ => {}
+        }
+    }
+}
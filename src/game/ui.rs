@@ -3,27 +3,36 @@
 use bevy::prelude::*;
 
 use super::{
-    Acceleration, BarricadeEffect, Block, BrutalityEffect, CombustEffect, CorruptionEffect, Cost,
-    DRAW_COUNT, DarkEmbraceEffect, Deck, DemonFormEffect, DiscardPile, EvolveEffect,
-    FeelNoPainEffect, FireBreathingEffect, GameResult, Hand, Health, JuggernautEffect, LocalPlayer,
-    MetallicizeEffect, Opponent, PendingInput, RageEffect, RuptureEffect, Strength, Thorns,
-    Vulnerable, Weak,
+    Block, CardRegistry, Cost, Deck, DiscardPile, GameMode, GameResult, Hand, Health, LocalPlayer,
+    MatchClock, MatchFormat, MatchStats, Opponent, PendingInput, PlayerHandle, Rules, Series,
+    SoloReward, Thorns,
     health::{DamageMessage, HealMessage},
+    hp_bar::hp_bar,
 };
 #[cfg(feature = "dev")]
 use crate::input::flags_from_key_string;
-use crate::{AppSystems, input::INPUT_DRAW, screens::Screen};
+use crate::{
+    AppSystems,
+    input::{INPUT_DRAW, INPUT_NEXT_GAME},
+    logging::hot_log,
+    run::RunState,
+    screens::Screen,
+};
 
 pub fn plugin(app: &mut App) {
     app.init_resource::<StatusSummary>();
     app.register_type::<StatusSummary>();
-    app.add_systems(OnEnter(Screen::Gameplay), spawn_game_ui);
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        (spawn_game_ui, spawn_match_clock_display),
+    );
     app.add_systems(
         Update,
         (
             update_cost_display,
             update_deck_display,
             update_health_display,
+            update_match_clock_display,
             spawn_damage_flash,
             update_damage_flash,
             spawn_heal_flash,
@@ -33,13 +42,6 @@ pub fn plugin(app: &mut App) {
             .run_if(in_state(Screen::Gameplay))
             .run_if(in_state(GameResult::Playing)),
     );
-    app.add_systems(
-        Update,
-        update_status_display
-            .in_set(AppSystems::Update)
-            .run_if(in_state(Screen::Gameplay))
-            .run_if(in_state(GameResult::Playing)),
-    );
     app.add_systems(
         Update,
         handle_draw_click
@@ -51,7 +53,12 @@ pub fn plugin(app: &mut App) {
     app.add_systems(OnEnter(GameResult::Defeat), spawn_defeat_overlay);
     app.add_systems(
         Update,
-        handle_result_input
+        (
+            handle_result_input,
+            update_series_text,
+            update_match_stats_text,
+        )
+            .chain()
             .run_if(in_state(Screen::Gameplay))
             .run_if(not(in_state(GameResult::Playing))),
     );
@@ -76,10 +83,18 @@ pub fn plugin(app: &mut App) {
 #[reflect(Resource)]
 pub struct SimulateInput(pub String);
 
+/// Marker for the main game UI root.
+#[derive(Component)]
+pub(super) struct GameUiRoot;
+
 /// Marker for the cost display text.
 #[derive(Component)]
 struct CostDisplay;
 
+/// Marker for the cost bar fill.
+#[derive(Component)]
+struct CostBar;
+
 /// Marker for the deck count display.
 #[derive(Component)]
 struct DeckDisplay;
@@ -92,18 +107,14 @@ struct DiscardDisplay;
 #[derive(Component)]
 struct PlayerHpDisplay;
 
-/// Marker for player HP bar fill.
+/// Marker for the opponent caption; online matches put the opponent's name in it.
 #[derive(Component)]
-struct PlayerHpBar;
+pub struct OpponentNameLabel;
 
 /// Marker for opponent HP display.
 #[derive(Component)]
 struct OpponentHpDisplay;
 
-/// Marker for opponent HP bar fill.
-#[derive(Component)]
-struct OpponentHpBar;
-
 /// Marker for player block display.
 #[derive(Component)]
 struct PlayerBlockDisplay;
@@ -120,6 +131,10 @@ struct PlayerThornsDisplay;
 #[derive(Component)]
 struct OpponentThornsDisplay;
 
+/// Marker for the match timer countdown.
+#[derive(Component)]
+struct MatchClockDisplay;
+
 /// Marker for the draw button.
 #[derive(Component)]
 struct DrawButton;
@@ -141,13 +156,13 @@ struct HealFlashOverlay {
     duration: f32,
 }
 
-/// Marker for player status effects display.
+/// Marker for the player's status icon bar.
 #[derive(Component)]
-struct PlayerStatusDisplay;
+pub(super) struct PlayerStatusDisplay;
 
-/// Marker for opponent status effects display.
+/// Marker for the opponent's status icon bar.
 #[derive(Component)]
-struct OpponentStatusDisplay;
+pub(super) struct OpponentStatusDisplay;
 
 /// BRP-queryable resource containing formatted status strings.
 #[derive(Resource, Default, Reflect)]
@@ -157,10 +172,11 @@ pub struct StatusSummary {
     pub opponent: String,
 }
 
-fn spawn_game_ui(mut commands: Commands) {
+fn spawn_game_ui(mut commands: Commands, rules: Res<Rules>) {
     // Main game UI container
     commands.spawn((
         Name::new("Game UI"),
+        GameUiRoot,
         Node {
             position_type: PositionType::Absolute,
             width: percent(100),
@@ -193,32 +209,15 @@ fn spawn_game_ui(mut commands: Commands) {
                         },
                         children![
                             (
+                                OpponentNameLabel,
                                 Text::new("Enemy: "),
                                 TextFont::from_font_size(20.0),
                                 TextColor(Color::srgb(1.0, 0.5, 0.5)),
                             ),
-                            // HP bar background
-                            (
-                                Name::new("Opponent HP Bar BG"),
-                                Node {
-                                    width: px(200),
-                                    height: px(20),
-                                    ..default()
-                                },
-                                BackgroundColor(Color::srgb(0.3, 0.1, 0.1)),
-                                children![
-                                    // HP bar fill
-                                    (
-                                        Name::new("Opponent HP Bar Fill"),
-                                        OpponentHpBar,
-                                        Node {
-                                            width: percent(100),
-                                            height: percent(100),
-                                            ..default()
-                                        },
-                                        BackgroundColor(Color::srgb(0.8, 0.2, 0.2)),
-                                    ),
-                                ],
+                            hp_bar(
+                                false,
+                                Color::srgb(0.3, 0.1, 0.1),
+                                Color::srgb(0.8, 0.2, 0.2)
                             ),
                             (
                                 OpponentHpDisplay,
@@ -238,12 +237,14 @@ fn spawn_game_ui(mut commands: Commands) {
                                 TextFont::from_font_size(16.0),
                                 TextColor(Color::srgb(1.0, 0.6, 0.3)),
                             ),
-                            // Status effects display
+                            // Status effect icons
                             (
                                 OpponentStatusDisplay,
-                                Text::new(""),
-                                TextFont::from_font_size(14.0),
-                                TextColor(Color::srgb(0.9, 0.9, 0.5)),
+                                Node {
+                                    flex_direction: FlexDirection::Row,
+                                    column_gap: px(4),
+                                    ..default()
+                                },
                             ),
                         ],
                     ),
@@ -256,13 +257,42 @@ fn spawn_game_ui(mut commands: Commands) {
                             ..default()
                         },
                         children![
-                            // Cost display (left)
+                            // Cost display and cap bar (left)
                             (
-                                Name::new("Cost Display"),
-                                CostDisplay,
-                                Text::new("Cost: 0.0"),
-                                TextFont::from_font_size(32.0),
-                                TextColor(Color::WHITE),
+                                Name::new("Cost Container"),
+                                Node {
+                                    flex_direction: FlexDirection::Column,
+                                    row_gap: px(4),
+                                    ..default()
+                                },
+                                children![
+                                    (
+                                        Name::new("Cost Display"),
+                                        CostDisplay,
+                                        Text::new("Cost: 0.0"),
+                                        TextFont::from_font_size(32.0),
+                                        TextColor(Color::WHITE),
+                                    ),
+                                    (
+                                        Name::new("Cost Bar BG"),
+                                        Node {
+                                            width: px(200),
+                                            height: px(12),
+                                            ..default()
+                                        },
+                                        BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+                                        children![(
+                                            Name::new("Cost Bar Fill"),
+                                            CostBar,
+                                            Node {
+                                                width: percent(0),
+                                                height: percent(100),
+                                                ..default()
+                                            },
+                                            BackgroundColor(COST_BAR_COLOR),
+                                        )],
+                                    ),
+                                ],
                             ),
                             // Deck/Discard display (right)
                             (
@@ -318,29 +348,7 @@ fn spawn_game_ui(mut commands: Commands) {
                                 TextFont::from_font_size(20.0),
                                 TextColor(Color::srgb(0.5, 1.0, 0.5)),
                             ),
-                            // HP bar background
-                            (
-                                Name::new("Player HP Bar BG"),
-                                Node {
-                                    width: px(200),
-                                    height: px(20),
-                                    ..default()
-                                },
-                                BackgroundColor(Color::srgb(0.1, 0.3, 0.1)),
-                                children![
-                                    // HP bar fill
-                                    (
-                                        Name::new("Player HP Bar Fill"),
-                                        PlayerHpBar,
-                                        Node {
-                                            width: percent(100),
-                                            height: percent(100),
-                                            ..default()
-                                        },
-                                        BackgroundColor(Color::srgb(0.2, 0.8, 0.2)),
-                                    ),
-                                ],
-                            ),
+                            hp_bar(true, Color::srgb(0.1, 0.3, 0.1), Color::srgb(0.2, 0.8, 0.2)),
                             (
                                 PlayerHpDisplay,
                                 Text::new("100 / 100"),
@@ -359,12 +367,14 @@ fn spawn_game_ui(mut commands: Commands) {
                                 TextFont::from_font_size(16.0),
                                 TextColor(Color::srgb(1.0, 0.6, 0.3)),
                             ),
-                            // Status effects display
+                            // Status effect icons
                             (
                                 PlayerStatusDisplay,
-                                Text::new(""),
-                                TextFont::from_font_size(14.0),
-                                TextColor(Color::srgb(0.9, 0.9, 0.5)),
+                                Node {
+                                    flex_direction: FlexDirection::Row,
+                                    column_gap: px(4),
+                                    ..default()
+                                },
                             ),
                         ],
                     ),
@@ -396,7 +406,7 @@ fn spawn_game_ui(mut commands: Commands) {
                                 BackgroundColor(Color::srgb(0.4, 0.2, 0.6)),
                                 children![(
                                     DrawButtonText,
-                                    Text::new(format!("Draw {}\n(0) [D]", DRAW_COUNT)),
+                                    Text::new(format!("Draw {}\n(0) [D]", rules.draw_count)),
                                     TextFont::from_font_size(14.0),
                                     TextColor(Color::WHITE),
                                     Pickable::IGNORE,
@@ -410,21 +420,79 @@ fn spawn_game_ui(mut commands: Commands) {
     ));
 }
 
+const COST_BAR_COLOR: Color = Color::srgb(1.0, 0.8, 0.2);
+const COST_OVERFLOW_COLOR: Color = Color::srgb(1.0, 0.3, 0.2);
+
+/// True on the frame the game UI is spawned, so displays that only refresh on
+/// change get their first values.
+fn ui_spawned(root: &Query<(), Added<GameUiRoot>>) -> bool {
+    !root.is_empty()
+}
+
 fn update_cost_display(
-    player_query: Query<&Cost, With<LocalPlayer>>,
-    mut display_query: Query<&mut Text, With<CostDisplay>>,
+    player_query: Query<Ref<Cost>, With<LocalPlayer>>,
+    root: Query<(), Added<GameUiRoot>>,
+    mut display_query: Query<(&mut Text, &mut TextColor), With<CostDisplay>>,
+    mut bar_query: Query<(&mut Node, &mut BackgroundColor), With<CostBar>>,
 ) {
     let Ok(cost) = player_query.single() else {
         return;
     };
+    if !cost.is_changed() && !ui_spawned(&root) {
+        return;
+    }
+
+    // At the cap, further cost gain is wasted - warn the player to spend it.
+    let capped = cost.is_capped();
+    let label = if capped {
+        format!(
+            "Cost: {:.1}/{:.0} MAX - overflowing!",
+            cost.current, cost.max
+        )
+    } else {
+        format!(
+            "Cost: {:.1}/{:.0} (+{:.1}/s)",
+            cost.current, cost.max, cost.rate
+        )
+    };
+    let color = if capped {
+        COST_OVERFLOW_COLOR
+    } else {
+        Color::WHITE
+    };
+    // Cost regenerates every frame but the rounded text changes far less often;
+    // only touch the text when it does so it isn't laid out again.
+    for (mut text, mut text_color) in &mut display_query {
+        if text.0 != label {
+            text.0.clone_from(&label);
+        }
+        text_color.set_if_neq(TextColor(color));
+    }
+    let width = Val::Percent(cost.fill() * 100.0);
+    let bar_color = if capped {
+        COST_OVERFLOW_COLOR
+    } else {
+        COST_BAR_COLOR
+    };
+    for (mut node, mut background) in &mut bar_query {
+        if node.width != width {
+            node.width = width;
+        }
+        background.set_if_neq(BackgroundColor(bar_color));
+    }
+}
 
-    for mut text in &mut display_query {
-        text.0 = format!("Cost: {:.1} (+{:.1}/s)", cost.current, cost.rate);
+/// Replace a text only when the new content differs.
+fn set_text(text: &mut Mut<Text>, content: String) {
+    if text.0 != content {
+        text.0 = content;
     }
 }
 
 fn update_deck_display(
-    player_query: Query<(&Deck, &DiscardPile, &Hand), With<LocalPlayer>>,
+    player_query: Query<(Ref<Deck>, Ref<DiscardPile>, Ref<Hand>), With<LocalPlayer>>,
+    rules: Res<Rules>,
+    root: Query<(), Added<GameUiRoot>>,
     mut deck_query: Query<
         &mut Text,
         (
@@ -453,25 +521,36 @@ fn update_deck_display(
     let Ok((deck, discard, hand)) = player_query.single() else {
         return;
     };
+    let spawned = ui_spawned(&root);
 
-    for mut text in &mut deck_query {
-        text.0 = format!("Deck: {}", deck.cards.len());
+    if deck.is_changed() || spawned {
+        for mut text in &mut deck_query {
+            set_text(&mut text, format!("Deck: {}", deck.cards.len()));
+        }
     }
 
-    for mut text in &mut discard_query {
-        text.0 = format!("Discard: {}", discard.cards.len());
+    if discard.is_changed() || spawned {
+        for mut text in &mut discard_query {
+            set_text(&mut text, format!("Discard: {}", discard.cards.len()));
+        }
     }
 
-    // Draw cost = hand size (0 cards = free draw)
-    let draw_cost = hand.len();
-    for mut text in &mut draw_button_query {
-        text.0 = format!("Draw {}\n({}) [D]", DRAW_COUNT, draw_cost);
+    // Draw cost grows with the hand (0 cards = free draw)
+    if hand.is_changed() || rules.is_changed() || spawned {
+        let draw_cost = rules.draw_cost(hand.len());
+        for mut text in &mut draw_button_query {
+            set_text(
+                &mut text,
+                format!("Draw {}\n({}) [D]", rules.draw_count, draw_cost),
+            );
+        }
     }
 }
 
 fn update_health_display(
-    player_query: Query<(&Health, &Block, &Thorns), With<LocalPlayer>>,
-    opponent_query: Query<(&Health, &Block, &Thorns), With<Opponent>>,
+    player_query: Query<(Ref<Health>, Ref<Block>, Ref<Thorns>), With<LocalPlayer>>,
+    opponent_query: Query<(Ref<Health>, Ref<Block>, Ref<Thorns>), With<Opponent>>,
+    root: Query<(), Added<GameUiRoot>>,
     mut text_sets: ParamSet<(
         Query<&mut Text, With<PlayerHpDisplay>>,
         Query<&mut Text, With<PlayerBlockDisplay>>,
@@ -480,225 +559,103 @@ fn update_health_display(
         Query<&mut Text, With<OpponentBlockDisplay>>,
         Query<&mut Text, With<OpponentThornsDisplay>>,
     )>,
-    mut bar_sets: ParamSet<(
-        Query<&mut Node, With<PlayerHpBar>>,
-        Query<&mut Node, With<OpponentHpBar>>,
-    )>,
 ) {
+    let spawned = ui_spawned(&root);
+
     // Update player HP
     if let Ok((health, block, thorns)) = player_query.single() {
-        for mut text in text_sets.p0().iter_mut() {
-            text.0 = format!("{:.0} / {:.0}", health.current, health.max);
-        }
-        for mut node in bar_sets.p0().iter_mut() {
-            node.width = Val::Percent(health.percentage() * 100.0);
+        if health.is_changed() || spawned {
+            for mut text in text_sets.p0().iter_mut() {
+                set_text(
+                    &mut text,
+                    format!("{:.0} / {:.0}", health.current, health.max),
+                );
+            }
         }
-        for mut text in text_sets.p1().iter_mut() {
-            text.0 = format!("Block: {:.0}", block.current);
+        if block.is_changed() || spawned {
+            for mut text in text_sets.p1().iter_mut() {
+                set_text(&mut text, format!("Block: {:.0}", block.current));
+            }
         }
-        for mut text in text_sets.p2().iter_mut() {
-            text.0 = format!("Thorns: {:.0}", thorns.damage);
+        if thorns.is_changed() || spawned {
+            for mut text in text_sets.p2().iter_mut() {
+                set_text(&mut text, format!("Thorns: {:.0}", thorns.damage));
+            }
         }
     }
 
     // Update opponent HP
     if let Ok((health, block, thorns)) = opponent_query.single() {
-        for mut text in text_sets.p3().iter_mut() {
-            text.0 = format!("{:.0} / {:.0}", health.current, health.max);
+        if health.is_changed() || spawned {
+            for mut text in text_sets.p3().iter_mut() {
+                set_text(
+                    &mut text,
+                    format!("{:.0} / {:.0}", health.current, health.max),
+                );
+            }
         }
-        for mut node in bar_sets.p1().iter_mut() {
-            node.width = Val::Percent(health.percentage() * 100.0);
+        if block.is_changed() || spawned {
+            for mut text in text_sets.p4().iter_mut() {
+                set_text(&mut text, format!("Block: {:.0}", block.current));
+            }
         }
-        for mut text in text_sets.p4().iter_mut() {
-            text.0 = format!("Block: {:.0}", block.current);
-        }
-        for mut text in text_sets.p5().iter_mut() {
-            text.0 = format!("Thorns: {:.0}", thorns.damage);
+        if thorns.is_changed() || spawned {
+            for mut text in text_sets.p5().iter_mut() {
+                set_text(&mut text, format!("Thorns: {:.0}", thorns.damage));
+            }
         }
     }
 }
 
-/// System to update status effects display for both players.
-/// Shows the most important status effects.
-#[allow(clippy::type_complexity)]
-fn update_status_display(
-    player_query: Query<
-        (
-            &Strength,
-            &Vulnerable,
-            &Weak,
-            Option<&Acceleration>,
-            Option<&RageEffect>,
-            Option<&MetallicizeEffect>,
-            Option<&DemonFormEffect>,
-            Option<&BarricadeEffect>,
-            Option<&CombustEffect>,
-            Option<&CorruptionEffect>,
-            Option<&BrutalityEffect>,
-        ),
-        (With<LocalPlayer>, Without<Opponent>),
-    >,
-    player_powers: Query<
-        (
-            Option<&DarkEmbraceEffect>,
-            Option<&EvolveEffect>,
-            Option<&FeelNoPainEffect>,
-            Option<&FireBreathingEffect>,
-            Option<&RuptureEffect>,
-            Option<&JuggernautEffect>,
-        ),
-        (With<LocalPlayer>, Without<Opponent>),
-    >,
-    opponent_query: Query<
-        (
-            &Strength,
-            &Vulnerable,
-            &Weak,
-            Option<&Acceleration>,
-            Option<&RageEffect>,
-            Option<&MetallicizeEffect>,
-            Option<&DemonFormEffect>,
-            Option<&BarricadeEffect>,
-            Option<&CombustEffect>,
-            Option<&CorruptionEffect>,
-            Option<&BrutalityEffect>,
-        ),
-        (With<Opponent>, Without<LocalPlayer>),
-    >,
-    opponent_powers: Query<
-        (
-            Option<&DarkEmbraceEffect>,
-            Option<&EvolveEffect>,
-            Option<&FeelNoPainEffect>,
-            Option<&FireBreathingEffect>,
-            Option<&RuptureEffect>,
-            Option<&JuggernautEffect>,
-        ),
-        (With<Opponent>, Without<LocalPlayer>),
-    >,
-    mut text_query: ParamSet<(
-        Query<&mut Text, With<PlayerStatusDisplay>>,
-        Query<&mut Text, With<OpponentStatusDisplay>>,
-    )>,
-    mut summary: ResMut<StatusSummary>,
-) {
-    // Update player status
-    if let (Ok(base), Ok(powers)) = (player_query.single(), player_powers.single()) {
-        let status = build_status_string(base, powers);
-        for mut text in text_query.p0().iter_mut() {
-            text.0.clone_from(&status);
-        }
-        summary.player = status;
-    }
-
-    // Update opponent status
-    if let (Ok(base), Ok(powers)) = (opponent_query.single(), opponent_powers.single()) {
-        let status = build_status_string(base, powers);
-        for mut text in text_query.p1().iter_mut() {
-            text.0.clone_from(&status);
-        }
-        summary.opponent = status;
-    }
+/// Handle clicking on draw button to draw cards.
+/// The countdown stays empty until a clock with a limit shows up, since the
+/// limit for this match may only be set later in the same frame.
+fn spawn_match_clock_display(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Match Clock"),
+        MatchClockDisplay,
+        Text::default(),
+        TextFont::from_font_size(28.0),
+        TextColor(Color::WHITE),
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(48),
+            width: percent(100),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        TextLayout::new_with_justify(Justify::Center),
+        Pickable::IGNORE,
+        DespawnOnExit(Screen::Gameplay),
+    ));
 }
 
-#[allow(clippy::type_complexity)]
-fn build_status_string(
-    (strength, vulnerable, weak, accel, rage, metal, demon, barricade, combust, corrupt, brutal): (
-        &Strength,
-        &Vulnerable,
-        &Weak,
-        Option<&Acceleration>,
-        Option<&RageEffect>,
-        Option<&MetallicizeEffect>,
-        Option<&DemonFormEffect>,
-        Option<&BarricadeEffect>,
-        Option<&CombustEffect>,
-        Option<&CorruptionEffect>,
-        Option<&BrutalityEffect>,
-    ),
-    (dark_embrace, evolve, feel_no_pain, fire_breathing, rupture, juggernaut): (
-        Option<&DarkEmbraceEffect>,
-        Option<&EvolveEffect>,
-        Option<&FeelNoPainEffect>,
-        Option<&FireBreathingEffect>,
-        Option<&RuptureEffect>,
-        Option<&JuggernautEffect>,
-    ),
-) -> String {
-    let mut effects = Vec::new();
-
-    if strength.amount > 0.0 {
-        effects.push(format!("Str+{:.0}", strength.amount));
-    } else if strength.amount < 0.0 {
-        effects.push(format!("Str{:.0}", strength.amount));
-    }
-    if vulnerable.is_active() {
-        effects.push(format!("Vuln({:.1}s)", vulnerable.duration));
-    }
-    if weak.is_active() {
-        effects.push(format!("Weak({:.1}s)", weak.duration));
-    }
-    if let Some(a) = accel {
-        if a.remaining > 0.0 {
-            effects.push(format!("Accel+{:.1}({:.1}s)", a.bonus_rate, a.remaining));
-        }
-    }
-    if let Some(r) = rage {
-        if r.is_active() {
-            effects.push(format!("Rage({:.1}s)", r.duration));
+fn update_match_clock_display(
+    clock: Query<&MatchClock, With<LocalPlayer>>,
+    mut display: Query<(&mut Text, &mut TextColor), With<MatchClockDisplay>>,
+) {
+    let remaining = clock.single().ok().and_then(MatchClock::remaining);
+    for (mut text, mut color) in &mut display {
+        // The clock ticks every frame; the text only changes once a second.
+        match remaining {
+            None => set_text(&mut text, String::new()),
+            Some(secs) if secs <= 0.0 => {
+                set_text(&mut text, "SUDDEN DEATH".to_string());
+                color.set_if_neq(TextColor(Color::srgb(0.9, 0.2, 0.2)));
+            }
+            Some(secs) => {
+                let secs = secs.ceil() as u32;
+                set_text(&mut text, format!("{}:{:02}", secs / 60, secs % 60));
+                color.set_if_neq(TextColor(if secs <= 30 {
+                    Color::srgb(1.0, 0.8, 0.3)
+                } else {
+                    Color::WHITE
+                }));
+            }
         }
     }
-    if let Some(m) = metal {
-        effects.push(format!("Metal+{:.0}/s", m.block_per_second));
-    }
-    if let Some(d) = demon {
-        effects.push(format!("Demon+{:.1}str/s", d.strength_per_second));
-    }
-    if barricade.is_some() {
-        effects.push("Barricade".to_string());
-    }
-    if let Some(c) = combust {
-        effects.push(format!(
-            "Combust({:.0}/{:.0}/s)",
-            c.self_damage_per_sec, c.enemy_damage_per_sec
-        ));
-    }
-    if corrupt.is_some() {
-        effects.push("Corrupt".to_string());
-    }
-    if let Some(b) = brutal {
-        effects.push(format!(
-            "Brutal({:.0}/s +{})",
-            b.self_damage_per_sec, b.draw
-        ));
-    }
-    if let Some(de) = dark_embrace {
-        effects.push(format!("DkEmb+{}", de.draw_on_exhaust));
-    }
-    if let Some(ev) = evolve {
-        effects.push(format!("Evolve+{}", ev.draw_on_status));
-    }
-    if let Some(fnp) = feel_no_pain {
-        effects.push(format!("FNP+{:.0}", fnp.block_on_exhaust));
-    }
-    if let Some(fb) = fire_breathing {
-        effects.push(format!("FBrea+{:.0}", fb.damage_on_status_draw));
-    }
-    if let Some(r) = rupture {
-        effects.push(format!("Rupt+{:.0}", r.strength_on_self_damage));
-    }
-    if let Some(j) = juggernaut {
-        effects.push(format!("Jugg+{:.0}", j.damage_on_block));
-    }
-
-    if effects.is_empty() {
-        String::new()
-    } else {
-        effects.join(" | ")
-    }
 }
 
-/// Handle clicking on draw button to draw cards.
 fn handle_draw_click(
     mut pending_input: ResMut<PendingInput>,
     draw_button: Query<&Interaction, (Changed<Interaction>, With<DrawButton>)>,
@@ -708,7 +665,7 @@ fn handle_draw_click(
             continue;
         }
 
-        info!("Draw button clicked! Pushing INPUT_DRAW flag");
+        hot_log!("Draw button clicked! Pushing INPUT_DRAW flag");
         pending_input.push_flags(INPUT_DRAW);
     }
 }
@@ -726,6 +683,18 @@ fn px(value: i32) -> Val {
 #[derive(Component)]
 struct ResultOverlay;
 
+/// Marker for the series score on the result overlay.
+#[derive(Component)]
+struct SeriesScoreText;
+
+/// Marker for the "Press SPACE" line on the result overlay.
+#[derive(Component)]
+struct ResultHintText;
+
+/// Marker for the match statistics on the result overlay.
+#[derive(Component)]
+struct MatchStatsText;
+
 fn spawn_victory_overlay(mut commands: Commands) {
     spawn_result_overlay(&mut commands, "VICTORY!", Color::srgb(0.2, 0.8, 0.2));
 }
@@ -757,7 +726,21 @@ fn spawn_result_overlay(commands: &mut Commands, text: &str, color: Color) {
                 TextColor(color),
             ),
             (
-                Text::new("Press SPACE to return to title"),
+                SeriesScoreText,
+                Text::default(),
+                TextFont::from_font_size(32.0),
+                TextColor(Color::srgb(0.9, 0.85, 0.6)),
+            ),
+            (
+                MatchStatsText,
+                Text::default(),
+                TextFont::from_font_size(20.0),
+                TextColor(Color::srgb(0.8, 0.8, 0.85)),
+                TextLayout::new_with_justify(Justify::Center),
+            ),
+            (
+                ResultHintText,
+                Text::new("Press SPACE to continue"),
                 TextFont::from_font_size(24.0),
                 TextColor(Color::WHITE),
             ),
@@ -767,16 +750,89 @@ fn spawn_result_overlay(commands: &mut Commands, text: &str, color: Color) {
 
 fn handle_result_input(
     keyboard: Res<ButtonInput<KeyCode>>,
+    run: Res<RunState>,
+    reward: Res<SoloReward>,
+    mode: Res<GameMode>,
+    format: Res<MatchFormat>,
+    mut series: ResMut<Series>,
+    mut pending_input: ResMut<PendingInput>,
     mut next_screen: ResMut<NextState<Screen>>,
     result_overlay: Query<Entity, With<ResultOverlay>>,
     mut commands: Commands,
 ) {
+    // Pick or skip the card reward first.
+    if reward.is_pending() {
+        return;
+    }
+    // Mid-series, keep telling the opponent we're ready until the next game
+    // starts; it starts once GGRS has both players' confirmation.
+    if *mode == GameMode::Online && !series.is_decided(*format) {
+        if keyboard.just_pressed(KeyCode::Space) {
+            series.ready = true;
+        }
+        if series.ready {
+            pending_input.push_flags(INPUT_NEXT_GAME);
+        }
+        return;
+    }
     if keyboard.just_pressed(KeyCode::Space) {
-        // Remove overlay and go back to title
+        // Remove overlay and go back to the run map or the title
         for entity in &result_overlay {
             commands.entity(entity).despawn();
         }
-        next_screen.set(Screen::Title);
+        next_screen.set(if run.is_active() {
+            Screen::Map
+        } else {
+            Screen::Title
+        });
+    }
+}
+
+fn update_series_text(
+    mode: Res<GameMode>,
+    format: Res<MatchFormat>,
+    series: Res<Series>,
+    mut score: Query<&mut Text, (With<SeriesScoreText>, Without<ResultHintText>)>,
+    mut hint: Query<&mut Text, With<ResultHintText>>,
+) {
+    if *mode != GameMode::Online || *format == MatchFormat::Single {
+        return;
+    }
+    let decided = series.is_decided(*format);
+    for mut text in &mut score {
+        let outcome = match (decided, series.wins > series.losses) {
+            (false, _) => "",
+            (true, true) => " - Series won!",
+            (true, false) => " - Series lost",
+        };
+        text.0 = format!(
+            "Series: You {} - {} Opponent{outcome}",
+            series.wins, series.losses
+        );
+    }
+    for mut text in &mut hint {
+        text.0 = if decided {
+            "Press SPACE to continue"
+        } else if series.ready {
+            "Waiting for the opponent..."
+        } else {
+            "Press SPACE for the next game"
+        }
+        .to_string();
+    }
+}
+
+fn update_match_stats_text(
+    stats: Res<MatchStats>,
+    registry: Res<CardRegistry>,
+    local: Query<(&PlayerHandle, &MatchClock), With<LocalPlayer>>,
+    mut text: Query<&mut Text, Added<MatchStatsText>>,
+) {
+    let Ok((handle, clock)) = local.single() else {
+        return;
+    };
+    for mut text in &mut text {
+        text.0 = stats.player(handle.0).summary(clock.elapsed, &registry);
     }
 }
 
@@ -926,3 +982,50 @@ fn handle_simulated_input(
         pending_input.push_flags(flags);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::change_detection::Tick;
+
+    use super::*;
+
+    fn hp_text(app: &mut App) -> (String, Tick) {
+        let mut texts = app
+            .world_mut()
+            .query_filtered::<Ref<Text>, With<PlayerHpDisplay>>();
+        let text = texts.single(app.world()).unwrap();
+        (text.0.clone(), text.last_changed())
+    }
+
+    #[test]
+    fn health_text_only_changes_with_health() {
+        let mut app = App::new();
+        app.add_systems(Update, update_health_display);
+        let player = app
+            .world_mut()
+            .spawn((
+                LocalPlayer,
+                Health::new(100.0),
+                Block::default(),
+                Thorns::default(),
+            ))
+            .id();
+        app.world_mut().spawn(GameUiRoot);
+        app.world_mut().spawn((PlayerHpDisplay, Text::default()));
+
+        app.update();
+        let (text, written) = hp_text(&mut app);
+        assert_eq!(text, "100 / 100");
+
+        // Idle frames leave the text alone.
+        app.update();
+        app.update();
+        assert_eq!(hp_text(&mut app).1, written);
+
+        app.world_mut().get_mut::<Health>(player).unwrap().current = 90.0;
+        app.update();
+        let (text, rewritten) = hp_text(&mut app);
+        assert_eq!(text, "90 / 100");
+        assert_ne!(rewritten, written);
+    }
+}
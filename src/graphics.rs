@@ -0,0 +1,325 @@
+//! Graphics settings: window mode, vsync, MSAA and render scale.
+//!
+//! [`GraphicsSettings`] is loaded from disk at startup, applied to the primary
+//! window and cameras whenever it changes, and saved back on every edit.
+//!
+//! Below 100% render scale the 3D camera draws into a smaller offscreen image
+//! that is stretched over the window behind the UI, so the UI stays sharp.
+//! Window cursor input is forwarded to that image through a custom picking
+//! pointer so cards stay clickable.
+
+use bevy::{
+    asset::uuid::Uuid,
+    camera::{ImageRenderTarget, NormalizedRenderTarget, RenderTarget},
+    input::{ButtonState, mouse::MouseButtonInput},
+    picking::{
+        PickingSystems,
+        pointer::{Location, PointerAction, PointerId, PointerInput},
+    },
+    prelude::*,
+    render::render_resource::{Extent3d, TextureFormat},
+    window::{
+        MonitorSelection, PresentMode, PrimaryWindow, VideoModeSelection, WindowMode, WindowRef,
+    },
+};
+
+use crate::{
+    MainCamera,
+    persist::{self, Persisted, save_on_change},
+};
+
+const SAVE_KEY: &str = "graphics.txt";
+/// Render scales offered in the settings menu, highest first.
+const RENDER_SCALES: [f32; 3] = [1.0, 0.75, 0.5];
+const SCALED_POINTER_ID: PointerId = PointerId::Custom(Uuid::from_u128(0x5e45_e400_0001));
+
+pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(GraphicsSettings::load());
+    app.add_systems(Startup, spawn_scaled_pointer);
+    app.add_systems(
+        Update,
+        (
+            (apply_window_settings, save_on_change::<GraphicsSettings>)
+                .run_if(resource_changed::<GraphicsSettings>),
+            apply_render_scale,
+        ),
+    );
+    app.add_systems(First, drive_scaled_pointer.in_set(PickingSystems::Input));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WindowModeSetting {
+    #[default]
+    Windowed,
+    Borderless,
+    Fullscreen,
+}
+
+impl WindowModeSetting {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Windowed => "Windowed",
+            Self::Borderless => "Borderless",
+            Self::Fullscreen => "Fullscreen",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Windowed => Self::Borderless,
+            // Browsers only offer borderless fullscreen.
+            #[cfg(target_family = "wasm")]
+            Self::Borderless => Self::Windowed,
+            #[cfg(not(target_family = "wasm"))]
+            Self::Borderless => Self::Fullscreen,
+            Self::Fullscreen => Self::Windowed,
+        }
+    }
+
+    fn window_mode(self) -> WindowMode {
+        match self {
+            Self::Windowed => WindowMode::Windowed,
+            Self::Borderless => WindowMode::BorderlessFullscreen(MonitorSelection::Current),
+            Self::Fullscreen => {
+                WindowMode::Fullscreen(MonitorSelection::Current, VideoModeSelection::Current)
+            }
+        }
+    }
+}
+
+/// Player-chosen graphics options, persisted across sessions.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct GraphicsSettings {
+    pub window_mode: WindowModeSetting,
+    pub vsync: bool,
+    /// 4x multisampling; the only sample count every backend (including WebGL2) supports.
+    pub msaa: bool,
+    /// Fraction of the window resolution the 3D scene renders at.
+    pub render_scale: f32,
+}
+
+impl Default for GraphicsSettings {
+    fn default() -> Self {
+        Self {
+            window_mode: WindowModeSetting::Windowed,
+            vsync: true,
+            msaa: false,
+            render_scale: 1.0,
+        }
+    }
+}
+
+impl GraphicsSettings {
+    /// The next lower render scale, wrapping back to 100%.
+    pub fn next_render_scale(&self) -> f32 {
+        RENDER_SCALES
+            .iter()
+            .copied()
+            .find(|scale| *scale < self.render_scale)
+            .unwrap_or(RENDER_SCALES[0])
+    }
+
+    fn load() -> Self {
+        let mut settings = Self::default();
+        let Some(contents) = persist::load(SAVE_KEY) else {
+            return settings;
+        };
+        for (key, value) in contents.lines().filter_map(|line| line.split_once('=')) {
+            match key {
+                "window_mode" => {
+                    settings.window_mode = match value {
+                        "borderless" => WindowModeSetting::Borderless,
+                        "fullscreen" => WindowModeSetting::Fullscreen,
+                        _ => WindowModeSetting::Windowed,
+                    }
+                }
+                "vsync" => settings.vsync = value == "on",
+                "msaa" => settings.msaa = value == "on",
+                "render_scale" => {
+                    settings.render_scale = value
+                        .parse::<f32>()
+                        .ok()
+                        .filter(|scale| RENDER_SCALES.contains(scale))
+                        .unwrap_or(1.0);
+                }
+                _ => {}
+            }
+        }
+        settings
+    }
+}
+
+impl Persisted for GraphicsSettings {
+    fn save(&self) {
+        let on_off = |enabled: bool| if enabled { "on" } else { "off" };
+        let lines = [
+            format!("window_mode={}", self.window_mode.label().to_lowercase()),
+            format!("vsync={}", on_off(self.vsync)),
+            format!("msaa={}", on_off(self.msaa)),
+            format!("render_scale={}", self.render_scale),
+        ];
+        persist::store(SAVE_KEY, &lines.join("\n"));
+    }
+}
+
+fn apply_window_settings(
+    settings: Res<GraphicsSettings>,
+    mut window: Single<&mut Window, With<PrimaryWindow>>,
+    mut cameras: Query<&mut Msaa, Or<(With<MainCamera>, With<IsDefaultUiCamera>)>>,
+) {
+    let mode = settings.window_mode.window_mode();
+    if window.mode != mode {
+        window.mode = mode;
+    }
+    let present_mode = if settings.vsync {
+        PresentMode::AutoVsync
+    } else {
+        PresentMode::AutoNoVsync
+    };
+    if window.present_mode != present_mode {
+        window.present_mode = present_mode;
+    }
+    // Every camera drawing to the window must agree on the sample count.
+    // Cameras rendering to an image (the opponent close-up) keep their own.
+    let msaa = if settings.msaa {
+        Msaa::Sample4
+    } else {
+        Msaa::Off
+    };
+    for mut camera_msaa in &mut cameras {
+        camera_msaa.set_if_neq(msaa);
+    }
+}
+
+/// The offscreen image the 3D camera renders into below 100% render scale.
+#[derive(Resource)]
+struct ScaledRender {
+    image: Handle<Image>,
+    size: UVec2,
+    /// Full-window UI node showing the image behind the rest of the UI.
+    display: Entity,
+}
+
+fn apply_render_scale(
+    mut commands: Commands,
+    settings: Res<GraphicsSettings>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    camera: Single<Entity, With<MainCamera>>,
+    scaled: Option<ResMut<ScaledRender>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    if settings.render_scale >= 1.0 {
+        if let Some(scaled) = scaled {
+            commands
+                .entity(*camera)
+                .insert(RenderTarget::Window(WindowRef::Primary));
+            commands.entity(scaled.display).despawn();
+            commands.remove_resource::<ScaledRender>();
+        }
+        return;
+    }
+
+    let size = (window.physical_size().as_vec2() * settings.render_scale)
+        .as_uvec2()
+        .max(UVec2::ONE);
+    match scaled {
+        Some(scaled) if scaled.size == size => {}
+        Some(mut scaled) => {
+            // Follow window resizes and scale changes without rebuilding the target.
+            if let Some(image) = images.get_mut(&scaled.image) {
+                image.resize(Extent3d {
+                    width: size.x,
+                    height: size.y,
+                    ..default()
+                });
+            }
+            scaled.size = size;
+        }
+        None => {
+            let image = images.add(Image::new_target_texture(
+                size.x,
+                size.y,
+                TextureFormat::Rgba8UnormSrgb,
+                None,
+            ));
+            commands
+                .entity(*camera)
+                .insert(RenderTarget::Image(image.clone().into()));
+            let display = commands
+                .spawn((
+                    Name::new("Scaled Render"),
+                    Node {
+                        position_type: PositionType::Absolute,
+                        width: percent(100),
+                        height: percent(100),
+                        ..default()
+                    },
+                    ImageNode::new(image.clone()),
+                    GlobalZIndex(i32::MIN),
+                    Pickable::IGNORE,
+                ))
+                .id();
+            commands.insert_resource(ScaledRender {
+                image,
+                size,
+                display,
+            });
+        }
+    }
+}
+
+fn spawn_scaled_pointer(mut commands: Commands) {
+    commands.spawn((Name::new("Scaled Render Pointer"), SCALED_POINTER_ID));
+}
+
+/// Mirror the mouse onto the offscreen image so mesh picking still works there.
+fn drive_scaled_pointer(
+    scaled: Option<Res<ScaledRender>>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    mut last_position: Local<Option<Vec2>>,
+    mut buttons: MessageReader<MouseButtonInput>,
+    mut pointer_inputs: MessageWriter<PointerInput>,
+) {
+    let Some(scaled) = scaled else {
+        *last_position = None;
+        buttons.clear();
+        return;
+    };
+    let Some(cursor) = window.cursor_position() else {
+        buttons.clear();
+        return;
+    };
+    let position = cursor * scaled.size.as_vec2() / window.size();
+    let location = Location {
+        target: NormalizedRenderTarget::Image(ImageRenderTarget::from(scaled.image.clone())),
+        position,
+    };
+
+    if *last_position != Some(position) {
+        let delta = last_position.map_or(Vec2::ZERO, |last| position - last);
+        pointer_inputs.write(PointerInput::new(
+            SCALED_POINTER_ID,
+            location.clone(),
+            PointerAction::Move { delta },
+        ));
+        *last_position = Some(position);
+    }
+
+    for input in buttons.read() {
+        let button = match input.button {
+            MouseButton::Left => PointerButton::Primary,
+            MouseButton::Right => PointerButton::Secondary,
+            MouseButton::Middle => PointerButton::Middle,
+            _ => continue,
+        };
+        let action = match input.state {
+            ButtonState::Pressed => PointerAction::Press(button),
+            ButtonState::Released => PointerAction::Release(button),
+        };
+        pointer_inputs.write(PointerInput::new(
+            SCALED_POINTER_ID,
+            location.clone(),
+            action,
+        ));
+    }
+}
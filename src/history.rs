@@ -0,0 +1,182 @@
+//! Match history - an append-only log with one summary line per finished match.
+//!
+//! Line format: `result|opponent|duration|hp|opponent hp|cards`, e.g.
+//! `Victory|Solo|83.2|420/1000|0/1000|Strike:4,Defend:2`.
+
+use bevy::prelude::*;
+
+use crate::{
+    game::{CardRegistry, GameResult, Health, LocalPlayer, Opponent, PlayLog, PlayerHandle},
+    network::NetworkPlayers,
+    persist,
+    replay::is_replay,
+    sandbox::is_sandbox,
+    screens::Screen,
+};
+
+const SAVE_KEY: &str = "history.txt";
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<MatchStarted>();
+    app.add_systems(OnEnter(Screen::Gameplay), stamp_match_start);
+    app.add_systems(
+        OnEnter(GameResult::Victory),
+        record_victory.run_if(not(is_sandbox.or(is_replay))),
+    );
+    app.add_systems(
+        OnEnter(GameResult::Defeat),
+        record_defeat.run_if(not(is_sandbox.or(is_replay))),
+    );
+}
+
+/// Summary of one finished match.
+#[derive(Debug, Clone)]
+pub struct MatchSummary {
+    pub won: bool,
+    /// Opponent peer id, or "Solo" offline.
+    pub opponent: String,
+    pub duration_secs: f32,
+    pub hp: (f32, f32),
+    pub opponent_hp: (f32, f32),
+    /// Local plays per card name, most played first.
+    pub cards: Vec<(String, u32)>,
+}
+
+impl MatchSummary {
+    fn to_line(&self) -> String {
+        let cards = self
+            .cards
+            .iter()
+            .map(|(name, count)| format!("{name}:{count}"))
+            .collect::<Vec<_>>()
+            .join(",");
+        format!(
+            "{}|{}|{:.1}|{:.0}/{:.0}|{:.0}/{:.0}|{cards}",
+            if self.won { "Victory" } else { "Defeat" },
+            self.opponent,
+            self.duration_secs,
+            self.hp.0,
+            self.hp.1,
+            self.opponent_hp.0,
+            self.opponent_hp.1,
+        )
+    }
+
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.split('|');
+        let won = fields.next()? == "Victory";
+        let opponent = fields.next()?.to_string();
+        let duration_secs = fields.next()?.parse().ok()?;
+        let hp = parse_hp(fields.next()?)?;
+        let opponent_hp = parse_hp(fields.next()?)?;
+        let cards = fields
+            .next()
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|entry| entry.rsplit_once(':'))
+            .filter_map(|(name, count)| Some((name.to_string(), count.parse().ok()?)))
+            .collect();
+        Some(Self {
+            won,
+            opponent,
+            duration_secs,
+            hp,
+            opponent_hp,
+            cards,
+        })
+    }
+
+    pub fn cards_played(&self) -> u32 {
+        self.cards.iter().map(|(_, count)| count).sum()
+    }
+}
+
+fn parse_hp(field: &str) -> Option<(f32, f32)> {
+    let (current, max) = field.split_once('/')?;
+    Some((current.parse().ok()?, max.parse().ok()?))
+}
+
+/// Every recorded match, newest first.
+pub fn load_history() -> Vec<MatchSummary> {
+    persist::load(SAVE_KEY)
+        .unwrap_or_default()
+        .lines()
+        .rev()
+        .filter_map(MatchSummary::parse)
+        .collect()
+}
+
+/// When the current match started, in seconds of app time.
+#[derive(Resource, Default)]
+struct MatchStarted(f32);
+
+fn stamp_match_start(time: Res<Time>, mut started: ResMut<MatchStarted>) {
+    started.0 = time.elapsed_secs();
+}
+
+fn record_victory(
+    time: Res<Time>,
+    started: Res<MatchStarted>,
+    registry: Res<CardRegistry>,
+    network_players: Option<Res<NetworkPlayers>>,
+    player: Query<(&Health, &PlayLog), With<LocalPlayer>>,
+    opponent: Query<(&Health, &PlayerHandle), With<Opponent>>,
+) {
+    record_match(
+        true,
+        time.elapsed_secs() - started.0,
+        &registry,
+        network_players.as_deref(),
+        &player,
+        &opponent,
+    );
+}
+
+fn record_defeat(
+    time: Res<Time>,
+    started: Res<MatchStarted>,
+    registry: Res<CardRegistry>,
+    network_players: Option<Res<NetworkPlayers>>,
+    player: Query<(&Health, &PlayLog), With<LocalPlayer>>,
+    opponent: Query<(&Health, &PlayerHandle), With<Opponent>>,
+) {
+    record_match(
+        false,
+        time.elapsed_secs() - started.0,
+        &registry,
+        network_players.as_deref(),
+        &player,
+        &opponent,
+    );
+}
+
+fn record_match(
+    won: bool,
+    duration_secs: f32,
+    registry: &CardRegistry,
+    network_players: Option<&NetworkPlayers>,
+    player: &Query<(&Health, &PlayLog), With<LocalPlayer>>,
+    opponent: &Query<(&Health, &PlayerHandle), With<Opponent>>,
+) {
+    let (Ok((health, play_log)), Ok((opponent_health, handle))) =
+        (player.single(), opponent.single())
+    else {
+        return;
+    };
+    let opponent_name = network_players
+        .and_then(|players| players.handles.get(handle.0))
+        .map_or_else(|| "Solo".to_string(), |peer| peer.0.to_string());
+    let summary = MatchSummary {
+        won,
+        opponent: opponent_name,
+        duration_secs,
+        hp: (health.current.max(0.0), health.max),
+        opponent_hp: (opponent_health.current.max(0.0), opponent_health.max),
+        cards: play_log
+            .counts()
+            .into_iter()
+            .filter_map(|(card, count)| registry.get(card).map(|def| (def.name.clone(), count)))
+            .collect(),
+    };
+    persist::append_line(SAVE_KEY, &summary.to_line());
+}
@@ -1,3 +1,9 @@
+//! Gameplay input shared by the offline and GGRS paths.
+//!
+//! Keyboard presses, clicks buffered in `PendingInput` and BRP-simulated keys
+//! all end up as the same [`GameInput`] bit flags, which offline play applies
+//! directly and online play sends through GGRS.
+
 use bevy::{input::ButtonInput, prelude::KeyCode};
 use bytemuck::{Pod, Zeroable};
 use serde::{Deserialize, Serialize};
@@ -14,6 +20,16 @@ pub const INPUT_CARD_7: u16 = 1 << 7;
 pub const INPUT_CARD_8: u16 = 1 << 8;
 pub const INPUT_CARD_9: u16 = 1 << 9;
 pub const INPUT_CARD_10: u16 = 1 << 10;
+/// The sending player concedes the match.
+pub const INPUT_CONCEDE: u16 = 1 << 11;
+/// Cards pressed in this input wait in the play queue until they are affordable.
+pub const INPUT_QUEUE_WAIT: u16 = 1 << 12;
+/// Clear the sending player's play queue.
+pub const INPUT_CANCEL_QUEUE: u16 = 1 << 13;
+/// The sending player is ready for the next game of a series.
+pub const INPUT_NEXT_GAME: u16 = 1 << 14;
+/// Every card flag, `INPUT_CARD_1` through `INPUT_CARD_10`.
+pub const INPUT_CARDS: u16 = 0b111_1111_1110;
 
 /// Network-synchronized game input.
 #[repr(C)]
@@ -32,71 +48,136 @@ pub fn card_flag(index: usize) -> Option<u16> {
     }
 }
 
+/// Keyboard bindings, with the key name [`flags_from_key_string`] accepts for each.
+const KEY_BINDINGS: [(KeyCode, &str, u16); 12] = [
+    (KeyCode::KeyD, "D", INPUT_DRAW),
+    (KeyCode::Digit1, "1", INPUT_CARD_1),
+    (KeyCode::Digit2, "2", INPUT_CARD_2),
+    (KeyCode::Digit3, "3", INPUT_CARD_3),
+    (KeyCode::Digit4, "4", INPUT_CARD_4),
+    (KeyCode::Digit5, "5", INPUT_CARD_5),
+    (KeyCode::Digit6, "6", INPUT_CARD_6),
+    (KeyCode::Digit7, "7", INPUT_CARD_7),
+    (KeyCode::Digit8, "8", INPUT_CARD_8),
+    (KeyCode::Digit9, "9", INPUT_CARD_9),
+    (KeyCode::Digit0, "0", INPUT_CARD_10),
+    (KeyCode::Backspace, "CANCEL", INPUT_CANCEL_QUEUE),
+];
+
 /// Build input flags from keyboard presses.
 pub fn flags_from_keyboard(keyboard: &ButtonInput<KeyCode>) -> u16 {
-    let mut flags = 0;
+    KEY_BINDINGS
+        .iter()
+        .filter(|(key, _, _)| keyboard.just_pressed(*key))
+        .fold(0, |flags, (_, _, flag)| flags | flag)
+}
 
-    if keyboard.just_pressed(KeyCode::KeyD) {
-        flags |= INPUT_DRAW;
+/// Build input flags from a simulated key string (e.g., "D", "1"-"9", "0",
+/// "CANCEL" or "CONCEDE").
+#[cfg(feature = "dev")]
+pub fn flags_from_key_string(key: &str) -> u16 {
+    let key = key.trim().to_uppercase();
+    // Conceding has no key; it goes through the pause menu.
+    if key == "CONCEDE" {
+        return INPUT_CONCEDE;
     }
+    KEY_BINDINGS
+        .iter()
+        .find(|(_, name, _)| *name == key)
+        .map_or(0, |(_, _, flag)| *flag)
+}
 
-    if keyboard.just_pressed(KeyCode::Digit1) {
-        flags |= INPUT_CARD_1;
-    }
-    if keyboard.just_pressed(KeyCode::Digit2) {
-        flags |= INPUT_CARD_2;
-    }
-    if keyboard.just_pressed(KeyCode::Digit3) {
-        flags |= INPUT_CARD_3;
-    }
-    if keyboard.just_pressed(KeyCode::Digit4) {
-        flags |= INPUT_CARD_4;
-    }
-    if keyboard.just_pressed(KeyCode::Digit5) {
-        flags |= INPUT_CARD_5;
-    }
-    if keyboard.just_pressed(KeyCode::Digit6) {
-        flags |= INPUT_CARD_6;
-    }
-    if keyboard.just_pressed(KeyCode::Digit7) {
-        flags |= INPUT_CARD_7;
-    }
-    if keyboard.just_pressed(KeyCode::Digit8) {
-        flags |= INPUT_CARD_8;
-    }
-    if keyboard.just_pressed(KeyCode::Digit9) {
-        flags |= INPUT_CARD_9;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn card_flags_match_hand_slots() {
+        let cards = [
+            INPUT_CARD_1,
+            INPUT_CARD_2,
+            INPUT_CARD_3,
+            INPUT_CARD_4,
+            INPUT_CARD_5,
+            INPUT_CARD_6,
+            INPUT_CARD_7,
+            INPUT_CARD_8,
+            INPUT_CARD_9,
+            INPUT_CARD_10,
+        ];
+        for (index, flag) in cards.iter().enumerate() {
+            assert_eq!(card_flag(index), Some(*flag));
+        }
+        assert_eq!(card_flag(10), None);
+        assert_eq!(cards.iter().fold(0, |mask, flag| mask | flag), INPUT_CARDS);
     }
-    if keyboard.just_pressed(KeyCode::Digit0) {
-        flags |= INPUT_CARD_10;
+
+    #[test]
+    fn flags_are_distinct() {
+        let flags = [
+            INPUT_DRAW,
+            INPUT_CARDS,
+            INPUT_CONCEDE,
+            INPUT_QUEUE_WAIT,
+            INPUT_CANCEL_QUEUE,
+            INPUT_NEXT_GAME,
+        ];
+        for (i, a) in flags.iter().enumerate() {
+            for b in &flags[i + 1..] {
+                assert_eq!(a & b, 0);
+            }
+        }
     }
 
-    flags
-}
+    #[test]
+    fn keyboard_maps_presses_to_flags() {
+        let mut keyboard = ButtonInput::<KeyCode>::default();
+        assert_eq!(flags_from_keyboard(&keyboard), 0);
 
-/// Build input flags from a simulated key string (e.g., "D", "1"-"9", "0").
-#[cfg(feature = "dev")]
-pub fn flags_from_key_string(key: &str) -> u16 {
-    let key = key.trim().to_uppercase();
-    if key == "D" {
-        return INPUT_DRAW;
+        keyboard.press(KeyCode::KeyD);
+        keyboard.press(KeyCode::Digit3);
+        keyboard.press(KeyCode::Digit0);
+        assert_eq!(
+            flags_from_keyboard(&keyboard),
+            INPUT_DRAW | INPUT_CARD_3 | INPUT_CARD_10
+        );
+
+        // Held keys only count on the frame they go down.
+        keyboard.clear();
+        assert_eq!(flags_from_keyboard(&keyboard), 0);
+
+        keyboard.press(KeyCode::Backspace);
+        assert_eq!(flags_from_keyboard(&keyboard), INPUT_CANCEL_QUEUE);
     }
 
-    if let Ok(num) = key.parse::<usize>() {
-        return match num {
-            1 => INPUT_CARD_1,
-            2 => INPUT_CARD_2,
-            3 => INPUT_CARD_3,
-            4 => INPUT_CARD_4,
-            5 => INPUT_CARD_5,
-            6 => INPUT_CARD_6,
-            7 => INPUT_CARD_7,
-            8 => INPUT_CARD_8,
-            9 => INPUT_CARD_9,
-            0 => INPUT_CARD_10,
-            _ => 0,
-        };
+    #[cfg(feature = "dev")]
+    #[test]
+    fn key_strings_map_to_flags() {
+        assert_eq!(flags_from_key_string("D"), INPUT_DRAW);
+        assert_eq!(flags_from_key_string(" d "), INPUT_DRAW);
+        assert_eq!(flags_from_key_string("1"), INPUT_CARD_1);
+        assert_eq!(flags_from_key_string("9"), INPUT_CARD_9);
+        assert_eq!(flags_from_key_string("0"), INPUT_CARD_10);
+        assert_eq!(flags_from_key_string("concede"), INPUT_CONCEDE);
+        assert_eq!(flags_from_key_string("Cancel"), INPUT_CANCEL_QUEUE);
     }
 
-    0
+    #[cfg(feature = "dev")]
+    #[test]
+    fn unknown_key_strings_are_ignored() {
+        for key in ["", "10", "X", "-1", "DRAW"] {
+            assert_eq!(flags_from_key_string(key), 0, "{key:?}");
+        }
+    }
+
+    #[cfg(feature = "dev")]
+    #[test]
+    fn keyboard_and_key_strings_agree() {
+        for (key, name, flag) in KEY_BINDINGS {
+            let mut keyboard = ButtonInput::<KeyCode>::default();
+            keyboard.press(key);
+            assert_eq!(flags_from_keyboard(&keyboard), flag);
+            assert_eq!(flags_from_key_string(name), flag);
+        }
+    }
 }
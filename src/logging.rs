@@ -0,0 +1,76 @@
+//! Logging policy.
+//!
+//! - `info!` is for one-off events and state transitions: a match starting,
+//!   a peer joining, a result. Never from something that runs every frame.
+//! - Systems that run every frame or on every input use [`hot_log!`], which
+//!   stays at trace level unless verbose logging is on.
+//! - Values polled every frame (session state, peer lists) go through
+//!   [`Transition`] so only changes are logged.
+//!
+//! Verbose logging starts on with `SENSEN_VERBOSE=1` and can be toggled from
+//! the dev console with `verbose`.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use bevy::prelude::*;
+
+/// A static so the macro works from any system without an extra parameter.
+static VERBOSE: AtomicBool = AtomicBool::new(false);
+
+pub(super) fn plugin(_app: &mut App) {
+    #[cfg(all(feature = "dev", not(target_family = "wasm")))]
+    if std::env::var("SENSEN_VERBOSE").is_ok_and(|value| value == "1") {
+        set_verbose(true);
+    }
+}
+
+pub fn verbose() -> bool {
+    VERBOSE.load(Ordering::Relaxed)
+}
+
+#[cfg_attr(not(feature = "dev"), allow(dead_code))]
+pub fn set_verbose(on: bool) {
+    VERBOSE.store(on, Ordering::Relaxed);
+}
+
+/// Log from a hot path: `info!` while verbose logging is on, `trace!` otherwise.
+macro_rules! hot_log {
+    ($($arg:tt)*) => {
+        if $crate::logging::verbose() {
+            ::bevy::log::info!($($arg)*);
+        } else {
+            ::bevy::log::trace!($($arg)*);
+        }
+    };
+}
+pub(crate) use hot_log;
+
+/// The last value seen of something polled every frame, for logging it only
+/// when it changes. Keep one in a `Local`.
+#[derive(Debug, Default)]
+pub struct Transition<T>(Option<T>);
+
+impl<T: PartialEq> Transition<T> {
+    /// Whether `value` differs from the last one seen (the first always does).
+    pub fn changed(&mut self, value: T) -> bool {
+        if self.0.as_ref() == Some(&value) {
+            return false;
+        }
+        self.0 = Some(value);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transition_reports_only_changes() {
+        let mut state = Transition::default();
+        assert!(state.changed("Synchronizing"));
+        assert!(!state.changed("Synchronizing"));
+        assert!(state.changed("Running"));
+        assert!(!state.changed("Running"));
+    }
+}
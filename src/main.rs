@@ -3,23 +3,35 @@
 // Disable console on Windows for non-dev builds.
 #![cfg_attr(not(feature = "dev"), windows_subsystem = "windows")]
 
+mod asset_fallback;
 mod asset_tracking;
 mod audio;
+mod cloud_sync;
+mod crash;
 mod demo;
 #[cfg(feature = "dev")]
 mod dev_tools;
 mod game;
+mod graphics;
+mod history;
 mod input;
+mod logging;
 mod menus;
 mod network;
+mod persist;
+mod replay;
+mod run;
+mod sandbox;
 mod screens;
+mod stats;
 mod theme;
+mod tutorial;
 
 #[cfg(feature = "dev_native")]
 use bevy::remote::http::RemoteHttpPlugin;
-use bevy::{asset::AssetMetaCheck, prelude::*};
+use bevy::{asset::AssetMetaCheck, log::LogPlugin, prelude::*};
 use bevy_defer::AsyncPlugin;
-use bevy_rich_text3d::{LoadFonts, Text3dPlugin};
+use bevy_rich_text3d::Text3dPlugin;
 
 fn main() -> AppExit {
     App::new().add_plugins(AppPlugin).run()
@@ -39,6 +51,11 @@ impl Plugin for AppPlugin {
                     meta_check: AssetMetaCheck::Never,
                     ..default()
                 })
+                .set(LogPlugin {
+                    // Keeps recent log lines around for crash reports.
+                    custom_layer: crash::log_layer,
+                    ..default()
+                })
                 .set(WindowPlugin {
                     primary_window: Window {
                         title: "Sensen".to_string(),
@@ -55,11 +72,6 @@ impl Plugin for AppPlugin {
                 ..default()
             },
         ));
-        // Embed a font for wasm (no system fonts available).
-        app.insert_resource(LoadFonts {
-            font_embedded: vec![include_bytes!("../assets/fonts/FiraSans-Bold.ttf")],
-            ..default()
-        });
 
         app.insert_resource(UiPickingSettings {
             require_markers: true,
@@ -71,16 +83,30 @@ impl Plugin for AppPlugin {
 
         // Add other plugins.
         app.add_plugins((
+            asset_fallback::plugin,
             asset_tracking::plugin,
             audio::plugin,
             demo::plugin,
             #[cfg(feature = "dev")]
             dev_tools::plugin,
             game::plugin,
+            graphics::plugin,
+            history::plugin,
             menus::plugin,
             network::plugin,
+            // Nested: a plugin tuple holds at most 15 entries.
+            (
+                cloud_sync::plugin,
+                crash::plugin,
+                logging::plugin,
+                replay::plugin,
+                run::plugin,
+                sandbox::plugin,
+            ),
             screens::plugin,
+            stats::plugin,
             theme::plugin,
+            tutorial::plugin,
         ));
 
         // Add Bevy Remote Protocol for debugging (native dev only)
@@ -133,6 +159,11 @@ pub(crate) enum AppSystems {
     Update,
 }
 
+/// The camera drawing the table. Other 3D cameras (like the opponent
+/// close-up) render into images and never move with it.
+#[derive(Component)]
+pub(crate) struct MainCamera;
+
 /// Whether or not the game is paused.
 #[derive(States, Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
 struct Pause(pub bool);
@@ -145,11 +176,24 @@ fn spawn_camera(mut commands: Commands) {
     let camera_transform = Transform::from_xyz(0.0, 12.0, 14.0).looking_at(Vec3::ZERO, Vec3::Y);
     commands.spawn((
         Name::new("Camera"),
+        MainCamera,
         Camera3d::default(),
         Msaa::Off,
         camera_transform,
+        MeshPickingCamera,
+    ));
+    // The UI gets its own camera so it stays at full resolution when the 3D
+    // scene renders at a lower render scale (see `graphics`).
+    commands.spawn((
+        Name::new("UI Camera"),
+        Camera2d,
+        Camera {
+            order: 1,
+            clear_color: ClearColorConfig::None,
+            ..default()
+        },
+        Msaa::Off,
         IsDefaultUiCamera,
         UiPickingCamera,
-        MeshPickingCamera,
     ));
 }
@@ -0,0 +1,198 @@
+//! Animated 3D scene behind the main menu: a slowly turning table with cards
+//! drifting above it and embers rising through the air.
+//!
+//! Everything is spawned on entering [`Menu::Main`] and despawned on exit, so
+//! the scene costs nothing outside the title screen. [`MenuBackgroundQuality`]
+//! drops the embers and most of the cards for weak GPUs.
+
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use crate::{AppSystems, menus::Menu};
+
+/// Radians per second the table turns.
+const TABLE_SPIN_SPEED: f32 = 0.08;
+const TABLE_RADIUS: f32 = 7.0;
+const CARD_SIZE: Vec3 = Vec3::new(1.2, 0.02, 1.7);
+/// How far the cards bob up and down around their resting height.
+const CARD_BOB_HEIGHT: f32 = 0.35;
+const EMBER_RADIUS: f32 = 0.05;
+/// Embers wrap back to the table once they rise past this height.
+const EMBER_CEILING: f32 = 8.0;
+const EMBER_COLOR: Color = Color::srgb(1.0, 0.45, 0.1);
+/// Spreads cards and embers evenly around the table without an RNG.
+const GOLDEN_ANGLE: f32 = 2.399_963;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<MenuBackgroundQuality>();
+    app.add_systems(OnEnter(Menu::Main), spawn_background);
+    app.add_systems(
+        Update,
+        (spin_table, drift_cards, rise_embers)
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Menu::Main)),
+    );
+}
+
+/// Detail level of the main menu background.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MenuBackgroundQuality {
+    #[default]
+    High,
+    /// Fewer cards, no embers and no shadows.
+    Low,
+}
+
+impl MenuBackgroundQuality {
+    fn card_count(self) -> usize {
+        match self {
+            Self::High => 10,
+            Self::Low => 4,
+        }
+    }
+
+    fn ember_count(self) -> usize {
+        match self {
+            Self::High => 60,
+            Self::Low => 0,
+        }
+    }
+}
+
+#[derive(Component)]
+struct BackgroundTable;
+
+#[derive(Component)]
+struct DriftingCard {
+    rest: Vec3,
+    phase: f32,
+}
+
+#[derive(Component)]
+struct Ember {
+    speed: f32,
+    phase: f32,
+}
+
+fn spawn_background(
+    mut commands: Commands,
+    quality: Res<MenuBackgroundQuality>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        Name::new("Menu Background Sun"),
+        DirectionalLight {
+            illuminance: 4000.0,
+            shadows_enabled: *quality == MenuBackgroundQuality::High,
+            ..default()
+        },
+        Transform::from_xyz(-4.0, 10.0, 6.0).looking_at(Vec3::ZERO, Vec3::Y),
+        DespawnOnExit(Menu::Main),
+    ));
+    commands.spawn((
+        Name::new("Menu Background Glow"),
+        PointLight {
+            color: EMBER_COLOR,
+            intensity: 400_000.0,
+            range: 20.0,
+            ..default()
+        },
+        Transform::from_xyz(0.0, 2.0, 0.0),
+        DespawnOnExit(Menu::Main),
+    ));
+
+    let card_mesh = meshes.add(Cuboid::from_size(CARD_SIZE));
+    let card_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.85, 0.8, 0.7),
+        perceptual_roughness: 0.7,
+        ..default()
+    });
+    let table = commands
+        .spawn((
+            Name::new("Menu Background Table"),
+            BackgroundTable,
+            Mesh3d(meshes.add(Cylinder::new(TABLE_RADIUS, 0.3))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgb(0.12, 0.08, 0.06),
+                perceptual_roughness: 0.9,
+                ..default()
+            })),
+            Transform::from_xyz(0.0, -0.15, 0.0),
+            DespawnOnExit(Menu::Main),
+        ))
+        .id();
+
+    // Cards are children of the table so they turn with it.
+    commands.entity(table).with_children(|table| {
+        for i in 0..quality.card_count() {
+            let angle = i as f32 * GOLDEN_ANGLE;
+            let distance =
+                TABLE_RADIUS * (0.3 + 0.6 * (i as f32 + 0.5) / quality.card_count() as f32);
+            let rest = Vec3::new(angle.cos() * distance, 1.2, angle.sin() * distance);
+            table.spawn((
+                Name::new("Menu Background Card"),
+                DriftingCard { rest, phase: angle },
+                Mesh3d(card_mesh.clone()),
+                MeshMaterial3d(card_material.clone()),
+                Transform::from_translation(rest),
+            ));
+        }
+    });
+
+    let ember_mesh = meshes.add(Sphere::new(EMBER_RADIUS));
+    let ember_material = materials.add(StandardMaterial {
+        base_color: EMBER_COLOR,
+        emissive: LinearRgba::from(EMBER_COLOR) * 4.0,
+        unlit: true,
+        ..default()
+    });
+    for i in 0..quality.ember_count() {
+        let angle = i as f32 * GOLDEN_ANGLE;
+        let distance = TABLE_RADIUS * (i as f32 / quality.ember_count() as f32).sqrt();
+        let height = (i as f32 * 0.37).fract() * EMBER_CEILING;
+        commands.spawn((
+            Name::new("Menu Background Ember"),
+            Ember {
+                speed: 0.4 + (i as f32 * 0.61).fract() * 0.6,
+                phase: angle,
+            },
+            Mesh3d(ember_mesh.clone()),
+            MeshMaterial3d(ember_material.clone()),
+            Transform::from_xyz(angle.cos() * distance, height, angle.sin() * distance),
+            DespawnOnExit(Menu::Main),
+        ));
+    }
+}
+
+fn spin_table(time: Res<Time>, mut table: Query<&mut Transform, With<BackgroundTable>>) {
+    for mut transform in &mut table {
+        transform.rotate_y(TABLE_SPIN_SPEED * time.delta_secs());
+    }
+}
+
+fn drift_cards(time: Res<Time>, mut cards: Query<(&DriftingCard, &mut Transform)>) {
+    let t = time.elapsed_secs();
+    for (card, mut transform) in &mut cards {
+        let bob = (t * 0.6 + card.phase).sin() * CARD_BOB_HEIGHT;
+        transform.translation = card.rest + Vec3::Y * bob;
+        transform.rotation = Quat::from_rotation_y(card.phase + t * 0.15)
+            * Quat::from_rotation_x((t * 0.4 + card.phase).sin() * PI / 12.0);
+    }
+}
+
+fn rise_embers(time: Res<Time>, mut embers: Query<(&Ember, &mut Transform)>) {
+    let dt = time.delta_secs();
+    let t = time.elapsed_secs();
+    for (ember, mut transform) in &mut embers {
+        transform.translation.y += ember.speed * dt;
+        // Sway sideways so the embers flicker rather than rise in lines.
+        transform.translation.x += (t * 1.3 + ember.phase).sin() * 0.2 * dt;
+        if transform.translation.y > EMBER_CEILING {
+            transform.translation.y -= EMBER_CEILING;
+        }
+        let flicker = 0.6 + 0.4 * (t * 5.0 + ember.phase).sin().abs();
+        transform.scale = Vec3::splat(flicker);
+    }
+}
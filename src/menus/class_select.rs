@@ -0,0 +1,112 @@
+//! The character class selection step before a match.
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+
+use crate::{
+    asset_tracking::ResourceHandles,
+    game::{CharacterClass, DeckMode},
+    menus::Menu,
+    sandbox::Sandbox,
+    screens::Screen,
+    theme::widget,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<ClassSelectNext>();
+    app.add_systems(OnEnter(Menu::ClassSelect), spawn_class_select_menu);
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::ClassSelect).and(input_just_pressed(KeyCode::Escape))),
+    );
+}
+
+/// Where to go once a class has been picked.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClassSelectNext {
+    #[default]
+    Solo,
+    Online,
+    /// Start a single-player run on the map screen.
+    Run,
+    /// A Solo match with the practice sandbox tools.
+    Sandbox,
+}
+
+fn spawn_class_select_menu(mut commands: Commands) {
+    commands.spawn((
+        widget::ui_root("Class Select Menu"),
+        GlobalZIndex(2),
+        DespawnOnExit(Menu::ClassSelect),
+        children![
+            widget::header("Choose your class"),
+            widget::button("Ironclad", pick_ironclad),
+            widget::button("Silent", pick_silent),
+            widget::button("Back", go_back_on_click),
+        ],
+    ));
+}
+
+fn pick_ironclad(
+    _: On<Pointer<Click>>,
+    mut class: ResMut<CharacterClass>,
+    next: Res<ClassSelectNext>,
+    deck_mode: Res<DeckMode>,
+    mut sandbox: ResMut<Sandbox>,
+    resource_handles: Res<ResourceHandles>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    *class = CharacterClass::Ironclad;
+    start_match(
+        *next,
+        *deck_mode,
+        &mut sandbox,
+        &resource_handles,
+        &mut next_screen,
+    );
+}
+
+fn pick_silent(
+    _: On<Pointer<Click>>,
+    mut class: ResMut<CharacterClass>,
+    next: Res<ClassSelectNext>,
+    deck_mode: Res<DeckMode>,
+    mut sandbox: ResMut<Sandbox>,
+    resource_handles: Res<ResourceHandles>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    *class = CharacterClass::Silent;
+    start_match(
+        *next,
+        *deck_mode,
+        &mut sandbox,
+        &resource_handles,
+        &mut next_screen,
+    );
+}
+
+fn start_match(
+    next: ClassSelectNext,
+    deck_mode: DeckMode,
+    sandbox: &mut Sandbox,
+    resource_handles: &ResourceHandles,
+    next_screen: &mut NextState<Screen>,
+) {
+    sandbox.active = next == ClassSelectNext::Sandbox;
+    next_screen.set(match next {
+        ClassSelectNext::Online => Screen::Lobby,
+        ClassSelectNext::Run => Screen::Map,
+        ClassSelectNext::Solo if deck_mode == DeckMode::Draft => Screen::Draft,
+        ClassSelectNext::Solo | ClassSelectNext::Sandbox if resource_handles.is_all_done() => {
+            Screen::Gameplay
+        }
+        ClassSelectNext::Solo | ClassSelectNext::Sandbox => Screen::Loading,
+    });
+}
+
+fn go_back_on_click(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
+
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
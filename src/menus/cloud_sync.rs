@@ -0,0 +1,228 @@
+//! The cloud sync menu: consent, the sync code, and upload / download.
+
+use bevy::{
+    input::{
+        ButtonState,
+        common_conditions::input_just_pressed,
+        keyboard::{Key, KeyboardInput},
+    },
+    prelude::*,
+};
+
+use crate::{
+    cloud_sync::{self, CloudSync, SYNC_CODE_LEN, SyncStatus, sanitize_code},
+    menus::Menu,
+    theme::prelude::*,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<CodeDraft>();
+    app.add_systems(OnEnter(Menu::CloudSync), spawn_cloud_sync_menu);
+    app.add_systems(OnExit(Menu::CloudSync), discard_code_draft);
+    app.add_systems(
+        Update,
+        (
+            go_back.run_if(input_just_pressed(KeyCode::Escape).and(not_editing_code)),
+            type_sync_code,
+            update_cloud_sync_labels,
+        )
+            .chain()
+            .run_if(in_state(Menu::CloudSync)),
+    );
+}
+
+fn spawn_cloud_sync_menu(mut commands: Commands) {
+    commands.spawn((
+        widget::ui_root("Cloud Sync Menu"),
+        GlobalZIndex(2),
+        DespawnOnExit(Menu::CloudSync),
+        children![
+            widget::header("Cloud Sync"),
+            widget::label(
+                "Sync sends your saved decks, stats, match history, rating and\n\
+                 display name to the server set in sync.txt. Nothing is sent\n\
+                 until you turn it on."
+            ),
+            (
+                Name::new("Cloud Sync Grid"),
+                Node {
+                    display: Display::Grid,
+                    row_gap: px(10),
+                    column_gap: px(30),
+                    grid_template_columns: RepeatedGridTrack::px(2, 400.0),
+                    ..default()
+                },
+                children![
+                    (
+                        widget::label("Cloud Sync"),
+                        Node {
+                            justify_self: JustifySelf::End,
+                            ..default()
+                        }
+                    ),
+                    (
+                        Name::new("Consent Widget"),
+                        Node {
+                            justify_self: JustifySelf::Start,
+                            align_items: AlignItems::Center,
+                            column_gap: px(10),
+                            ..default()
+                        },
+                        children![
+                            (widget::label(""), CloudSyncLabel::Enabled),
+                            widget::button_small("Toggle", toggle_cloud_sync),
+                        ],
+                    ),
+                    (
+                        widget::label("Sync Code"),
+                        Node {
+                            justify_self: JustifySelf::End,
+                            ..default()
+                        }
+                    ),
+                    (
+                        Name::new("Code Widget"),
+                        Node {
+                            justify_self: JustifySelf::Start,
+                            align_items: AlignItems::Center,
+                            column_gap: px(10),
+                            ..default()
+                        },
+                        children![
+                            (widget::label(""), CloudSyncLabel::Code),
+                            widget::button_small("Edit", edit_sync_code),
+                        ],
+                    ),
+                ],
+            ),
+            (
+                Name::new("Sync Buttons"),
+                Node {
+                    column_gap: px(20),
+                    ..default()
+                },
+                children![
+                    widget::button_medium("Upload", upload_profile),
+                    widget::button_medium("Download", download_profile),
+                ],
+            ),
+            (widget::label(""), CloudSyncLabel::Status),
+            widget::button("Back", go_back_on_click),
+        ],
+    ));
+}
+
+#[derive(Component, Clone, Copy)]
+enum CloudSyncLabel {
+    Enabled,
+    Code,
+    Status,
+}
+
+fn toggle_cloud_sync(
+    _: On<Pointer<Click>>,
+    mut sync: ResMut<CloudSync>,
+    mut status: ResMut<SyncStatus>,
+) {
+    if sync.enabled {
+        sync.enabled = false;
+    } else if let Err(error) = sync.enable() {
+        warn!("{error}");
+        *status = SyncStatus::Failed(error);
+    }
+}
+
+fn upload_profile(_: On<Pointer<Click>>, mut commands: Commands) {
+    commands.queue(cloud_sync::upload);
+}
+
+fn download_profile(_: On<Pointer<Click>>, mut commands: Commands) {
+    commands.queue(cloud_sync::download);
+}
+
+fn update_cloud_sync_labels(
+    sync: Res<CloudSync>,
+    status: Res<SyncStatus>,
+    draft: Res<CodeDraft>,
+    mut labels: Query<(&CloudSyncLabel, &mut Text)>,
+) {
+    for (label, mut text) in &mut labels {
+        text.0 = match label {
+            CloudSyncLabel::Enabled => if sync.enabled { "On" } else { "Off" }.to_string(),
+            CloudSyncLabel::Code => match &draft.0 {
+                Some(draft) => format!("{draft}_"),
+                None if sync.code.is_empty() => "-".to_string(),
+                None => sync.code.clone(),
+            },
+            CloudSyncLabel::Status => status.label().to_string(),
+        };
+    }
+}
+
+/// The sync code being typed, while the code field is being edited.
+#[derive(Resource, Default)]
+struct CodeDraft(Option<String>);
+
+fn not_editing_code(draft: Res<CodeDraft>) -> bool {
+    draft.0.is_none()
+}
+
+fn edit_sync_code(_: On<Pointer<Click>>, mut draft: ResMut<CodeDraft>) {
+    draft.0 = Some(String::new());
+}
+
+/// Type a code from another machine: Enter keeps a valid code, Escape drops
+/// the edit.
+fn type_sync_code(
+    mut keys: MessageReader<KeyboardInput>,
+    mut draft: ResMut<CodeDraft>,
+    mut sync: ResMut<CloudSync>,
+) {
+    let Some(text) = draft.0.as_mut() else {
+        keys.clear();
+        return;
+    };
+    let mut done = None;
+    for key in keys.read() {
+        if key.state != ButtonState::Pressed {
+            continue;
+        }
+        match &key.logical_key {
+            Key::Enter => done = Some(true),
+            Key::Escape => done = Some(false),
+            Key::Backspace => {
+                text.pop();
+            }
+            _ => {
+                if let Some(typed) = &key.text
+                    && text.len() < SYNC_CODE_LEN
+                {
+                    text.extend(typed.chars().filter(char::is_ascii_alphanumeric));
+                }
+            }
+        }
+    }
+    match done {
+        Some(true) => {
+            if let Some(code) = draft.0.take().as_deref().and_then(sanitize_code)
+                && code != sync.code
+            {
+                sync.code = code;
+            }
+        }
+        Some(false) => draft.0 = None,
+        None => {}
+    }
+}
+
+fn discard_code_draft(mut draft: ResMut<CodeDraft>) {
+    draft.0 = None;
+}
+
+fn go_back_on_click(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Settings);
+}
+
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Settings);
+}
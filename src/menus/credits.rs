@@ -1,55 +1,150 @@
 //! The credits menu.
+//!
+//! Content comes from `assets/data/game.credits.ron`, so adding a name or an
+//! asset attribution doesn't need a code change. The list scrolls by itself;
+//! scrolling manually takes over until the player leaves it alone for a moment.
 
-use bevy::{ecs::spawn::SpawnIter, input::common_conditions::input_just_pressed, prelude::*};
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    ecs::spawn::SpawnIter,
+    input::{
+        common_conditions::input_just_pressed,
+        mouse::{MouseScrollUnit, MouseWheel},
+    },
+    prelude::*,
+};
+use serde::Deserialize;
 
-use crate::{asset_tracking::LoadResource, audio::music, menus::Menu, theme::prelude::*};
+use crate::{asset_tracking::LoadResource, menus::Menu, theme::prelude::*};
+
+const CREDITS_PATH: &str = "data/game.credits.ron";
+/// Pixels per second the list scrolls on its own.
+const AUTO_SCROLL_SPEED: f32 = 30.0;
+/// Seconds after the last manual scroll before auto-scroll resumes.
+const MANUAL_SCROLL_HOLD_SECS: f32 = 3.0;
+/// Pixels scrolled per mouse wheel line.
+const SCROLL_LINE_HEIGHT: f32 = 24.0;
 
 pub(super) fn plugin(app: &mut App) {
+    app.init_asset::<Credits>();
+    app.register_asset_loader(CreditsLoader);
+
     app.add_systems(OnEnter(Menu::Credits), spawn_credits_menu);
     app.add_systems(
         Update,
-        go_back.run_if(in_state(Menu::Credits).and(input_just_pressed(KeyCode::Escape))),
+        (
+            scroll_credits.run_if(in_state(Menu::Credits)),
+            go_back.run_if(in_state(Menu::Credits).and(input_just_pressed(KeyCode::Escape))),
+        ),
     );
 
     app.load_resource::<CreditsAssets>();
-    app.add_systems(OnEnter(Menu::Credits), start_credits_music);
 }
 
-fn spawn_credits_menu(mut commands: Commands) {
-    commands.spawn((
-        widget::ui_root("Credits Menu"),
-        GlobalZIndex(2),
-        DespawnOnExit(Menu::Credits),
-        children![
-            widget::header("Created by"),
-            created_by(),
-            widget::header("Assets"),
-            assets(),
-            widget::button("Back", go_back_on_click),
-        ],
-    ));
+/// Everyone and everything credited, grouped into titled sections.
+#[derive(Asset, TypePath, Debug, Deserialize)]
+struct Credits {
+    sections: Vec<CreditsSection>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CreditsSection {
+    title: String,
+    entries: Vec<CreditsEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CreditsEntry {
+    name: String,
+    detail: String,
+    /// License for third-party assets and code; people have none.
+    #[serde(default)]
+    license: Option<String>,
+}
+
+#[derive(Default, TypePath)]
+struct CreditsLoader;
+
+impl AssetLoader for CreditsLoader {
+    type Asset = Credits;
+    type Settings = ();
+    type Error = BevyError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["credits.ron"]
+    }
 }
 
-fn created_by() -> impl Bundle {
-    grid(vec![
-        ["Joe Shmoe", "Implemented alligator wrestling AI"],
-        ["Jane Doe", "Made the music for the alien invasion"],
-    ])
+#[derive(Component, Default)]
+struct CreditsScroll {
+    /// Seconds left before auto-scroll takes over again.
+    manual_hold: f32,
 }
 
-fn assets() -> impl Bundle {
-    grid(vec![
-        ["Ducky sprite", "CC0 by Caz Creates Games"],
-        ["Button SFX", "CC0 by Jaszunio15"],
-        ["Music", "CC BY 3.0 by Kevin MacLeod"],
-        [
-            "Bevy logo",
-            "All rights reserved by the Bevy Foundation, permission granted for splash screen use when unmodified",
-        ],
-    ])
+fn spawn_credits_menu(
+    mut commands: Commands,
+    credits_assets: Res<CreditsAssets>,
+    credits: Res<Assets<Credits>>,
+) {
+    let root = commands
+        .spawn((
+            widget::ui_root("Credits Menu"),
+            GlobalZIndex(2),
+            DespawnOnExit(Menu::Credits),
+        ))
+        .id();
+
+    commands.entity(root).with_children(|parent| {
+        parent
+            .spawn((
+                Name::new("Credits List"),
+                CreditsScroll::default(),
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: px(20),
+                    max_height: percent(70),
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                },
+                ScrollPosition::default(),
+            ))
+            .with_children(|list| {
+                let Some(credits) = credits.get(&credits_assets.credits) else {
+                    list.spawn(widget::label("Credits unavailable"));
+                    return;
+                };
+                for section in &credits.sections {
+                    list.spawn(widget::header(section.title.clone()));
+                    list.spawn(grid(&section.entries));
+                }
+            });
+        parent.spawn(widget::button("Back", go_back_on_click));
+    });
 }
 
-fn grid(content: Vec<[&'static str; 2]>) -> impl Bundle {
+fn grid(entries: &[CreditsEntry]) -> impl Bundle {
+    let cells: Vec<String> = entries
+        .iter()
+        .flat_map(|entry| {
+            let detail = match &entry.license {
+                Some(license) => format!("{} ({license})", entry.detail),
+                None => entry.detail.clone(),
+            };
+            [entry.name.clone(), detail]
+        })
+        .collect();
     (
         Name::new("Grid"),
         Node {
@@ -59,24 +154,48 @@ fn grid(content: Vec<[&'static str; 2]>) -> impl Bundle {
             grid_template_columns: RepeatedGridTrack::px(2, 400.0),
             ..default()
         },
-        Children::spawn(SpawnIter(content.into_iter().flatten().enumerate().map(
-            |(i, text)| {
-                (
-                    widget::label(text),
-                    Node {
-                        justify_self: if i.is_multiple_of(2) {
-                            JustifySelf::End
-                        } else {
-                            JustifySelf::Start
-                        },
-                        ..default()
+        Children::spawn(SpawnIter(cells.into_iter().enumerate().map(|(i, text)| {
+            (
+                widget::label(text),
+                Node {
+                    justify_self: if i.is_multiple_of(2) {
+                        JustifySelf::End
+                    } else {
+                        JustifySelf::Start
                     },
-                )
-            },
-        ))),
+                    ..default()
+                },
+            )
+        }))),
     )
 }
 
+fn scroll_credits(
+    time: Res<Time>,
+    mut wheel: MessageReader<MouseWheel>,
+    mut list: Query<(&mut CreditsScroll, &mut ScrollPosition, &ComputedNode)>,
+) {
+    let manual: f32 = wheel
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y * SCROLL_LINE_HEIGHT,
+            MouseScrollUnit::Pixel => event.y,
+        })
+        .sum();
+    for (mut scroll, mut position, node) in &mut list {
+        let max = (node.content_size.y - node.size.y).max(0.0) * node.inverse_scale_factor;
+        if manual != 0.0 {
+            scroll.manual_hold = MANUAL_SCROLL_HOLD_SECS;
+            position.0.y -= manual;
+        } else if scroll.manual_hold > 0.0 {
+            scroll.manual_hold -= time.delta_secs();
+        } else {
+            position.0.y += AUTO_SCROLL_SPEED * time.delta_secs();
+        }
+        position.0.y = position.0.y.clamp(0.0, max);
+    }
+}
+
 fn go_back_on_click(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
     next_menu.set(Menu::Main);
 }
@@ -89,22 +208,14 @@ fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
 #[reflect(Resource)]
 struct CreditsAssets {
     #[dependency]
-    music: Handle<AudioSource>,
+    credits: Handle<Credits>,
 }
 
 impl FromWorld for CreditsAssets {
     fn from_world(world: &mut World) -> Self {
         let assets = world.resource::<AssetServer>();
         Self {
-            music: assets.load("audio/music/Monkeys Spinning Monkeys.ogg"),
+            credits: assets.load(CREDITS_PATH),
         }
     }
 }
-
-fn start_credits_music(mut commands: Commands, credits_music: Res<CreditsAssets>) {
-    commands.spawn((
-        Name::new("Credits Music"),
-        DespawnOnExit(Menu::Credits),
-        music(credits_music.music.clone()),
-    ));
-}
@@ -0,0 +1,78 @@
+//! The match history menu - a scrollable list of past match summaries.
+
+use bevy::{ecs::spawn::SpawnIter, input::common_conditions::input_just_pressed, prelude::*};
+
+use crate::{history::load_history, menus::Menu, theme::widget};
+
+/// Most favorite cards named per entry.
+const CARDS_PER_ENTRY: usize = 3;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Menu::History), spawn_history_menu);
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::History).and(input_just_pressed(KeyCode::Escape))),
+    );
+}
+
+fn spawn_history_menu(mut commands: Commands) {
+    let history = load_history();
+    let root = commands
+        .spawn((
+            widget::ui_root("History Menu"),
+            GlobalZIndex(2),
+            DespawnOnExit(Menu::History),
+        ))
+        .id();
+
+    commands.entity(root).with_children(|parent| {
+        parent.spawn(widget::header("Match History"));
+        let entries = if history.is_empty() {
+            vec!["No matches played yet".to_string()]
+        } else {
+            history
+                .iter()
+                .map(|summary| {
+                    let top_cards = summary
+                        .cards
+                        .iter()
+                        .take(CARDS_PER_ENTRY)
+                        .map(|(name, count)| format!("{name} x{count}"))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    format!(
+                        "{} vs {} - {}:{:02} - HP {:.0}/{:.0} vs {:.0}/{:.0}\n{} cards played: {}",
+                        if summary.won { "Victory" } else { "Defeat" },
+                        summary.opponent,
+                        summary.duration_secs as u32 / 60,
+                        summary.duration_secs as u32 % 60,
+                        summary.hp.0,
+                        summary.hp.1,
+                        summary.opponent_hp.0,
+                        summary.opponent_hp.1,
+                        summary.cards_played(),
+                        top_cards,
+                    )
+                })
+                .collect()
+        };
+        parent.spawn(widget::scroll_list(
+            Node {
+                width: px(720),
+                max_height: percent(60),
+                ..default()
+            },
+            px(8),
+            Children::spawn(SpawnIter(entries.into_iter().map(widget::label))),
+        ));
+        parent.spawn(widget::button("Back", go_back_on_click));
+    });
+}
+
+fn go_back_on_click(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
+
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
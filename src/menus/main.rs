@@ -2,7 +2,15 @@
 
 use bevy::prelude::*;
 
-use crate::{asset_tracking::ResourceHandles, menus::Menu, screens::Screen, theme::widget};
+use crate::{
+    asset_tracking::ResourceHandles,
+    game::CharacterClass,
+    menus::{Menu, Tournament, class_select::ClassSelectNext},
+    run::RunState,
+    screens::Screen,
+    theme::widget,
+    tutorial::{Tutorial, start_tutorial},
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Menu::Main), spawn_main_menu);
@@ -15,16 +23,28 @@ fn spawn_main_menu(mut commands: Commands) {
         DespawnOnExit(Menu::Main),
         #[cfg(not(target_family = "wasm"))]
         children![
-            widget::button("Online", enter_lobby_screen),
-            widget::button("Solo", enter_loading_or_gameplay_screen),
+            widget::button("Online", choose_class_for_online),
+            widget::button("Solo", choose_class_for_solo),
+            widget::button("Sandbox", choose_class_for_sandbox),
+            widget::button("Tutorial", open_tutorial),
+            widget::button("Run", open_run),
+            widget::button("Tournament", open_tournament_menu),
+            widget::button("Profile", open_profile_menu),
+            widget::button("History", open_history_menu),
             widget::button("Settings", open_settings_menu),
             widget::button("Credits", open_credits_menu),
-            widget::button("Exit", exit_app),
+            widget::button("Exit", confirm_exit),
         ],
         #[cfg(target_family = "wasm")]
         children![
-            widget::button("Online", enter_lobby_screen),
-            widget::button("Solo", enter_loading_or_gameplay_screen),
+            widget::button("Online", choose_class_for_online),
+            widget::button("Solo", choose_class_for_solo),
+            widget::button("Sandbox", choose_class_for_sandbox),
+            widget::button("Tutorial", open_tutorial),
+            widget::button("Run", open_run),
+            widget::button("Tournament", open_tournament_menu),
+            widget::button("Profile", open_profile_menu),
+            widget::button("History", open_history_menu),
             widget::button("Settings", open_settings_menu),
             widget::button("Credits", open_credits_menu),
         ],
@@ -33,22 +53,84 @@ fn spawn_main_menu(mut commands: Commands) {
 
 // ── Menu button handlers ─────────────────────────────────────────────
 
-fn enter_lobby_screen(_: On<Pointer<Click>>, mut next_screen: ResMut<NextState<Screen>>) {
-    next_screen.set(Screen::Lobby);
+fn choose_class_for_online(
+    _: On<Pointer<Click>>,
+    mut class_select_next: ResMut<ClassSelectNext>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    *class_select_next = ClassSelectNext::Online;
+    next_menu.set(Menu::ClassSelect);
+}
+
+fn choose_class_for_solo(
+    _: On<Pointer<Click>>,
+    mut class_select_next: ResMut<ClassSelectNext>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    *class_select_next = ClassSelectNext::Solo;
+    next_menu.set(Menu::ClassSelect);
 }
 
-fn enter_loading_or_gameplay_screen(
+fn choose_class_for_sandbox(
     _: On<Pointer<Click>>,
+    mut class_select_next: ResMut<ClassSelectNext>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    *class_select_next = ClassSelectNext::Sandbox;
+    next_menu.set(Menu::ClassSelect);
+}
+
+fn open_tutorial(
+    _: On<Pointer<Click>>,
+    mut tutorial: ResMut<Tutorial>,
+    mut class: ResMut<CharacterClass>,
     resource_handles: Res<ResourceHandles>,
     mut next_screen: ResMut<NextState<Screen>>,
 ) {
-    if resource_handles.is_all_done() {
-        next_screen.set(Screen::Gameplay);
+    start_tutorial(
+        &mut tutorial,
+        &mut class,
+        &resource_handles,
+        &mut next_screen,
+    );
+}
+
+fn open_run(
+    _: On<Pointer<Click>>,
+    run: Res<RunState>,
+    mut class_select_next: ResMut<ClassSelectNext>,
+    mut next_menu: ResMut<NextState<Menu>>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    // Resume a run in progress, otherwise pick a class for a new one.
+    if run.is_active() {
+        next_screen.set(Screen::Map);
     } else {
-        next_screen.set(Screen::Loading);
+        *class_select_next = ClassSelectNext::Run;
+        next_menu.set(Menu::ClassSelect);
     }
 }
 
+fn open_tournament_menu(
+    _: On<Pointer<Click>>,
+    tournament: Res<Tournament>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    next_menu.set(if tournament.is_active() {
+        Menu::Bracket
+    } else {
+        Menu::TournamentSetup
+    });
+}
+
+fn open_profile_menu(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Profile);
+}
+
+fn open_history_menu(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::History);
+}
+
 fn open_settings_menu(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
     next_menu.set(Menu::Settings);
 }
@@ -57,6 +139,14 @@ fn open_credits_menu(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu
     next_menu.set(Menu::Credits);
 }
 
+#[cfg(not(target_family = "wasm"))]
+fn confirm_exit(_: On<Pointer<Click>>, mut commands: Commands) {
+    commands.spawn((
+        widget::confirm_dialog("Exit", "Quit the game?", exit_app),
+        DespawnOnExit(Menu::Main),
+    ));
+}
+
 #[cfg(not(target_family = "wasm"))]
 fn exit_app(_: On<Pointer<Click>>, mut app_exit: MessageWriter<AppExit>) {
     app_exit.write(AppExit::Success);
@@ -1,20 +1,35 @@
 //! The game's menus and transitions between them.
 
+mod background;
+mod class_select;
+mod cloud_sync;
 mod credits;
+mod history;
 mod main;
 mod pause;
+mod profile;
 mod settings;
+mod tournament;
 
 use bevy::prelude::*;
 
+pub use background::MenuBackgroundQuality;
+pub use tournament::Tournament;
+
 pub(super) fn plugin(app: &mut App) {
     app.init_state::<Menu>();
 
     app.add_plugins((
+        background::plugin,
+        class_select::plugin,
+        cloud_sync::plugin,
         credits::plugin,
+        history::plugin,
         main::plugin,
         settings::plugin,
         pause::plugin,
+        profile::plugin,
+        tournament::plugin,
     ));
 }
 
@@ -23,7 +38,13 @@ pub enum Menu {
     #[default]
     None,
     Main,
+    ClassSelect,
     Credits,
     Settings,
+    CloudSync,
     Pause,
+    Profile,
+    History,
+    TournamentSetup,
+    Bracket,
 }
@@ -2,7 +2,13 @@
 
 use bevy::{input::common_conditions::input_just_pressed, prelude::*};
 
-use crate::{menus::Menu, screens::Screen, theme::widget};
+use crate::{
+    game::{GameResult, PendingInput},
+    input::INPUT_CONCEDE,
+    menus::Menu,
+    screens::Screen,
+    theme::widget,
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Menu::Pause), spawn_pause_menu);
@@ -12,18 +18,24 @@ pub(super) fn plugin(app: &mut App) {
     );
 }
 
-fn spawn_pause_menu(mut commands: Commands) {
-    commands.spawn((
-        widget::ui_root("Pause Menu"),
-        GlobalZIndex(2),
-        DespawnOnExit(Menu::Pause),
-        children![
-            widget::header("Game paused"),
-            widget::button("Continue", close_menu),
-            widget::button("Settings", open_settings_menu),
-            widget::button("Quit to title", quit_to_title),
-        ],
-    ));
+fn spawn_pause_menu(mut commands: Commands, result: Res<State<GameResult>>) {
+    let root = commands
+        .spawn((
+            widget::ui_root("Pause Menu"),
+            GlobalZIndex(2),
+            DespawnOnExit(Menu::Pause),
+        ))
+        .id();
+    commands.entity(root).with_children(|parent| {
+        parent.spawn(widget::header("Game paused"));
+        parent.spawn(widget::button("Resume", close_menu));
+        parent.spawn(widget::button("Settings", open_settings_menu));
+        // Nothing to concede once the match is decided.
+        if *result.get() == GameResult::Playing {
+            parent.spawn(widget::button("Concede", confirm_concede));
+        }
+        parent.spawn(widget::button("Quit to title", quit_to_title));
+    });
 }
 
 fn open_settings_menu(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
@@ -34,6 +46,27 @@ fn close_menu(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
     next_menu.set(Menu::None);
 }
 
+fn confirm_concede(_: On<Pointer<Click>>, mut commands: Commands) {
+    commands.spawn((
+        widget::confirm_dialog(
+            "Concede",
+            "Give up this match? It counts as a loss.",
+            concede,
+        ),
+        DespawnOnExit(Menu::Pause),
+    ));
+}
+
+/// Concede through the input stream, so an online opponent sees it too.
+fn concede(
+    _: On<Pointer<Click>>,
+    mut pending_input: ResMut<PendingInput>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    pending_input.push_flags(INPUT_CONCEDE);
+    next_menu.set(Menu::None);
+}
+
 fn quit_to_title(_: On<Pointer<Click>>, mut next_screen: ResMut<NextState<Screen>>) {
     next_screen.set(Screen::Title);
 }
@@ -0,0 +1,63 @@
+//! The profile menu - lifetime match statistics.
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+
+use crate::{menus::Menu, network::Rating, stats::ProfileStats, theme::widget};
+
+/// Favorite cards listed on the profile.
+const FAVORITE_CARDS: usize = 3;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Menu::Profile), spawn_profile_menu);
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::Profile).and(input_just_pressed(KeyCode::Escape))),
+    );
+}
+
+fn spawn_profile_menu(mut commands: Commands, stats: Res<ProfileStats>, rating: Res<Rating>) {
+    let favorites = stats.favorite_cards(FAVORITE_CARDS);
+    let favorites = if favorites.is_empty() {
+        "-".to_string()
+    } else {
+        favorites
+            .iter()
+            .map(|(name, plays)| format!("{name} ({plays})"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    commands.spawn((
+        widget::ui_root("Profile Menu"),
+        GlobalZIndex(2),
+        DespawnOnExit(Menu::Profile),
+        children![
+            widget::header("Profile"),
+            widget::label(format!("Matches played: {}", stats.matches_played())),
+            widget::label(format!(
+                "Solo: {}W / {}L",
+                stats.solo.wins, stats.solo.losses
+            )),
+            widget::label(format!(
+                "Online: {}W / {}L",
+                stats.online.wins, stats.online.losses
+            )),
+            widget::label(format!("Rating: {}", rating.0)),
+            widget::label(format!("Damage dealt: {:.0}", stats.damage_dealt)),
+            widget::label(format!(
+                "Win streak: {} (best {})",
+                stats.current_streak, stats.longest_streak
+            )),
+            widget::label(format!("Favorite cards: {favorites}")),
+            widget::button("Back", go_back_on_click),
+        ],
+    ));
+}
+
+fn go_back_on_click(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
+
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
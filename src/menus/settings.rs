@@ -2,20 +2,56 @@
 //!
 //! Additional settings and accessibility options should go here.
 
-use bevy::{audio::Volume, input::common_conditions::input_just_pressed, prelude::*};
+use bevy::{
+    audio::Volume,
+    ecs::system::IntoObserverSystem,
+    input::{
+        ButtonState,
+        common_conditions::input_just_pressed,
+        keyboard::{Key, KeyboardInput},
+    },
+    prelude::*,
+};
 
-use crate::{menus::Menu, screens::Screen, theme::prelude::*};
+use crate::{
+    audio::{AudioBus, AudioMix},
+    game::{CameraSettings, DeckMode, InputSettings, MatchTimer},
+    graphics::GraphicsSettings,
+    menus::{Menu, MenuBackgroundQuality},
+    network::{MAX_NAME_CHARS, NetworkTuning, PlayerName, sanitize_name},
+    screens::Screen,
+    theme::prelude::*,
+};
 
 pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<NameDraft>();
     app.add_systems(OnEnter(Menu::Settings), spawn_settings_menu);
+    app.add_systems(OnExit(Menu::Settings), discard_name_draft);
     app.add_systems(
         Update,
-        go_back.run_if(in_state(Menu::Settings).and(input_just_pressed(KeyCode::Escape))),
+        (
+            go_back.run_if(input_just_pressed(KeyCode::Escape).and(not_editing_name)),
+            type_player_name,
+        )
+            .chain()
+            .run_if(in_state(Menu::Settings)),
     );
 
     app.add_systems(
         Update,
-        update_global_volume_label.run_if(in_state(Menu::Settings)),
+        (
+            update_global_volume_label,
+            update_bus_volume_labels,
+            update_feedback_sounds_label,
+            update_deck_mode_label,
+            update_queue_mode_label,
+            update_match_timer_label,
+            update_background_quality_label,
+            update_graphics_labels,
+            update_camera_angle_label,
+            update_network_labels,
+        )
+            .run_if(in_state(Menu::Settings)),
     );
 }
 
@@ -27,6 +63,13 @@ fn spawn_settings_menu(mut commands: Commands) {
         children![
             widget::header("Settings"),
             settings_grid(),
+            widget::header("Audio"),
+            audio_grid(),
+            widget::header("Graphics"),
+            graphics_grid(),
+            widget::header("Network"),
+            network_grid(),
+            widget::button("Cloud Sync", open_cloud_sync),
             widget::button("Back", go_back_on_click),
         ],
     ));
@@ -42,6 +85,53 @@ fn settings_grid() -> impl Bundle {
             grid_template_columns: RepeatedGridTrack::px(2, 400.0),
             ..default()
         },
+        children![
+            (
+                widget::label("Deck"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                }
+            ),
+            deck_mode_widget(),
+            (
+                widget::label("Queue Unaffordable Cards"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                }
+            ),
+            queue_mode_widget(),
+            (
+                widget::label("Match Timer"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                }
+            ),
+            match_timer_widget(),
+            (
+                widget::label("Menu Background"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                }
+            ),
+            background_quality_widget(),
+        ],
+    )
+}
+
+fn audio_grid() -> impl Bundle {
+    (
+        Name::new("Audio Grid"),
+        Node {
+            display: Display::Grid,
+            row_gap: px(10),
+            column_gap: px(30),
+            grid_template_columns: RepeatedGridTrack::px(2, 400.0),
+            ..default()
+        },
         children![
             (
                 widget::label("Master Volume"),
@@ -51,6 +141,38 @@ fn settings_grid() -> impl Bundle {
                 }
             ),
             global_volume_widget(),
+            (
+                widget::label("Music Volume"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                }
+            ),
+            bus_volume_widget(AudioBus::Music),
+            (
+                widget::label("Effects Volume"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                }
+            ),
+            bus_volume_widget(AudioBus::Sfx),
+            (
+                widget::label("Interface Volume"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                }
+            ),
+            bus_volume_widget(AudioBus::Ui),
+            (
+                widget::label("Feedback Sounds"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                }
+            ),
+            feedback_sounds_widget(),
         ],
     )
 }
@@ -103,6 +225,556 @@ fn update_global_volume_label(
     label.0 = format!("{percent:3.0}%");
 }
 
+fn bus_volume_widget(bus: AudioBus) -> impl Bundle {
+    (
+        Name::new(format!("{} Volume Widget", bus.label())),
+        Node {
+            justify_self: JustifySelf::Start,
+            ..default()
+        },
+        children![
+            widget::button_small(
+                "-",
+                move |_: On<Pointer<Click>>, mut mix: ResMut<AudioMix>| mix.adjust(bus, -0.1),
+            ),
+            (
+                Name::new("Current Volume"),
+                Node {
+                    padding: UiRect::horizontal(px(10)),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                children![(widget::label(""), BusVolumeLabel(bus))],
+            ),
+            widget::button_small(
+                "+",
+                move |_: On<Pointer<Click>>, mut mix: ResMut<AudioMix>| mix.adjust(bus, 0.1),
+            ),
+        ],
+    )
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct BusVolumeLabel(AudioBus);
+
+fn update_bus_volume_labels(mix: Res<AudioMix>, mut labels: Query<(&mut Text, &BusVolumeLabel)>) {
+    for (mut text, label) in &mut labels {
+        let percent = 100.0 * mix.volume(label.0);
+        text.0 = format!("{percent:3.0}%");
+    }
+}
+
+fn feedback_sounds_widget() -> impl Bundle {
+    (
+        Name::new("Feedback Sounds Widget"),
+        Node {
+            justify_self: JustifySelf::Start,
+            ..default()
+        },
+        children![
+            widget::button_small("<", toggle_feedback_sounds),
+            (
+                Name::new("Current Feedback Sounds"),
+                Node {
+                    padding: UiRect::horizontal(px(10)),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                children![(widget::label(""), FeedbackSoundsLabel)],
+            ),
+            widget::button_small(">", toggle_feedback_sounds),
+        ],
+    )
+}
+
+fn toggle_feedback_sounds(_: On<Pointer<Click>>, mut mix: ResMut<AudioMix>) {
+    mix.feedback = !mix.feedback;
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct FeedbackSoundsLabel;
+
+fn update_feedback_sounds_label(
+    mix: Res<AudioMix>,
+    mut label: Single<&mut Text, With<FeedbackSoundsLabel>>,
+) {
+    label.0 = if mix.feedback { "On" } else { "Off" }.to_string();
+}
+
+fn deck_mode_widget() -> impl Bundle {
+    (
+        Name::new("Deck Mode Widget"),
+        Node {
+            justify_self: JustifySelf::Start,
+            ..default()
+        },
+        children![
+            widget::button_small("<", toggle_deck_mode),
+            (
+                Name::new("Current Deck Mode"),
+                Node {
+                    padding: UiRect::horizontal(px(10)),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                children![(widget::label(""), DeckModeLabel)],
+            ),
+            widget::button_small(">", toggle_deck_mode),
+        ],
+    )
+}
+
+fn toggle_deck_mode(_: On<Pointer<Click>>, mut deck_mode: ResMut<DeckMode>) {
+    *deck_mode = match *deck_mode {
+        DeckMode::Starter => DeckMode::Random,
+        DeckMode::Random => DeckMode::Draft,
+        DeckMode::Draft => DeckMode::Starter,
+    };
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct DeckModeLabel;
+
+fn update_deck_mode_label(
+    deck_mode: Res<DeckMode>,
+    mut label: Single<&mut Text, With<DeckModeLabel>>,
+) {
+    label.0 = match *deck_mode {
+        DeckMode::Starter => "Starter",
+        DeckMode::Random => "Random",
+        DeckMode::Draft => "Draft",
+    }
+    .to_string();
+}
+
+fn queue_mode_widget() -> impl Bundle {
+    (
+        Name::new("Queue Mode Widget"),
+        Node {
+            justify_self: JustifySelf::Start,
+            ..default()
+        },
+        children![
+            widget::button_small("<", toggle_queue_mode),
+            (
+                Name::new("Current Queue Mode"),
+                Node {
+                    padding: UiRect::horizontal(px(10)),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                children![(widget::label(""), QueueModeLabel)],
+            ),
+            widget::button_small(">", toggle_queue_mode),
+        ],
+    )
+}
+
+fn toggle_queue_mode(_: On<Pointer<Click>>, mut settings: ResMut<InputSettings>) {
+    settings.queue_until_affordable = !settings.queue_until_affordable;
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct QueueModeLabel;
+
+fn update_queue_mode_label(
+    settings: Res<InputSettings>,
+    mut label: Single<&mut Text, With<QueueModeLabel>>,
+) {
+    label.0 = if settings.queue_until_affordable {
+        "On"
+    } else {
+        "Off"
+    }
+    .to_string();
+}
+
+fn match_timer_widget() -> impl Bundle {
+    (
+        Name::new("Match Timer Widget"),
+        Node {
+            justify_self: JustifySelf::Start,
+            ..default()
+        },
+        children![
+            widget::button_small("<", cycle_match_timer),
+            (
+                Name::new("Current Match Timer"),
+                Node {
+                    padding: UiRect::horizontal(px(10)),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                children![(widget::label(""), MatchTimerLabel)],
+            ),
+            widget::button_small(">", cycle_match_timer),
+        ],
+    )
+}
+
+fn cycle_match_timer(_: On<Pointer<Click>>, mut timer: ResMut<MatchTimer>) {
+    *timer = timer.next();
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct MatchTimerLabel;
+
+fn update_match_timer_label(
+    timer: Res<MatchTimer>,
+    mut label: Single<&mut Text, With<MatchTimerLabel>>,
+) {
+    label.0 = timer.label();
+}
+
+fn background_quality_widget() -> impl Bundle {
+    (
+        Name::new("Background Quality Widget"),
+        Node {
+            justify_self: JustifySelf::Start,
+            ..default()
+        },
+        children![
+            widget::button_small("<", toggle_background_quality),
+            (
+                Name::new("Current Background Quality"),
+                Node {
+                    padding: UiRect::horizontal(px(10)),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                children![(widget::label(""), BackgroundQualityLabel)],
+            ),
+            widget::button_small(">", toggle_background_quality),
+        ],
+    )
+}
+
+fn toggle_background_quality(_: On<Pointer<Click>>, mut quality: ResMut<MenuBackgroundQuality>) {
+    *quality = match *quality {
+        MenuBackgroundQuality::High => MenuBackgroundQuality::Low,
+        MenuBackgroundQuality::Low => MenuBackgroundQuality::High,
+    };
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct BackgroundQualityLabel;
+
+fn update_background_quality_label(
+    quality: Res<MenuBackgroundQuality>,
+    mut label: Single<&mut Text, With<BackgroundQualityLabel>>,
+) {
+    label.0 = match *quality {
+        MenuBackgroundQuality::High => "High",
+        MenuBackgroundQuality::Low => "Low",
+    }
+    .to_string();
+}
+
+fn graphics_grid() -> impl Bundle {
+    (
+        Name::new("Graphics Grid"),
+        Node {
+            display: Display::Grid,
+            row_gap: px(10),
+            column_gap: px(30),
+            grid_template_columns: RepeatedGridTrack::px(2, 400.0),
+            ..default()
+        },
+        children![
+            (
+                widget::label("Window Mode"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                }
+            ),
+            cycle_widget(GraphicsOption::WindowMode, cycle_window_mode),
+            (
+                widget::label("VSync"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                }
+            ),
+            cycle_widget(GraphicsOption::Vsync, toggle_vsync),
+            (
+                widget::label("MSAA"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                }
+            ),
+            cycle_widget(GraphicsOption::Msaa, toggle_msaa),
+            (
+                widget::label("Render Scale"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                }
+            ),
+            cycle_widget(GraphicsOption::RenderScale, cycle_render_scale),
+            (
+                widget::label("Camera Angle"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                }
+            ),
+            cycle_widget(CameraAngleLabel, cycle_camera_angle),
+        ],
+    )
+}
+
+#[derive(Component, Clone, Copy)]
+enum GraphicsOption {
+    WindowMode,
+    Vsync,
+    Msaa,
+    RenderScale,
+}
+
+/// A `< value >` row for a setting with a few options; both arrows step through them.
+fn cycle_widget<E, B, M, I>(option: impl Component, action: I) -> impl Bundle
+where
+    E: EntityEvent,
+    B: Bundle,
+    I: IntoObserverSystem<E, B, M> + Clone,
+{
+    (
+        Name::new("Option Widget"),
+        Node {
+            justify_self: JustifySelf::Start,
+            ..default()
+        },
+        children![
+            widget::button_small("<", action.clone()),
+            (
+                Name::new("Current Option"),
+                Node {
+                    padding: UiRect::horizontal(px(10)),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                children![(widget::label(""), option)],
+            ),
+            widget::button_small(">", action),
+        ],
+    )
+}
+
+fn cycle_window_mode(_: On<Pointer<Click>>, mut settings: ResMut<GraphicsSettings>) {
+    settings.window_mode = settings.window_mode.next();
+}
+
+fn toggle_vsync(_: On<Pointer<Click>>, mut settings: ResMut<GraphicsSettings>) {
+    settings.vsync = !settings.vsync;
+}
+
+fn toggle_msaa(_: On<Pointer<Click>>, mut settings: ResMut<GraphicsSettings>) {
+    settings.msaa = !settings.msaa;
+}
+
+fn cycle_render_scale(_: On<Pointer<Click>>, mut settings: ResMut<GraphicsSettings>) {
+    settings.render_scale = settings.next_render_scale();
+}
+
+fn cycle_camera_angle(_: On<Pointer<Click>>, mut settings: ResMut<CameraSettings>) {
+    settings.angle = settings.angle.next();
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct CameraAngleLabel;
+
+fn update_camera_angle_label(
+    settings: Res<CameraSettings>,
+    mut label: Single<&mut Text, With<CameraAngleLabel>>,
+) {
+    label.0 = settings.angle.label().to_string();
+}
+
+fn update_graphics_labels(
+    settings: Res<GraphicsSettings>,
+    mut labels: Query<(&GraphicsOption, &mut Text)>,
+) {
+    let on_off = |enabled: bool| if enabled { "On" } else { "Off" };
+    for (option, mut text) in &mut labels {
+        text.0 = match option {
+            GraphicsOption::WindowMode => settings.window_mode.label().to_string(),
+            GraphicsOption::Vsync => on_off(settings.vsync).to_string(),
+            GraphicsOption::Msaa => if settings.msaa { "4x" } else { "Off" }.to_string(),
+            GraphicsOption::RenderScale => format!("{:.0}%", settings.render_scale * 100.0),
+        };
+    }
+}
+
+fn network_grid() -> impl Bundle {
+    (
+        Name::new("Network Grid"),
+        Node {
+            display: Display::Grid,
+            row_gap: px(10),
+            column_gap: px(30),
+            grid_template_columns: RepeatedGridTrack::px(2, 400.0),
+            ..default()
+        },
+        children![
+            (
+                widget::label("Input Delay"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                }
+            ),
+            cycle_widget(NetworkOption::InputDelay, cycle_input_delay),
+            (
+                widget::label("Prediction Window"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                }
+            ),
+            cycle_widget(NetworkOption::PredictionWindow, cycle_prediction_window),
+            (
+                widget::label("Disconnect Timeout"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                }
+            ),
+            cycle_widget(NetworkOption::DisconnectTimeout, cycle_disconnect_timeout),
+            (
+                widget::label("Display Name"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                }
+            ),
+            (
+                Name::new("Name Widget"),
+                Node {
+                    justify_self: JustifySelf::Start,
+                    align_items: AlignItems::Center,
+                    column_gap: px(10),
+                    ..default()
+                },
+                children![
+                    (widget::label(""), NetworkOption::DisplayName),
+                    widget::button_small("Edit", edit_player_name),
+                ],
+            ),
+        ],
+    )
+}
+
+#[derive(Component, Clone, Copy)]
+enum NetworkOption {
+    InputDelay,
+    PredictionWindow,
+    DisconnectTimeout,
+    DisplayName,
+}
+
+fn cycle_input_delay(_: On<Pointer<Click>>, mut tuning: ResMut<NetworkTuning>) {
+    tuning.input_delay = tuning.next_input_delay();
+}
+
+fn cycle_prediction_window(_: On<Pointer<Click>>, mut tuning: ResMut<NetworkTuning>) {
+    tuning.prediction_window = tuning.next_prediction_window();
+}
+
+fn cycle_disconnect_timeout(_: On<Pointer<Click>>, mut tuning: ResMut<NetworkTuning>) {
+    tuning.disconnect_timeout_secs = tuning.next_disconnect_timeout();
+}
+
+fn update_network_labels(
+    tuning: Res<NetworkTuning>,
+    name: Res<PlayerName>,
+    draft: Res<NameDraft>,
+    mut labels: Query<(&NetworkOption, &mut Text)>,
+) {
+    for (option, mut text) in &mut labels {
+        text.0 = match option {
+            NetworkOption::InputDelay => format!("{} frames", tuning.input_delay),
+            NetworkOption::PredictionWindow => format!("{} frames", tuning.prediction_window),
+            NetworkOption::DisconnectTimeout => format!("{} s", tuning.disconnect_timeout_secs),
+            NetworkOption::DisplayName => match &draft.0 {
+                Some(draft) => format!("{draft}_"),
+                None => name.0.clone(),
+            },
+        };
+    }
+}
+
+/// The display name being typed, while the name field is being edited.
+#[derive(Resource, Default)]
+struct NameDraft(Option<String>);
+
+fn not_editing_name(draft: Res<NameDraft>) -> bool {
+    draft.0.is_none()
+}
+
+fn edit_player_name(_: On<Pointer<Click>>, name: Res<PlayerName>, mut draft: ResMut<NameDraft>) {
+    draft.0 = Some(name.0.clone());
+}
+
+/// Type into the name field: Enter keeps the name, Escape drops the edit.
+fn type_player_name(
+    mut keys: MessageReader<KeyboardInput>,
+    mut draft: ResMut<NameDraft>,
+    mut name: ResMut<PlayerName>,
+) {
+    let Some(text) = draft.0.as_mut() else {
+        keys.clear();
+        return;
+    };
+    let mut done = None;
+    for key in keys.read() {
+        if key.state != ButtonState::Pressed {
+            continue;
+        }
+        match &key.logical_key {
+            Key::Enter => done = Some(true),
+            Key::Escape => done = Some(false),
+            Key::Backspace => {
+                text.pop();
+            }
+            _ => {
+                if let Some(typed) = &key.text
+                    && text.chars().count() < MAX_NAME_CHARS
+                {
+                    text.extend(typed.chars().filter(|c| !c.is_control()));
+                }
+            }
+        }
+    }
+    match done {
+        Some(true) => {
+            if let Some(cleaned) = draft.0.take().as_deref().and_then(sanitize_name)
+                && cleaned != name.0
+            {
+                name.0 = cleaned;
+            }
+        }
+        Some(false) => draft.0 = None,
+        None => {}
+    }
+}
+
+fn discard_name_draft(mut draft: ResMut<NameDraft>) {
+    draft.0 = None;
+}
+
+fn open_cloud_sync(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::CloudSync);
+}
+
 fn go_back_on_click(
     _: On<Pointer<Click>>,
     screen: Res<State<Screen>>,
@@ -0,0 +1,429 @@
+//! Local tournament mode - a single-elimination bracket layered on top of
+//! the normal Solo / Online match flow.
+//!
+//! The player at this machine always plays the first-listed participant of a
+//! pairing, so a Victory advances that participant and a Defeat advances the
+//! other one. Results can also be entered by hand (e.g. for matches played on
+//! another machine).
+
+use bevy::{
+    input::{ButtonState, common_conditions::input_just_pressed, keyboard::KeyboardInput},
+    prelude::*,
+};
+
+use crate::{
+    game::GameResult,
+    menus::{Menu, class_select::ClassSelectNext},
+    theme::widget,
+};
+
+/// Fewest participants a tournament can start with.
+const MIN_PARTICIPANTS: usize = 4;
+/// Most participants a tournament can hold.
+const MAX_PARTICIPANTS: usize = 8;
+/// Longest participant name accepted by the entry screen.
+const MAX_NAME_LENGTH: usize = 16;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<Tournament>();
+    app.init_resource::<NameEntry>();
+    app.add_systems(OnEnter(Menu::TournamentSetup), spawn_setup_menu);
+    app.add_systems(OnEnter(Menu::Bracket), spawn_bracket_menu);
+    app.add_systems(OnEnter(Menu::Main), clear_pending_match);
+    app.add_systems(OnEnter(GameResult::Victory), record_victory);
+    app.add_systems(OnEnter(GameResult::Defeat), record_defeat);
+    app.add_systems(
+        Update,
+        (
+            type_participant_name.run_if(in_state(Menu::TournamentSetup)),
+            go_back.run_if(
+                in_state(Menu::TournamentSetup)
+                    .or(in_state(Menu::Bracket))
+                    .and(input_just_pressed(KeyCode::Escape)),
+            ),
+        ),
+    );
+}
+
+/// The active tournament, if any.
+#[derive(Resource, Default)]
+pub struct Tournament {
+    participants: Vec<String>,
+    /// Bracket slots per round; round 0 is the seeded field, the last round
+    /// holds the champion. `None` is a bye in round 0 and undecided later.
+    rounds: Vec<Vec<Option<usize>>>,
+    /// Pairing currently being played, as `(round, match)`.
+    pending: Option<(usize, usize)>,
+}
+
+/// One pairing in the bracket that still needs a result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Pairing {
+    round: usize,
+    index: usize,
+    first: usize,
+    second: usize,
+}
+
+impl Tournament {
+    pub fn is_active(&self) -> bool {
+        !self.rounds.is_empty()
+    }
+
+    /// Seed a fresh bracket. Byes are spread so no two byes meet.
+    fn start(&mut self, participants: Vec<String>) {
+        let size = participants.len().next_power_of_two();
+        let seed_order: Vec<usize> = (0..size).step_by(2).chain((1..size).step_by(2)).collect();
+
+        let mut field = vec![None; size];
+        for (participant, slot) in seed_order.iter().enumerate().take(participants.len()) {
+            field[*slot] = Some(participant);
+        }
+
+        let mut rounds = vec![field];
+        let mut round_size = size / 2;
+        while round_size >= 1 {
+            rounds.push(vec![None; round_size]);
+            round_size /= 2;
+        }
+
+        // Byes advance immediately.
+        for index in 0..size / 2 {
+            if let [Some(only), None] | [None, Some(only)] =
+                [rounds[0][index * 2], rounds[0][index * 2 + 1]]
+            {
+                rounds[1][index] = Some(only);
+            }
+        }
+
+        self.participants = participants;
+        self.rounds = rounds;
+        self.pending = None;
+    }
+
+    fn next_pairing(&self) -> Option<Pairing> {
+        for round in 0..self.rounds.len().saturating_sub(1) {
+            for index in 0..self.rounds[round + 1].len() {
+                if self.rounds[round + 1][index].is_some() {
+                    continue;
+                }
+                if let (Some(first), Some(second)) = (
+                    self.rounds[round][index * 2],
+                    self.rounds[round][index * 2 + 1],
+                ) {
+                    return Some(Pairing {
+                        round,
+                        index,
+                        first,
+                        second,
+                    });
+                }
+            }
+        }
+        None
+    }
+
+    fn record(&mut self, round: usize, index: usize, first_won: bool) {
+        let offset = if first_won { 0 } else { 1 };
+        let winner = self.rounds[round][index * 2 + offset];
+        self.rounds[round + 1][index] = winner;
+    }
+
+    fn champion(&self) -> Option<&str> {
+        let winner = (*self.rounds.last()?.first()?)?;
+        Some(self.participants[winner].as_str())
+    }
+
+    fn name(&self, slot: Option<usize>) -> &str {
+        match slot {
+            Some(participant) => self.participants[participant].as_str(),
+            None => "-",
+        }
+    }
+}
+
+/// Names typed so far on the setup screen.
+#[derive(Resource, Default)]
+struct NameEntry {
+    names: Vec<String>,
+    current: String,
+}
+
+#[derive(Component)]
+struct NameEntryText;
+
+fn spawn_setup_menu(mut commands: Commands, mut entry: ResMut<NameEntry>) {
+    entry.current.clear();
+    commands.spawn((
+        widget::ui_root("Tournament Setup Menu"),
+        GlobalZIndex(2),
+        DespawnOnExit(Menu::TournamentSetup),
+        children![
+            widget::header("Tournament"),
+            widget::label(format!(
+                "Type a name and press Enter ({MIN_PARTICIPANTS}-{MAX_PARTICIPANTS} players)"
+            )),
+            (NameEntryText, widget::label(name_entry_text(&entry))),
+            widget::button("Start", start_tournament),
+            widget::button("Clear", clear_names),
+            widget::button("Back", go_back_on_click),
+        ],
+    ));
+}
+
+fn name_entry_text(entry: &NameEntry) -> String {
+    let mut lines: Vec<String> = entry
+        .names
+        .iter()
+        .enumerate()
+        .map(|(index, name)| format!("{}. {name}", index + 1))
+        .collect();
+    if entry.names.len() < MAX_PARTICIPANTS {
+        lines.push(format!("> {}_", entry.current));
+    }
+    lines.join("\n")
+}
+
+fn type_participant_name(
+    mut keyboard: MessageReader<KeyboardInput>,
+    mut entry: ResMut<NameEntry>,
+    mut text: Query<&mut Text, With<NameEntryText>>,
+) {
+    let mut changed = false;
+    for input in keyboard.read() {
+        if input.state != ButtonState::Pressed {
+            continue;
+        }
+        match input.key_code {
+            KeyCode::Enter | KeyCode::NumpadEnter => {
+                let name = entry.current.trim().to_string();
+                if !name.is_empty() && entry.names.len() < MAX_PARTICIPANTS {
+                    entry.names.push(name);
+                }
+                entry.current.clear();
+            }
+            KeyCode::Backspace => {
+                if entry.current.pop().is_none() && !input.repeat {
+                    entry.names.pop();
+                }
+            }
+            _ => {
+                let Some(typed) = &input.text else {
+                    continue;
+                };
+                for c in typed.chars().filter(|c| !c.is_control()) {
+                    if entry.current.chars().count() < MAX_NAME_LENGTH {
+                        entry.current.push(c);
+                    }
+                }
+            }
+        }
+        changed = true;
+    }
+
+    if changed {
+        for mut text in &mut text {
+            text.0 = name_entry_text(&entry);
+        }
+    }
+}
+
+fn start_tournament(
+    _: On<Pointer<Click>>,
+    mut entry: ResMut<NameEntry>,
+    mut tournament: ResMut<Tournament>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    // Accept a typed-but-unconfirmed last name.
+    let pending = entry.current.trim().to_string();
+    if !pending.is_empty() && entry.names.len() < MAX_PARTICIPANTS {
+        entry.names.push(pending);
+        entry.current.clear();
+    }
+    if entry.names.len() < MIN_PARTICIPANTS {
+        return;
+    }
+
+    tournament.start(entry.names.clone());
+    next_menu.set(Menu::Bracket);
+}
+
+fn clear_names(
+    _: On<Pointer<Click>>,
+    mut entry: ResMut<NameEntry>,
+    mut text: Query<&mut Text, With<NameEntryText>>,
+) {
+    entry.names.clear();
+    entry.current.clear();
+    for mut text in &mut text {
+        text.0 = name_entry_text(&entry);
+    }
+}
+
+fn spawn_bracket_menu(mut commands: Commands, mut tournament: ResMut<Tournament>) {
+    // A match that was left without a result can simply be replayed.
+    tournament.pending = None;
+
+    let bracket = bracket_text(&tournament);
+    let mut root = commands.spawn((
+        widget::ui_root("Bracket Menu"),
+        GlobalZIndex(2),
+        DespawnOnExit(Menu::Bracket),
+    ));
+
+    if let Some(champion) = tournament.champion() {
+        root.with_children(|parent| {
+            parent.spawn(widget::header(format!("Champion: {champion}")));
+            parent.spawn(widget::label(bracket));
+            parent.spawn(widget::button("Finish", finish_tournament));
+        });
+        return;
+    }
+
+    let Some(pairing) = tournament.next_pairing() else {
+        return;
+    };
+    let first = tournament.participants[pairing.first].clone();
+    let second = tournament.participants[pairing.second].clone();
+    root.with_children(|parent| {
+        parent.spawn(widget::header(format!("{first} vs {second}")));
+        parent.spawn(widget::label(bracket));
+        parent.spawn(widget::button("Play Solo", play_solo));
+        parent.spawn(widget::button("Play Online", play_online));
+        parent.spawn(widget::button(format!("{first} won"), report_first_won));
+        parent.spawn(widget::button(format!("{second} won"), report_second_won));
+        parent.spawn(widget::button("Abandon", finish_tournament));
+    });
+}
+
+fn bracket_text(tournament: &Tournament) -> String {
+    let last = tournament.rounds.len() - 1;
+    tournament.rounds[..last]
+        .iter()
+        .enumerate()
+        .map(|(round, slots)| {
+            let title = match slots.len() {
+                2 => "Final".to_string(),
+                4 => "Semifinals".to_string(),
+                _ => format!("Round {}", round + 1),
+            };
+            let matches = slots
+                .chunks(2)
+                .map(|pair| {
+                    format!(
+                        "{} vs {}",
+                        tournament.name(pair[0]),
+                        tournament.name(pair[1])
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("   ");
+            format!("{title}: {matches}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn play_solo(
+    _: On<Pointer<Click>>,
+    tournament: ResMut<Tournament>,
+    class_select_next: ResMut<ClassSelectNext>,
+    next_menu: ResMut<NextState<Menu>>,
+) {
+    play_pairing(
+        ClassSelectNext::Solo,
+        tournament,
+        class_select_next,
+        next_menu,
+    );
+}
+
+fn play_online(
+    _: On<Pointer<Click>>,
+    tournament: ResMut<Tournament>,
+    class_select_next: ResMut<ClassSelectNext>,
+    next_menu: ResMut<NextState<Menu>>,
+) {
+    play_pairing(
+        ClassSelectNext::Online,
+        tournament,
+        class_select_next,
+        next_menu,
+    );
+}
+
+/// Hand the pairing to the regular match flow, starting at class selection.
+fn play_pairing(
+    next: ClassSelectNext,
+    mut tournament: ResMut<Tournament>,
+    mut class_select_next: ResMut<ClassSelectNext>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    let Some(pairing) = tournament.next_pairing() else {
+        return;
+    };
+    tournament.pending = Some((pairing.round, pairing.index));
+    *class_select_next = next;
+    next_menu.set(Menu::ClassSelect);
+}
+
+fn report_first_won(
+    _: On<Pointer<Click>>,
+    tournament: ResMut<Tournament>,
+    next_menu: ResMut<NextState<Menu>>,
+) {
+    report_result(true, tournament, next_menu);
+}
+
+fn report_second_won(
+    _: On<Pointer<Click>>,
+    tournament: ResMut<Tournament>,
+    next_menu: ResMut<NextState<Menu>>,
+) {
+    report_result(false, tournament, next_menu);
+}
+
+fn report_result(
+    first_won: bool,
+    mut tournament: ResMut<Tournament>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    if let Some(pairing) = tournament.next_pairing() {
+        tournament.record(pairing.round, pairing.index, first_won);
+    }
+    // Re-enter the bracket menu to rebuild it.
+    next_menu.set(Menu::Bracket);
+}
+
+fn record_victory(mut tournament: ResMut<Tournament>) {
+    if let Some((round, index)) = tournament.pending.take() {
+        tournament.record(round, index, true);
+    }
+}
+
+fn record_defeat(mut tournament: ResMut<Tournament>) {
+    if let Some((round, index)) = tournament.pending.take() {
+        tournament.record(round, index, false);
+    }
+}
+
+fn clear_pending_match(mut tournament: ResMut<Tournament>) {
+    tournament.pending = None;
+}
+
+fn finish_tournament(
+    _: On<Pointer<Click>>,
+    mut tournament: ResMut<Tournament>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    *tournament = Tournament::default();
+    next_menu.set(Menu::Main);
+}
+
+fn go_back_on_click(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
+
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
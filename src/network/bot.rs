@@ -0,0 +1,237 @@
+//! Bot client for testing online matches alone (native dev only).
+//!
+//! Start a second copy of the game with `--bot` and it plays the other side:
+//! it goes straight to the lobby, readies up, vetoes the first mutator
+//! offered, takes a turn every half second once the match starts, and queues
+//! up again a few seconds after the match ends.
+//!
+//! - `--bot` or `--bot=random`: draw or play a random affordable card.
+//! - `--bot=D,1,WAIT,2,QUIT`: step through a script, one entry per turn,
+//!   looping at the end. Entries are the key names `SimulatedGgrsInput`
+//!   accepts, `WAIT` to skip a turn, or `QUIT` to close the game mid-match
+//!   and test the peer's disconnect handling.
+
+use bevy::prelude::*;
+use bevy_ggrs::prelude::*;
+
+use super::{
+    LobbyHandshake, SensenGgrsConfig,
+    lobby::{mark_ready, veto_mutator},
+};
+use crate::{
+    game::{
+        CardRegistry, Cost, GameResult, GameRng, Hand, LocalPlayer, PendingInput, RngStream,
+        is_online,
+    },
+    input::{INPUT_DRAW, card_flag, flags_from_key_string},
+    screens::Screen,
+};
+
+/// Seconds between the bot's turns.
+const TURN_SECS: f32 = 0.5;
+/// Seconds to wait after a match ends before queueing for the next one.
+const REQUEUE_SECS: f32 = 3.0;
+/// Hand size the random policy draws up to before it only plays.
+const RANDOM_HAND_SIZE: usize = 5;
+
+pub(super) fn plugin(app: &mut App) {
+    let Some(bot) = Bot::from_args(std::env::args()) else {
+        return;
+    };
+    info!("Running as a bot: {:?}", bot.policy);
+    app.insert_resource(bot);
+    app.add_systems(OnEnter(Screen::Title), go_to_lobby);
+    app.add_systems(
+        Update,
+        (auto_ready, auto_veto).run_if(in_state(Screen::Lobby)),
+    );
+    app.add_systems(
+        Update,
+        (
+            take_turn.run_if(in_state(GameResult::Playing)),
+            requeue.run_if(not(in_state(GameResult::Playing))),
+        )
+            .run_if(is_online)
+            .run_if(in_state(Screen::Gameplay))
+            .run_if(resource_exists::<Session<SensenGgrsConfig>>),
+    );
+}
+
+#[derive(Resource, Debug)]
+struct Bot {
+    policy: BotPolicy,
+    turn: Timer,
+    requeue: Timer,
+    /// Next script entry to play.
+    step: usize,
+    rng: GameRng,
+}
+
+#[derive(Debug, PartialEq)]
+enum BotPolicy {
+    Random,
+    Script(Vec<BotStep>),
+}
+
+#[derive(Debug, PartialEq)]
+enum BotStep {
+    Press(u16),
+    Wait,
+    Quit,
+}
+
+impl Bot {
+    /// The bot requested on the command line, if any.
+    fn from_args(mut args: impl Iterator<Item = String>) -> Option<Self> {
+        let policy = args.find_map(|arg| match arg.as_str() {
+            "--bot" | "--bot=random" => Some(BotPolicy::Random),
+            _ => arg
+                .strip_prefix("--bot=")
+                .map(|script| BotPolicy::Script(parse_script(script))),
+        })?;
+
+        let mut buf = [0u8; 8];
+        getrandom::fill(&mut buf).unwrap_or_default();
+        Some(Self {
+            policy,
+            turn: Timer::from_seconds(TURN_SECS, TimerMode::Repeating),
+            requeue: Timer::from_seconds(REQUEUE_SECS, TimerMode::Once),
+            step: 0,
+            rng: GameRng::new(u64::from_le_bytes(buf)),
+        })
+    }
+}
+
+fn parse_script(script: &str) -> Vec<BotStep> {
+    script
+        .split(',')
+        .filter_map(|entry| match entry.trim().to_uppercase().as_str() {
+            "WAIT" => Some(BotStep::Wait),
+            "QUIT" => Some(BotStep::Quit),
+            key => match flags_from_key_string(key) {
+                0 => {
+                    warn!("Ignoring unknown bot script entry {:?}", entry);
+                    None
+                }
+                flags => Some(BotStep::Press(flags)),
+            },
+        })
+        .collect()
+}
+
+fn go_to_lobby(mut next_screen: ResMut<NextState<Screen>>) {
+    next_screen.set(Screen::Lobby);
+}
+
+fn auto_ready(handshake: Option<ResMut<LobbyHandshake>>) {
+    if let Some(handshake) = handshake.filter(|handshake| handshake.awaiting_local_ready()) {
+        mark_ready(handshake);
+    }
+}
+
+fn auto_veto(handshake: Option<ResMut<LobbyHandshake>>) {
+    if let Some(handshake) = handshake.filter(|handshake| handshake.awaiting_local_veto()) {
+        veto_mutator(0, handshake);
+    }
+}
+
+fn take_turn(
+    time: Res<Time>,
+    mut bot: ResMut<Bot>,
+    registry: Res<CardRegistry>,
+    player: Single<(&Hand, &Cost), With<LocalPlayer>>,
+    mut pending: ResMut<PendingInput>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    let bot = &mut *bot;
+    bot.requeue.reset();
+    if !bot.turn.tick(time.delta()).just_finished() {
+        return;
+    }
+    let (hand, cost) = *player;
+
+    let flags = match &bot.policy {
+        BotPolicy::Script(steps) if steps.is_empty() => return,
+        BotPolicy::Script(steps) => {
+            let step = bot.step % steps.len();
+            let flags = match steps[step] {
+                BotStep::Press(flags) => flags,
+                BotStep::Wait => 0,
+                BotStep::Quit => {
+                    info!("Bot script quit mid-match");
+                    exit.write(AppExit::Success);
+                    return;
+                }
+            };
+            bot.step += 1;
+            flags
+        }
+        BotPolicy::Random => {
+            let affordable: Vec<usize> = hand
+                .cards
+                .iter()
+                .enumerate()
+                .filter(|(_, card)| {
+                    registry
+                        .get(**card)
+                        .is_some_and(|def| cost.can_afford(def.cost))
+                })
+                .map(|(index, _)| index)
+                .collect();
+            // Drawing costs one per card already in hand.
+            let can_draw = cost.can_afford(hand.len() as f32);
+            if can_draw && (affordable.is_empty() || hand.len() < RANDOM_HAND_SIZE) {
+                INPUT_DRAW
+            } else if affordable.is_empty() {
+                return;
+            } else {
+                let index = bot.rng.below(RngStream::Bot, affordable.len());
+                card_flag(affordable[index]).unwrap_or_default()
+            }
+        }
+    };
+    pending.push_flags(flags);
+}
+
+fn requeue(time: Res<Time>, mut bot: ResMut<Bot>, mut next_screen: ResMut<NextState<Screen>>) {
+    if bot.requeue.tick(time.delta()).just_finished() {
+        info!("Bot queueing for the next match");
+        bot.step = 0;
+        next_screen.set(Screen::Lobby);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::{INPUT_CARD_1, INPUT_CARD_2};
+
+    fn policy(args: &[&str]) -> Option<BotPolicy> {
+        Bot::from_args(args.iter().map(|arg| arg.to_string())).map(|bot| bot.policy)
+    }
+
+    #[test]
+    fn no_bot_without_the_flag() {
+        assert_eq!(policy(&["sensen", "--lobby"]), None);
+    }
+
+    #[test]
+    fn bare_flag_plays_randomly() {
+        assert_eq!(policy(&["sensen", "--bot"]), Some(BotPolicy::Random));
+        assert_eq!(policy(&["sensen", "--bot=random"]), Some(BotPolicy::Random));
+    }
+
+    #[test]
+    fn script_entries_map_to_steps() {
+        assert_eq!(
+            policy(&["sensen", "--bot=d, 1,wait,2,bogus,QUIT"]),
+            Some(BotPolicy::Script(vec![
+                BotStep::Press(INPUT_DRAW),
+                BotStep::Press(INPUT_CARD_1),
+                BotStep::Wait,
+                BotStep::Press(INPUT_CARD_2),
+                BotStep::Quit,
+            ]))
+        );
+    }
+}
@@ -0,0 +1,297 @@
+//! Quick chat: preset messages between online opponents.
+//!
+//! Messages go over their own reliable Matchbox channel, so they work in the
+//! lobby and during an online match alike, and show up as a speech bubble by
+//! the sender's side of the table. Muting drops the opponent's messages.
+
+use bevy::prelude::*;
+use bevy_matchbox::prelude::*;
+
+use crate::{game::is_online, persist, screens::Screen, theme::widget};
+
+/// Reliable channel for chat (0 carries GGRS, 1 the lobby handshake).
+pub(super) const CHAT_CHANNEL: usize = 2;
+/// Seconds between messages the local player may send.
+const SEND_COOLDOWN_SECS: f32 = 2.0;
+const BUBBLE_SECS: f32 = 3.0;
+const SAVE_KEY: &str = "chat.txt";
+
+pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(ChatSettings::load());
+    app.init_resource::<ChatCooldown>();
+    app.add_message::<QuickChatMessage>();
+
+    app.add_systems(OnEnter(Screen::Lobby), spawn_chat_bar);
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_chat_bar.run_if(is_online));
+    app.add_systems(
+        Update,
+        (
+            (send_quick_chat, receive_quick_chat).run_if(resource_exists::<MatchboxSocket>),
+            expire_bubbles,
+            update_mute_label,
+        )
+            .chain(),
+    );
+    app.add_systems(
+        Update,
+        save_chat_settings
+            .run_if(resource_changed::<ChatSettings>.and(not(resource_added::<ChatSettings>))),
+    );
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuickChat {
+    GoodLuck,
+    NicePlay,
+    ThumbsUp,
+    GoodGame,
+}
+
+impl QuickChat {
+    pub fn text(self) -> &'static str {
+        match self {
+            Self::GoodLuck => "Good luck!",
+            Self::NicePlay => "Nice play!",
+            Self::ThumbsUp => "Thumbs up!",
+            Self::GoodGame => "Good game!",
+        }
+    }
+
+    fn to_byte(self) -> u8 {
+        match self {
+            Self::GoodLuck => 0,
+            Self::NicePlay => 1,
+            Self::ThumbsUp => 2,
+            Self::GoodGame => 3,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(Self::GoodLuck),
+            1 => Some(Self::NicePlay),
+            2 => Some(Self::ThumbsUp),
+            3 => Some(Self::GoodGame),
+            _ => None,
+        }
+    }
+}
+
+/// Ask to send a quick chat message to every connected peer.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct QuickChatMessage(pub QuickChat);
+
+#[derive(Resource, Debug, Default)]
+pub struct ChatSettings {
+    /// Hide messages from the opponent.
+    pub muted: bool,
+}
+
+impl ChatSettings {
+    fn load() -> Self {
+        let muted = persist::load(SAVE_KEY)
+            .is_some_and(|contents| contents.lines().any(|line| line.trim() == "muted=true"));
+        Self { muted }
+    }
+
+    fn save(&self) {
+        persist::store(SAVE_KEY, &format!("muted={}", self.muted));
+    }
+}
+
+/// Real time the local player last sent a message.
+#[derive(Resource, Default)]
+struct ChatCooldown(Option<f32>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChatSide {
+    Local,
+    Opponent,
+}
+
+#[derive(Component)]
+struct ChatBubble {
+    side: ChatSide,
+    timer: Timer,
+}
+
+#[derive(Component)]
+struct MuteLabel;
+
+fn spawn_chat_bar(mut commands: Commands, screen: Res<State<Screen>>) {
+    commands.spawn((
+        Name::new("Quick Chat"),
+        Node {
+            position_type: PositionType::Absolute,
+            right: px(20),
+            top: percent(35),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::End,
+            row_gap: px(6),
+            ..default()
+        },
+        GlobalZIndex(5),
+        DespawnOnExit(*screen.get()),
+        children![
+            widget::button_medium(QuickChat::GoodLuck.text(), send_good_luck),
+            widget::button_medium(QuickChat::NicePlay.text(), send_nice_play),
+            widget::button_medium(QuickChat::ThumbsUp.text(), send_thumbs_up),
+            widget::button_medium(QuickChat::GoodGame.text(), send_good_game),
+            widget::button_medium("Mute Chat", toggle_mute),
+            (
+                MuteLabel,
+                Text::default(),
+                TextFont::from_font_size(16.0),
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ),
+        ],
+    ));
+}
+
+fn send_good_luck(_: On<Pointer<Click>>, mut messages: MessageWriter<QuickChatMessage>) {
+    messages.write(QuickChatMessage(QuickChat::GoodLuck));
+}
+
+fn send_nice_play(_: On<Pointer<Click>>, mut messages: MessageWriter<QuickChatMessage>) {
+    messages.write(QuickChatMessage(QuickChat::NicePlay));
+}
+
+fn send_thumbs_up(_: On<Pointer<Click>>, mut messages: MessageWriter<QuickChatMessage>) {
+    messages.write(QuickChatMessage(QuickChat::ThumbsUp));
+}
+
+fn send_good_game(_: On<Pointer<Click>>, mut messages: MessageWriter<QuickChatMessage>) {
+    messages.write(QuickChatMessage(QuickChat::GoodGame));
+}
+
+fn toggle_mute(_: On<Pointer<Click>>, mut settings: ResMut<ChatSettings>) {
+    settings.muted = !settings.muted;
+}
+
+fn send_quick_chat(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    screen: Res<State<Screen>>,
+    mut messages: MessageReader<QuickChatMessage>,
+    mut cooldown: ResMut<ChatCooldown>,
+    mut socket: ResMut<MatchboxSocket>,
+    bubbles: Query<(Entity, &ChatBubble)>,
+) {
+    for QuickChatMessage(chat) in messages.read() {
+        let now = time.elapsed_secs();
+        if cooldown
+            .0
+            .is_some_and(|sent_at| now - sent_at < SEND_COOLDOWN_SECS)
+        {
+            continue;
+        }
+        cooldown.0 = Some(now);
+
+        let peers: Vec<PeerId> = socket.connected_peers().collect();
+        for peer_id in peers {
+            let packet = vec![chat.to_byte()].into_boxed_slice();
+            socket.channel_mut(CHAT_CHANNEL).send(packet, peer_id);
+        }
+        show_bubble(
+            &mut commands,
+            &bubbles,
+            ChatSide::Local,
+            *chat,
+            *screen.get(),
+        );
+    }
+}
+
+fn receive_quick_chat(
+    mut commands: Commands,
+    settings: Res<ChatSettings>,
+    screen: Res<State<Screen>>,
+    mut socket: ResMut<MatchboxSocket>,
+    bubbles: Query<(Entity, &ChatBubble)>,
+) {
+    for (peer_id, packet) in socket.channel_mut(CHAT_CHANNEL).receive() {
+        let Some(chat) = (match *packet {
+            [byte] => QuickChat::from_byte(byte),
+            _ => None,
+        }) else {
+            warn!("Ignoring malformed chat packet from {:?}", peer_id);
+            continue;
+        };
+        if !settings.muted {
+            show_bubble(
+                &mut commands,
+                &bubbles,
+                ChatSide::Opponent,
+                chat,
+                *screen.get(),
+            );
+        }
+    }
+}
+
+/// Show `chat` by the sender's side, replacing the bubble already there.
+fn show_bubble(
+    commands: &mut Commands,
+    bubbles: &Query<(Entity, &ChatBubble)>,
+    side: ChatSide,
+    chat: QuickChat,
+    screen: Screen,
+) {
+    for (entity, bubble) in bubbles {
+        if bubble.side == side {
+            commands.entity(entity).despawn();
+        }
+    }
+    // The opponent's HUD is at the top of the screen, ours above the hand.
+    let (top, bottom) = match side {
+        ChatSide::Opponent => (px(70), Val::Auto),
+        ChatSide::Local => (Val::Auto, px(220)),
+    };
+    commands.spawn((
+        Name::new("Chat Bubble"),
+        ChatBubble {
+            side,
+            timer: Timer::from_seconds(BUBBLE_SECS, TimerMode::Once),
+        },
+        Node {
+            position_type: PositionType::Absolute,
+            left: px(20),
+            top,
+            bottom,
+            padding: UiRect::axes(px(14), px(8)),
+            border_radius: BorderRadius::all(px(12)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.95, 0.9, 0.95)),
+        GlobalZIndex(30),
+        Pickable::IGNORE,
+        DespawnOnExit(screen),
+        children![(
+            Text::new(chat.text()),
+            TextFont::from_font_size(20.0),
+            TextColor(Color::srgb(0.1, 0.1, 0.15)),
+        )],
+    ));
+}
+
+fn expire_bubbles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut bubbles: Query<(Entity, &mut ChatBubble)>,
+) {
+    for (entity, mut bubble) in &mut bubbles {
+        if bubble.timer.tick(time.delta()).just_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn update_mute_label(settings: Res<ChatSettings>, mut labels: Query<&mut Text, With<MuteLabel>>) {
+    for mut text in &mut labels {
+        text.0 = if settings.muted { "Opponent muted" } else { "" }.to_string();
+    }
+}
+
+fn save_chat_settings(settings: Res<ChatSettings>) {
+    settings.save();
+}
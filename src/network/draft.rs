@@ -0,0 +1,75 @@
+//! Online drafting: picks travel as card input flags through GGRS.
+//!
+//! The draft is rolled back with the session, so a pick seen only in a
+//! predicted input is undone if the prediction was wrong. The match starts
+//! once the frame that completed the draft is confirmed.
+
+use bevy::prelude::*;
+use bevy_ggrs::{ConfirmedFrameCount, RollbackFrameCount, prelude::*};
+
+use super::SensenGgrsConfig;
+use crate::{
+    game::{Draft, PACK_SIZE, is_online},
+    input::card_flag,
+    screens::Screen,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<DraftCompletedAt>();
+    app.rollback_resource_with_clone::<Draft>();
+    app.rollback_resource_with_clone::<DraftCompletedAt>();
+    app.add_systems(OnEnter(Screen::Draft), reset_draft_completion);
+    app.add_systems(
+        GgrsSchedule,
+        apply_draft_picks
+            .run_if(is_online)
+            .run_if(in_state(Screen::Draft))
+            .run_if(resource_exists::<Draft>),
+    );
+    app.add_systems(
+        Update,
+        start_drafted_match
+            .run_if(is_online)
+            .run_if(in_state(Screen::Draft))
+            .run_if(resource_exists::<ConfirmedFrameCount>),
+    );
+}
+
+/// Rollback frame on which both decks were full.
+#[derive(Resource, Debug, Clone, Default)]
+struct DraftCompletedAt(Option<i32>);
+
+fn reset_draft_completion(mut completed: ResMut<DraftCompletedAt>) {
+    completed.0 = None;
+}
+
+fn apply_draft_picks(
+    inputs: Res<PlayerInputs<SensenGgrsConfig>>,
+    frame: Res<RollbackFrameCount>,
+    mut draft: ResMut<Draft>,
+    mut completed: ResMut<DraftCompletedAt>,
+) {
+    for (handle, (input, _status)) in inputs.iter().enumerate() {
+        let slot = (0..PACK_SIZE)
+            .find(|slot| card_flag(*slot).is_some_and(|flag| input.flags & flag != 0));
+        if let Some(slot) = slot
+            && draft.picker == handle
+        {
+            draft.pick(handle, slot);
+        }
+    }
+    if draft.is_complete() && completed.0.is_none() {
+        completed.0 = Some(frame.0);
+    }
+}
+
+fn start_drafted_match(
+    confirmed: Res<ConfirmedFrameCount>,
+    completed: Res<DraftCompletedAt>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    let confirmed = i32::from(*confirmed);
+    if completed.0.is_some_and(|frame| confirmed >= frame) {
+        next_screen.set(Screen::Gameplay);
+    }
+}
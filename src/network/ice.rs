@@ -0,0 +1,134 @@
+//! ICE (NAT traversal) configuration and the direct-then-relay fallback.
+//!
+//! STUN and TURN servers come from `ice.txt` in the save directory:
+//!
+//! ```text
+//! stun=stun:stun.l.google.com:19302
+//! turn=turn:turn.example.com:3478
+//! turn_username=sensen
+//! turn_credential=secret
+//! ```
+//!
+//! The lobby first connects with STUN only, so a working connection is
+//! direct. If a peer is found but the connection stalls, it reconnects with
+//! the TURN servers and relays through them. [`IceRoute`] records which one
+//! got through, for the lobby text and the diagnostics overlay.
+
+use bevy::prelude::*;
+use bevy_matchbox::matchbox_socket::RtcIceServerConfig;
+
+use crate::persist;
+
+const SAVE_KEY: &str = "ice.txt";
+/// Used when `ice.txt` lists no STUN server; matchbox's own default.
+const DEFAULT_STUN: &str = "stun:stun.l.google.com:19302";
+
+pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(IceSettings::load());
+}
+
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct IceSettings {
+    pub stun: Vec<String>,
+    pub turn: Vec<String>,
+    pub turn_username: Option<String>,
+    pub turn_credential: Option<String>,
+}
+
+impl IceSettings {
+    fn load() -> Self {
+        Self::parse(&persist::load(SAVE_KEY).unwrap_or_default())
+    }
+
+    fn parse(contents: &str) -> Self {
+        let mut settings = Self::default();
+        for (key, value) in contents.lines().filter_map(|line| line.split_once('=')) {
+            let value = value.trim().to_string();
+            match key.trim() {
+                "stun" => settings.stun.push(value),
+                "turn" => settings.turn.push(value),
+                "turn_username" => settings.turn_username = Some(value),
+                "turn_credential" => settings.turn_credential = Some(value),
+                _ => {}
+            }
+        }
+        if settings.stun.is_empty() {
+            settings.stun.push(DEFAULT_STUN.to_string());
+        }
+        settings
+    }
+}
+
+/// How the lobby is reaching (or reached) its peer.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IceRoute {
+    /// Host candidates only; the signaling server is on this machine.
+    Local,
+    /// Host and STUN (server reflexive) candidates.
+    Direct,
+    /// TURN relay candidates.
+    Relay,
+}
+
+impl IceRoute {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Local => "local",
+            Self::Direct => "direct",
+            Self::Relay => "TURN relay",
+        }
+    }
+
+    /// ICE servers to offer for this route.
+    pub(super) fn server_config(self, settings: &IceSettings) -> RtcIceServerConfig {
+        match self {
+            Self::Local => RtcIceServerConfig {
+                urls: vec![],
+                username: None,
+                credential: None,
+            },
+            Self::Direct => RtcIceServerConfig {
+                urls: settings.stun.clone(),
+                username: None,
+                credential: None,
+            },
+            Self::Relay => RtcIceServerConfig {
+                urls: settings.turn.clone(),
+                username: settings.turn_username.clone(),
+                credential: settings.turn_credential.clone(),
+            },
+        }
+    }
+
+    /// The route to try when this one stalls, if there is one.
+    pub(super) fn fallback(self, settings: &IceSettings) -> Option<IceRoute> {
+        match self {
+            Self::Direct if !settings.turn.is_empty() => Some(Self::Relay),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_servers_and_credentials() {
+        let settings = IceSettings::parse(
+            "stun=stun:a.example:3478\nturn=turn:b.example:3478\nturn=turns:b.example:5349\n\
+             turn_username=user\nturn_credential=pass",
+        );
+        assert_eq!(settings.stun, ["stun:a.example:3478"]);
+        assert_eq!(settings.turn.len(), 2);
+        assert_eq!(settings.turn_username.as_deref(), Some("user"));
+        assert_eq!(IceRoute::Direct.fallback(&settings), Some(IceRoute::Relay));
+    }
+
+    #[test]
+    fn no_turn_means_no_fallback() {
+        let settings = IceSettings::parse("");
+        assert_eq!(settings.stun, [DEFAULT_STUN]);
+        assert_eq!(IceRoute::Direct.fallback(&settings), None);
+    }
+}
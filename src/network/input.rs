@@ -7,8 +7,9 @@ use bevy_ggrs::{LocalInputs, LocalPlayers};
 #[cfg(feature = "dev")]
 use crate::input::flags_from_key_string;
 use crate::{
-    game::PendingInput,
+    game::{CardInspect, InputSettings, PendingInput},
     input::{GameInput, flags_from_keyboard},
+    logging::hot_log,
 };
 
 use super::SensenGgrsConfig;
@@ -25,11 +26,18 @@ pub fn read_local_inputs(
     keyboard: Res<ButtonInput<KeyCode>>,
     local_players: Res<LocalPlayers>,
     mut pending_input: ResMut<PendingInput>,
+    inspect: Res<CardInspect>,
+    input_settings: Res<InputSettings>,
     #[cfg(feature = "dev")] sim_input: Option<Res<SimulatedGgrsInput>>,
 ) {
-    let keyboard_flags = flags_from_keyboard(&keyboard);
+    // Keys don't play cards while one is zoomed in for inspection.
+    let keyboard_flags = if inspect.is_open() {
+        0
+    } else {
+        flags_from_keyboard(&keyboard)
+    };
     if keyboard_flags != 0 {
-        info!("Keyboard input flags: {}", keyboard_flags);
+        hot_log!("Keyboard input flags: {}", keyboard_flags);
     }
     let mut flags = keyboard_flags;
 
@@ -39,19 +47,20 @@ pub fn read_local_inputs(
         commands.remove_resource::<SimulatedGgrsInput>();
         let sim_flags = flags_from_key_string(&sim.0);
         if sim_flags != 0 {
-            info!("BRP simulated input: {} -> flags {}", sim.0, sim_flags);
+            hot_log!("BRP simulated input: {} -> flags {}", sim.0, sim_flags);
         }
         flags |= sim_flags;
     }
 
     let pending_flags = pending_input.take_flags();
     if pending_flags != 0 {
-        info!("Pending input flags: {}", pending_flags);
+        hot_log!("Pending input flags: {}", pending_flags);
     }
     flags |= pending_flags;
+    let flags = input_settings.tag_flags(flags);
 
     if flags != 0 {
-        info!("Total input flags for GGRS: {}", flags);
+        hot_log!("Total input flags for GGRS: {}", flags);
     }
 
     let input = GameInput { flags };
@@ -1,29 +1,106 @@
 //! Lobby system for matchmaking.
 
-use std::ops::DerefMut;
+use std::{collections::HashMap, ops::DerefMut};
 
-use bevy::prelude::*;
+use bevy::{
+    ecs::{spawn::SpawnWith, system::SystemParam},
+    prelude::*,
+};
 use bevy_ggrs::Session;
 use bevy_ggrs::ggrs::SessionState;
 use bevy_ggrs::prelude::*;
-use bevy_matchbox::matchbox_socket::{RtcIceServerConfig, WebRtcSocketBuilder};
+use bevy_matchbox::matchbox_socket::WebRtcSocketBuilder;
 use bevy_matchbox::prelude::*;
 
-use super::{NetworkPlayers, SensenGgrsConfig, match_seed_from_peers};
+use super::{
+    IceRoute, IceSettings, NetworkPlayers, NetworkTuning, PlayerName, Rating, SensenGgrsConfig,
+    match_seed_from_peers, rating::BUCKET_WIDTH, sanitize_name,
+};
 use crate::{
-    game::{GameMode, MatchSeed},
+    game::{
+        CharacterClass, DeckMode, GameMode, MUTATOR_OFFER_COUNT, MatchFormat, MatchMutator,
+        MatchSeed, MatchTimeLimit, MatchTimer, Mutator, Rules,
+    },
+    logging::Transition,
     screens::Screen,
+    theme::widget,
 };
 
 /// Number of players in a match.
 const NUM_PLAYERS: usize = 2;
 
+/// Reliable channel used to exchange pre-match setup (channel 0 carries GGRS, 2 chat).
+const HANDSHAKE_CHANNEL: usize = 1;
+/// Handshake packet tags: `[tag, value]`.
+const HANDSHAKE_CLASS: u8 = 0;
+const HANDSHAKE_VETO: u8 = 1;
+/// `[tag, high byte, low byte]`.
+const HANDSHAKE_RATING: u8 = 2;
+/// `[tag]`.
+const HANDSHAKE_READY: u8 = 3;
+/// `[tag, UTF-8 bytes..]`.
+const HANDSHAKE_NAME: u8 = 4;
+/// `[tag, minutes]`.
+const HANDSHAKE_TIMER: u8 = 5;
+/// `[tag, rules fingerprint as 8 big-endian bytes]`.
+const HANDSHAKE_RULES: u8 = 6;
+
+/// Seconds without an opponent before asking whether to keep waiting.
+const LOBBY_TIMEOUT_SECS: f32 = 90.0;
+/// Seconds a found peer may take to connect before trying the next ICE route.
+const ICE_TIMEOUT_SECS: f32 = 15.0;
+/// Seconds both players get to click Ready once paired.
+const READY_TIMEOUT_SECS: f32 = 30.0;
+
+/// Seconds to wait in the rating bucket's room before moving to the catch-all room.
+const BUCKET_TIMEOUT_SECS: f32 = 30.0;
+
 /// Matchbox server URL.
 /// dev: ローカルmatchbox_server、release: fly.io
 #[cfg(feature = "dev")]
-const MATCHBOX_SERVER: &str = "ws://localhost:3536/sensen?next=2";
+const MATCHBOX_SERVER: &str = "ws://localhost:3536";
 #[cfg(not(feature = "dev"))]
-const MATCHBOX_SERVER: &str = "wss://sensen-matchbox.fly.dev/sensen?next=2";
+const MATCHBOX_SERVER: &str = "wss://sensen-matchbox.fly.dev";
+
+/// Matchbox room for the selected deck mode, match format and rating bucket.
+/// Random-deck, draft and best-of-three players only get paired with each
+/// other, so both peers have opted in.
+/// `None` is the catch-all room for players whose own bucket stayed empty.
+fn room_url(deck_mode: DeckMode, format: MatchFormat, bucket: Option<u16>) -> String {
+    let room = match deck_mode {
+        DeckMode::Starter => "sensen",
+        DeckMode::Random => "sensen_random",
+        DeckMode::Draft => "sensen_draft",
+    };
+    let series = match format {
+        MatchFormat::Single => "",
+        MatchFormat::BestOfThree => "_bo3",
+    };
+    let range = bucket.map_or_else(|| "any".to_string(), |bucket| format!("r{bucket}"));
+    format!("{MATCHBOX_SERVER}/{room}{series}_{range}?next={NUM_PLAYERS}")
+}
+
+/// Which room the lobby is searching: the local rating bucket first, then any rating.
+#[derive(Resource)]
+pub struct RoomSearch {
+    bucket: Option<u16>,
+    timer: Timer,
+    /// Runs while nobody is connected or connecting.
+    idle: Timer,
+}
+
+impl RoomSearch {
+    fn describe(&self) -> String {
+        match self.bucket {
+            Some(bucket) => format!(
+                "rating {}-{}",
+                bucket * BUCKET_WIDTH,
+                (bucket + 1) * BUCKET_WIDTH - 1
+            ),
+            None => "any rating".to_string(),
+        }
+    }
+}
 
 /// Marker for lobby UI elements.
 #[derive(Component)]
@@ -33,30 +110,440 @@ pub struct LobbyUI;
 #[derive(Component)]
 pub struct LobbyText;
 
-/// Start the matchbox socket connection.
-pub fn start_matchbox_socket(mut commands: Commands) {
-    let room_url = MATCHBOX_SERVER.to_string();
-    info!("Connecting to matchbox server: {}", room_url);
+/// Pre-match setup exchanged with peers before the GGRS session starts.
+#[derive(Resource, Default)]
+pub struct LobbyHandshake {
+    /// Peers that have been sent our class.
+    sent_to: Vec<PeerId>,
+    /// Classes announced by each peer.
+    peer_classes: HashMap<PeerId, CharacterClass>,
+    /// Ratings announced by each peer.
+    peer_ratings: HashMap<PeerId, Rating>,
+    /// Sanitized display names announced by each peer.
+    peer_names: HashMap<PeerId, String>,
+    /// Match timers asked for by each peer.
+    peer_timers: HashMap<PeerId, MatchTimer>,
+    /// Balance rules fingerprints announced by each peer.
+    peer_rules: HashMap<PeerId, u64>,
+    local_ready: bool,
+    ready_sent: bool,
+    /// Peers that have clicked Ready.
+    peer_ready: Vec<PeerId>,
+    /// Runs while the ready check is waiting on someone.
+    ready_timer: Option<Timer>,
+    /// Runs while a found peer hasn't finished connecting.
+    ice_stall: Option<Timer>,
+    /// Mutators offered for this pairing, once both players are known.
+    offers: Option<[Mutator; MUTATOR_OFFER_COUNT]>,
+    /// Offer index vetoed by the local player.
+    local_veto: Option<usize>,
+    veto_sent: bool,
+    /// Offer index vetoed by each peer.
+    peer_vetoes: HashMap<PeerId, usize>,
+}
+
+impl LobbyHandshake {
+    /// Whether a peer is paired and the local player hasn't clicked Ready yet.
+    pub(super) fn awaiting_local_ready(&self) -> bool {
+        self.ready_timer.is_some() && !self.local_ready
+    }
+
+    fn reset_ready_check(&mut self) {
+        self.local_ready = false;
+        self.ready_sent = false;
+        self.peer_ready.clear();
+        self.ready_timer = None;
+    }
 
-    let mut builder = WebRtcSocketBuilder::new(room_url).add_unreliable_channel();
+    /// Whether the mutator offers are up and the local player hasn't vetoed yet.
+    pub(super) fn awaiting_local_veto(&self) -> bool {
+        self.offers.is_some() && self.local_veto.is_none()
+    }
+}
 
+/// What the local player announces to peers in the handshake.
+#[derive(SystemParam)]
+pub struct LocalProfile<'w> {
+    class: Res<'w, CharacterClass>,
+    rating: Res<'w, Rating>,
+    name: Res<'w, PlayerName>,
+    timer: Res<'w, MatchTimer>,
+    rules: Res<'w, Rules>,
+}
+
+/// Marker for the mutator veto buttons.
+#[derive(Component)]
+pub struct MutatorVoteUi;
+
+/// Marker for the Ready button.
+#[derive(Component)]
+pub struct ReadyCheckUi;
+
+fn class_to_byte(class: CharacterClass) -> u8 {
+    match class {
+        CharacterClass::Ironclad => 0,
+        CharacterClass::Silent => 1,
+    }
+}
+
+fn class_from_byte(byte: u8) -> Option<CharacterClass> {
+    match byte {
+        0 => Some(CharacterClass::Ironclad),
+        1 => Some(CharacterClass::Silent),
+        _ => None,
+    }
+}
+
+/// Start the matchbox socket connection in the local rating bucket's room.
+pub fn start_matchbox_socket(
+    mut commands: Commands,
+    deck_mode: Res<DeckMode>,
+    format: Res<MatchFormat>,
+    rating: Res<Rating>,
+    settings: Res<IceSettings>,
+) {
     // localhostではSTUN不要。デフォルトのGoogle STUNはICE gathering完了まで~40秒かかるため、
     // ICEサーバー0個にしてhost候補のみで即接続する。
-    // 本番では適切なSTUN/TURNサーバーを設定すること。
-    if MATCHBOX_SERVER.contains("localhost") || MATCHBOX_SERVER.contains("127.0.0.1") {
-        builder = builder.ice_server(RtcIceServerConfig {
-            urls: vec![],
-            username: None,
-            credential: None,
-        });
+    let route = if MATCHBOX_SERVER.contains("localhost") || MATCHBOX_SERVER.contains("127.0.0.1") {
+        IceRoute::Local
+    } else {
+        IceRoute::Direct
+    };
+    let bucket = Some(rating.bucket());
+    commands.insert_resource(open_socket(
+        &room_url(*deck_mode, *format, bucket),
+        route,
+        &settings,
+    ));
+    commands.insert_resource(route);
+    commands.insert_resource(LobbyHandshake::default());
+    commands.insert_resource(RoomSearch {
+        bucket,
+        timer: Timer::from_seconds(BUCKET_TIMEOUT_SECS, TimerMode::Once),
+        idle: Timer::from_seconds(LOBBY_TIMEOUT_SECS, TimerMode::Once),
+    });
+}
+
+/// Move to the catch-all room once nobody has turned up in the rating bucket.
+pub fn widen_search(
+    mut commands: Commands,
+    time: Res<Time>,
+    deck_mode: Res<DeckMode>,
+    format: Res<MatchFormat>,
+    settings: Res<IceSettings>,
+    route: Option<Res<IceRoute>>,
+    search: Option<ResMut<RoomSearch>>,
+    socket: Option<Res<MatchboxSocket>>,
+    session: Option<Res<Session<SensenGgrsConfig>>>,
+) {
+    let (Some(mut search), Some(socket), Some(route)) = (search, socket, route) else {
+        return;
+    };
+    if search.bucket.is_none() || session.is_some() {
+        return;
+    }
+    let peers_found =
+        socket.connected_peers().count() > 0 || socket.connecting_peers().next().is_some();
+    if !search.timer.tick(time.delta()).is_finished() || peers_found {
+        return;
+    }
+    info!("No opponent in rating bucket; searching any rating");
+    search.bucket = None;
+    commands.insert_resource(open_socket(
+        &room_url(*deck_mode, *format, None),
+        *route,
+        &settings,
+    ));
+    commands.insert_resource(LobbyHandshake::default());
+}
+
+/// Give up on a ready check nobody finished: an idle local player leaves the
+/// lobby, otherwise the search starts over without the idle peer.
+pub fn expire_ready_check(
+    mut commands: Commands,
+    time: Res<Time>,
+    deck_mode: Res<DeckMode>,
+    format: Res<MatchFormat>,
+    settings: Res<IceSettings>,
+    route: Option<Res<IceRoute>>,
+    handshake: Option<ResMut<LobbyHandshake>>,
+    search: Option<ResMut<RoomSearch>>,
+    ready_ui: Query<Entity, With<ReadyCheckUi>>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    let Some(mut handshake) = handshake else {
+        return;
+    };
+    let local_ready = handshake.local_ready;
+    let Some(timer) = handshake.ready_timer.as_mut() else {
+        return;
+    };
+    if !timer.tick(time.delta()).just_finished() {
+        return;
+    }
+    if !local_ready {
+        info!("Ready check timed out; leaving the lobby");
+        next_screen.set(Screen::Title);
+        return;
+    }
+    info!("Opponent missed the ready check; searching again");
+    for entity in &ready_ui {
+        commands.entity(entity).despawn();
+    }
+    let bucket = search.and_then(|mut search| {
+        search.timer.reset();
+        search.bucket
+    });
+    let route = route.map_or(IceRoute::Direct, |route| *route);
+    commands.insert_resource(open_socket(
+        &room_url(*deck_mode, *format, bucket),
+        route,
+        &settings,
+    ));
+    commands.insert_resource(LobbyHandshake::default());
+}
+
+/// Fall back to the TURN relay when a peer is found but never finishes
+/// connecting, and show the failure screen once there is nothing left to try.
+pub fn watch_ice(
+    mut commands: Commands,
+    time: Res<Time>,
+    deck_mode: Res<DeckMode>,
+    format: Res<MatchFormat>,
+    settings: Res<IceSettings>,
+    route: Option<ResMut<IceRoute>>,
+    search: Option<Res<RoomSearch>>,
+    socket: Option<Res<MatchboxSocket>>,
+    handshake: Option<ResMut<LobbyHandshake>>,
+    lobby_root: Query<Entity, With<LobbyUI>>,
+    mut lobby_text: Query<&mut Text, With<LobbyText>>,
+) {
+    let (Some(mut route), Some(socket), Some(mut handshake)) = (route, socket, handshake) else {
+        return;
+    };
+    if socket.connecting_peers().next().is_none() {
+        handshake.ice_stall = None;
+        return;
+    }
+    let stall = handshake
+        .ice_stall
+        .get_or_insert_with(|| Timer::from_seconds(ICE_TIMEOUT_SECS, TimerMode::Once));
+    if !stall.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    if let Some(fallback) = route.fallback(&settings) {
+        warn!(
+            "Connection stalled on the {} route; retrying over {}",
+            route.label(),
+            fallback.label()
+        );
+        *route = fallback;
+        let room_url = room_url(*deck_mode, *format, search.and_then(|search| search.bucket));
+        commands.insert_resource(open_socket(&room_url, fallback, &settings));
+        commands.insert_resource(LobbyHandshake::default());
+        return;
+    }
+    error!("Connection failed on the {} route", route.label());
+    commands.remove_resource::<MatchboxSocket>();
+    show_lobby_prompt(
+        &mut commands,
+        &lobby_root,
+        &mut lobby_text,
+        LobbyStall::IceFailed(*route),
+    );
+}
+
+/// Ask the player what to do once nobody has turned up for a while, and take
+/// the question down again if someone does.
+pub fn watch_lobby_timeout(
+    mut commands: Commands,
+    time: Res<Time>,
+    search: Option<ResMut<RoomSearch>>,
+    socket: Option<Res<MatchboxSocket>>,
+    prompt: Query<Entity, With<LobbyPrompt>>,
+    lobby_root: Query<Entity, With<LobbyUI>>,
+    mut lobby_text: Query<&mut Text, With<LobbyText>>,
+) {
+    let (Some(mut search), Some(socket)) = (search, socket) else {
+        return;
+    };
+    if socket.connected_peers().next().is_some() || socket.connecting_peers().next().is_some() {
+        search.idle.reset();
+        for entity in &prompt {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+    if search.idle.tick(time.delta()).just_finished() {
+        info!("No opponent after {LOBBY_TIMEOUT_SECS}s");
+        show_lobby_prompt(
+            &mut commands,
+            &lobby_root,
+            &mut lobby_text,
+            LobbyStall::NoOpponent,
+        );
+    }
+}
+
+fn open_socket(room_url: &str, route: IceRoute, settings: &IceSettings) -> MatchboxSocket {
+    info!(
+        "Connecting to matchbox server: {} ({} ICE)",
+        room_url,
+        route.label()
+    );
+    let builder = WebRtcSocketBuilder::new(room_url)
+        .add_unreliable_channel()
+        .add_reliable_channel()
+        .add_reliable_channel()
+        .ice_server(route.server_config(settings));
+    MatchboxSocket::from(builder)
+}
+
+/// Why the lobby stopped to ask the player what to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LobbyStall {
+    /// Nobody joined within `LOBBY_TIMEOUT_SECS`; the search is still running.
+    NoOpponent,
+    /// The connection to the matchbox server was lost.
+    SocketDropped,
+    /// A peer was found but no ICE route reached it.
+    IceFailed(IceRoute),
+    /// A peer's balance rules differ from ours, so a match would desync.
+    RulesMismatch,
+}
+
+impl LobbyStall {
+    fn message(self) -> &'static str {
+        match self {
+            Self::NoOpponent => "No opponent has turned up yet.",
+            Self::SocketDropped => "Lost the connection to the matchmaking server.",
+            Self::IceFailed(_) => "Couldn't connect to your opponent.",
+            Self::RulesMismatch => "Your opponent is playing with different balance rules.",
+        }
+    }
+
+    fn hint(self) -> &'static str {
+        match self {
+            Self::NoOpponent => "Keep waiting, or reconnect in case the server lost track of you.",
+            Self::SocketDropped => "Check your internet connection and try again.",
+            Self::IceFailed(IceRoute::Relay) => {
+                "The TURN relay didn't get through either.\n\
+                 Check turn, turn_username and turn_credential in save/ice.txt."
+            }
+            Self::IceFailed(IceRoute::Local | IceRoute::Direct) => {
+                "Your network may be blocking direct peer-to-peer connections.\n\
+                 Add a TURN server to save/ice.txt to relay through it."
+            }
+            Self::RulesMismatch => {
+                "Both players need the same game version and assets/data/game.balance.ron."
+            }
+        }
+    }
+}
+
+/// Marker for the keep waiting / retry / back prompt.
+#[derive(Component)]
+pub struct LobbyPrompt;
+
+fn show_lobby_prompt(
+    commands: &mut Commands,
+    lobby_root: &Query<Entity, With<LobbyUI>>,
+    lobby_text: &mut Query<&mut Text, With<LobbyText>>,
+    stall: LobbyStall,
+) {
+    for mut text in lobby_text.iter_mut() {
+        text.0 = stall.message().to_string();
+    }
+    for root in lobby_root {
+        commands.entity(root).with_child(lobby_prompt(stall));
+    }
+}
+
+fn lobby_prompt(stall: LobbyStall) -> impl Bundle {
+    (
+        Name::new("Lobby Prompt"),
+        LobbyPrompt,
+        Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(10.0),
+            ..default()
+        },
+        Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+            parent.spawn(widget::label(stall.hint()));
+            // Only a search that is still running can be waited on.
+            if stall == LobbyStall::NoOpponent {
+                parent.spawn(widget::button("Keep Waiting", keep_waiting));
+            }
+            // Reconnecting can't change either player's rules.
+            if stall != LobbyStall::RulesMismatch {
+                parent.spawn(widget::button("Retry Connection", retry_connection));
+            }
+            parent.spawn(widget::button("Back to Menu", leave_lobby));
+        })),
+    )
+}
+
+fn keep_waiting(
+    _: On<Pointer<Click>>,
+    mut commands: Commands,
+    search: Option<ResMut<RoomSearch>>,
+    prompt: Query<Entity, With<LobbyPrompt>>,
+) {
+    if let Some(mut search) = search {
+        search.idle.reset();
+    }
+    for entity in &prompt {
+        commands.entity(entity).despawn();
     }
+}
+
+fn retry_connection(
+    _: On<Pointer<Click>>,
+    mut commands: Commands,
+    prompt: Query<Entity, With<LobbyPrompt>>,
+) {
+    for entity in &prompt {
+        commands.entity(entity).despawn();
+    }
+    // A fresh socket on the first ICE route, back in the rating bucket's room.
+    commands.run_system_cached(start_matchbox_socket);
+}
+
+fn leave_lobby(_: On<Pointer<Click>>, mut next_screen: ResMut<NextState<Screen>>) {
+    next_screen.set(Screen::Title);
+}
+
+/// Marker for the match format text.
+#[derive(Component)]
+struct MatchFormatLabel;
 
-    let socket = MatchboxSocket::from(builder);
-    commands.insert_resource(socket);
+fn switch_match_format(
+    _: On<Pointer<Click>>,
+    mut commands: Commands,
+    mut format: ResMut<MatchFormat>,
+    session: Option<Res<Session<SensenGgrsConfig>>>,
+    prompt: Query<Entity, With<LobbyPrompt>>,
+    ready_ui: Query<Entity, With<ReadyCheckUi>>,
+    mut labels: Query<&mut Text, With<MatchFormatLabel>>,
+) {
+    if session.is_some() {
+        return;
+    }
+    *format = format.next();
+    for mut text in &mut labels {
+        text.0 = format!("Format: {}", format.label());
+    }
+    for entity in prompt.iter().chain(&ready_ui) {
+        commands.entity(entity).despawn();
+    }
+    // Each format has its own rooms, so the search starts over.
+    commands.run_system_cached(start_matchbox_socket);
 }
 
 /// Setup lobby UI.
-pub fn lobby_startup(mut commands: Commands) {
+pub fn lobby_startup(mut commands: Commands, format: Res<MatchFormat>) {
     commands.spawn((
         Name::new("Lobby UI"),
         LobbyUI,
@@ -84,10 +571,34 @@ pub fn lobby_startup(mut commands: Commands) {
                 TextFont::from_font_size(24.0),
                 TextColor(Color::srgb(0.7, 0.7, 0.7)),
             ),
+            (
+                Name::new("Match Format"),
+                Node {
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(16.0),
+                    ..default()
+                },
+                children![
+                    (
+                        MatchFormatLabel,
+                        Text::new(format!("Format: {}", format.label())),
+                        TextFont::from_font_size(22.0),
+                        TextColor(Color::WHITE),
+                    ),
+                    widget::button_medium("Switch", switch_match_format),
+                ],
+            ),
         ],
     ));
 }
 
+/// Lobby values polled every frame, logged only when they change.
+#[derive(Default)]
+pub struct LobbyLog {
+    session: Transition<String>,
+    peers: Transition<Vec<PeerId>>,
+}
+
 /// Main lobby system - handles matchmaking and session creation.
 pub fn lobby_system(
     mut commands: Commands,
@@ -96,6 +607,16 @@ pub fn lobby_system(
     mut next_screen: ResMut<NextState<Screen>>,
     mut lobby_text: Query<&mut Text, With<LobbyText>>,
     mut game_mode: ResMut<GameMode>,
+    mut handshake: ResMut<LobbyHandshake>,
+    profile: LocalProfile,
+    deck_mode: Res<DeckMode>,
+    search: Option<Res<RoomSearch>>,
+    tuning: Res<NetworkTuning>,
+    route: Option<Res<IceRoute>>,
+    lobby_root: Query<Entity, With<LobbyUI>>,
+    vote_ui: Query<(), With<MutatorVoteUi>>,
+    ready_ui: Query<Entity, With<ReadyCheckUi>>,
+    mut log: Local<LobbyLog>,
 ) {
     let Some(mut socket) = socket else {
         return;
@@ -104,6 +625,13 @@ pub fn lobby_system(
     // Update socket state
     let Ok(peer_changes) = socket.try_update_peers() else {
         warn!("Socket dropped");
+        commands.remove_resource::<MatchboxSocket>();
+        show_lobby_prompt(
+            &mut commands,
+            &lobby_root,
+            &mut lobby_text,
+            LobbyStall::SocketDropped,
+        );
         return;
     };
 
@@ -120,7 +648,9 @@ pub fn lobby_system(
             }
             _ => (false, "Unknown".to_string()),
         };
-        info!("GGRS session state: {}", state_str);
+        if log.session.changed(state_str.clone()) {
+            info!("GGRS session state: {}", state_str);
+        }
         for mut text in &mut lobby_text {
             text.0 = if running {
                 "Synchronized. Starting game...".to_string()
@@ -129,7 +659,12 @@ pub fn lobby_system(
             };
         }
         if running {
-            next_screen.set(Screen::Gameplay);
+            // Drafting runs over the session too, so both peers see the same picks.
+            next_screen.set(if *deck_mode == DeckMode::Draft {
+                Screen::Draft
+            } else {
+                Screen::Gameplay
+            });
         }
         return;
     }
@@ -140,25 +675,176 @@ pub fn lobby_system(
     // Get connected peers
     let connected_peers = socket.connected_peers().count();
     let all_peers: Vec<_> = socket.connected_peers().collect();
-    if !all_peers.is_empty() {
+    if log.peers.changed(all_peers.clone()) && !all_peers.is_empty() {
         info!("Connected peers: {} {:?}", connected_peers, all_peers);
     }
 
     // Update UI
     for mut text in &mut lobby_text {
         if connected_peers == 0 {
-            text.0 = "Waiting for opponent...".to_string();
+            let range = search
+                .as_ref()
+                .map_or_else(String::new, |search| format!(" ({})", search.describe()));
+            text.0 = format!("Waiting for opponent{range}...");
         } else {
-            text.0 = format!("Connected: {}/{}", connected_peers + 1, NUM_PLAYERS);
+            let route = route
+                .as_ref()
+                .map_or_else(String::new, |route| format!(" ({})", route.label()));
+            text.0 = format!("Connected: {}/{}{route}", connected_peers + 1, NUM_PLAYERS);
+        }
+    }
+
+    // Announce our class, rating, name, match timer and rules to every connected peer once,
+    // and collect theirs.
+    for peer_id in &all_peers {
+        if !handshake.sent_to.contains(peer_id) {
+            let packet = vec![HANDSHAKE_CLASS, class_to_byte(*profile.class)].into_boxed_slice();
+            socket.channel_mut(HANDSHAKE_CHANNEL).send(packet, *peer_id);
+            let [high, low] = profile.rating.0.to_be_bytes();
+            let packet = vec![HANDSHAKE_RATING, high, low].into_boxed_slice();
+            socket.channel_mut(HANDSHAKE_CHANNEL).send(packet, *peer_id);
+            let mut packet = vec![HANDSHAKE_NAME];
+            packet.extend_from_slice(profile.name.0.as_bytes());
+            socket
+                .channel_mut(HANDSHAKE_CHANNEL)
+                .send(packet.into_boxed_slice(), *peer_id);
+            let packet = vec![HANDSHAKE_TIMER, profile.timer.minutes].into_boxed_slice();
+            socket.channel_mut(HANDSHAKE_CHANNEL).send(packet, *peer_id);
+            let mut packet = vec![HANDSHAKE_RULES];
+            packet.extend_from_slice(&profile.rules.fingerprint().to_be_bytes());
+            socket
+                .channel_mut(HANDSHAKE_CHANNEL)
+                .send(packet.into_boxed_slice(), *peer_id);
+            handshake.sent_to.push(*peer_id);
+        }
+    }
+    for (peer_id, packet) in socket.channel_mut(HANDSHAKE_CHANNEL).receive() {
+        match *packet {
+            [HANDSHAKE_CLASS, byte] => match class_from_byte(byte) {
+                Some(class) => {
+                    handshake.peer_classes.insert(peer_id, class);
+                }
+                None => warn!("Ignoring unknown class {} from {:?}", byte, peer_id),
+            },
+            [HANDSHAKE_RATING, high, low] => {
+                handshake
+                    .peer_ratings
+                    .insert(peer_id, Rating(u16::from_be_bytes([high, low])));
+            }
+            [HANDSHAKE_TIMER, minutes] => {
+                handshake
+                    .peer_timers
+                    .insert(peer_id, MatchTimer { minutes });
+            }
+            [HANDSHAKE_RULES, a, b, c, d, e, f, g, h] => {
+                handshake
+                    .peer_rules
+                    .insert(peer_id, u64::from_be_bytes([a, b, c, d, e, f, g, h]));
+            }
+            [HANDSHAKE_READY] => {
+                if !handshake.peer_ready.contains(&peer_id) {
+                    handshake.peer_ready.push(peer_id);
+                }
+            }
+            [HANDSHAKE_VETO, index] if (index as usize) < MUTATOR_OFFER_COUNT => {
+                handshake.peer_vetoes.insert(peer_id, index as usize);
+            }
+            // The name is cosmetic: a peer without one shows as the default.
+            [HANDSHAKE_NAME, ref bytes @ ..] => {
+                match std::str::from_utf8(bytes).ok().and_then(sanitize_name) {
+                    Some(name) => {
+                        handshake.peer_names.insert(peer_id, name);
+                    }
+                    None => warn!("Ignoring unusable name from {:?}", peer_id),
+                }
+            }
+            _ => warn!("Ignoring malformed handshake packet from {:?}", peer_id),
         }
     }
 
     // Check if we have enough players
     if connected_peers + 1 < NUM_PLAYERS {
+        // A peer left mid ready check; the next one starts a fresh check.
+        if handshake.ready_timer.is_some() {
+            handshake.reset_ready_check();
+            for entity in &ready_ui {
+                commands.entity(entity).despawn();
+            }
+        }
         return;
     }
 
-    info!("All players connected. Starting synchronization...");
+    if !all_peers.iter().all(|peer_id| {
+        handshake.peer_classes.contains_key(peer_id)
+            && handshake.peer_ratings.contains_key(peer_id)
+            && handshake.peer_timers.contains_key(peer_id)
+            && handshake.peer_rules.contains_key(peer_id)
+    }) {
+        for mut text in &mut lobby_text {
+            text.0 = "Exchanging class, rating, match timer and rules...".to_string();
+        }
+        return;
+    }
+
+    // Different balance numbers would desync the first time they mattered.
+    let local_rules = profile.rules.fingerprint();
+    if let Some(peer_id) = all_peers
+        .iter()
+        .find(|peer_id| handshake.peer_rules.get(*peer_id) != Some(&local_rules))
+    {
+        warn!(
+            "Balance rules differ from {:?} ({:016x} here, {:016x} there)",
+            peer_id,
+            local_rules,
+            handshake
+                .peer_rules
+                .get(peer_id)
+                .copied()
+                .unwrap_or_default()
+        );
+        commands.remove_resource::<MatchboxSocket>();
+        show_lobby_prompt(
+            &mut commands,
+            &lobby_root,
+            &mut lobby_text,
+            LobbyStall::RulesMismatch,
+        );
+        return;
+    }
+
+    // Ready check: nobody is pulled into a match until both players confirm.
+    if handshake.local_ready && !handshake.ready_sent {
+        for peer_id in &all_peers {
+            let packet = vec![HANDSHAKE_READY].into_boxed_slice();
+            socket.channel_mut(HANDSHAKE_CHANNEL).send(packet, *peer_id);
+        }
+        handshake.ready_sent = true;
+    }
+    let everyone_ready = handshake.local_ready
+        && all_peers
+            .iter()
+            .all(|peer_id| handshake.peer_ready.contains(peer_id));
+    if !everyone_ready {
+        let local_ready = handshake.local_ready;
+        let timer = handshake
+            .ready_timer
+            .get_or_insert_with(|| Timer::from_seconds(READY_TIMEOUT_SECS, TimerMode::Once));
+        let secs_left = timer.remaining_secs().ceil();
+        if !local_ready && ready_ui.is_empty() {
+            for root in &lobby_root {
+                commands.entity(root).with_child(ready_check_ui());
+            }
+        }
+        for mut text in &mut lobby_text {
+            text.0 = if local_ready {
+                format!("Waiting for opponent to ready up... ({secs_left}s)")
+            } else {
+                format!("Opponent found! Ready? ({secs_left}s)")
+            };
+        }
+        return;
+    }
+    handshake.ready_timer = None;
 
     let Some(local_peer_id) = socket.id() else {
         warn!("Matchbox socket has no local peer id yet.");
@@ -169,10 +855,62 @@ pub fn lobby_system(
     peer_ids.push(local_peer_id);
     peer_ids.sort();
 
+    let match_seed = match_seed_from_peers(&peer_ids);
+
+    // Mutator veto: both peers roll the same offer from the shared seed.
+    let offers = *handshake
+        .offers
+        .get_or_insert_with(|| Mutator::roll(match_seed));
+    if vote_ui.is_empty() {
+        for root in &lobby_root {
+            commands.entity(root).with_child(mutator_vote_ui(offers));
+        }
+    }
+    let Some(local_veto) = handshake.local_veto else {
+        for mut text in &mut lobby_text {
+            text.0 = "Veto one mutator".to_string();
+        }
+        return;
+    };
+    if !handshake.veto_sent {
+        for peer_id in &all_peers {
+            let packet = vec![HANDSHAKE_VETO, local_veto as u8].into_boxed_slice();
+            socket.channel_mut(HANDSHAKE_CHANNEL).send(packet, *peer_id);
+        }
+        handshake.veto_sent = true;
+    }
+    let vetoes: Option<Vec<usize>> = peer_ids
+        .iter()
+        .map(|peer_id| {
+            if *peer_id == local_peer_id {
+                Some(local_veto)
+            } else {
+                handshake.peer_vetoes.get(peer_id).copied()
+            }
+        })
+        .collect();
+    let Some(vetoes) = vetoes else {
+        for mut text in &mut lobby_text {
+            text.0 = "Waiting for opponent's veto...".to_string();
+        }
+        return;
+    };
+    let mutator = Mutator::resolve(offers, &vetoes, match_seed);
+    info!("Match mutator: {:?}", mutator);
+    let time_limit = all_peers
+        .iter()
+        .filter_map(|peer_id| handshake.peer_timers.get(peer_id))
+        .fold(*profile.timer, |agreed, timer| agreed.agree(*timer));
+    info!("Match timer: {}", time_limit.label());
+
+    info!("All players connected. Starting synchronization...");
+    if let Some(route) = &route {
+        info!("Peer connection route: {}", route.label());
+    }
+
     // Create GGRS P2P session
-    let mut session_builder = SessionBuilder::<SensenGgrsConfig>::new()
-        .with_num_players(NUM_PLAYERS)
-        .with_input_delay(2);
+    let mut session_builder =
+        tuning.configure(SessionBuilder::<SensenGgrsConfig>::new().with_num_players(NUM_PLAYERS));
 
     // Add players in a deterministic order across peers.
     for (i, peer_id) in peer_ids.iter().copied().enumerate() {
@@ -186,8 +924,6 @@ pub fn lobby_system(
             .expect("Failed to add player");
     }
 
-    let match_seed = match_seed_from_peers(&peer_ids);
-
     // Build session with socket
     let channel = socket.take_channel(0).unwrap();
     let session = session_builder
@@ -195,26 +931,119 @@ pub fn lobby_system(
         .expect("Failed to start P2P session");
 
     commands.insert_resource(Session::P2P(session));
-    commands.insert_resource(build_network_players(local_peer_id, &peer_ids));
+    commands.insert_resource(build_network_players(
+        local_peer_id,
+        &peer_ids,
+        &profile,
+        &handshake,
+    ));
     commands.insert_resource(MatchSeed(match_seed));
+    commands.insert_resource(MatchMutator(Some(mutator)));
+    commands.insert_resource(MatchTimeLimit(time_limit));
     *game_mode = GameMode::Online;
 }
 
-fn build_network_players(local_peer_id: PeerId, peer_ids: &[PeerId]) -> NetworkPlayers {
-    NetworkPlayers {
-        local_peer_id,
-        handles: peer_ids.to_vec(),
+fn mutator_vote_ui(offers: [Mutator; MUTATOR_OFFER_COUNT]) -> impl Bundle {
+    let [first, second, third] = offers;
+    let descriptions = offers
+        .iter()
+        .map(|mutator| format!("{}: {}", mutator.name(), mutator.description()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    (
+        Name::new("Mutator Vote"),
+        MutatorVoteUi,
+        Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(10.0),
+            ..default()
+        },
+        children![
+            widget::label(descriptions),
+            widget::button(format!("Veto {}", first.name()), veto_first),
+            widget::button(format!("Veto {}", second.name()), veto_second),
+            widget::button(format!("Veto {}", third.name()), veto_third),
+        ],
+    )
+}
+
+fn ready_check_ui() -> impl Bundle {
+    (
+        Name::new("Ready Check"),
+        ReadyCheckUi,
+        Node::default(),
+        children![widget::button("Ready", ready_up)],
+    )
+}
+
+fn ready_up(
+    _: On<Pointer<Click>>,
+    mut commands: Commands,
+    handshake: ResMut<LobbyHandshake>,
+    ready_ui: Query<Entity, With<ReadyCheckUi>>,
+) {
+    mark_ready(handshake);
+    for entity in &ready_ui {
+        commands.entity(entity).despawn();
     }
 }
 
-/// Log GGRS events during gameplay.
-pub fn log_ggrs_events(mut session: ResMut<Session<SensenGgrsConfig>>) {
-    match session.as_mut() {
-        Session::P2P(s) => {
-            for event in s.events() {
-                info!("GGRS Event: {:?}", event);
-            }
+/// Confirm the local player is ready; it's sent to peers by `lobby_system`.
+pub(super) fn mark_ready(mut handshake: ResMut<LobbyHandshake>) {
+    handshake.local_ready = true;
+}
+
+fn veto_first(_: On<Pointer<Click>>, handshake: ResMut<LobbyHandshake>) {
+    veto_mutator(0, handshake);
+}
+
+fn veto_second(_: On<Pointer<Click>>, handshake: ResMut<LobbyHandshake>) {
+    veto_mutator(1, handshake);
+}
+
+fn veto_third(_: On<Pointer<Click>>, handshake: ResMut<LobbyHandshake>) {
+    veto_mutator(2, handshake);
+}
+
+/// Lock in the local veto; it's sent to peers by `lobby_system`.
+pub(super) fn veto_mutator(index: usize, mut handshake: ResMut<LobbyHandshake>) {
+    if handshake.local_veto.is_none() {
+        handshake.local_veto = Some(index);
+    }
+}
+
+fn build_network_players(
+    local_peer_id: PeerId,
+    peer_ids: &[PeerId],
+    profile: &LocalProfile,
+    handshake: &LobbyHandshake,
+) -> NetworkPlayers {
+    let mut players = NetworkPlayers {
+        local_peer_id,
+        handles: peer_ids.to_vec(),
+        classes: Vec::new(),
+        ratings: Vec::new(),
+        names: Vec::new(),
+    };
+    for peer_id in peer_ids {
+        if *peer_id == local_peer_id {
+            players.classes.push(*profile.class);
+            players.ratings.push(*profile.rating);
+            players.names.push(profile.name.0.clone());
+        } else {
+            let class = handshake.peer_classes.get(peer_id).copied();
+            let rating = handshake.peer_ratings.get(peer_id).copied();
+            players.classes.push(class.unwrap_or_default());
+            players.ratings.push(rating.unwrap_or_default());
+            players.names.push(
+                handshake
+                    .peer_names
+                    .get(peer_id)
+                    .cloned()
+                    .unwrap_or_else(|| super::names::UNKNOWN_OPPONENT.to_string()),
+            );
         }
-        _ => {}
     }
+    players
 }
@@ -1,33 +1,51 @@
 //! Network module for P2P multiplayer using GGRS and Matchbox.
 
+#[cfg(feature = "dev_native")]
+mod bot;
+mod chat;
 mod config;
+mod draft;
+mod ice;
 mod input;
 mod lobby;
+mod names;
+mod quality;
+mod rating;
+mod rollback;
+mod series;
+mod transition;
+mod tuning;
 
 pub use config::SensenGgrsConfig;
+pub use ice::{IceRoute, IceSettings};
 pub use input::*;
 pub use lobby::*;
+pub use names::{MAX_NAME_CHARS, PlayerName, sanitize_name};
+pub use rating::Rating;
+pub use tuning::NetworkTuning;
 
 use bevy::prelude::*;
-use bevy_ggrs::RollbackFrameCount;
-use bevy_ggrs::prelude::*;
-use bevy_matchbox::prelude::{MatchboxSocket, PeerId};
+use bevy_ggrs::{RollbackFrameCount, prelude::*};
+use bevy_matchbox::prelude::PeerId;
 
 use crate::game::{
-    Acceleration, BarricadeEffect, Block, BrutalityEffect, CardRegistry, CombustEffect,
-    CorruptionEffect, Cost, DarkEmbraceEffect, Deck, DemonFormEffect, DiscardPile,
-    DrawCardsMessage, EvolveEffect, FeelNoPainEffect, FireBreathingEffect, GameMode, GameResult,
-    GameplaySystems, Hand, Health, JuggernautEffect, MetallicizeEffect, PlayCardMessage,
-    PlayerHandle, RageEffect, RuptureEffect, Strength, Thorns, Vulnerable, Weak,
-    apply_local_input_flags, is_online,
+    CardRegistry, CharacterClass, CorruptionEffect, Cost, DeathMessage, DrawCardsMessage,
+    FreeCards, GameResult, GameplaySystems, Hand, PlayCardMessage, PlayQueue, PlayerHandle, Rules,
+    SIM_TICK_RATE, apply_input_flags, is_online,
 };
-use crate::screens::Screen;
+use crate::{replay::ReplayRecorder, screens::Screen};
 
 /// Mapping from GGRS player handles to Matchbox peer IDs.
 #[derive(Resource, Debug, Clone)]
 pub struct NetworkPlayers {
     pub local_peer_id: PeerId,
     pub handles: Vec<PeerId>,
+    /// Character class picked by each player, indexed by handle.
+    pub classes: Vec<CharacterClass>,
+    /// Rating announced by each player, indexed by handle.
+    pub ratings: Vec<Rating>,
+    /// Sanitized display name of each player, indexed by handle.
+    pub names: Vec<String>,
 }
 
 impl NetworkPlayers {
@@ -36,36 +54,46 @@ impl NetworkPlayers {
             .iter()
             .position(|peer_id| *peer_id == self.local_peer_id)
     }
+
+    pub fn class_for(&self, handle: usize) -> CharacterClass {
+        self.classes.get(handle).copied().unwrap_or_default()
+    }
+
+    /// Rating of the first remote player.
+    pub fn opponent_rating(&self) -> Option<Rating> {
+        let local = self.local_handle()?;
+        (0..self.handles.len())
+            .find(|handle| *handle != local)
+            .and_then(|handle| self.ratings.get(handle).copied())
+    }
+
+    /// Display name of the first remote player.
+    pub fn opponent_name(&self) -> &str {
+        self.local_handle()
+            .and_then(|local| (0..self.handles.len()).find(|handle| *handle != local))
+            .and_then(|handle| self.names.get(handle))
+            .map_or(names::UNKNOWN_OPPONENT, String::as_str)
+    }
 }
 
 pub fn plugin(app: &mut App) {
-    app.add_plugins(GgrsPlugin::<SensenGgrsConfig>::default());
-
-    // Rollback configuration
-    app.rollback_component_with_clone::<Health>();
-    app.rollback_component_with_clone::<Block>();
-    app.rollback_component_with_clone::<Thorns>();
-    app.rollback_component_with_clone::<Strength>();
-    app.rollback_component_with_clone::<Vulnerable>();
-    app.rollback_component_with_clone::<Weak>();
-    app.rollback_component_with_clone::<Cost>();
-    app.rollback_component_with_clone::<Acceleration>();
-    app.rollback_component_with_clone::<RageEffect>();
-    app.rollback_component_with_clone::<MetallicizeEffect>();
-    app.rollback_component_with_clone::<DemonFormEffect>();
-    app.rollback_component_with_clone::<BarricadeEffect>();
-    app.rollback_component_with_clone::<JuggernautEffect>();
-    app.rollback_component_with_clone::<CombustEffect>();
-    app.rollback_component_with_clone::<DarkEmbraceEffect>();
-    app.rollback_component_with_clone::<EvolveEffect>();
-    app.rollback_component_with_clone::<FeelNoPainEffect>();
-    app.rollback_component_with_clone::<FireBreathingEffect>();
-    app.rollback_component_with_clone::<RuptureEffect>();
-    app.rollback_component_with_clone::<CorruptionEffect>();
-    app.rollback_component_with_clone::<BrutalityEffect>();
-    app.rollback_component_with_clone::<Hand>();
-    app.rollback_component_with_clone::<Deck>();
-    app.rollback_component_with_clone::<DiscardPile>();
+    app.add_plugins((
+        GgrsPlugin::<SensenGgrsConfig>::default(),
+        #[cfg(feature = "dev_native")]
+        bot::plugin,
+        chat::plugin,
+        draft::plugin,
+        ice::plugin,
+        names::plugin,
+        quality::plugin,
+        rating::plugin,
+        rollback::plugin,
+        series::plugin,
+        transition::plugin,
+        tuning::plugin,
+    ));
+    // Same rate as the offline fixed timestep.
+    app.insert_resource(RollbackFrameRate(SIM_TICK_RATE));
 
     // Register input system
     app.add_systems(ReadInputs, read_local_inputs.run_if(is_online));
@@ -89,15 +117,17 @@ pub fn plugin(app: &mut App) {
         OnEnter(Screen::Lobby),
         (start_matchbox_socket, lobby_startup),
     );
-    app.add_systems(Update, lobby_system.run_if(in_state(Screen::Lobby)));
-    app.add_systems(OnEnter(Screen::Title), cleanup_network_session);
-
-    // GGRS event logging
     app.add_systems(
         Update,
-        log_ggrs_events
-            .run_if(is_online)
-            .run_if(in_state(Screen::Gameplay)),
+        (
+            lobby_system,
+            widen_search,
+            expire_ready_check,
+            watch_ice,
+            watch_lobby_timeout,
+        )
+            .chain()
+            .run_if(in_state(Screen::Lobby)),
     );
 }
 
@@ -122,63 +152,52 @@ pub fn match_seed_from_peers(peer_ids: &[PeerId]) -> u64 {
 /// - Remote player's input → damages LocalPlayer (opponent attacked us)
 fn process_ggrs_inputs(
     inputs: Res<PlayerInputs<SensenGgrsConfig>>,
+    frame: Res<RollbackFrameCount>,
     card_registry: Res<CardRegistry>,
+    rules: Res<Rules>,
+    mut recorder: ResMut<ReplayRecorder>,
     mut player_query: Query<(
         Entity,
         &Hand,
+        &mut PlayQueue,
         &mut Cost,
+        &mut FreeCards,
         &PlayerHandle,
         Option<&CorruptionEffect>,
     )>,
     mut draw_messages: MessageWriter<DrawCardsMessage>,
     mut play_messages: MessageWriter<PlayCardMessage>,
+    mut death_messages: MessageWriter<DeathMessage>,
 ) {
+    let mut recorded = [0; 2];
     for (handle, (input, _status)) in inputs.iter().enumerate() {
         let flags = input.flags;
-        for (player_entity, hand, mut cost, player_handle, corruption) in &mut player_query {
+        if let Some(slot) = recorded.get_mut(handle) {
+            *slot = flags;
+        }
+        for (player_entity, hand, mut queue, mut cost, mut free_cards, player_handle, corruption) in
+            &mut player_query
+        {
             if player_handle.0 != handle {
                 continue;
             }
 
-            apply_local_input_flags(
+            apply_input_flags(
                 flags,
                 player_entity,
                 hand,
+                &mut queue,
                 &mut cost,
+                &mut free_cards,
                 corruption.is_some(),
                 &card_registry,
+                &rules,
                 &mut draw_messages,
                 &mut play_messages,
+                &mut death_messages,
             );
             break;
         }
     }
-}
-
-fn cleanup_network_session(
-    mut commands: Commands,
-    session: Option<Res<Session<SensenGgrsConfig>>>,
-    socket: Option<Res<MatchboxSocket>>,
-    network_players: Option<Res<NetworkPlayers>>,
-    ggrs_time: Option<ResMut<Time<GgrsTime>>>,
-    rollback_frame: Option<ResMut<RollbackFrameCount>>,
-    mut game_mode: ResMut<GameMode>,
-) {
-    if session.is_some() {
-        commands.remove_resource::<Session<SensenGgrsConfig>>();
-    }
-    if socket.is_some() {
-        commands.remove_resource::<MatchboxSocket>();
-    }
-    if network_players.is_some() {
-        commands.remove_resource::<NetworkPlayers>();
-    }
-    if let Some(mut time) = ggrs_time {
-        *time = Time::new_with(GgrsTime);
-    }
-    if let Some(mut frame) = rollback_frame {
-        frame.0 = 0;
-    }
-    commands.remove_resource::<bevy_ggrs::ConfirmedFrameCount>();
-    *game_mode = GameMode::Offline;
+    recorder.record(Some(frame.0), recorded);
 }
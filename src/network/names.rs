@@ -0,0 +1,141 @@
+//! Player display names, announced to the opponent in the lobby handshake.
+//!
+//! Names are cleaned the same way on both ends, so a peer running a modified
+//! client still can't put control characters or a wall of text on our screen.
+
+use bevy::prelude::*;
+
+use super::NetworkPlayers;
+use crate::{
+    game::{OpponentNameLabel, is_online},
+    persist,
+    screens::Screen,
+};
+
+const SAVE_KEY: &str = "name.txt";
+/// Longest name shown, in characters.
+pub const MAX_NAME_CHARS: usize = 16;
+const DEFAULT_NAME: &str = "Player";
+/// Shown for a peer that never sent a usable name.
+pub const UNKNOWN_OPPONENT: &str = "Opponent";
+
+pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(PlayerName::load());
+    app.add_systems(
+        Update,
+        save_player_name
+            .run_if(resource_changed::<PlayerName>.and(not(resource_added::<PlayerName>))),
+    );
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        spawn_versus_header.run_if(is_online),
+    );
+    app.add_systems(
+        Update,
+        label_opponent
+            .run_if(is_online)
+            .run_if(in_state(Screen::Gameplay))
+            .run_if(resource_exists::<NetworkPlayers>),
+    );
+}
+
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct PlayerName(pub String);
+
+impl Default for PlayerName {
+    fn default() -> Self {
+        Self(DEFAULT_NAME.to_string())
+    }
+}
+
+impl PlayerName {
+    pub(crate) fn load() -> Self {
+        persist::load(SAVE_KEY)
+            .and_then(|contents| {
+                contents
+                    .lines()
+                    .find_map(|line| line.strip_prefix("name=").and_then(sanitize_name))
+            })
+            .map(Self)
+            .unwrap_or_default()
+    }
+
+    fn save(&self) {
+        persist::store(SAVE_KEY, &format!("name={}", self.0));
+    }
+}
+
+/// Keep letters, digits, spaces and `-_.`, collapse runs of spaces, and cap
+/// the length. `None` if nothing is left.
+pub fn sanitize_name(raw: &str) -> Option<String> {
+    let kept: String = raw
+        .chars()
+        .filter(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_' | '.'))
+        .collect();
+    let collapsed = kept.split_whitespace().collect::<Vec<_>>().join(" ");
+    let capped: String = collapsed.chars().take(MAX_NAME_CHARS).collect();
+    let name = capped.trim_end();
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+#[derive(Component)]
+struct VersusHeader;
+
+fn spawn_versus_header(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Versus Header"),
+        VersusHeader,
+        Text::default(),
+        TextFont::from_font_size(22.0),
+        TextColor(Color::srgb(0.9, 0.9, 0.9)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(20),
+            width: percent(100),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        TextLayout::new_with_justify(Justify::Center),
+        Pickable::IGNORE,
+        DespawnOnExit(Screen::Gameplay),
+    ));
+}
+
+fn label_opponent(
+    players: Res<NetworkPlayers>,
+    mut header: Query<&mut Text, (Added<VersusHeader>, Without<OpponentNameLabel>)>,
+    mut labels: Query<&mut Text, Added<OpponentNameLabel>>,
+) {
+    let name = players.opponent_name();
+    for mut text in &mut header {
+        text.0 = format!("You vs {name}");
+    }
+    for mut text in &mut labels {
+        text.0 = format!("{name}: ");
+    }
+}
+
+fn save_player_name(name: Res<PlayerName>) {
+    name.save();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_control_characters_and_collapses_spaces() {
+        assert_eq!(
+            sanitize_name("  Ada\n\u{202e}  Love-lace\t"),
+            Some("Ada Love-lace".to_string())
+        );
+        assert_eq!(sanitize_name("せんせん"), Some("せんせん".to_string()));
+    }
+
+    #[test]
+    fn caps_the_length_and_rejects_empty_names() {
+        let long = sanitize_name("abcdefghijklmnopqrstuvwxyz").unwrap();
+        assert_eq!(long.chars().count(), MAX_NAME_CHARS);
+        assert_eq!(sanitize_name(" \n!!"), None);
+    }
+}
@@ -0,0 +1,264 @@
+//! Connection quality indicator for online matches.
+//!
+//! Rates the connection from the GGRS session every frame: round-trip time to
+//! the peer, how far the local simulation runs ahead, and the frames GGRS has
+//! recently asked us to skip to let the peer catch up. A dot in the corner
+//! shows the rating, and a banner covers the top of the screen while GGRS
+//! reports the connection as interrupted.
+
+use bevy::prelude::*;
+use bevy_ggrs::Session;
+use bevy_ggrs::ggrs::GgrsEvent;
+
+use super::SensenGgrsConfig;
+use crate::{game::is_online, screens::Screen};
+
+/// Round-trip times (ms) above which the connection rates fair and poor.
+const FAIR_PING_MS: u128 = 80;
+const POOR_PING_MS: u128 = 150;
+/// Frames ahead of the peer above which the connection rates fair and poor.
+const FAIR_FRAMES_AHEAD: i32 = 2;
+const POOR_FRAMES_AHEAD: i32 = 4;
+/// Recently skipped frames above which the connection rates poor.
+const POOR_SKIPPED_FRAMES: u32 = 6;
+/// Seconds a skip recommendation counts against the connection.
+const SKIP_WINDOW_SECS: f32 = 5.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<ConnectionQuality>();
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        (reset_connection_quality, spawn_quality_ui).run_if(is_online),
+    );
+    app.add_systems(
+        Update,
+        (read_ggrs_events, rate_connection, update_quality_ui)
+            .chain()
+            .run_if(is_online)
+            .run_if(in_state(Screen::Gameplay))
+            .run_if(resource_exists::<Session<SensenGgrsConfig>>),
+    );
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QualityLevel {
+    #[default]
+    Good,
+    Fair,
+    Poor,
+}
+
+impl QualityLevel {
+    fn color(self) -> Color {
+        match self {
+            Self::Good => Color::srgb(0.3, 0.85, 0.3),
+            Self::Fair => Color::srgb(0.95, 0.8, 0.2),
+            Self::Poor => Color::srgb(0.95, 0.3, 0.3),
+        }
+    }
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct ConnectionQuality {
+    pub level: QualityLevel,
+    /// Round-trip time to the peer, once GGRS has measured it.
+    pub ping_ms: Option<u128>,
+    pub frames_ahead: i32,
+    /// Real time and size of each recent skip recommendation.
+    skips: Vec<(f32, u32)>,
+    /// GGRS reported the peer as unreachable and hasn't reported it back.
+    pub interrupted: bool,
+}
+
+impl ConnectionQuality {
+    pub fn skipped_frames(&self) -> u32 {
+        self.skips.iter().map(|(_, frames)| frames).sum()
+    }
+
+    fn rate(&self) -> QualityLevel {
+        let ping = self.ping_ms.unwrap_or_default();
+        let ahead = self.frames_ahead.abs();
+        if self.interrupted
+            || ping > POOR_PING_MS
+            || ahead > POOR_FRAMES_AHEAD
+            || self.skipped_frames() > POOR_SKIPPED_FRAMES
+        {
+            QualityLevel::Poor
+        } else if ping > FAIR_PING_MS || ahead > FAIR_FRAMES_AHEAD || self.skipped_frames() > 0 {
+            QualityLevel::Fair
+        } else {
+            QualityLevel::Good
+        }
+    }
+}
+
+#[derive(Component)]
+struct QualityDot;
+
+#[derive(Component)]
+struct QualityText;
+
+#[derive(Component)]
+struct InterruptedBanner;
+
+fn reset_connection_quality(mut quality: ResMut<ConnectionQuality>) {
+    *quality = ConnectionQuality::default();
+}
+
+fn spawn_quality_ui(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Connection Quality"),
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(20),
+            right: px(20),
+            align_items: AlignItems::Center,
+            column_gap: px(6),
+            ..default()
+        },
+        Pickable::IGNORE,
+        DespawnOnExit(Screen::Gameplay),
+        children![
+            (
+                QualityDot,
+                Node {
+                    width: px(12),
+                    height: px(12),
+                    border_radius: BorderRadius::MAX,
+                    ..default()
+                },
+                BackgroundColor(QualityLevel::Good.color()),
+            ),
+            (
+                QualityText,
+                Text::new("-- ms"),
+                TextFont::from_font_size(16.0),
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            ),
+        ],
+    ));
+    commands.spawn((
+        Name::new("Connection Interrupted Banner"),
+        InterruptedBanner,
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(60),
+            width: percent(100),
+            justify_content: JustifyContent::Center,
+            padding: UiRect::vertical(px(8)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.5, 0.1, 0.1, 0.85)),
+        Visibility::Hidden,
+        GlobalZIndex(20),
+        Pickable::IGNORE,
+        DespawnOnExit(Screen::Gameplay),
+        children![(
+            Text::new("Connection interrupted - waiting for opponent..."),
+            TextFont::from_font_size(22.0),
+            TextColor(Color::WHITE),
+        )],
+    ));
+}
+
+/// Log GGRS events and track interruptions and skip recommendations.
+fn read_ggrs_events(
+    time: Res<Time<Real>>,
+    mut session: ResMut<Session<SensenGgrsConfig>>,
+    mut quality: ResMut<ConnectionQuality>,
+) {
+    let Session::P2P(session) = session.as_mut() else {
+        return;
+    };
+    let now = time.elapsed_secs();
+    for event in session.events() {
+        info!("GGRS Event: {:?}", event);
+        match event {
+            GgrsEvent::NetworkInterrupted { .. } => {
+                warn!("Connection to the opponent stalled");
+                quality.interrupted = true;
+            }
+            GgrsEvent::NetworkResumed { .. } => {
+                info!("Connection to the opponent resumed");
+                quality.interrupted = false;
+            }
+            GgrsEvent::WaitRecommendation { skip_frames } => {
+                quality.skips.push((now, skip_frames));
+            }
+            _ => {}
+        }
+    }
+    quality.skips.retain(|(at, _)| now - at <= SKIP_WINDOW_SECS);
+}
+
+fn rate_connection(
+    session: Res<Session<SensenGgrsConfig>>,
+    mut quality: ResMut<ConnectionQuality>,
+) {
+    let Session::P2P(session) = session.as_ref() else {
+        return;
+    };
+    quality.frames_ahead = session.frames_ahead();
+    quality.ping_ms = session
+        .remote_player_handles()
+        .into_iter()
+        .filter_map(|handle| session.network_stats(handle).ok())
+        .map(|stats| stats.ping)
+        .max();
+    quality.level = quality.rate();
+}
+
+fn update_quality_ui(
+    quality: Res<ConnectionQuality>,
+    mut dot: Query<&mut BackgroundColor, With<QualityDot>>,
+    mut text: Query<&mut Text, With<QualityText>>,
+    mut banner: Query<&mut Visibility, With<InterruptedBanner>>,
+) {
+    for mut color in &mut dot {
+        color.0 = quality.level.color();
+    }
+    for mut text in &mut text {
+        text.0 = quality
+            .ping_ms
+            .map_or_else(|| "-- ms".to_string(), |ping| format!("{ping} ms"));
+    }
+    for mut visibility in &mut banner {
+        *visibility = if quality.interrupted {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thresholds_pick_the_worst_signal() {
+        let mut quality = ConnectionQuality {
+            ping_ms: Some(40),
+            ..default()
+        };
+        assert_eq!(quality.rate(), QualityLevel::Good);
+        quality.frames_ahead = -3;
+        assert_eq!(quality.rate(), QualityLevel::Fair);
+        quality.ping_ms = Some(200);
+        assert_eq!(quality.rate(), QualityLevel::Poor);
+    }
+
+    #[test]
+    fn interruptions_and_skips_count_against_the_connection() {
+        let mut quality = ConnectionQuality {
+            skips: vec![(0.0, 2)],
+            ..default()
+        };
+        assert_eq!(quality.rate(), QualityLevel::Fair);
+        quality.skips.push((1.0, 5));
+        assert_eq!(quality.rate(), QualityLevel::Poor);
+        quality.skips.clear();
+        quality.interrupted = true;
+        assert_eq!(quality.rate(), QualityLevel::Poor);
+    }
+}
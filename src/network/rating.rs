@@ -0,0 +1,118 @@
+//! Local Elo rating used to pair players of similar skill.
+//!
+//! The rating is kept on this machine only. Peers swap ratings in the lobby
+//! handshake, and each side updates its own after an online match, so the
+//! two numbers move by the same amount in opposite directions.
+
+use bevy::prelude::*;
+
+use super::NetworkPlayers;
+use crate::{
+    game::{GameResult, is_online},
+    persist,
+};
+
+const SAVE_KEY: &str = "rating.txt";
+const INITIAL_RATING: u16 = 1000;
+const MIN_RATING: u16 = 100;
+const MAX_RATING: u16 = 3000;
+/// Largest change a single match can make.
+const K_FACTOR: f32 = 32.0;
+/// Rating points per matchmaking room.
+pub(super) const BUCKET_WIDTH: u16 = 200;
+
+pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(Rating::load());
+    app.add_systems(
+        OnEnter(GameResult::Victory),
+        record_victory.run_if(is_online),
+    );
+    app.add_systems(OnEnter(GameResult::Defeat), record_defeat.run_if(is_online));
+}
+
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rating(pub u16);
+
+impl Default for Rating {
+    fn default() -> Self {
+        Self(INITIAL_RATING)
+    }
+}
+
+impl Rating {
+    /// Matchmaking bucket: ratings `bucket * BUCKET_WIDTH` up to the next one.
+    pub fn bucket(self) -> u16 {
+        self.0 / BUCKET_WIDTH
+    }
+
+    /// Rating after a match against `opponent`.
+    pub fn after_match(self, opponent: Rating, won: bool) -> Rating {
+        let expected = 1.0 / (1.0 + 10f32.powf((opponent.0 as f32 - self.0 as f32) / 400.0));
+        let score = if won { 1.0 } else { 0.0 };
+        let change = (K_FACTOR * (score - expected)).round();
+        Rating((self.0 as f32 + change).clamp(MIN_RATING as f32, MAX_RATING as f32) as u16)
+    }
+
+    pub(crate) fn load() -> Self {
+        persist::load(SAVE_KEY)
+            .and_then(|contents| {
+                contents
+                    .lines()
+                    .filter_map(|line| line.split_once('='))
+                    .find_map(|(key, value)| (key == "rating").then(|| value.trim().parse().ok()))
+                    .flatten()
+            })
+            .map(|rating: u16| Rating(rating.clamp(MIN_RATING, MAX_RATING)))
+            .unwrap_or_default()
+    }
+
+    fn save(&self) {
+        persist::store(SAVE_KEY, &format!("rating={}", self.0));
+    }
+}
+
+fn record_victory(players: Option<Res<NetworkPlayers>>, rating: ResMut<Rating>) {
+    record_result(players.as_deref(), rating, true);
+}
+
+fn record_defeat(players: Option<Res<NetworkPlayers>>, rating: ResMut<Rating>) {
+    record_result(players.as_deref(), rating, false);
+}
+
+fn record_result(players: Option<&NetworkPlayers>, mut rating: ResMut<Rating>, won: bool) {
+    let Some(opponent) = players.and_then(NetworkPlayers::opponent_rating) else {
+        return;
+    };
+    let updated = rating.after_match(opponent, won);
+    info!("Rating {} -> {}", rating.0, updated.0);
+    *rating = updated;
+    rating.save();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn even_match_moves_half_the_k_factor() {
+        assert_eq!(Rating(1000).after_match(Rating(1000), true), Rating(1016));
+        assert_eq!(Rating(1000).after_match(Rating(1000), false), Rating(984));
+    }
+
+    #[test]
+    fn upsets_move_more_than_expected_results() {
+        let upset = Rating(1000).after_match(Rating(1400), true).0 - 1000;
+        let expected = Rating(1400).after_match(Rating(1000), true).0 - 1400;
+        assert!(upset > expected);
+    }
+
+    #[test]
+    fn rating_stays_in_range() {
+        assert_eq!(
+            Rating(MIN_RATING).after_match(Rating(MIN_RATING), false),
+            Rating(MIN_RATING)
+        );
+        assert_eq!(Rating(1199).bucket(), 5);
+        assert_eq!(Rating(1200).bucket(), 6);
+    }
+}
@@ -0,0 +1,185 @@
+//! Rollback registration for gameplay state.
+//!
+//! Every component the simulation changes during a match is snapshotted and
+//! restored by GGRS. The simulation keeps no state in resources between
+//! frames, apart from [`GameRng`], [`MatchStats`] and [`CombatLog`]: work is
+//! handed on through messages consumed within the frame, and everything that
+//! lasts lives on the player entities.
+//!
+//! In dev builds, player entities are checked for gameplay components that
+//! were left off the list below, since a missing one only shows up as a
+//! desync after a rollback.
+
+use std::any::TypeId;
+
+#[cfg(any(feature = "dev", test))]
+use bevy::ecs::component::{ComponentId, Components};
+use bevy::{ecs::component::Mutable, platform::collections::HashSet, prelude::*};
+use bevy_ggrs::prelude::*;
+
+use crate::game::{
+    Acceleration, BarricadeEffect, Block, BrutalityEffect, CombatLog, CombustEffect,
+    CorruptionEffect, Cost, CostCapBoost, DarkEmbraceEffect, Deck, DemonFormEffect, DiscardPile,
+    EvolveEffect, FeelNoPainEffect, FireBreathingEffect, FreeCards, GameRng, Hand, Health,
+    JuggernautEffect, MatchClock, MatchStats, MetallicizeEffect, PlayLog, PlayQueue, Poison,
+    RageEffect, RuptureEffect, Strength, Thorns, Vulnerable, Weak,
+};
+#[cfg(any(feature = "dev", test))]
+use crate::game::{LocalPlayer, Opponent, PlayerHandle};
+#[cfg(feature = "dev")]
+use crate::{game::is_online, screens::Screen};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<RollbackComponents>();
+
+    register::<Health>(app);
+    register::<Block>(app);
+    register::<Thorns>(app);
+    register::<Strength>(app);
+    register::<Vulnerable>(app);
+    register::<Weak>(app);
+    register::<Poison>(app);
+    register::<Cost>(app);
+    register::<Acceleration>(app);
+    register::<CostCapBoost>(app);
+    register::<RageEffect>(app);
+    register::<MetallicizeEffect>(app);
+    register::<DemonFormEffect>(app);
+    register::<BarricadeEffect>(app);
+    register::<JuggernautEffect>(app);
+    register::<CombustEffect>(app);
+    register::<DarkEmbraceEffect>(app);
+    register::<EvolveEffect>(app);
+    register::<FeelNoPainEffect>(app);
+    register::<FireBreathingEffect>(app);
+    register::<RuptureEffect>(app);
+    register::<CorruptionEffect>(app);
+    register::<BrutalityEffect>(app);
+    register::<Hand>(app);
+    register::<Deck>(app);
+    register::<DiscardPile>(app);
+    register::<PlayLog>(app);
+    register::<PlayQueue>(app);
+    register::<FreeCards>(app);
+    register::<MatchClock>(app);
+    app.rollback_resource_with_clone::<GameRng>();
+    app.rollback_resource_with_clone::<MatchStats>();
+    app.rollback_resource_with_clone::<CombatLog>();
+
+    #[cfg(feature = "dev")]
+    app.add_systems(
+        Update,
+        assert_rollback_coverage
+            .run_if(is_online)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// Component types registered for rollback.
+#[derive(Resource, Default)]
+struct RollbackComponents(HashSet<TypeId>);
+
+fn register<T: Component<Mutability = Mutable> + Clone>(app: &mut App) {
+    app.rollback_component_with_clone::<T>();
+    app.world_mut()
+        .resource_mut::<RollbackComponents>()
+        .0
+        .insert(TypeId::of::<T>());
+}
+
+/// Components from this module on a player entity must roll back.
+#[cfg(feature = "dev")]
+const GAMEPLAY_MODULE: &str = concat!(env!("CARGO_CRATE_NAME"), "::game::");
+
+/// Gameplay components that say who a player is and never change in a match.
+#[cfg(any(feature = "dev", test))]
+fn is_identity(type_id: TypeId) -> bool {
+    [
+        TypeId::of::<LocalPlayer>(),
+        TypeId::of::<Opponent>(),
+        TypeId::of::<PlayerHandle>(),
+    ]
+    .contains(&type_id)
+}
+
+/// Panic on any component from the `game` module found on a player entity
+/// but not registered for rollback.
+#[cfg(feature = "dev")]
+fn assert_rollback_coverage(
+    registered: Res<RollbackComponents>,
+    components: &Components,
+    players: Query<EntityRef, With<PlayerHandle>>,
+    mut checked: Local<HashSet<ComponentId>>,
+) {
+    let mut missing = Vec::new();
+    for player in &players {
+        let unchecked: Vec<ComponentId> = player
+            .archetype()
+            .components()
+            .iter()
+            .copied()
+            .filter(|id| checked.insert(*id))
+            .collect();
+        missing.extend(unregistered(
+            &unchecked,
+            components,
+            &registered,
+            GAMEPLAY_MODULE,
+        ));
+    }
+    assert!(
+        missing.is_empty(),
+        "gameplay components not registered for rollback: {}",
+        missing.join(", ")
+    );
+}
+
+/// Names of the components in `ids` from `module` that aren't registered for
+/// rollback. Goes by component name rather than the type registry, so
+/// components that don't derive `Reflect` are caught too.
+#[cfg(any(feature = "dev", test))]
+fn unregistered(
+    ids: &[ComponentId],
+    components: &Components,
+    registered: &RollbackComponents,
+    module: &str,
+) -> Vec<String> {
+    ids.iter()
+        .filter_map(|id| components.get_info(*id))
+        .filter(|info| {
+            !info
+                .type_id()
+                .is_some_and(|type_id| registered.0.contains(&type_id) || is_identity(type_id))
+        })
+        .map(|info| info.name().to_string())
+        .filter(|name| name.starts_with(module))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Left off the rollback list on purpose, and not `Reflect`.
+    #[derive(Component)]
+    struct Forgotten;
+
+    #[test]
+    fn unregistered_components_are_reported() {
+        let mut world = World::new();
+        let mut registered = RollbackComponents::default();
+        registered.0.insert(TypeId::of::<Health>());
+        let player = world
+            .spawn((PlayerHandle(0), Health::new(100.0), Forgotten))
+            .id();
+        let ids = world.entity(player).archetype().components().to_vec();
+
+        let missing = unregistered(
+            &ids,
+            world.components(),
+            &registered,
+            env!("CARGO_CRATE_NAME"),
+        );
+        assert_eq!(missing, vec![format!("{}::Forgotten", module_path!())]);
+    }
+}
@@ -0,0 +1,76 @@
+//! Starting the next game of a series over the running GGRS session.
+
+use bevy::prelude::*;
+use bevy_ggrs::{ConfirmedFrameCount, RollbackFrameCount, prelude::*};
+
+use super::SensenGgrsConfig;
+use crate::{
+    game::{GameResult, MatchFormat, MatchSeed, Series, is_online},
+    input::INPUT_NEXT_GAME,
+    screens::Screen,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<NextGameRequested>();
+    app.rollback_resource_with_clone::<NextGameRequested>();
+    app.add_systems(
+        GgrsSchedule,
+        note_next_game_request
+            .run_if(is_online)
+            .run_if(in_state(Screen::Gameplay))
+            .run_if(not(in_state(GameResult::Playing))),
+    );
+    app.add_systems(
+        Update,
+        start_next_game
+            .run_if(is_online)
+            .run_if(in_state(Screen::Gameplay))
+            .run_if(resource_exists::<ConfirmedFrameCount>),
+    );
+}
+
+/// Rollback frame on which every player was asking for the next game.
+///
+/// Rolled back with the rest of the match, so a request seen only in a
+/// predicted input disappears again if the prediction was wrong.
+#[derive(Resource, Debug, Clone, Default)]
+struct NextGameRequested(Option<i32>);
+
+fn note_next_game_request(
+    inputs: Res<PlayerInputs<SensenGgrsConfig>>,
+    frame: Res<RollbackFrameCount>,
+    mut requested: ResMut<NextGameRequested>,
+) {
+    if requested.0.is_some() {
+        return;
+    }
+    if inputs
+        .iter()
+        .all(|(input, _status)| input.flags & INPUT_NEXT_GAME != 0)
+    {
+        requested.0 = Some(frame.0);
+    }
+}
+
+/// Re-enter Gameplay with the next seed once the request frame is confirmed
+/// on both peers, so neither starts a game the other never asked for.
+fn start_next_game(
+    confirmed: Res<ConfirmedFrameCount>,
+    format: Res<MatchFormat>,
+    mut requested: ResMut<NextGameRequested>,
+    mut series: ResMut<Series>,
+    mut seed: ResMut<MatchSeed>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    let Some(frame) = requested.0 else {
+        return;
+    };
+    if i32::from(*confirmed) < frame || series.advancing || series.is_decided(*format) {
+        return;
+    }
+    info!("Starting game {} of the series", series.games_played() + 1);
+    requested.0 = None;
+    series.advancing = true;
+    seed.0 = series.next_seed();
+    next_screen.set(Screen::Gameplay);
+}
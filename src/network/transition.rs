@@ -0,0 +1,196 @@
+//! Mode-transition manager: every entry into Gameplay decides Solo vs Online
+//! from scratch, so switching modes within one app run never sees stale state.
+//!
+//! - Entering Gameplay with a live GGRS session is an Online match; anything
+//!   else is Solo and gets a clean, session-free world.
+//! - Leaving Gameplay (or returning to the title) tears the network down,
+//!   except when Gameplay is re-entered for the next game of a series.
+
+use bevy::prelude::*;
+use bevy_ggrs::prelude::*;
+use bevy_ggrs::{ConfirmedFrameCount, RollbackFrameCount};
+use bevy_matchbox::prelude::MatchboxSocket;
+
+use super::{IceRoute, LobbyHandshake, NetworkPlayers, RoomSearch, SensenGgrsConfig};
+use crate::{
+    game::{GameMode, MatchMutator, MatchSeed, MatchTimeLimit, MatchTimer, Series},
+    replay::ReplayPlayback,
+    sandbox::Sandbox,
+    screens::Screen,
+    tutorial::{TUTORIAL_SEED, Tutorial},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), enter_match_mode);
+    app.add_systems(OnExit(Screen::Gameplay), leave_match);
+    app.add_systems(OnEnter(Screen::Title), teardown_network_session);
+}
+
+/// Pick the mode for the match that is about to start.
+fn enter_match_mode(world: &mut World) {
+    if world.contains_resource::<Session<SensenGgrsConfig>>() {
+        // The lobby already set the shared seed, mutator and time limit.
+        world.insert_resource(GameMode::Online);
+        return;
+    }
+    teardown_network_session(world);
+    // A replay is dealt exactly as it was recorded.
+    if let Some(header) = world
+        .get_resource::<ReplayPlayback>()
+        .and_then(ReplayPlayback::header)
+        .cloned()
+    {
+        world.insert_resource(MatchSeed(header.seed));
+        world.insert_resource(MatchMutator(header.mutator));
+        world.insert_resource(MatchTimeLimit(MatchTimer {
+            minutes: header.time_limit,
+        }));
+        return;
+    }
+    // The tutorial replays the same hands every time.
+    let tutorial = world
+        .get_resource::<Tutorial>()
+        .is_some_and(|tutorial| tutorial.active);
+    world.insert_resource(if tutorial {
+        MatchSeed(TUTORIAL_SEED)
+    } else {
+        MatchSeed::default()
+    });
+    // Mutators are only voted on in the online lobby.
+    world.insert_resource(MatchMutator::default());
+    // Nobody should be hurried through the tutorial or the sandbox.
+    let sandbox = world
+        .get_resource::<Sandbox>()
+        .is_some_and(|sandbox| sandbox.active);
+    let timer = world.get_resource::<MatchTimer>().copied();
+    world.insert_resource(MatchTimeLimit(
+        timer
+            .filter(|_| !tutorial && !sandbox)
+            .unwrap_or(MatchTimer { minutes: 0 }),
+    ));
+}
+
+fn leave_match(world: &mut World) {
+    let next_game = world
+        .get_resource::<Series>()
+        .is_some_and(|series| series.advancing);
+    if !next_game {
+        teardown_network_session(world);
+    }
+}
+
+/// Drop every piece of network state and fall back to Offline.
+fn teardown_network_session(world: &mut World) {
+    world.remove_resource::<Session<SensenGgrsConfig>>();
+    world.remove_resource::<MatchboxSocket>();
+    world.remove_resource::<NetworkPlayers>();
+    world.remove_resource::<LobbyHandshake>();
+    world.remove_resource::<RoomSearch>();
+    world.remove_resource::<IceRoute>();
+    world.remove_resource::<ConfirmedFrameCount>();
+    if let Some(mut time) = world.get_resource_mut::<Time<GgrsTime>>() {
+        *time = Time::new_with(GgrsTime);
+    }
+    if let Some(mut frame) = world.get_resource_mut::<RollbackFrameCount>() {
+        frame.0 = 0;
+    }
+    world.insert_resource(GameMode::Offline);
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::state::app::StatesPlugin;
+    use bevy_ggrs::ggrs::SessionBuilder;
+
+    use super::*;
+    use crate::game::{Mutator, test_support::go_to};
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin));
+        app.init_state::<Screen>();
+        app.init_resource::<GameMode>();
+        app.init_resource::<MatchMutator>();
+        app.add_plugins(plugin);
+        app.update();
+        app
+    }
+
+    /// What the lobby leaves behind once both peers are connected.
+    fn connect_online(app: &mut App) {
+        let session = SessionBuilder::<SensenGgrsConfig>::new()
+            .with_num_players(2)
+            .start_synctest_session()
+            .expect("synctest session");
+        let world = app.world_mut();
+        world.insert_resource(Session::SyncTest(session));
+        world.insert_resource(LobbyHandshake::default());
+        world.insert_resource(MatchMutator(Some(Mutator::Overclock)));
+        world.insert_resource(GameMode::Online);
+    }
+
+    fn assert_offline(app: &App) {
+        let world = app.world();
+        assert_eq!(*world.resource::<GameMode>(), GameMode::Offline);
+        assert!(!world.contains_resource::<Session<SensenGgrsConfig>>());
+        assert!(!world.contains_resource::<LobbyHandshake>());
+        assert!(world.resource::<MatchMutator>().0.is_none());
+    }
+
+    #[test]
+    fn cycling_solo_and_online_never_leaks_state() {
+        let mut app = test_app();
+        go_to(&mut app, Screen::Title);
+
+        for _ in 0..3 {
+            // Solo
+            go_to(&mut app, Screen::Gameplay);
+            assert_offline(&app);
+            go_to(&mut app, Screen::Title);
+
+            // Online
+            go_to(&mut app, Screen::Lobby);
+            connect_online(&mut app);
+            go_to(&mut app, Screen::Gameplay);
+            assert_eq!(*app.world().resource::<GameMode>(), GameMode::Online);
+            assert!(app.world().contains_resource::<Session<SensenGgrsConfig>>());
+            go_to(&mut app, Screen::Title);
+            assert_eq!(*app.world().resource::<GameMode>(), GameMode::Offline);
+            assert!(!app.world().contains_resource::<Session<SensenGgrsConfig>>());
+        }
+
+        go_to(&mut app, Screen::Gameplay);
+        assert_offline(&app);
+    }
+
+    #[test]
+    fn next_series_game_keeps_the_session() {
+        let mut app = test_app();
+        go_to(&mut app, Screen::Lobby);
+        connect_online(&mut app);
+        go_to(&mut app, Screen::Gameplay);
+
+        app.world_mut().insert_resource(Series::default());
+        app.world_mut().resource_mut::<Series>().advancing = true;
+        go_to(&mut app, Screen::Gameplay);
+        assert_eq!(*app.world().resource::<GameMode>(), GameMode::Online);
+        assert!(app.world().contains_resource::<Session<SensenGgrsConfig>>());
+
+        app.world_mut().resource_mut::<Series>().advancing = false;
+        go_to(&mut app, Screen::Title);
+        assert_eq!(*app.world().resource::<GameMode>(), GameMode::Offline);
+    }
+
+    #[test]
+    fn solo_after_abandoned_lobby_is_offline() {
+        let mut app = test_app();
+        go_to(&mut app, Screen::Lobby);
+        // Stale flags from a lobby that never produced a session.
+        app.world_mut().insert_resource(GameMode::Online);
+        app.world_mut()
+            .insert_resource(MatchMutator(Some(Mutator::GlassCannon)));
+
+        go_to(&mut app, Screen::Gameplay);
+        assert_offline(&app);
+    }
+}
@@ -0,0 +1,175 @@
+//! Rollback session tuning: input delay, prediction window and disconnect
+//! timeout.
+//!
+//! [`NetworkTuning`] is loaded from disk at startup, edited in the settings
+//! menu, and applied when the lobby builds the GGRS session, so changes take
+//! effect from the next match. The `--input-delay=N`, `--prediction-window=N`
+//! and `--disconnect-timeout=SECS` command line flags override the saved
+//! values for the session.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_ggrs::ggrs::SessionBuilder;
+
+use super::SensenGgrsConfig;
+use crate::persist;
+
+const SAVE_KEY: &str = "network.txt";
+/// Options offered in the settings menu, in frames or seconds.
+const INPUT_DELAYS: [usize; 6] = [0, 1, 2, 3, 4, 6];
+const PREDICTION_WINDOWS: [usize; 5] = [4, 6, 8, 10, 12];
+const DISCONNECT_TIMEOUTS: [u64; 4] = [2, 5, 10, 30];
+
+pub(super) fn plugin(app: &mut App) {
+    let mut tuning = NetworkTuning::load();
+    tuning.apply_args(std::env::args());
+    app.insert_resource(tuning);
+    app.add_systems(
+        Update,
+        save_tuning
+            .run_if(resource_changed::<NetworkTuning>.and(not(resource_added::<NetworkTuning>))),
+    );
+}
+
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetworkTuning {
+    /// Frames local input is held back before it is simulated.
+    pub input_delay: usize,
+    /// Frames the simulation may run ahead of confirmed remote input.
+    pub prediction_window: usize,
+    /// Seconds without packets before the peer counts as disconnected.
+    pub disconnect_timeout_secs: u64,
+}
+
+impl Default for NetworkTuning {
+    fn default() -> Self {
+        Self {
+            input_delay: 2,
+            prediction_window: 8,
+            disconnect_timeout_secs: 2,
+        }
+    }
+}
+
+/// The option after `current` in `options`, wrapping around.
+fn next_option<T: Copy + PartialEq>(options: &[T], current: T) -> T {
+    let index = options.iter().position(|option| *option == current);
+    options[index.map_or(0, |index| (index + 1) % options.len())]
+}
+
+impl NetworkTuning {
+    pub fn next_input_delay(&self) -> usize {
+        next_option(&INPUT_DELAYS, self.input_delay)
+    }
+
+    pub fn next_prediction_window(&self) -> usize {
+        next_option(&PREDICTION_WINDOWS, self.prediction_window)
+    }
+
+    pub fn next_disconnect_timeout(&self) -> u64 {
+        next_option(&DISCONNECT_TIMEOUTS, self.disconnect_timeout_secs)
+    }
+
+    /// Apply the tuning to a session about to be started.
+    pub fn configure(
+        &self,
+        builder: SessionBuilder<SensenGgrsConfig>,
+    ) -> SessionBuilder<SensenGgrsConfig> {
+        builder
+            .with_input_delay(self.input_delay)
+            .with_max_prediction_window(self.prediction_window)
+            .with_disconnect_timeout(Duration::from_secs(self.disconnect_timeout_secs))
+    }
+
+    fn apply_args(&mut self, args: impl Iterator<Item = String>) {
+        for arg in args {
+            let Some((flag, value)) = arg.split_once('=') else {
+                continue;
+            };
+            match flag {
+                "--input-delay" => parse_into(value, &mut self.input_delay),
+                "--prediction-window" => parse_into(value, &mut self.prediction_window),
+                "--disconnect-timeout" => parse_into(value, &mut self.disconnect_timeout_secs),
+                _ => {}
+            }
+        }
+    }
+
+    fn load() -> Self {
+        let mut tuning = Self::default();
+        let Some(contents) = persist::load(SAVE_KEY) else {
+            return tuning;
+        };
+        for (key, value) in contents.lines().filter_map(|line| line.split_once('=')) {
+            match key {
+                "input_delay" => parse_into(value, &mut tuning.input_delay),
+                "prediction_window" => parse_into(value, &mut tuning.prediction_window),
+                "disconnect_timeout" => parse_into(value, &mut tuning.disconnect_timeout_secs),
+                _ => {}
+            }
+        }
+        tuning
+    }
+
+    fn save(&self) {
+        let lines = [
+            format!("input_delay={}", self.input_delay),
+            format!("prediction_window={}", self.prediction_window),
+            format!("disconnect_timeout={}", self.disconnect_timeout_secs),
+        ];
+        persist::store(SAVE_KEY, &lines.join("\n"));
+    }
+}
+
+/// Overwrite `target` with `value` if it parses; a bad value keeps the old one.
+fn parse_into<T: std::str::FromStr>(value: &str, target: &mut T) {
+    match value.trim().parse() {
+        Ok(parsed) => *target = parsed,
+        Err(_) => warn!("Ignoring invalid network setting {value:?}"),
+    }
+}
+
+fn save_tuning(tuning: Res<NetworkTuning>) {
+    tuning.save();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_line_overrides_settings() {
+        let mut tuning = NetworkTuning::default();
+        tuning.apply_args(
+            [
+                "sensen",
+                "--input-delay=0",
+                "--prediction-window=x",
+                "--disconnect-timeout=10",
+            ]
+            .into_iter()
+            .map(String::from),
+        );
+        assert_eq!(
+            tuning,
+            NetworkTuning {
+                input_delay: 0,
+                prediction_window: 8,
+                disconnect_timeout_secs: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn options_wrap_around() {
+        let mut tuning = NetworkTuning {
+            input_delay: 6,
+            ..default()
+        };
+        assert_eq!(tuning.next_input_delay(), 0);
+        // A value from the command line that isn't offered starts the cycle over.
+        tuning.prediction_window = 7;
+        assert_eq!(tuning.next_prediction_window(), PREDICTION_WINDOWS[0]);
+    }
+}
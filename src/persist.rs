@@ -0,0 +1,132 @@
+//! Small key-value save files for data that outlives a session.
+//!
+//! Native builds keep one text file per key in the `save/` directory. Web
+//! builds keep each key in the browser's `localStorage` under a `sensen/`
+//! prefix, so decks, settings and stats survive a page reload too.
+
+use bevy::prelude::*;
+
+#[cfg(not(target_family = "wasm"))]
+pub use backend::SAVE_DIR;
+
+/// A settings resource that writes itself to its own save key.
+pub trait Persisted: Resource {
+    fn save(&self);
+}
+
+/// Save `T` whenever it changes. Run it with `resource_changed::<T>`.
+pub fn save_on_change<T: Persisted>(value: Res<T>) {
+    // A freshly loaded value is already on disk.
+    if !value.is_added() {
+        value.save();
+    }
+}
+
+/// Read a saved value, if one exists.
+pub fn load(key: &str) -> Option<String> {
+    backend::read(key)
+}
+
+/// Write a value, replacing any previous save. Failures are logged, not fatal.
+pub fn store(key: &str, contents: &str) {
+    if let Err(error) = backend::write(key, contents) {
+        warn!("Failed to save {key}: {error}");
+    }
+}
+
+/// Append one line to a saved value, creating it if needed.
+pub fn append_line(key: &str, line: &str) {
+    if let Err(error) = backend::append_line(key, line) {
+        warn!("Failed to append to {key}: {error}");
+    }
+}
+
+/// Delete a saved value. Missing values are fine.
+pub fn remove(key: &str) {
+    if let Err(error) = backend::remove(key) {
+        warn!("Failed to remove {key}: {error}");
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+mod backend {
+    use std::{
+        fs,
+        io::{self, Write},
+        path::Path,
+    };
+
+    pub const SAVE_DIR: &str = "save";
+
+    pub fn read(key: &str) -> Option<String> {
+        fs::read_to_string(Path::new(SAVE_DIR).join(key)).ok()
+    }
+
+    pub fn write(key: &str, contents: &str) -> io::Result<()> {
+        let dir = Path::new(SAVE_DIR);
+        fs::create_dir_all(dir)?;
+        fs::write(dir.join(key), contents)
+    }
+
+    pub fn append_line(key: &str, line: &str) -> io::Result<()> {
+        let dir = Path::new(SAVE_DIR);
+        fs::create_dir_all(dir)?;
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(dir.join(key))?;
+        writeln!(file, "{line}")
+    }
+
+    pub fn remove(key: &str) -> io::Result<()> {
+        match fs::remove_file(Path::new(SAVE_DIR).join(key)) {
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod backend {
+    use web_sys::Storage;
+
+    /// Keeps our keys apart from anything else served from the same origin.
+    const KEY_PREFIX: &str = "sensen/";
+
+    fn storage() -> Result<Storage, String> {
+        web_sys::window()
+            .ok_or("no window")?
+            .local_storage()
+            .map_err(|error| format!("{error:?}"))?
+            .ok_or_else(|| "localStorage is unavailable".to_string())
+    }
+
+    pub fn read(key: &str) -> Option<String> {
+        storage()
+            .ok()?
+            .get_item(&format!("{KEY_PREFIX}{key}"))
+            .ok()
+            .flatten()
+    }
+
+    /// Fails when the browser blocks storage or the origin's quota is full.
+    pub fn write(key: &str, contents: &str) -> Result<(), String> {
+        storage()?
+            .set_item(&format!("{KEY_PREFIX}{key}"), contents)
+            .map_err(|error| format!("{error:?}"))
+    }
+
+    /// `localStorage` only holds whole strings, so the value is rewritten.
+    pub fn append_line(key: &str, line: &str) -> Result<(), String> {
+        let mut contents = read(key).unwrap_or_default();
+        contents.push_str(line);
+        contents.push('\n');
+        write(key, &contents)
+    }
+
+    pub fn remove(key: &str) -> Result<(), String> {
+        storage()?
+            .remove_item(&format!("{KEY_PREFIX}{key}"))
+            .map_err(|error| format!("{error:?}"))
+    }
+}
@@ -0,0 +1,509 @@
+//! Replays: a finished match saved to a file that can be shared and watched.
+//!
+//! A replay file is RON. Its [`ReplayHeader`] holds everything needed to deal
+//! the same match again (file format, game version, seed, rules, mutator,
+//! time limit, classes and both decks), followed by the input flags of both
+//! players for every simulation frame. Online, only frames GGRS has confirmed
+//! are kept, so the two players' files agree.
+//!
+//! After a match, Export Replay writes the file to the save directory. Passing
+//! `--replay <file>`, or dropping a file on the window at the title screen,
+//! plays one back: a Solo match dealt from the header, with inputs read from
+//! the file instead of the keyboard. Sandbox, tutorial and run fights aren't
+//! recorded, since things other than inputs change how they play out.
+
+use bevy::prelude::*;
+use bevy_ggrs::ConfirmedFrameCount;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    asset_tracking::ResourceHandles,
+    game::{
+        AddGameplaySystems, CardRegistry, CharacterClass, CorruptionEffect, Cost, DeathMessage,
+        Deck, DeckSnapshot, DrawCardsMessage, FreeCards, GameMode, GameResult, GameplaySystems,
+        Hand, LocalPlayer, MatchMutator, MatchSeed, MatchTimeLimit, Mutator, PendingInput,
+        PlayCardMessage, PlayQueue, PlayerHandle, Rules, apply_input_flags, is_offline,
+    },
+    network::NetworkPlayers,
+    persist,
+    run::RunState,
+    sandbox::Sandbox,
+    screens::Screen,
+    theme::widget,
+    tutorial::Tutorial,
+};
+
+/// Bumped whenever the file layout changes; files in another format are refused.
+pub const REPLAY_FORMAT: u32 = 1;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<ReplayRecorder>();
+    app.init_resource::<ReplayPlayback>();
+    #[cfg(not(target_family = "wasm"))]
+    if let Some(path) = replay_arg(std::env::args()) {
+        app.insert_resource(ReplayPlayback::from_file(&path));
+    }
+
+    app.add_systems(OnEnter(Screen::Title), start_replay.run_if(is_replay));
+    #[cfg(not(target_family = "wasm"))]
+    app.add_systems(Update, open_dropped_replay.run_if(in_state(Screen::Title)));
+
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        (reset_recorder, spawn_replay_banner.run_if(is_replay)),
+    );
+    app.add_systems(OnExit(Screen::Gameplay), leave_replay);
+    app.add_gameplay_systems(GameplaySystems::Tick, capture_replay_header);
+    app.add_systems(
+        FixedUpdate,
+        feed_replay_inputs
+            .in_set(GameplaySystems::Input)
+            .run_if(is_offline.and(is_replay))
+            .run_if(in_state(Screen::Gameplay))
+            .run_if(in_state(GameResult::Playing)),
+    );
+    app.add_systems(OnEnter(GameResult::Victory), spawn_export_button);
+    app.add_systems(OnEnter(GameResult::Defeat), spawn_export_button);
+}
+
+/// A whole match: how it was dealt, then every frame's inputs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Replay {
+    pub header: ReplayHeader,
+    /// Input flags per simulation frame, indexed by player handle.
+    pub inputs: Vec<[u16; 2]>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayHeader {
+    /// [`REPLAY_FORMAT`] of the file.
+    pub format: u32,
+    /// Game version that recorded it, for bug reports.
+    pub game_version: String,
+    pub seed: u64,
+    /// [`Rules::fingerprint`] of the balance numbers it was played with.
+    pub rules: u64,
+    pub mutator: Option<Mutator>,
+    /// Minutes before sudden death; 0 for no limit.
+    pub time_limit: u8,
+    /// Handle of the player who recorded it, shown as the local player.
+    pub local_handle: usize,
+    /// Per player handle.
+    pub classes: [CharacterClass; 2],
+    /// Both decks as dealt, before the opening hands, per player handle.
+    pub decks: [DeckSnapshot; 2],
+}
+
+impl Replay {
+    pub fn to_ron(&self) -> String {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .expect("replays always serialize")
+    }
+
+    pub fn from_ron(contents: &str) -> Result<Self, String> {
+        let replay: Self = ron::from_str(contents).map_err(|error| error.to_string())?;
+        let header = &replay.header;
+        if header.format != REPLAY_FORMAT {
+            return Err(format!(
+                "replay format {} isn't supported (expected {REPLAY_FORMAT})",
+                header.format
+            ));
+        }
+        if header.local_handle > 1 {
+            return Err(format!("invalid local handle {}", header.local_handle));
+        }
+        Ok(replay)
+    }
+}
+
+/// The match being recorded. Empty until both players are on the table, and
+/// for matches that can't be replayed.
+#[derive(Resource, Debug, Default)]
+pub struct ReplayRecorder {
+    header: Option<ReplayHeader>,
+    inputs: Vec<[u16; 2]>,
+    /// Online, the rollback frame of the first recorded input.
+    first_frame: Option<i32>,
+}
+
+impl ReplayRecorder {
+    /// Store one frame's inputs: the next frame offline, or rollback `frame`
+    /// online, replacing it and anything after it when a rollback
+    /// re-simulates it.
+    pub fn record(&mut self, frame: Option<i32>, inputs: [u16; 2]) {
+        if self.header.is_none() {
+            return;
+        }
+        if let Some(frame) = frame {
+            let first = *self.first_frame.get_or_insert(frame);
+            let Ok(index) = usize::try_from(frame - first) else {
+                return;
+            };
+            self.inputs.resize(index, [0; 2]);
+        }
+        self.inputs.push(inputs);
+    }
+
+    /// The replay so far, cut at `confirmed` online.
+    fn replay(&self, confirmed: Option<i32>) -> Option<Replay> {
+        let mut inputs = self.inputs.clone();
+        if let (Some(confirmed), Some(first)) = (confirmed, self.first_frame) {
+            inputs.truncate(usize::try_from(confirmed - first + 1).unwrap_or(0));
+        }
+        Some(Replay {
+            header: self.header.clone()?,
+            inputs,
+        })
+    }
+}
+
+/// The replay being watched (or about to start), and how far it has got.
+#[derive(Resource, Debug, Default)]
+pub struct ReplayPlayback {
+    replay: Option<Replay>,
+    frame: usize,
+}
+
+impl ReplayPlayback {
+    pub fn header(&self) -> Option<&ReplayHeader> {
+        self.replay.as_ref().map(|replay| &replay.header)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn from_file(path: &str) -> Self {
+        let replay = std::fs::read_to_string(path)
+            .map_err(|error| error.to_string())
+            .and_then(|contents| Replay::from_ron(&contents));
+        match replay {
+            Ok(replay) => Self {
+                replay: Some(replay),
+                frame: 0,
+            },
+            Err(error) => {
+                warn!("Couldn't load replay {path}: {error}");
+                Self::default()
+            }
+        }
+    }
+}
+
+pub fn is_replay(playback: Res<ReplayPlayback>) -> bool {
+    playback.replay.is_some()
+}
+
+/// The file after `--replay`, if given.
+#[cfg(not(target_family = "wasm"))]
+fn replay_arg(mut args: impl Iterator<Item = String>) -> Option<String> {
+    args.find(|arg| arg == "--replay")?;
+    args.next()
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn open_dropped_replay(
+    mut drops: MessageReader<FileDragAndDrop>,
+    mut playback: ResMut<ReplayPlayback>,
+    mut class: ResMut<CharacterClass>,
+    resource_handles: Res<ResourceHandles>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    for drop in drops.read() {
+        let FileDragAndDrop::DroppedFile { path_buf, .. } = drop else {
+            continue;
+        };
+        *playback = ReplayPlayback::from_file(&path_buf.to_string_lossy());
+        if playback.replay.is_some() {
+            enter_replay(&playback, &mut class, &resource_handles, &mut next_screen);
+            return;
+        }
+    }
+}
+
+fn start_replay(
+    playback: Res<ReplayPlayback>,
+    mut class: ResMut<CharacterClass>,
+    resource_handles: Res<ResourceHandles>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    enter_replay(&playback, &mut class, &resource_handles, &mut next_screen);
+}
+
+fn enter_replay(
+    playback: &ReplayPlayback,
+    class: &mut CharacterClass,
+    resource_handles: &ResourceHandles,
+    next_screen: &mut NextState<Screen>,
+) {
+    let Some(header) = playback.header() else {
+        return;
+    };
+    info!(
+        "Playing a replay recorded with version {} ({} frames)",
+        header.game_version,
+        playback
+            .replay
+            .as_ref()
+            .map_or(0, |replay| replay.inputs.len())
+    );
+    *class = header.classes[header.local_handle];
+    next_screen.set(if resource_handles.is_all_done() {
+        Screen::Gameplay
+    } else {
+        Screen::Loading
+    });
+}
+
+fn leave_replay(mut playback: ResMut<ReplayPlayback>) {
+    *playback = ReplayPlayback::default();
+}
+
+fn reset_recorder(mut recorder: ResMut<ReplayRecorder>) {
+    *recorder = ReplayRecorder::default();
+}
+
+/// Note how the match was dealt, once both players are on the table and
+/// before anything has been drawn.
+fn capture_replay_header(
+    mut recorder: ResMut<ReplayRecorder>,
+    players: Query<(&PlayerHandle, &Deck, Has<LocalPlayer>)>,
+    match_seed: Res<MatchSeed>,
+    match_mutator: Res<MatchMutator>,
+    time_limit: Res<MatchTimeLimit>,
+    rules: Res<Rules>,
+    local_class: Res<CharacterClass>,
+    network_players: Option<Res<NetworkPlayers>>,
+    game_mode: Res<GameMode>,
+    sandbox: Res<Sandbox>,
+    tutorial: Res<Tutorial>,
+    run: Res<RunState>,
+    playback: Res<ReplayPlayback>,
+) {
+    if recorder.header.is_some() || playback.replay.is_some() {
+        return;
+    }
+    let offline = *game_mode == GameMode::Offline;
+    if offline && (sandbox.active || tutorial.active || run.is_active()) {
+        return;
+    }
+    let mut local_handle = 0;
+    let mut decks = [None, None];
+    for (handle, deck, local) in &players {
+        if local {
+            local_handle = handle.0;
+        }
+        decks[handle.0] = Some(deck.snapshot());
+    }
+    let [Some(first), Some(second)] = decks else {
+        return;
+    };
+    let classes = match network_players.filter(|_| !offline) {
+        Some(players) => [players.class_for(0), players.class_for(1)],
+        None => [*local_class; 2],
+    };
+    recorder.header = Some(ReplayHeader {
+        format: REPLAY_FORMAT,
+        game_version: env!("CARGO_PKG_VERSION").to_string(),
+        seed: match_seed.0,
+        rules: rules.fingerprint(),
+        mutator: match_mutator.0,
+        time_limit: time_limit.0.minutes,
+        local_handle,
+        classes,
+        decks: [first, second],
+    });
+}
+
+/// Play the replay's inputs for both players in place of the keyboard.
+fn feed_replay_inputs(
+    mut playback: ResMut<ReplayPlayback>,
+    mut pending: ResMut<PendingInput>,
+    mut player_query: Query<(
+        Entity,
+        &Hand,
+        &mut PlayQueue,
+        &mut Cost,
+        &mut FreeCards,
+        &PlayerHandle,
+        Option<&CorruptionEffect>,
+    )>,
+    card_registry: Res<CardRegistry>,
+    rules: Res<Rules>,
+    mut play_messages: MessageWriter<PlayCardMessage>,
+    mut draw_messages: MessageWriter<DrawCardsMessage>,
+    mut death_messages: MessageWriter<DeathMessage>,
+) {
+    // Clicks and keys do nothing while watching.
+    pending.take_flags();
+    if player_query.iter().len() != 2 {
+        return;
+    }
+    let frame = playback.frame;
+    let inputs = playback
+        .replay
+        .as_ref()
+        .and_then(|replay| replay.inputs.get(frame).copied())
+        .unwrap_or_default();
+    playback.frame += 1;
+
+    for (player_entity, hand, mut queue, mut cost, mut free_cards, handle, corruption) in
+        &mut player_query
+    {
+        apply_input_flags(
+            inputs[handle.0],
+            player_entity,
+            hand,
+            &mut queue,
+            &mut cost,
+            &mut free_cards,
+            corruption.is_some(),
+            &card_registry,
+            &rules,
+            &mut draw_messages,
+            &mut play_messages,
+            &mut death_messages,
+        );
+    }
+}
+
+fn spawn_replay_banner(mut commands: Commands, playback: Res<ReplayPlayback>, rules: Res<Rules>) {
+    let Some(header) = playback.header() else {
+        return;
+    };
+    let mut text = format!("Replay (version {})", header.game_version);
+    if header.rules != rules.fingerprint() {
+        warn!("Replay was recorded with different balance numbers; it may play out differently");
+        text.push_str(" - different balance, may not match");
+    }
+    commands.spawn((
+        Name::new("Replay Banner"),
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(12),
+            width: percent(100),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        Pickable::IGNORE,
+        DespawnOnExit(Screen::Gameplay),
+        children![(
+            Text::new(text),
+            TextFont::from_font_size(24.0),
+            TextColor(Color::srgb(0.9, 0.85, 0.6)),
+        )],
+    ));
+}
+
+#[derive(Component)]
+struct ExportStatus;
+
+fn spawn_export_button(mut commands: Commands, recorder: Res<ReplayRecorder>) {
+    if recorder.header.is_none() {
+        return;
+    }
+    commands.spawn((
+        Name::new("Replay Export"),
+        Node {
+            position_type: PositionType::Absolute,
+            right: px(16),
+            bottom: px(16),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::End,
+            row_gap: px(8),
+            ..default()
+        },
+        // Above the result overlay.
+        GlobalZIndex(5),
+        DespawnOnExit(Screen::Gameplay),
+        children![
+            (widget::label(""), ExportStatus),
+            widget::button_medium("Export Replay", export_replay),
+        ],
+    ));
+}
+
+fn export_replay(
+    _: On<Pointer<Click>>,
+    recorder: Res<ReplayRecorder>,
+    game_mode: Res<GameMode>,
+    confirmed: Option<Res<ConfirmedFrameCount>>,
+    mut status: Query<&mut Text, With<ExportStatus>>,
+) {
+    let confirmed = confirmed
+        .filter(|_| *game_mode == GameMode::Online)
+        .map(|confirmed| i32::from(*confirmed));
+    let Some(replay) = recorder.replay(confirmed) else {
+        return;
+    };
+    let key = format!("replay-{:016x}.ron", replay.header.seed);
+    persist::store(&key, &replay.to_ron());
+    #[cfg(not(target_family = "wasm"))]
+    let key = format!("{}/{key}", persist::SAVE_DIR);
+    for mut text in &mut status {
+        text.0 = format!("Saved to {key}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::CardId;
+
+    fn header() -> ReplayHeader {
+        let deck = Deck::new_with_seed(vec![CardId::Strike, CardId::Defend], 7);
+        ReplayHeader {
+            format: REPLAY_FORMAT,
+            game_version: "0.0.0".to_string(),
+            seed: 42,
+            rules: Rules::default().fingerprint(),
+            mutator: Some(Mutator::Fortified),
+            time_limit: 3,
+            local_handle: 1,
+            classes: [CharacterClass::Ironclad, CharacterClass::Silent],
+            decks: [deck.snapshot(), Deck::default().snapshot()],
+        }
+    }
+
+    #[test]
+    fn replay_survives_the_file_format() {
+        let replay = Replay {
+            header: header(),
+            inputs: vec![[0, 1], [2, 0]],
+        };
+        assert_eq!(Replay::from_ron(&replay.to_ron()), Ok(replay.clone()));
+
+        let mut newer = replay;
+        newer.header.format += 1;
+        assert!(Replay::from_ron(&newer.to_ron()).is_err());
+        assert!(Replay::from_ron("not a replay").is_err());
+    }
+
+    #[test]
+    fn rollback_rewrites_frames_and_export_stops_at_confirmed() {
+        let mut recorder = ReplayRecorder::default();
+        recorder.record(Some(10), [1, 1]);
+        assert!(recorder.inputs.is_empty(), "nothing before the header");
+
+        recorder.header = Some(header());
+        for frame in 20..24 {
+            recorder.record(Some(frame), [frame as u16, 0]);
+        }
+        // Frame 22 comes back with the remote input corrected.
+        recorder.record(Some(22), [22, 5]);
+        assert_eq!(recorder.inputs, [[20, 0], [21, 0], [22, 5]]);
+
+        let replay = recorder.replay(Some(21)).unwrap();
+        assert_eq!(replay.inputs, [[20, 0], [21, 0]]);
+        assert_eq!(recorder.replay(None).unwrap().inputs.len(), 3);
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    #[test]
+    fn replay_arg_takes_the_next_argument() {
+        let args = |list: &[&str]| list.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            replay_arg(args(&["sensen", "--replay", "match.ron"]).into_iter()),
+            Some("match.ron".to_string())
+        );
+        assert_eq!(replay_arg(args(&["sensen", "--replay"]).into_iter()), None);
+        assert_eq!(replay_arg(args(&["sensen"]).into_iter()), None);
+    }
+}
@@ -0,0 +1,120 @@
+//! The run map screen: pick the next node, or a card reward after a fight.
+
+use bevy::prelude::*;
+
+use super::{RunOutcome, RunState};
+use crate::{
+    asset_tracking::ResourceHandles,
+    game::{CardRegistry, CharacterClass, MatchSeed, Rules},
+    screens::Screen,
+    theme::{font::Language, widget},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Map), (prepare_run, spawn_map).chain());
+}
+
+/// Start a run if none is going, and drop a fight that was quit mid-way.
+fn prepare_run(mut run: ResMut<RunState>, class: Res<CharacterClass>, rules: Res<Rules>) {
+    if !run.is_active() {
+        run.start(*class, MatchSeed::default().0, &rules);
+    }
+    run.fight = None;
+}
+
+fn spawn_map(
+    mut commands: Commands,
+    run: Res<RunState>,
+    registry: Res<CardRegistry>,
+    language: Res<Language>,
+) {
+    let root = commands
+        .spawn((
+            widget::ui_root("Run Map"),
+            GlobalZIndex(2),
+            DespawnOnExit(Screen::Map),
+        ))
+        .id();
+
+    commands.entity(root).with_children(|parent| {
+        parent.spawn(widget::header(format!(
+            "Floor {}/{}",
+            (run.floor() + 1).min(run.floor_count()),
+            run.floor_count()
+        )));
+        parent.spawn(widget::label(format!(
+            "HP: {:.0}/{:.0}   Deck: {} cards",
+            run.hp,
+            run.max_hp,
+            run.deck.len()
+        )));
+
+        if let Some(outcome) = run.outcome() {
+            parent.spawn(widget::header(match outcome {
+                RunOutcome::Won => "Run complete!",
+                RunOutcome::Lost => "Run over",
+            }));
+            parent.spawn(widget::button("Return to Title", finish_run));
+            return;
+        }
+
+        if let Some(reward) = run.reward() {
+            parent.spawn(widget::label("Choose a card to add to your deck"));
+            for (index, card) in reward.iter().enumerate() {
+                let Some(def) = registry.get(*card) else {
+                    continue;
+                };
+                parent.spawn(widget::button(
+                    format!("{} ({})", def.name, def.cost),
+                    pick_reward(Some(index)),
+                ));
+                parent.spawn(widget::label(def.description(*language)));
+            }
+            parent.spawn(widget::button("Skip", pick_reward(None)));
+            return;
+        }
+
+        for (index, kind) in run.choices().iter().enumerate() {
+            parent.spawn(widget::button(kind.name(), enter_node(index)));
+        }
+        parent.spawn(widget::button("Abandon Run", finish_run));
+    });
+}
+
+fn enter_node(
+    index: usize,
+) -> impl FnMut(
+    On<Pointer<Click>>,
+    ResMut<RunState>,
+    Res<CardRegistry>,
+    Res<ResourceHandles>,
+    ResMut<NextState<Screen>>,
+) {
+    move |_, mut run, registry, resource_handles, mut next_screen| {
+        let fight = run.enter(index, &registry);
+        next_screen.set(match fight {
+            true if resource_handles.is_all_done() => Screen::Gameplay,
+            true => Screen::Loading,
+            // Re-enter the map to show the next floor.
+            false => Screen::Map,
+        });
+    }
+}
+
+fn pick_reward(
+    index: Option<usize>,
+) -> impl FnMut(On<Pointer<Click>>, ResMut<RunState>, ResMut<NextState<Screen>>) {
+    move |_, mut run, mut next_screen| {
+        run.pick_reward(index);
+        next_screen.set(Screen::Map);
+    }
+}
+
+fn finish_run(
+    _: On<Pointer<Click>>,
+    mut run: ResMut<RunState>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    run.abandon();
+    next_screen.set(Screen::Title);
+}
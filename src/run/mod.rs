@@ -0,0 +1,230 @@
+//! Single-player run mode - a short roguelike climb through a node map.
+//!
+//! A run carries the deck and HP between fights. Each floor offers a few
+//! nodes to pick from; winning a fight grants a card reward, and beating the
+//! boss on the last floor completes the run.
+
+mod map;
+
+use bevy::prelude::*;
+
+use crate::game::{
+    CardId, CardRegistry, CharacterClass, Deck, GameResult, GameRng, Health, LocalPlayer,
+    RngStream, Rules, create_starter_deck, roll_card_reward,
+};
+
+/// Floors in a run, including the final boss floor.
+const RUN_FLOORS: usize = 8;
+/// Most nodes offered on a single floor.
+const MAX_NODES_PER_FLOOR: usize = 3;
+/// Fraction of max HP restored at a rest site.
+const REST_HEAL_FRACTION: f32 = 0.3;
+
+pub fn plugin(app: &mut App) {
+    app.init_resource::<RunState>();
+    app.add_systems(OnEnter(GameResult::Victory), win_fight);
+    app.add_systems(OnEnter(GameResult::Defeat), lose_fight);
+    app.add_plugins(map::plugin);
+}
+
+/// A stop on the run map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeKind {
+    Fight,
+    Elite,
+    Rest,
+    Reward,
+    Boss,
+}
+
+impl NodeKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            NodeKind::Fight => "Fight",
+            NodeKind::Elite => "Elite",
+            NodeKind::Rest => "Rest Site",
+            NodeKind::Reward => "Treasure",
+            NodeKind::Boss => "Boss",
+        }
+    }
+
+    /// Opponent HP and Thorns for a fight node.
+    pub fn enemy_stats(self, rules: &Rules) -> (f32, f32) {
+        match self {
+            NodeKind::Elite => (rules.initial_hp * 0.9, 5.0),
+            NodeKind::Boss => (rules.initial_hp * 1.5, 10.0),
+            _ => (rules.initial_hp * 0.6, 0.0),
+        }
+    }
+}
+
+/// How a finished run ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunOutcome {
+    Won,
+    Lost,
+}
+
+/// The run in progress, if any.
+#[derive(Resource, Default)]
+pub struct RunState {
+    active: bool,
+    class: CharacterClass,
+    pub deck: Vec<CardId>,
+    pub hp: f32,
+    pub max_hp: f32,
+    seed: u64,
+    floors: Vec<Vec<NodeKind>>,
+    floor: usize,
+    /// Fight node currently being played.
+    pub(super) fight: Option<NodeKind>,
+    /// Cards waiting to be picked from.
+    reward: Option<Vec<CardId>>,
+    outcome: Option<RunOutcome>,
+}
+
+impl RunState {
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Start a fresh run with the class's starter deck.
+    pub fn start(&mut self, class: CharacterClass, seed: u64, rules: &Rules) {
+        *self = Self {
+            active: true,
+            class,
+            deck: create_starter_deck(class),
+            hp: rules.initial_hp,
+            max_hp: rules.initial_hp,
+            seed,
+            floors: generate_map(seed),
+            ..default()
+        };
+    }
+
+    /// Drop the run entirely.
+    pub fn abandon(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn floor(&self) -> usize {
+        self.floor
+    }
+
+    pub fn floor_count(&self) -> usize {
+        self.floors.len()
+    }
+
+    /// Nodes to choose from on the current floor.
+    pub fn choices(&self) -> &[NodeKind] {
+        self.floors.get(self.floor).map_or(&[], Vec::as_slice)
+    }
+
+    /// Fight node about to be (or being) played.
+    pub fn current_fight(&self) -> Option<NodeKind> {
+        self.fight.filter(|_| self.active)
+    }
+
+    pub fn reward(&self) -> Option<&[CardId]> {
+        self.reward.as_deref()
+    }
+
+    pub fn outcome(&self) -> Option<RunOutcome> {
+        self.outcome
+    }
+
+    /// Enter a node on the current floor. Returns true if it starts a fight.
+    pub fn enter(&mut self, index: usize, registry: &CardRegistry) -> bool {
+        let Some(&kind) = self.choices().get(index) else {
+            return false;
+        };
+        match kind {
+            NodeKind::Rest => {
+                self.hp = (self.hp + self.max_hp * REST_HEAL_FRACTION).min(self.max_hp);
+                self.floor += 1;
+            }
+            NodeKind::Reward => {
+                self.reward = Some(self.roll_reward(registry));
+                self.floor += 1;
+            }
+            _ => {
+                self.fight = Some(kind);
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Take a card from the pending reward (or skip with `None`).
+    pub fn pick_reward(&mut self, index: Option<usize>) {
+        let Some(reward) = self.reward.take() else {
+            return;
+        };
+        if let Some(card) = index.and_then(|index| reward.get(index)) {
+            self.deck.push(*card);
+        }
+    }
+
+    /// Seeded by run and floor so a reload offers the same cards.
+    fn roll_reward(&self, registry: &CardRegistry) -> Vec<CardId> {
+        roll_card_reward(
+            registry,
+            self.class,
+            Deck::seed_for_handle(self.seed, self.floor),
+        )
+    }
+}
+
+/// Lay out the floors: a plain fight first, the boss last, and a mix of
+/// nodes in between.
+fn generate_map(seed: u64) -> Vec<Vec<NodeKind>> {
+    let mut rng = GameRng::new(seed);
+    (0..RUN_FLOORS)
+        .map(|floor| {
+            if floor == 0 {
+                return vec![NodeKind::Fight];
+            }
+            if floor == RUN_FLOORS - 1 {
+                return vec![NodeKind::Boss];
+            }
+            let count = 2 + rng.below(RngStream::RunMap, MAX_NODES_PER_FLOOR - 1);
+            (0..count)
+                .map(|_| match rng.below(RngStream::RunMap, 10) {
+                    0..=4 => NodeKind::Fight,
+                    5 | 6 => NodeKind::Elite,
+                    7 | 8 => NodeKind::Rest,
+                    _ => NodeKind::Reward,
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn win_fight(
+    mut run: ResMut<RunState>,
+    registry: Res<CardRegistry>,
+    player: Query<&Health, With<LocalPlayer>>,
+) {
+    let Some(kind) = run.current_fight() else {
+        return;
+    };
+    run.fight = None;
+    if let Ok(health) = player.single() {
+        run.hp = health.current.min(run.max_hp);
+    }
+    if kind == NodeKind::Boss {
+        run.outcome = Some(RunOutcome::Won);
+        return;
+    }
+    run.reward = Some(run.roll_reward(&registry));
+    run.floor += 1;
+}
+
+fn lose_fight(mut run: ResMut<RunState>) {
+    if run.current_fight().is_none() {
+        return;
+    }
+    run.fight = None;
+    run.hp = 0.0;
+    run.outcome = Some(RunOutcome::Lost);
+}
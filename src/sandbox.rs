@@ -0,0 +1,332 @@
+//! Practice sandbox: a Solo match with free access to every card.
+//!
+//! A side panel toggles infinite cost, opens a searchable card picker that
+//! adds any card straight to the hand, sets the opponent's HP and statuses,
+//! and resets the board. Sandbox matches don't count toward stats, history or
+//! card rewards.
+
+use bevy::{
+    input::{
+        ButtonState,
+        keyboard::{Key, KeyboardInput},
+    },
+    prelude::*,
+};
+
+use crate::{
+    AppSystems,
+    game::{
+        Block, CardId, CardRegistry, Cost, GameResult, GameplaySystems, Hand, Health, LocalPlayer,
+        MAX_HAND_SIZE, Opponent, Poison, Strength, Thorns, Vulnerable, Weak,
+    },
+    screens::Screen,
+    theme::widget,
+};
+
+/// Status duration (seconds) or stacks added per button press.
+const STATUS_STEP: f32 = 5.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<Sandbox>();
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        spawn_sandbox_panel.run_if(is_sandbox),
+    );
+    app.add_systems(OnExit(Screen::Gameplay), leave_sandbox);
+    app.add_systems(
+        FixedUpdate,
+        fill_cost
+            .before(GameplaySystems::Input)
+            .run_if(in_state(Screen::Gameplay).and(is_sandbox))
+            .run_if(|sandbox: Res<Sandbox>| sandbox.infinite_cost),
+    );
+    app.add_systems(
+        Update,
+        (
+            (type_search, rebuild_picker).chain().run_if(picker_open),
+            update_sandbox_status,
+        )
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay).and(is_sandbox)),
+    );
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct Sandbox {
+    /// The current (or next) Solo match is a sandbox match.
+    pub active: bool,
+    infinite_cost: bool,
+    picker_open: bool,
+    search: String,
+}
+
+pub fn is_sandbox(sandbox: Res<Sandbox>) -> bool {
+    sandbox.active
+}
+
+/// Whether the card picker is taking keyboard input.
+pub fn picker_open(sandbox: Res<Sandbox>) -> bool {
+    sandbox.active && sandbox.picker_open
+}
+
+fn leave_sandbox(mut sandbox: ResMut<Sandbox>) {
+    *sandbox = Sandbox::default();
+}
+
+#[derive(Component)]
+struct SandboxStatus;
+
+#[derive(Component)]
+struct CardPicker;
+
+#[derive(Component)]
+struct CardPickerList;
+
+#[derive(Component)]
+struct CardPickerSearch;
+
+fn spawn_sandbox_panel(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Sandbox Panel"),
+        Node {
+            position_type: PositionType::Absolute,
+            left: px(10),
+            top: px(120),
+            flex_direction: FlexDirection::Column,
+            row_gap: px(6),
+            padding: UiRect::all(px(10)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 0.8)),
+        GlobalZIndex(10),
+        DespawnOnExit(Screen::Gameplay),
+        children![
+            widget::label("Sandbox"),
+            (widget::label(""), SandboxStatus),
+            widget::button_medium("Infinite Cost", toggle_infinite_cost),
+            widget::button_medium("Add Card...", toggle_picker),
+            widget::button_medium("Opponent HP: 1", set_opponent_hp(0.0)),
+            widget::button_medium("Opponent HP: Half", set_opponent_hp(0.5)),
+            widget::button_medium("Opponent HP: Full", set_opponent_hp(1.0)),
+            widget::button_medium("+ Vulnerable", add_vulnerable),
+            widget::button_medium("+ Weak", add_weak),
+            widget::button_medium("+ Poison", add_poison),
+            widget::button_medium("+ Strength", add_strength),
+            widget::button_medium("Clear Statuses", clear_opponent_statuses),
+            widget::button_medium("Reset", reset_board),
+        ],
+    ));
+    commands.spawn((
+        Name::new("Card Picker"),
+        CardPicker,
+        Node {
+            position_type: PositionType::Absolute,
+            left: percent(30),
+            top: px(120),
+            width: percent(40),
+            max_height: percent(60),
+            flex_direction: FlexDirection::Column,
+            row_gap: px(8),
+            padding: UiRect::all(px(12)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 0.95)),
+        GlobalZIndex(11),
+        Visibility::Hidden,
+        DespawnOnExit(Screen::Gameplay),
+        children![
+            (widget::label(""), CardPickerSearch),
+            widget::scroll_list(
+                Node {
+                    min_height: px(0),
+                    ..default()
+                },
+                px(4),
+                CardPickerList,
+            ),
+        ],
+    ));
+}
+
+fn toggle_infinite_cost(_: On<Pointer<Click>>, mut sandbox: ResMut<Sandbox>) {
+    sandbox.infinite_cost = !sandbox.infinite_cost;
+}
+
+fn toggle_picker(
+    _: On<Pointer<Click>>,
+    mut sandbox: ResMut<Sandbox>,
+    mut picker: Query<&mut Visibility, With<CardPicker>>,
+) {
+    sandbox.picker_open = !sandbox.picker_open;
+    for mut visibility in &mut picker {
+        *visibility = if sandbox.picker_open {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+fn fill_cost(mut player: Query<&mut Cost, With<LocalPlayer>>) {
+    for mut cost in &mut player {
+        cost.current = cost.max;
+    }
+}
+
+fn update_sandbox_status(sandbox: Res<Sandbox>, mut status: Query<&mut Text, With<SandboxStatus>>) {
+    if !sandbox.is_changed() {
+        return;
+    }
+    for mut text in &mut status {
+        text.0 = format!(
+            "Infinite cost: {}",
+            if sandbox.infinite_cost { "On" } else { "Off" }
+        );
+    }
+}
+
+/// Type into the picker's search box while it is open.
+fn type_search(mut keys: MessageReader<KeyboardInput>, mut sandbox: ResMut<Sandbox>) {
+    for key in keys.read() {
+        if key.state != ButtonState::Pressed {
+            continue;
+        }
+        match &key.logical_key {
+            Key::Character(text) => sandbox.search.push_str(text),
+            Key::Space => sandbox.search.push(' '),
+            Key::Backspace => {
+                sandbox.search.pop();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Refill the picker with the cards matching the search.
+fn rebuild_picker(
+    mut commands: Commands,
+    sandbox: Res<Sandbox>,
+    registry: Res<CardRegistry>,
+    mut search_label: Query<&mut Text, With<CardPickerSearch>>,
+    list: Query<Entity, With<CardPickerList>>,
+    mut shown_search: Local<Option<String>>,
+) {
+    if shown_search.as_deref() == Some(sandbox.search.as_str()) {
+        return;
+    }
+    *shown_search = Some(sandbox.search.clone());
+    for mut text in &mut search_label {
+        text.0 = format!("Search: {}_", sandbox.search);
+    }
+
+    let search = sandbox.search.to_lowercase();
+    for list in &list {
+        commands.entity(list).despawn_children();
+        commands.entity(list).with_children(|list| {
+            for def in registry
+                .iter()
+                .filter(|def| def.name.to_lowercase().contains(&search))
+            {
+                list.spawn(widget::button_medium(
+                    format!("{} ({:.0})", def.name, def.cost),
+                    add_card(def.id),
+                ));
+            }
+        });
+    }
+}
+
+fn add_card(card: CardId) -> impl FnMut(On<Pointer<Click>>, Query<&mut Hand, With<LocalPlayer>>) {
+    move |_, mut player| {
+        for mut hand in &mut player {
+            if hand.len() < MAX_HAND_SIZE {
+                hand.add_card(card);
+            }
+        }
+    }
+}
+
+/// Set the opponent's HP to a fraction of its max, leaving at least 1 HP.
+fn set_opponent_hp(
+    fraction: f32,
+) -> impl FnMut(On<Pointer<Click>>, Query<&mut Health, With<Opponent>>) {
+    move |_, mut opponent| {
+        for mut health in &mut opponent {
+            health.current = (health.max * fraction).max(1.0);
+        }
+    }
+}
+
+fn add_vulnerable(_: On<Pointer<Click>>, mut opponent: Query<&mut Vulnerable, With<Opponent>>) {
+    for mut vulnerable in &mut opponent {
+        vulnerable.apply(STATUS_STEP);
+    }
+}
+
+fn add_weak(_: On<Pointer<Click>>, mut opponent: Query<&mut Weak, With<Opponent>>) {
+    for mut weak in &mut opponent {
+        weak.apply(STATUS_STEP);
+    }
+}
+
+fn add_poison(_: On<Pointer<Click>>, mut opponent: Query<&mut Poison, With<Opponent>>) {
+    for mut poison in &mut opponent {
+        poison.apply(STATUS_STEP);
+    }
+}
+
+fn add_strength(_: On<Pointer<Click>>, mut opponent: Query<&mut Strength, With<Opponent>>) {
+    for mut strength in &mut opponent {
+        strength.gain(1.0);
+    }
+}
+
+fn clear_opponent_statuses(
+    _: On<Pointer<Click>>,
+    mut opponent: Query<(&mut Vulnerable, &mut Weak, &mut Poison, &mut Strength), With<Opponent>>,
+) {
+    for (mut vulnerable, mut weak, mut poison, mut strength) in &mut opponent {
+        *vulnerable = Vulnerable::default();
+        *weak = Weak::default();
+        *poison = Poison::default();
+        *strength = Strength::default();
+    }
+}
+
+/// Restore both sides' HP, block, thorns, cost and statuses, and resume a finished match.
+fn reset_board(
+    _: On<Pointer<Click>>,
+    mut players: Query<(
+        &mut Health,
+        &mut Block,
+        &mut Thorns,
+        &mut Cost,
+        &mut Vulnerable,
+        &mut Weak,
+        &mut Poison,
+        &mut Strength,
+    )>,
+    mut next_result: ResMut<NextState<GameResult>>,
+) {
+    for (
+        mut health,
+        mut block,
+        mut thorns,
+        mut cost,
+        mut vulnerable,
+        mut weak,
+        mut poison,
+        mut strength,
+    ) in &mut players
+    {
+        health.current = health.max;
+        block.current = 0.0;
+        *thorns = Thorns::default();
+        cost.current = 0.0;
+        *vulnerable = Vulnerable::default();
+        *weak = Weak::default();
+        *poison = Poison::default();
+        *strength = Strength::default();
+    }
+    next_result.set(GameResult::Playing);
+}
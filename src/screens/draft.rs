@@ -0,0 +1,187 @@
+//! The draft screen: take turns picking cards from shared packs, then play
+//! the match with the drafted decks.
+//!
+//! Solo, the opponent takes the rarest card after a short pause. Online, a
+//! pick goes out as a card input flag through GGRS and the network module
+//! applies it, so both peers see the same picks in the same order.
+
+use bevy::prelude::*;
+
+use crate::{
+    asset_tracking::ResourceHandles,
+    game::{
+        CardRegistry, CharacterClass, DRAFT_DECK_SIZE, Draft, GameMode, MatchSeed, PendingInput,
+        is_offline,
+    },
+    input::card_flag,
+    network::NetworkPlayers,
+    screens::Screen,
+    theme::{font::Language, widget},
+};
+
+/// Seconds the Solo opponent takes over each pick.
+const OPPONENT_PICK_SECS: f32 = 0.6;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        OnEnter(Screen::Draft),
+        (start_draft, spawn_draft_screen).chain(),
+    );
+    app.add_systems(
+        Update,
+        (
+            (opponent_picks, finish_solo_draft).run_if(is_offline),
+            refresh_draft_panel.run_if(resource_changed::<Draft>),
+        )
+            .chain()
+            .run_if(in_state(Screen::Draft)),
+    );
+}
+
+/// Handle the local player drafts for.
+#[derive(Resource)]
+struct DraftSeat(usize);
+
+#[derive(Component)]
+struct DraftPanel;
+
+/// Online the lobby has already agreed on the seed and both classes; Solo
+/// drafts from the local class's pool against a mirror opponent.
+fn start_draft(
+    mut commands: Commands,
+    mode: Res<GameMode>,
+    players: Option<Res<NetworkPlayers>>,
+    class: Res<CharacterClass>,
+    seed: Res<MatchSeed>,
+    registry: Res<CardRegistry>,
+) {
+    let online = players.filter(|_| *mode == GameMode::Online);
+    let (classes, seed, seat) = match online {
+        Some(players) => (
+            players.classes.clone(),
+            seed.0,
+            players.local_handle().unwrap_or_default(),
+        ),
+        None => (vec![*class], MatchSeed::default().0, 0),
+    };
+    commands.insert_resource(Draft::new(&registry, &classes, seed));
+    commands.insert_resource(DraftSeat(seat));
+}
+
+fn spawn_draft_screen(mut commands: Commands) {
+    commands.spawn((
+        widget::ui_root("Draft Screen"),
+        GlobalZIndex(2),
+        DespawnOnExit(Screen::Draft),
+        children![
+            widget::header("Draft"),
+            (
+                Name::new("Draft Panel"),
+                DraftPanel,
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: px(10),
+                    ..default()
+                },
+            ),
+            widget::button("Leave Draft", leave_draft),
+        ],
+    ));
+}
+
+fn leave_draft(_: On<Pointer<Click>>, mut next_screen: ResMut<NextState<Screen>>) {
+    next_screen.set(Screen::Title);
+}
+
+fn refresh_draft_panel(
+    mut commands: Commands,
+    draft: Res<Draft>,
+    seat: Res<DraftSeat>,
+    registry: Res<CardRegistry>,
+    language: Res<Language>,
+    panel: Query<Entity, With<DraftPanel>>,
+) {
+    let Ok(panel) = panel.single() else {
+        return;
+    };
+    commands.entity(panel).despawn_children();
+    commands.entity(panel).with_children(|parent| {
+        parent.spawn(widget::label(format!(
+            "You {}/{DRAFT_DECK_SIZE}   Opponent {}/{DRAFT_DECK_SIZE}",
+            draft.picks[seat.0].len(),
+            draft.picks[1 - seat.0].len(),
+        )));
+        if draft.is_complete() {
+            parent.spawn(widget::label("Draft complete. Starting the match..."));
+            return;
+        }
+        let our_pick = draft.picker == seat.0;
+        parent.spawn(widget::label(if our_pick {
+            "Your pick"
+        } else {
+            "Opponent is picking..."
+        }));
+        for (slot, card) in draft.pack.iter().enumerate() {
+            let Some(def) = registry.get(*card) else {
+                continue;
+            };
+            let name = format!("{} ({})", def.name, def.cost);
+            if our_pick {
+                parent.spawn(widget::button(name, pick_card(slot)));
+            } else {
+                parent.spawn(widget::label(name));
+            }
+            parent.spawn(widget::label(def.description(*language)));
+        }
+    });
+}
+
+fn pick_card(
+    slot: usize,
+) -> impl FnMut(On<Pointer<Click>>, Res<GameMode>, Res<DraftSeat>, ResMut<Draft>, ResMut<PendingInput>)
+{
+    move |_, mode, seat, mut draft, mut pending_input| {
+        if *mode == GameMode::Online {
+            if let Some(flag) = card_flag(slot) {
+                pending_input.push_flags(flag);
+            }
+        } else {
+            draft.pick(seat.0, slot);
+        }
+    }
+}
+
+fn opponent_picks(
+    time: Res<Time>,
+    registry: Res<CardRegistry>,
+    seat: Res<DraftSeat>,
+    mut draft: ResMut<Draft>,
+    mut waited: Local<f32>,
+) {
+    if draft.is_complete() || draft.picker == seat.0 {
+        *waited = 0.0;
+        return;
+    }
+    *waited += time.delta_secs();
+    if *waited < OPPONENT_PICK_SECS {
+        return;
+    }
+    *waited = 0.0;
+    let (picker, slot) = (draft.picker, draft.preferred_slot(&registry));
+    draft.pick(picker, slot);
+}
+
+fn finish_solo_draft(
+    draft: Res<Draft>,
+    resource_handles: Res<ResourceHandles>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    if draft.is_complete() {
+        next_screen.set(if resource_handles.is_all_done() {
+            Screen::Gameplay
+        } else {
+            Screen::Loading
+        });
+    }
+}
@@ -2,18 +2,34 @@
 
 use bevy::{input::common_conditions::input_just_pressed, prelude::*};
 
-use crate::{Pause, menus::Menu, screens::Screen};
+use crate::{
+    Pause,
+    game::{is_inspecting, is_offline},
+    menus::Menu,
+    screens::Screen,
+};
 
 pub(super) fn plugin(app: &mut App) {
     // Toggle pause on key press.
     app.add_systems(
         Update,
         (
-            (pause, spawn_pause_overlay, open_pause_menu).run_if(
-                in_state(Screen::Gameplay)
-                    .and(in_state(Menu::None))
-                    .and(input_just_pressed(KeyCode::KeyP).or(input_just_pressed(KeyCode::Escape))),
-            ),
+            (
+                pause,
+                freeze_time.run_if(is_offline),
+                spawn_pause_overlay,
+                open_pause_menu,
+            )
+                .run_if(
+                    in_state(Screen::Gameplay)
+                        .and(in_state(Menu::None))
+                        // Escape closes an inspected card first.
+                        .and(not(is_inspecting))
+                        .and(
+                            input_just_pressed(KeyCode::KeyP)
+                                .or(input_just_pressed(KeyCode::Escape)),
+                        ),
+                ),
             close_menu.run_if(
                 in_state(Screen::Gameplay)
                     .and(not(in_state(Menu::None)))
@@ -28,14 +44,21 @@ pub(super) fn plugin(app: &mut App) {
     );
 }
 
-fn unpause(mut next_pause: ResMut<NextState<Pause>>) {
+fn unpause(mut next_pause: ResMut<NextState<Pause>>, mut time: ResMut<Time<Virtual>>) {
     next_pause.set(Pause(false));
+    time.unpause();
 }
 
 fn pause(mut next_pause: ResMut<NextState<Pause>>) {
     next_pause.set(Pause(true));
 }
 
+/// Offline matches stop the clock while paused. Online matches keep running:
+/// the opponent can't be paused, so the overlay is only a menu there.
+fn freeze_time(mut time: ResMut<Time<Virtual>>) {
+    time.pause();
+}
+
 fn spawn_pause_overlay(mut commands: Commands) {
     commands.spawn((
         Name::new("Pause Overlay"),
@@ -1,9 +1,16 @@
 //! A loading screen during which game assets are loaded if necessary.
 //! This reduces stuttering, especially for audio on Wasm.
+//!
+//! Entering it starts loading the gameplay [`AssetGroup`]; the match starts
+//! once that group and everything loaded at startup are ready.
 
 use bevy::prelude::*;
 
-use crate::{asset_tracking::ResourceHandles, screens::Screen, theme::prelude::*};
+use crate::{
+    asset_tracking::{AssetGroup, ResourceHandles},
+    screens::Screen,
+    theme::prelude::*,
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Screen::Loading), spawn_loading_screen);
@@ -27,5 +34,5 @@ fn enter_gameplay_screen(mut next_screen: ResMut<NextState<Screen>>) {
 }
 
 fn all_assets_loaded(resource_handles: Res<ResourceHandles>) -> bool {
-    resource_handles.is_all_done()
+    resource_handles.is_all_done() && resource_handles.is_group_done(AssetGroup::Gameplay)
 }
@@ -1,5 +1,6 @@
 //! The game's main screen states and transitions between them.
 
+mod draft;
 mod gameplay;
 mod loading;
 mod splash;
@@ -12,6 +13,7 @@ pub(super) fn plugin(app: &mut App) {
     app.register_type::<Screen>();
 
     app.add_plugins((
+        draft::plugin,
         gameplay::plugin,
         loading::plugin,
         splash::plugin,
@@ -44,7 +46,9 @@ pub enum Screen {
     Title,
     Loading,
     Lobby,
+    Draft,
     Gameplay,
+    Map,
 }
 
 /// Resource to trigger screen transition via BRP.
@@ -2,15 +2,43 @@
 
 use bevy::prelude::*;
 
-use crate::{menus::Menu, screens::Screen};
+use crate::{
+    asset_tracking::ResourceHandles,
+    game::CharacterClass,
+    menus::{Menu, Tournament},
+    screens::Screen,
+    tutorial::{Tutorial, start_tutorial},
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Screen::Title), open_main_menu);
     app.add_systems(OnExit(Screen::Title), close_menu);
 }
 
-fn open_main_menu(mut next_menu: ResMut<NextState<Menu>>) {
-    next_menu.set(Menu::Main);
+fn open_main_menu(
+    tournament: Res<Tournament>,
+    mut tutorial: ResMut<Tutorial>,
+    mut class: ResMut<CharacterClass>,
+    resource_handles: Res<ResourceHandles>,
+    mut next_menu: ResMut<NextState<Menu>>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    // First launch goes straight into the tutorial.
+    if !tutorial.completed {
+        start_tutorial(
+            &mut tutorial,
+            &mut class,
+            &resource_handles,
+            &mut next_screen,
+        );
+        return;
+    }
+    // Come back to the bracket after a tournament match.
+    next_menu.set(if tournament.is_active() {
+        Menu::Bracket
+    } else {
+        Menu::Main
+    });
 }
 
 fn close_menu(mut next_menu: ResMut<NextState<Menu>>) {
@@ -0,0 +1,178 @@
+//! Player profile statistics, kept across sessions.
+//!
+//! Saved as `key=value` lines through [`persist`]; favorite cards are stored
+//! as `card=<name>:<plays>`.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::{
+    game::{CardRegistry, GameMode, GameResult, Health, LocalPlayer, Opponent, PlayLog},
+    persist,
+    replay::is_replay,
+    sandbox::is_sandbox,
+};
+
+const SAVE_KEY: &str = "profile.txt";
+
+pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(ProfileStats::load());
+    app.add_systems(
+        OnEnter(GameResult::Victory),
+        record_victory.run_if(not(is_sandbox.or(is_replay))),
+    );
+    app.add_systems(
+        OnEnter(GameResult::Defeat),
+        record_defeat.run_if(not(is_sandbox.or(is_replay))),
+    );
+}
+
+/// Wins and losses for one game mode.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Record {
+    pub wins: u32,
+    pub losses: u32,
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct ProfileStats {
+    pub solo: Record,
+    pub online: Record,
+    /// HP taken off opponents, summed over every finished match.
+    pub damage_dealt: f32,
+    pub current_streak: u32,
+    pub longest_streak: u32,
+    /// Plays per card name.
+    card_plays: HashMap<String, u32>,
+}
+
+impl ProfileStats {
+    pub fn matches_played(&self) -> u32 {
+        self.solo.wins + self.solo.losses + self.online.wins + self.online.losses
+    }
+
+    /// Most played cards, most first.
+    pub fn favorite_cards(&self, count: usize) -> Vec<(&str, u32)> {
+        let mut cards: Vec<(&str, u32)> = self
+            .card_plays
+            .iter()
+            .map(|(name, plays)| (name.as_str(), *plays))
+            .collect();
+        cards.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        cards.truncate(count);
+        cards
+    }
+
+    pub(crate) fn load() -> Self {
+        let mut stats = Self::default();
+        let Some(contents) = persist::load(SAVE_KEY) else {
+            return stats;
+        };
+        for (key, value) in contents.lines().filter_map(|line| line.split_once('=')) {
+            let number = || value.parse().unwrap_or_default();
+            match key {
+                "solo_wins" => stats.solo.wins = number(),
+                "solo_losses" => stats.solo.losses = number(),
+                "online_wins" => stats.online.wins = number(),
+                "online_losses" => stats.online.losses = number(),
+                "damage_dealt" => stats.damage_dealt = value.parse().unwrap_or_default(),
+                "current_streak" => stats.current_streak = number(),
+                "longest_streak" => stats.longest_streak = number(),
+                "card" => {
+                    if let Some((name, plays)) = value.rsplit_once(':') {
+                        stats
+                            .card_plays
+                            .insert(name.to_string(), plays.parse().unwrap_or_default());
+                    }
+                }
+                _ => {}
+            }
+        }
+        stats
+    }
+
+    fn save(&self) {
+        let mut lines = vec![
+            format!("solo_wins={}", self.solo.wins),
+            format!("solo_losses={}", self.solo.losses),
+            format!("online_wins={}", self.online.wins),
+            format!("online_losses={}", self.online.losses),
+            format!("damage_dealt={}", self.damage_dealt),
+            format!("current_streak={}", self.current_streak),
+            format!("longest_streak={}", self.longest_streak),
+        ];
+        lines.extend(
+            self.card_plays
+                .iter()
+                .map(|(name, plays)| format!("card={name}:{plays}")),
+        );
+        persist::store(SAVE_KEY, &lines.join("\n"));
+    }
+
+    fn record(&mut self, mode: GameMode, won: bool, damage_dealt: f32, plays: Vec<(&str, u32)>) {
+        let record = match mode {
+            GameMode::Offline => &mut self.solo,
+            GameMode::Online => &mut self.online,
+        };
+        if won {
+            record.wins += 1;
+            self.current_streak += 1;
+            self.longest_streak = self.longest_streak.max(self.current_streak);
+        } else {
+            record.losses += 1;
+            self.current_streak = 0;
+        }
+        self.damage_dealt += damage_dealt;
+        for (name, count) in plays {
+            *self.card_plays.entry(name.to_string()).or_default() += count;
+        }
+        self.save();
+    }
+}
+
+fn record_victory(
+    mode: Res<GameMode>,
+    registry: Res<CardRegistry>,
+    player: Query<&PlayLog, With<LocalPlayer>>,
+    opponent: Query<&Health, With<Opponent>>,
+    stats: ResMut<ProfileStats>,
+) {
+    record_result(*mode, &registry, &player, &opponent, stats, true);
+}
+
+fn record_defeat(
+    mode: Res<GameMode>,
+    registry: Res<CardRegistry>,
+    player: Query<&PlayLog, With<LocalPlayer>>,
+    opponent: Query<&Health, With<Opponent>>,
+    stats: ResMut<ProfileStats>,
+) {
+    record_result(*mode, &registry, &player, &opponent, stats, false);
+}
+
+fn record_result(
+    mode: GameMode,
+    registry: &CardRegistry,
+    player: &Query<&PlayLog, With<LocalPlayer>>,
+    opponent: &Query<&Health, With<Opponent>>,
+    mut stats: ResMut<ProfileStats>,
+    won: bool,
+) {
+    // HP removed and the play log are both rollback-safe, unlike messages.
+    let damage_dealt = opponent
+        .single()
+        .map_or(0.0, |health| (health.max - health.current).max(0.0));
+    let plays = player.single().map_or_else(
+        |_| Vec::new(),
+        |log| {
+            log.counts()
+                .into_iter()
+                .filter_map(|(card, count)| {
+                    registry.get(card).map(|def| (def.name.as_str(), count))
+                })
+                .collect()
+        },
+    );
+    stats.record(mode, won, damage_dealt, plays);
+}
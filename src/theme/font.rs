@@ -0,0 +1,80 @@
+//! Fonts and the display language.
+//!
+//! UI text uses Bevy's built-in Latin font. No CJK font ships with the game,
+//! so Japanese text (the `Language::Japanese` card descriptions, the update
+//! notes) renders as boxes and the language has no settings entry.
+//!
+//! 3D text (card faces) has its own font database in [`TextRenderer`]. Its
+//! font streams in through the asset server and is handed over once loaded,
+//! so it isn't embedded in the binary (the web build in particular).
+//!
+//! The language is read from `language.txt` (`language=ja` for Japanese).
+
+use bevy::prelude::*;
+use bevy_rich_text3d::TextRenderer;
+
+use crate::persist;
+
+/// Latin font for 3D text; UI text uses Bevy's built-in font.
+const TEXT3D_FONT_PATH: &str = "fonts/FiraSans-Bold.ttf";
+const SAVE_KEY: &str = "language.txt";
+
+pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(Language::load());
+    app.add_systems(Startup, load_text3d_font);
+    app.add_systems(
+        Update,
+        hand_font_to_text3d.run_if(resource_exists::<PendingText3dFont>),
+    );
+}
+
+/// Language the UI is displayed in, read from `language.txt` at startup.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    #[default]
+    English,
+    Japanese,
+}
+
+impl Language {
+    fn load() -> Self {
+        let Some(contents) = persist::load(SAVE_KEY) else {
+            return Self::default();
+        };
+        contents
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .find(|(key, _)| *key == "language")
+            .map_or_else(Self::default, |(_, value)| match value {
+                "ja" => Self::Japanese,
+                _ => Self::English,
+            })
+    }
+}
+
+/// The 3D text font, until it has loaded and been handed to the renderer.
+#[derive(Resource)]
+struct PendingText3dFont(Handle<Font>);
+
+fn load_text3d_font(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(PendingText3dFont(asset_server.load(TEXT3D_FONT_PATH)));
+}
+
+fn hand_font_to_text3d(
+    mut commands: Commands,
+    pending: Res<PendingText3dFont>,
+    fonts: Res<Assets<Font>>,
+    asset_server: Res<AssetServer>,
+    renderer: Option<ResMut<TextRenderer>>,
+) {
+    let Some(mut renderer) = renderer else {
+        return;
+    };
+    if let Some(font) = fonts.get(&pending.0) {
+        renderer.lock().db_mut().load_font_data(font.data.to_vec());
+    } else if !asset_server.load_state(&pending.0).is_failed() {
+        return;
+    }
+    // A failed load is already logged by the asset server.
+    commands.remove_resource::<PendingText3dFont>();
+}
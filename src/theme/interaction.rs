@@ -1,12 +1,18 @@
 use bevy::prelude::*;
 
-use crate::{asset_tracking::LoadResource, audio::sound_effect};
+use crate::{
+    asset_tracking::LoadResource,
+    audio::{AudioBus, AudioMix},
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(Update, apply_interaction_palette);
 
     app.load_resource::<InteractionAssets>();
-    app.add_systems(Update, play_on_hover_sound_effect);
+    app.add_systems(
+        Update,
+        play_on_hover_sound_effect.run_if(|mix: Res<AudioMix>| mix.feedback),
+    );
     app.add_observer(play_on_click_sound_effect);
 }
 
@@ -67,7 +73,7 @@ fn play_on_hover_sound_effect(
 
     for interaction in &interaction_query {
         if *interaction == Interaction::Hovered {
-            commands.spawn(sound_effect(interaction_assets.hover.clone()));
+            commands.spawn(AudioBus::Ui.one_shot(interaction_assets.hover.clone()));
         }
     }
 }
@@ -77,12 +83,16 @@ fn play_on_click_sound_effect(
     mut commands: Commands,
     interaction_assets: Option<Res<InteractionAssets>>,
     interaction_query: Query<(), With<Interaction>>,
+    mix: Res<AudioMix>,
 ) {
     let Some(interaction_assets) = interaction_assets else {
         return;
     };
+    if !mix.feedback {
+        return;
+    }
 
     if interaction_query.contains(trigger.entity) {
-        commands.spawn(sound_effect(interaction_assets.click.clone()));
+        commands.spawn(AudioBus::Ui.one_shot(interaction_assets.click.clone()));
     }
 }
@@ -14,3 +14,13 @@ pub const BUTTON_BACKGROUND: Color = Color::srgb(0.275, 0.400, 0.750);
 pub const BUTTON_HOVERED_BACKGROUND: Color = Color::srgb(0.384, 0.600, 0.820);
 /// #3d4999
 pub const BUTTON_PRESSED_BACKGROUND: Color = Color::srgb(0.239, 0.286, 0.600);
+
+/// #000000b3
+pub const DIALOG_BACKDROP: Color = Color::srgba(0.0, 0.0, 0.0, 0.7);
+/// #1a1f33
+pub const DIALOG_BACKGROUND: Color = Color::srgb(0.102, 0.122, 0.200);
+
+/// #ffffff1a
+pub const SCROLLBAR_TRACK: Color = Color::srgba(1.0, 1.0, 1.0, 0.1);
+/// #6299d1
+pub const SCROLLBAR_THUMB: Color = Color::srgb(0.384, 0.600, 0.820);
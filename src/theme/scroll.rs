@@ -0,0 +1,206 @@
+//! Behavior for [`widget::scroll_list`](super::widget::scroll_list): the mouse
+//! wheel scrolls the list under the pointer, dragging scrolls the list or its
+//! scrollbar thumb, and the arrow, Page Up/Down, Home and End keys scroll every
+//! visible list.
+
+use bevy::{input::mouse::MouseScrollUnit, prelude::*};
+
+/// Pixels scrolled per mouse wheel line or arrow key press.
+const SCROLL_LINE_HEIGHT: f32 = 24.0;
+/// Share of the visible height scrolled by Page Up/Down.
+const PAGE_FRACTION: f32 = 0.9;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_observer(scroll_on_wheel);
+    app.add_observer(scroll_on_drag);
+    app.add_systems(
+        Update,
+        (scroll_on_keys, follow_end, update_scrollbars).chain(),
+    );
+}
+
+/// The clipped node whose children scroll.
+#[derive(Component, Debug)]
+pub struct ScrollList;
+
+/// Add to a [`ScrollList`] to keep its end in view as content is added, for
+/// as long as it is scrolled to the bottom.
+#[derive(Component, Debug)]
+pub struct FollowEnd {
+    /// Cleared by scrolling up, set again by scrolling back to the bottom.
+    pub following: bool,
+}
+
+impl Default for FollowEnd {
+    fn default() -> Self {
+        Self { following: true }
+    }
+}
+
+/// The scrollbar thumb of a [`ScrollList`], sized and moved to match it.
+#[derive(Component, Debug)]
+pub struct ScrollbarThumb {
+    pub list: Entity,
+}
+
+/// Scroll `position` by `delta` logical pixels, staying within the content.
+fn scroll_by(
+    position: &mut ScrollPosition,
+    node: &ComputedNode,
+    follow: Option<Mut<FollowEnd>>,
+    delta: f32,
+) {
+    let max = max_scroll(node);
+    position.0.y = (position.0.y + delta).clamp(0.0, max);
+    if let Some(mut follow) = follow {
+        follow.following = position.0.y >= max;
+    }
+}
+
+/// How far a list can scroll, in logical pixels.
+fn max_scroll(node: &ComputedNode) -> f32 {
+    (node.content_size.y - node.size.y).max(0.0) * node.inverse_scale_factor
+}
+
+/// The thumb's top and height as fractions of the track, or `None` when the
+/// content fits and there is nothing to scroll.
+fn thumb_span(visible: f32, content: f32, offset: f32) -> Option<(f32, f32)> {
+    if content <= visible || content <= 0.0 {
+        return None;
+    }
+    let height = visible / content;
+    let top = (offset / content).clamp(0.0, 1.0 - height);
+    Some((top, height))
+}
+
+fn scroll_on_wheel(
+    mut scroll: On<Pointer<Scroll>>,
+    mut lists: Query<
+        (&mut ScrollPosition, &ComputedNode, Option<&mut FollowEnd>),
+        With<ScrollList>,
+    >,
+) {
+    let Ok((mut position, node, follow)) = lists.get_mut(scroll.entity) else {
+        return;
+    };
+    let delta = match scroll.unit {
+        MouseScrollUnit::Line => scroll.y * SCROLL_LINE_HEIGHT,
+        MouseScrollUnit::Pixel => scroll.y,
+    };
+    scroll_by(&mut position, node, follow, -delta);
+    scroll.propagate(false);
+}
+
+/// Dragging a list moves its content with the pointer; dragging a thumb moves
+/// the thumb with the pointer.
+fn scroll_on_drag(
+    mut drag: On<Pointer<Drag>>,
+    thumbs: Query<&ScrollbarThumb>,
+    mut lists: Query<
+        (&mut ScrollPosition, &ComputedNode, Option<&mut FollowEnd>),
+        With<ScrollList>,
+    >,
+) {
+    if let Ok(thumb) = thumbs.get(drag.entity) {
+        if let Ok((mut position, node, follow)) = lists.get_mut(thumb.list) {
+            let visible = node.size.y.max(1.0);
+            let delta = drag.delta.y * node.content_size.y / visible;
+            scroll_by(&mut position, node, follow, delta);
+        }
+        drag.propagate(false);
+    } else if let Ok((mut position, node, follow)) = lists.get_mut(drag.entity) {
+        scroll_by(&mut position, node, follow, -drag.delta.y);
+        drag.propagate(false);
+    }
+}
+
+fn scroll_on_keys(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut lists: Query<
+        (
+            &mut ScrollPosition,
+            &ComputedNode,
+            &InheritedVisibility,
+            Option<&mut FollowEnd>,
+        ),
+        With<ScrollList>,
+    >,
+) {
+    for (mut position, node, visibility, follow) in &mut lists {
+        if !visibility.get() {
+            continue;
+        }
+        let page = node.size.y * node.inverse_scale_factor * PAGE_FRACTION;
+        let delta = if keyboard.just_pressed(KeyCode::Home) {
+            f32::NEG_INFINITY
+        } else if keyboard.just_pressed(KeyCode::End) {
+            f32::INFINITY
+        } else if keyboard.just_pressed(KeyCode::PageUp) {
+            -page
+        } else if keyboard.just_pressed(KeyCode::PageDown) {
+            page
+        } else if keyboard.just_pressed(KeyCode::ArrowUp) {
+            -SCROLL_LINE_HEIGHT
+        } else if keyboard.just_pressed(KeyCode::ArrowDown) {
+            SCROLL_LINE_HEIGHT
+        } else {
+            continue;
+        };
+        scroll_by(&mut position, node, follow, delta);
+    }
+}
+
+fn follow_end(mut lists: Query<(&mut ScrollPosition, &ComputedNode, &FollowEnd)>) {
+    for (mut position, node, follow) in &mut lists {
+        let max = max_scroll(node);
+        if follow.following && position.0.y != max {
+            position.0.y = max;
+        }
+    }
+}
+
+fn update_scrollbars(
+    lists: Query<(&ScrollPosition, &ComputedNode), With<ScrollList>>,
+    mut thumbs: Query<(&ScrollbarThumb, &mut Node, &ChildOf)>,
+    mut tracks: Query<&mut Visibility>,
+) {
+    for (thumb, mut node, track) in &mut thumbs {
+        let Ok((position, list)) = lists.get(thumb.list) else {
+            continue;
+        };
+        let span = thumb_span(
+            list.size.y * list.inverse_scale_factor,
+            list.content_size.y * list.inverse_scale_factor,
+            position.0.y,
+        );
+        if let Ok(mut visibility) = tracks.get_mut(track.parent()) {
+            visibility.set_if_neq(if span.is_some() {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            });
+        }
+        let Some((top, height)) = span else {
+            continue;
+        };
+        let (top, height) = (percent(top * 100.0), percent(height * 100.0));
+        if node.top != top || node.height != height {
+            node.top = top;
+            node.height = height;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thumb_tracks_the_visible_part_of_the_content() {
+        assert_eq!(thumb_span(200.0, 200.0, 0.0), None);
+        assert_eq!(thumb_span(200.0, 800.0, 0.0), Some((0.0, 0.25)));
+        assert_eq!(thumb_span(200.0, 800.0, 600.0), Some((0.75, 0.25)));
+        // Overscrolled positions keep the thumb inside the track.
+        assert_eq!(thumb_span(200.0, 800.0, 900.0), Some((0.75, 0.25)));
+    }
+}
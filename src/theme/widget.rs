@@ -7,7 +7,11 @@ use bevy::{
     prelude::*,
 };
 
-use crate::theme::{interaction::InteractionPalette, palette::*};
+use crate::theme::{
+    interaction::InteractionPalette,
+    palette::*,
+    scroll::{ScrollList, ScrollbarThumb},
+};
 
 /// A root UI node that fills the window and centers its content.
 pub fn ui_root(name: impl Into<Cow<'static, str>>) -> impl Bundle {
@@ -58,6 +62,7 @@ where
     button_base(
         text,
         action,
+        40.0,
         Node {
             width: px(380),
             height: px(80),
@@ -69,6 +74,29 @@ where
     )
 }
 
+/// A compact button for dense panels, with text and an action defined as an [`Observer`].
+pub fn button_medium<E, B, M, I>(text: impl Into<String>, action: I) -> impl Bundle
+where
+    E: EntityEvent,
+    B: Bundle,
+    I: IntoObserverSystem<E, B, M>,
+{
+    button_base(
+        text,
+        action,
+        20.0,
+        Node {
+            min_width: px(200),
+            height: px(36),
+            padding: UiRect::horizontal(px(12)),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            border_radius: BorderRadius::all(px(8)),
+            ..default()
+        },
+    )
+}
+
 /// A small square button with text and an action defined as an [`Observer`].
 pub fn button_small<E, B, M, I>(text: impl Into<String>, action: I) -> impl Bundle
 where
@@ -79,6 +107,7 @@ where
     button_base(
         text,
         action,
+        40.0,
         Node {
             width: px(30),
             height: px(30),
@@ -89,10 +118,162 @@ where
     )
 }
 
+/// A clipped list that scrolls vertically, with a scrollbar that shows while
+/// the items don't fit. `frame` sizes the whole widget, `row_gap` spaces the
+/// items, and `list` goes on the list node itself: a marker to find it by, or
+/// its items as `Children`. See [`super::scroll`] for the controls.
+pub fn scroll_list(frame: Node, row_gap: Val, list: impl Bundle) -> impl Bundle {
+    (
+        Name::new("Scroll List"),
+        Node {
+            flex_direction: FlexDirection::Row,
+            column_gap: px(6),
+            ..frame
+        },
+        Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+            let list = parent
+                .spawn((
+                    Name::new("Scroll List Items"),
+                    ScrollList,
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        flex_grow: 1.0,
+                        row_gap,
+                        overflow: Overflow::scroll_y(),
+                        ..default()
+                    },
+                    ScrollPosition::default(),
+                    // Picked for wheel and drag scrolling.
+                    Pickable::default(),
+                    list,
+                ))
+                .id();
+            parent
+                .spawn((
+                    Name::new("Scrollbar"),
+                    Node {
+                        width: px(6),
+                        border_radius: BorderRadius::MAX,
+                        ..default()
+                    },
+                    BackgroundColor(SCROLLBAR_TRACK),
+                    Visibility::Hidden,
+                ))
+                .with_child((
+                    Name::new("Scrollbar Thumb"),
+                    ScrollbarThumb { list },
+                    Node {
+                        position_type: PositionType::Absolute,
+                        width: percent(100),
+                        border_radius: BorderRadius::MAX,
+                        ..default()
+                    },
+                    BackgroundColor(SCROLLBAR_THUMB),
+                    Pickable::default(),
+                ));
+        })),
+    )
+}
+
+/// A modal dialog asking the player to confirm an action defined as an
+/// [`Observer`]. Either button closes the dialog, and nothing underneath can be
+/// clicked while it is open.
+pub fn confirm_dialog<E, B, M, I>(
+    title: impl Into<String>,
+    message: impl Into<String>,
+    confirm: I,
+) -> impl Bundle
+where
+    E: EntityEvent,
+    B: Bundle,
+    I: IntoObserverSystem<E, B, M>,
+{
+    confirm_dialog_with_cancel(title, message, confirm, |_: On<Pointer<Click>>| {})
+}
+
+/// A [`confirm_dialog`] that also runs a `cancel` action when dismissed.
+pub fn confirm_dialog_with_cancel<E1, B1, M1, I1, E2, B2, M2, I2>(
+    title: impl Into<String>,
+    message: impl Into<String>,
+    confirm: I1,
+    cancel: I2,
+) -> impl Bundle
+where
+    E1: EntityEvent,
+    B1: Bundle,
+    I1: IntoObserverSystem<E1, B1, M1>,
+    E2: EntityEvent,
+    B2: Bundle,
+    I2: IntoObserverSystem<E2, B2, M2>,
+{
+    let title = title.into();
+    let message = message.into();
+    let confirm = IntoObserverSystem::into_system(confirm);
+    let cancel = IntoObserverSystem::into_system(cancel);
+    (
+        Name::new("Confirm Dialog"),
+        Node {
+            position_type: PositionType::Absolute,
+            width: percent(100),
+            height: percent(100),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        BackgroundColor(DIALOG_BACKDROP),
+        // Catches clicks meant for whatever is underneath.
+        Pickable::default(),
+        GlobalZIndex(20),
+        Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+            let dialog = parent.target_entity();
+            parent
+                .spawn((
+                    Name::new("Dialog Panel"),
+                    Node {
+                        max_width: px(640),
+                        padding: UiRect::all(px(24)),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: px(16),
+                        border_radius: BorderRadius::all(px(12)),
+                        ..default()
+                    },
+                    BackgroundColor(DIALOG_BACKGROUND),
+                ))
+                .with_children(|panel| {
+                    panel.spawn(header(title));
+                    panel.spawn((
+                        label(message),
+                        TextLayout::new_with_justify(Justify::Center),
+                    ));
+                    panel
+                        .spawn(Node {
+                            column_gap: px(16),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            row.spawn(button_medium("Confirm", confirm))
+                                .observe(close_dialog(dialog));
+                            row.spawn(button_medium("Cancel", cancel))
+                                .observe(close_dialog(dialog));
+                        });
+                });
+        })),
+    )
+}
+
+/// Clicks on a dialog button bubble up to here once its action has run.
+fn close_dialog(dialog: Entity) -> impl Fn(On<Pointer<Click>>, Commands) {
+    move |_, mut commands| {
+        commands.entity(dialog).try_despawn();
+    }
+}
+
 /// A simple button with text and an action defined as an [`Observer`]. The button's layout is provided by `button_bundle`.
 fn button_base<E, B, M, I>(
     text: impl Into<String>,
     action: I,
+    font_size: f32,
     button_bundle: impl Bundle,
 ) -> impl Bundle
 where
@@ -105,7 +286,7 @@ where
     (
         Name::new("Button"),
         Node::default(),
-        Children::spawn(SpawnWith(|parent: &mut ChildSpawner| {
+        Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
             parent
                 .spawn((
                     Name::new("Button Inner"),
@@ -120,7 +301,7 @@ where
                     children![(
                         Name::new("Button Text"),
                         Text(text),
-                        TextFont::from_font_size(40.0),
+                        TextFont::from_font_size(font_size),
                         TextColor(BUTTON_TEXT),
                         // Don't bubble picking events from the text up to the button.
                         Pickable::IGNORE,
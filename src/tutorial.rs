@@ -0,0 +1,348 @@
+//! Guided first match that teaches the basics with step-by-step popups.
+//!
+//! The tutorial is a Solo match with a fixed seed, a fixed class and deck, and
+//! an opponent that follows a short script instead of standing still. Popups
+//! explain cost, drawing, playing cards, block decay and statuses; some wait
+//! for a click, others for the player to try the action themselves.
+//!
+//! It starts automatically on the first launch and stays on the main menu
+//! afterwards. Leaving the tutorial match, finished or not, marks it completed.
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+
+use crate::{
+    AppSystems, PausableSystems,
+    asset_tracking::ResourceHandles,
+    game::{
+        CardRegistry, CardType, CharacterClass, Cost, DrawCardsMessage, GameResult, Hand,
+        LocalPlayer, Opponent, PlayCardMessage, Rules,
+    },
+    persist,
+    screens::Screen,
+};
+
+const SAVE_KEY: &str = "tutorial.txt";
+/// Seed for the tutorial match, so both hands are the same every time.
+pub const TUTORIAL_SEED: u64 = 0x5e45_e400_0000_7001;
+/// Seconds between opponent plays once the opening script is used up.
+const OPPONENT_PLAY_INTERVAL_SECS: f32 = 7.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(Tutorial::load());
+    app.init_resource::<TutorialProgress>();
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        (reset_progress, spawn_tutorial_popup).run_if(is_tutorial),
+    );
+    app.add_systems(
+        OnExit(Screen::Gameplay),
+        finish_tutorial.run_if(is_tutorial),
+    );
+    app.add_systems(
+        Update,
+        (
+            advance_on_action,
+            advance_on_click.run_if(input_just_pressed(KeyCode::Enter)),
+            update_tutorial_popup,
+        )
+            .chain()
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay).and(is_tutorial)),
+    );
+    app.add_systems(
+        Update,
+        run_opponent_script
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems)
+            .run_if(
+                in_state(Screen::Gameplay)
+                    .and(in_state(GameResult::Playing))
+                    .and(is_tutorial),
+            ),
+    );
+}
+
+/// Whether the tutorial has been completed, and whether the current match is it.
+#[derive(Resource, Debug, Default)]
+pub struct Tutorial {
+    /// The match being played (or about to start) is the tutorial.
+    pub active: bool,
+    pub completed: bool,
+}
+
+impl Tutorial {
+    fn load() -> Self {
+        Self {
+            active: false,
+            completed: persist::load(SAVE_KEY)
+                .is_some_and(|contents| contents.trim() == "completed"),
+        }
+    }
+}
+
+pub fn is_tutorial(tutorial: Res<Tutorial>) -> bool {
+    tutorial.active
+}
+
+/// Set up and enter the tutorial match.
+pub fn start_tutorial(
+    tutorial: &mut Tutorial,
+    class: &mut CharacterClass,
+    resource_handles: &ResourceHandles,
+    next_screen: &mut NextState<Screen>,
+) {
+    tutorial.active = true;
+    *class = CharacterClass::Ironclad;
+    next_screen.set(if resource_handles.is_all_done() {
+        Screen::Gameplay
+    } else {
+        Screen::Loading
+    });
+}
+
+fn finish_tutorial(mut tutorial: ResMut<Tutorial>) {
+    tutorial.active = false;
+    if !tutorial.completed {
+        tutorial.completed = true;
+        persist::store(SAVE_KEY, "completed");
+    }
+}
+
+/// What moves a popup on to the next one.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Advance {
+    /// Click the popup or press Enter.
+    Click,
+    /// Draw cards.
+    Draw,
+    /// Play a card.
+    Play,
+}
+
+const STEPS: &[(&str, Advance)] = &[
+    (
+        "Welcome to Sensen! There are no turns here: both players act at the same time.",
+        Advance::Click,
+    ),
+    (
+        "Cost: the bar under your HP fills by 1 every second, and every card spends some of it. \
+         Cost above the cap burns off, so don't sit on it.",
+        Advance::Click,
+    ),
+    (
+        "Drawing: press D to draw. Drawing costs 1 for every card already in your hand, \
+         so an empty hand draws for free. Draw now.",
+        Advance::Draw,
+    ),
+    (
+        "Playing cards: click a card or press its number key once you can afford it. \
+         Affordable cards glow green. Play a card now.",
+        Advance::Play,
+    ),
+    (
+        "Block: Defend gives Block, which absorbs damage before your HP. \
+         Block decays every second, so raise it just before a hit lands.",
+        Advance::Click,
+    ),
+    (
+        "Statuses: the icons under each HP bar are statuses like Vulnerable and Weak. \
+         Hover an icon, or a card, for details.",
+        Advance::Click,
+    ),
+    (
+        "That's everything. Defeat the opponent to finish the tutorial!",
+        Advance::Click,
+    ),
+];
+
+/// Steps in the opponent's opening script, by seconds since the match started.
+const OPPONENT_SCRIPT: &[(f32, OpponentAction)] = &[
+    (10.0, OpponentAction::Play(Some(CardType::Attack))),
+    (16.0, OpponentAction::Draw),
+    (22.0, OpponentAction::Play(Some(CardType::Skill))),
+    (28.0, OpponentAction::Play(Some(CardType::Attack))),
+];
+
+#[derive(Clone, Copy)]
+enum OpponentAction {
+    /// Play the first card of a type (or any card if the hand has none of
+    /// that type), waiting until it is affordable.
+    Play(Option<CardType>),
+    Draw,
+}
+
+#[derive(Resource, Default)]
+struct TutorialProgress {
+    step: usize,
+    /// Seconds of unpaused play since the match started.
+    elapsed: f32,
+    /// Next entry of [`OPPONENT_SCRIPT`] to run.
+    script_index: usize,
+    /// When the opponent last acted after the script ran out.
+    last_free_play: f32,
+}
+
+#[derive(Component)]
+struct TutorialPopup;
+
+fn reset_progress(mut progress: ResMut<TutorialProgress>) {
+    *progress = TutorialProgress::default();
+}
+
+fn spawn_tutorial_popup(mut commands: Commands) {
+    commands
+        .spawn((
+            Name::new("Tutorial Popup"),
+            TutorialPopup,
+            Node {
+                position_type: PositionType::Absolute,
+                top: px(90),
+                left: percent(25),
+                width: percent(50),
+                padding: UiRect::all(px(16)),
+                ..default()
+            },
+            Text::new(""),
+            TextFont::from_font_size(22.0),
+            TextColor(Color::WHITE),
+            BackgroundColor(Color::srgba(0.05, 0.08, 0.15, 0.92)),
+            GlobalZIndex(20),
+            DespawnOnExit(Screen::Gameplay),
+        ))
+        .observe(advance_popup_on_click);
+}
+
+fn advance_popup_on_click(_: On<Pointer<Click>>, progress: ResMut<TutorialProgress>) {
+    advance_clicked_step(progress);
+}
+
+fn advance_on_click(progress: ResMut<TutorialProgress>) {
+    advance_clicked_step(progress);
+}
+
+fn advance_clicked_step(mut progress: ResMut<TutorialProgress>) {
+    if STEPS
+        .get(progress.step)
+        .is_some_and(|(_, advance)| *advance == Advance::Click)
+    {
+        progress.step += 1;
+    }
+}
+
+fn advance_on_action(
+    mut progress: ResMut<TutorialProgress>,
+    mut draws: MessageReader<DrawCardsMessage>,
+    mut plays: MessageReader<PlayCardMessage>,
+    player: Query<Entity, With<LocalPlayer>>,
+) {
+    let Ok(player) = player.single() else {
+        return;
+    };
+    // Count rather than `any` so every message is consumed this frame.
+    let drew = draws.read().filter(|msg| msg.player == player).count() > 0;
+    let played = plays.read().filter(|msg| msg.player == player).count() > 0;
+    let done = match STEPS.get(progress.step) {
+        Some((_, Advance::Draw)) => drew,
+        Some((_, Advance::Play)) => played,
+        _ => false,
+    };
+    if done {
+        progress.step += 1;
+    }
+}
+
+fn update_tutorial_popup(
+    progress: Res<TutorialProgress>,
+    mut shown_step: Local<usize>,
+    mut popup: Query<(Ref<TutorialPopup>, &mut Text, &mut Visibility)>,
+) {
+    for (marker, mut text, mut visibility) in &mut popup {
+        // The popup is respawned every match, so a new one is always filled in.
+        if *shown_step == progress.step && !marker.is_added() {
+            continue;
+        }
+        let Some((message, advance)) = STEPS.get(progress.step) else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+        let hint = match advance {
+            Advance::Click => "\n\n(click or press Enter)",
+            Advance::Draw | Advance::Play => "",
+        };
+        text.0 = format!("{message}{hint}");
+        *visibility = Visibility::Inherited;
+    }
+    *shown_step = progress.step;
+}
+
+fn run_opponent_script(
+    time: Res<Time>,
+    mut progress: ResMut<TutorialProgress>,
+    registry: Res<CardRegistry>,
+    rules: Res<Rules>,
+    mut opponent: Query<(Entity, &Hand, &mut Cost), With<Opponent>>,
+    mut play_messages: MessageWriter<PlayCardMessage>,
+    mut draw_messages: MessageWriter<DrawCardsMessage>,
+) {
+    progress.elapsed += time.delta_secs();
+    let Ok((entity, hand, mut cost)) = opponent.single_mut() else {
+        return;
+    };
+
+    let action = match OPPONENT_SCRIPT.get(progress.script_index) {
+        Some((at, action)) if progress.elapsed >= *at => *action,
+        Some(_) => return,
+        // Script used up: keep playing whatever it can at a steady pace.
+        None if progress.elapsed - progress.last_free_play >= OPPONENT_PLAY_INTERVAL_SECS => {
+            if hand.is_empty() {
+                OpponentAction::Draw
+            } else {
+                OpponentAction::Play(None)
+            }
+        }
+        None => return,
+    };
+
+    let acted = match action {
+        OpponentAction::Draw => {
+            let acted = cost.try_spend(rules.draw_cost(hand.len()));
+            if acted {
+                draw_messages.write(DrawCardsMessage {
+                    player: entity,
+                    count: rules.draw_count,
+                });
+            }
+            acted
+        }
+        OpponentAction::Play(card_type) => {
+            let cards: Vec<_> = hand
+                .cards
+                .iter()
+                .enumerate()
+                .filter_map(|(index, card)| Some((index, registry.get(*card)?)))
+                .filter(|(_, def)| def.is_playable())
+                .collect();
+            let chosen = cards
+                .iter()
+                .find(|(_, def)| card_type.is_none_or(|card_type| def.card_type == card_type))
+                .or_else(|| cards.first());
+            match chosen {
+                Some((hand_index, def)) if cost.try_spend(def.cost) => {
+                    play_messages.write(PlayCardMessage {
+                        player: entity,
+                        hand_index: *hand_index,
+                    });
+                    true
+                }
+                _ => false,
+            }
+        }
+    };
+    // Unaffordable steps wait for more cost rather than being skipped.
+    if acted {
+        if progress.script_index < OPPONENT_SCRIPT.len() {
+            progress.script_index += 1;
+        }
+        progress.last_free_play = progress.elapsed;
+    }
+}
@@ -227,6 +227,10 @@ async fn message_loop<M: Messenger>(
                             };
                         },
                         PeerEvent::NewPeer(peer_uuid) => {
+                            if peer_state_tx.unbounded_send((peer_uuid, PeerState::Connecting)).is_err() {
+                                // socket dropped, exit cleanly
+                                break Ok(());
+                            }
                             let (signal_tx, signal_rx) = futures_channel::mpsc::unbounded();
                             handshake_signals.insert(peer_uuid, signal_tx);
                             let signal_peer = SignalPeer::new(peer_uuid, requests_sender.clone());
@@ -240,6 +244,8 @@ async fn message_loop<M: Messenger>(
                         },
                         PeerEvent::Signal { sender, data } => {
                             let signal_tx = handshake_signals.entry(sender).or_insert_with(|| {
+                                // Only fails on socket drop, which the next send notices.
+                                let _ = peer_state_tx.unbounded_send((sender, PeerState::Connecting));
                                 let (from_peer_tx, peer_signal_rx) = futures_channel::mpsc::unbounded();
                                 let signal_peer = SignalPeer::new(sender, requests_sender.clone());
                                 handshakes.push(M::accept_handshake(signal_peer, peer_signal_rx, messages_from_peers_tx.clone(), ice_server_config, channel_configs));
@@ -262,6 +262,9 @@ impl WebRtcSocketBuilder {
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 /// The state of a connection to a peer
 pub enum PeerState {
+    /// The handshake with this peer has started but its data channels aren't
+    /// open yet. A peer that stays here usually means ICE couldn't find a route.
+    Connecting,
     /// The peer is connected
     ///
     /// This means all of the following should be true:
@@ -592,6 +595,20 @@ impl WebRtcSocket {
         })
     }
 
+    /// Returns an iterator of the ids of peers still in the handshake.
+    ///
+    /// Note: You have to call [`WebRtcSocket::update_peers`] for this list to be
+    /// accurate.
+    pub fn connecting_peers(&self) -> impl std::iter::Iterator<Item = &PeerId> {
+        self.peers.iter().filter_map(|(id, state)| {
+            if state == &PeerState::Connecting {
+                Some(id)
+            } else {
+                None
+            }
+        })
+    }
+
     /// Returns an iterator of the ids of peers that are no longer connected.
     ///
     /// Note: You have to call [`WebRtcSocket::update_peers`] for this list to be